@@ -0,0 +1,1441 @@
+//! `BT_CONTROLLER_INIT_CONFIG_DEFAULT()` of ESP-IDF v5.5.1, generated by `cargo xtask bt-config`
+
+pub const IDF_VERSION: &str = "ESP-IDF v5.5.1";
+
+/// A field of the default controller configuration
+#[derive(Debug, Clone, Copy)]
+pub struct Field {
+    pub name: &'static str,
+    /// The initializer in `esp_bt.h`
+    pub expression: &'static str,
+    /// `None` if only known at runtime, like the task priority or the chip revision
+    pub value: Option<i64>,
+    /// The macros the initializer expands through, outermost first
+    pub macros: &'static [&'static str],
+}
+
+pub mod esp32 {
+    use super::Field;
+
+    /// The fields of `esp_bt_controller_config_t` in `include/esp32/esp_bt.h` order
+    pub const BT_CONTROLLER_INIT_CONFIG_DEFAULT: &[Field] = &[
+        Field {
+            name: "controller_task_stack_size",
+            expression: "ESP_TASK_BT_CONTROLLER_STACK",
+            value: Some(4096),
+            macros: &["ESP_TASK_BT_CONTROLLER_STACK", "TASK_EXTRA_STACK_SIZE"],
+        },
+        Field {
+            name: "controller_task_prio",
+            expression: "ESP_TASK_BT_CONTROLLER_PRIO",
+            value: None,
+            macros: &["ESP_TASK_BT_CONTROLLER_PRIO", "ESP_TASK_PRIO_MAX"],
+        },
+        Field {
+            name: "hci_uart_no",
+            expression: "BT_HCI_UART_NO_DEFAULT",
+            value: Some(1),
+            macros: &["BT_HCI_UART_NO_DEFAULT"],
+        },
+        Field {
+            name: "hci_uart_baudrate",
+            expression: "BT_HCI_UART_BAUDRATE_DEFAULT",
+            value: Some(921600),
+            macros: &["BT_HCI_UART_BAUDRATE_DEFAULT"],
+        },
+        Field {
+            name: "scan_duplicate_mode",
+            expression: "SCAN_DUPLICATE_MODE",
+            value: Some(0),
+            macros: &["SCAN_DUPLICATE_MODE", "SCAN_DUPLICATE_MODE_NORMAL_ADV_ONLY"],
+        },
+        Field {
+            name: "scan_duplicate_type",
+            expression: "SCAN_DUPLICATE_TYPE_VALUE",
+            value: Some(0),
+            macros: &["SCAN_DUPLICATE_TYPE_VALUE", "CONFIG_BTDM_SCAN_DUPL_TYPE"],
+        },
+        Field {
+            name: "normal_adv_size",
+            expression: "NORMAL_SCAN_DUPLICATE_CACHE_SIZE",
+            value: Some(100),
+            macros: &["NORMAL_SCAN_DUPLICATE_CACHE_SIZE", "CONFIG_BTDM_SCAN_DUPL_CACHE_SIZE"],
+        },
+        Field {
+            name: "mesh_adv_size",
+            expression: "MESH_DUPLICATE_SCAN_CACHE_SIZE",
+            value: Some(0),
+            macros: &["MESH_DUPLICATE_SCAN_CACHE_SIZE"],
+        },
+        Field {
+            name: "send_adv_reserved_size",
+            expression: "SCAN_SEND_ADV_RESERVED_SIZE",
+            value: Some(1000),
+            macros: &["SCAN_SEND_ADV_RESERVED_SIZE"],
+        },
+        Field {
+            name: "controller_debug_flag",
+            expression: "BTDM_CTRL_CONTROLLER_DEBUG_FLAG",
+            value: Some(0),
+            macros: &["BTDM_CTRL_CONTROLLER_DEBUG_FLAG", "BTDM_CTRL_CONTROLLER_DEBUG_MODE_1", "CONTROLLER_ADV_LOST_DEBUG_BIT"],
+        },
+        Field {
+            name: "mode",
+            expression: "BTDM_CONTROLLER_MODE_EFF",
+            value: Some(1),
+            macros: &["BTDM_CONTROLLER_MODE_EFF", "ESP_BT_MODE_BLE"],
+        },
+        Field {
+            name: "ble_max_conn",
+            expression: "CONFIG_BTDM_CTRL_BLE_MAX_CONN_EFF",
+            value: Some(3),
+            macros: &["CONFIG_BTDM_CTRL_BLE_MAX_CONN_EFF"],
+        },
+        Field {
+            name: "bt_max_acl_conn",
+            expression: "CONFIG_BTDM_CTRL_BR_EDR_MAX_ACL_CONN_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BTDM_CTRL_BR_EDR_MAX_ACL_CONN_EFF"],
+        },
+        Field {
+            name: "bt_sco_datapath",
+            expression: "CONFIG_BTDM_CTRL_BR_EDR_SCO_DATA_PATH_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BTDM_CTRL_BR_EDR_SCO_DATA_PATH_EFF"],
+        },
+        Field {
+            name: "auto_latency",
+            expression: "BTDM_CTRL_AUTO_LATENCY_EFF",
+            value: Some(0),
+            macros: &["BTDM_CTRL_AUTO_LATENCY_EFF"],
+        },
+        Field {
+            name: "bt_legacy_auth_vs_evt",
+            expression: "BTDM_CTRL_LEGACY_AUTH_VENDOR_EVT_EFF",
+            value: Some(0),
+            macros: &["BTDM_CTRL_LEGACY_AUTH_VENDOR_EVT_EFF"],
+        },
+        Field {
+            name: "bt_max_sync_conn",
+            expression: "CONFIG_BTDM_CTRL_BR_EDR_MAX_SYNC_CONN_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BTDM_CTRL_BR_EDR_MAX_SYNC_CONN_EFF"],
+        },
+        Field {
+            name: "ble_sca",
+            expression: "CONFIG_BTDM_BLE_SLEEP_CLOCK_ACCURACY_INDEX_EFF",
+            value: Some(1),
+            macros: &["CONFIG_BTDM_BLE_SLEEP_CLOCK_ACCURACY_INDEX_EFF"],
+        },
+        Field {
+            name: "pcm_role",
+            expression: "CONFIG_BTDM_CTRL_PCM_ROLE_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BTDM_CTRL_PCM_ROLE_EFF"],
+        },
+        Field {
+            name: "pcm_polar",
+            expression: "CONFIG_BTDM_CTRL_PCM_POLAR_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BTDM_CTRL_PCM_POLAR_EFF"],
+        },
+        Field {
+            name: "pcm_fsyncshp",
+            expression: "CONFIG_BTDM_CTRL_PCM_FSYNCSHP_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BTDM_CTRL_PCM_FSYNCSHP_EFF"],
+        },
+        Field {
+            name: "hli",
+            expression: "BTDM_CTRL_HLI",
+            value: Some(1),
+            macros: &["BTDM_CTRL_HLI", "CONFIG_BTDM_CTRL_HLI"],
+        },
+        Field {
+            name: "enc_key_sz_min",
+            expression: "CONFIG_BTDM_CTRL_BR_EDR_MIN_ENC_KEY_SZ_DFT_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BTDM_CTRL_BR_EDR_MIN_ENC_KEY_SZ_DFT_EFF"],
+        },
+        Field {
+            name: "dup_list_refresh_period",
+            expression: "SCAN_DUPL_CACHE_REFRESH_PERIOD",
+            value: Some(0),
+            macros: &["SCAN_DUPL_CACHE_REFRESH_PERIOD", "CONFIG_BTDM_SCAN_DUPL_CACHE_REFRESH_PERIOD"],
+        },
+        Field {
+            name: "ble_scan_backoff",
+            expression: "BTDM_CTRL_SCAN_BACKOFF_UPPERLIMITMAX",
+            value: Some(0),
+            macros: &["BTDM_CTRL_SCAN_BACKOFF_UPPERLIMITMAX"],
+        },
+        Field {
+            name: "ble_llcp_disc_flag",
+            expression: "BTDM_BLE_LLCP_DISC_FLAG",
+            value: Some(0),
+            macros: &["BTDM_BLE_LLCP_DISC_FLAG", "BTDM_BLE_LLCP_CONN_UPDATE", "BTDM_BLE_LLCP_CHAN_MAP_UPDATE"],
+        },
+        Field {
+            name: "ble_aa_check",
+            expression: "BTDM_CTRL_CHECK_CONNECT_IND_ACCESS_ADDRESS_ENABLED",
+            value: Some(0),
+            macros: &["BTDM_CTRL_CHECK_CONNECT_IND_ACCESS_ADDRESS_ENABLED"],
+        },
+        Field {
+            name: "ble_chan_ass_en",
+            expression: "BTDM_BLE_CHAN_ASS_EN",
+            value: Some(1),
+            macros: &["BTDM_BLE_CHAN_ASS_EN", "CONFIG_BTDM_BLE_CHAN_ASS_EN"],
+        },
+        Field {
+            name: "ble_ping_en",
+            expression: "BTDM_BLE_PING_EN",
+            value: Some(1),
+            macros: &["BTDM_BLE_PING_EN", "CONFIG_BTDM_BLE_PING_EN"],
+        },
+        Field {
+            name: "magic",
+            expression: "ESP_BT_CONTROLLER_CONFIG_MAGIC_VAL",
+            value: Some(539296536),
+            macros: &["ESP_BT_CONTROLLER_CONFIG_MAGIC_VAL"],
+        },
+    ];
+}
+
+pub mod esp32s3 {
+    use super::Field;
+
+    /// The fields of `esp_bt_controller_config_t` in `include/esp32s3/esp_bt.h` order
+    pub const BT_CONTROLLER_INIT_CONFIG_DEFAULT: &[Field] = &[
+        Field {
+            name: "magic",
+            expression: "ESP_BT_CTRL_CONFIG_MAGIC_VAL",
+            value: Some(1515890085),
+            macros: &["ESP_BT_CTRL_CONFIG_MAGIC_VAL"],
+        },
+        Field {
+            name: "version",
+            expression: "ESP_BT_CTRL_CONFIG_VERSION",
+            value: Some(38817920),
+            macros: &["ESP_BT_CTRL_CONFIG_VERSION"],
+        },
+        Field {
+            name: "controller_task_stack_size",
+            expression: "ESP_TASK_BT_CONTROLLER_STACK",
+            value: Some(4096),
+            macros: &["ESP_TASK_BT_CONTROLLER_STACK", "TASK_EXTRA_STACK_SIZE"],
+        },
+        Field {
+            name: "controller_task_prio",
+            expression: "ESP_TASK_BT_CONTROLLER_PRIO",
+            value: None,
+            macros: &["ESP_TASK_BT_CONTROLLER_PRIO", "ESP_TASK_PRIO_MAX"],
+        },
+        Field {
+            name: "controller_task_run_cpu",
+            expression: "CONFIG_BT_CTRL_PINNED_TO_CORE",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_PINNED_TO_CORE"],
+        },
+        Field {
+            name: "bluetooth_mode",
+            expression: "CONFIG_BT_CTRL_MODE_EFF",
+            value: Some(1),
+            macros: &["CONFIG_BT_CTRL_MODE_EFF"],
+        },
+        Field {
+            name: "ble_max_act",
+            expression: "CONFIG_BT_CTRL_BLE_MAX_ACT_EFF",
+            value: Some(6),
+            macros: &["CONFIG_BT_CTRL_BLE_MAX_ACT_EFF"],
+        },
+        Field {
+            name: "sleep_mode",
+            expression: "CONFIG_BT_CTRL_SLEEP_MODE_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_SLEEP_MODE_EFF"],
+        },
+        Field {
+            name: "sleep_clock",
+            expression: "CONFIG_BT_CTRL_SLEEP_CLOCK_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_SLEEP_CLOCK_EFF"],
+        },
+        Field {
+            name: "ble_st_acl_tx_buf_nb",
+            expression: "CONFIG_BT_CTRL_BLE_STATIC_ACL_TX_BUF_NB",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_BLE_STATIC_ACL_TX_BUF_NB"],
+        },
+        Field {
+            name: "ble_hw_cca_check",
+            expression: "CONFIG_BT_CTRL_HW_CCA_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_HW_CCA_EFF"],
+        },
+        Field {
+            name: "ble_adv_dup_filt_max",
+            expression: "CONFIG_BT_CTRL_ADV_DUP_FILT_MAX",
+            value: Some(30),
+            macros: &["CONFIG_BT_CTRL_ADV_DUP_FILT_MAX"],
+        },
+        Field {
+            name: "coex_param_en",
+            expression: "false",
+            value: Some(0),
+            macros: &[],
+        },
+        Field {
+            name: "ce_len_type",
+            expression: "CONFIG_BT_CTRL_CE_LENGTH_TYPE_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_CE_LENGTH_TYPE_EFF"],
+        },
+        Field {
+            name: "coex_use_hooks",
+            expression: "false",
+            value: Some(0),
+            macros: &[],
+        },
+        Field {
+            name: "hci_tl_type",
+            expression: "CONFIG_BT_CTRL_HCI_TL_EFF",
+            value: Some(1),
+            macros: &["CONFIG_BT_CTRL_HCI_TL_EFF"],
+        },
+        Field {
+            name: "hci_tl_funcs",
+            expression: "NULL",
+            value: None,
+            macros: &[],
+        },
+        Field {
+            name: "txant_dft",
+            expression: "CONFIG_BT_CTRL_TX_ANTENNA_INDEX_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_TX_ANTENNA_INDEX_EFF"],
+        },
+        Field {
+            name: "rxant_dft",
+            expression: "CONFIG_BT_CTRL_RX_ANTENNA_INDEX_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_RX_ANTENNA_INDEX_EFF"],
+        },
+        Field {
+            name: "txpwr_dft",
+            expression: "CONFIG_BT_CTRL_DFT_TX_POWER_LEVEL_EFF",
+            value: Some(11),
+            macros: &["CONFIG_BT_CTRL_DFT_TX_POWER_LEVEL_EFF"],
+        },
+        Field {
+            name: "cfg_mask",
+            expression: "CFG_MASK",
+            value: Some(1),
+            macros: &["CFG_MASK", "CFG_MASK_BIT_SCAN_DUPLICATE_OPTION"],
+        },
+        Field {
+            name: "scan_duplicate_mode",
+            expression: "SCAN_DUPLICATE_MODE",
+            value: Some(0),
+            macros: &["SCAN_DUPLICATE_MODE", "SCAN_DUPLICATE_MODE_NORMAL_ADV_ONLY"],
+        },
+        Field {
+            name: "scan_duplicate_type",
+            expression: "SCAN_DUPLICATE_TYPE_VALUE",
+            value: Some(0),
+            macros: &["SCAN_DUPLICATE_TYPE_VALUE", "CONFIG_BT_CTRL_SCAN_DUPL_TYPE"],
+        },
+        Field {
+            name: "normal_adv_size",
+            expression: "NORMAL_SCAN_DUPLICATE_CACHE_SIZE",
+            value: Some(100),
+            macros: &["NORMAL_SCAN_DUPLICATE_CACHE_SIZE", "CONFIG_BT_CTRL_SCAN_DUPL_CACHE_SIZE"],
+        },
+        Field {
+            name: "mesh_adv_size",
+            expression: "MESH_DUPLICATE_SCAN_CACHE_SIZE",
+            value: Some(0),
+            macros: &["MESH_DUPLICATE_SCAN_CACHE_SIZE"],
+        },
+        Field {
+            name: "coex_phy_coded_tx_rx_time_limit",
+            expression: "CONFIG_BT_CTRL_COEX_PHY_CODED_TX_RX_TLIM_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_COEX_PHY_CODED_TX_RX_TLIM_EFF"],
+        },
+        Field {
+            name: "hw_target_code",
+            expression: "BLE_HW_TARGET_CODE_CHIP_ECO0",
+            value: Some(33619968),
+            macros: &["BLE_HW_TARGET_CODE_CHIP_ECO0"],
+        },
+        Field {
+            name: "slave_ce_len_min",
+            expression: "SLAVE_CE_LEN_MIN_DEFAULT",
+            value: Some(5),
+            macros: &["SLAVE_CE_LEN_MIN_DEFAULT"],
+        },
+        Field {
+            name: "hw_recorrect_en",
+            expression: "AGC_RECORRECT_EN",
+            value: Some(0),
+            macros: &["AGC_RECORRECT_EN", "BT_CTRL_AGC_RECORRECT_EN", "BT_CTRL_CODED_AGC_RECORRECT", "BT_CTRL_AGC_RECORRECT_NEW"],
+        },
+        Field {
+            name: "cca_thresh",
+            expression: "CONFIG_BT_CTRL_HW_CCA_VAL",
+            value: Some(75),
+            macros: &["CONFIG_BT_CTRL_HW_CCA_VAL"],
+        },
+        Field {
+            name: "scan_backoff_upperlimitmax",
+            expression: "BT_CTRL_SCAN_BACKOFF_UPPERLIMITMAX",
+            value: Some(0),
+            macros: &["BT_CTRL_SCAN_BACKOFF_UPPERLIMITMAX"],
+        },
+        Field {
+            name: "dup_list_refresh_period",
+            expression: "DUPL_SCAN_CACHE_REFRESH_PERIOD",
+            value: Some(0),
+            macros: &["DUPL_SCAN_CACHE_REFRESH_PERIOD", "CONFIG_BT_CTRL_DUPL_SCAN_CACHE_REFRESH_PERIOD"],
+        },
+        Field {
+            name: "ble_50_feat_supp",
+            expression: "BT_CTRL_50_FEATURE_SUPPORT",
+            value: Some(1),
+            macros: &["BT_CTRL_50_FEATURE_SUPPORT", "CONFIG_BT_BLE_50_FEATURES_SUPPORTED"],
+        },
+        Field {
+            name: "ble_cca_mode",
+            expression: "BT_BLE_CCA_MODE",
+            value: Some(0),
+            macros: &["BT_BLE_CCA_MODE", "CONFIG_BT_BLE_CCA_MODE"],
+        },
+        Field {
+            name: "ble_data_lenth_zero_aux",
+            expression: "BT_BLE_ADV_DATA_LENGTH_ZERO_AUX",
+            value: Some(0),
+            macros: &["BT_BLE_ADV_DATA_LENGTH_ZERO_AUX"],
+        },
+        Field {
+            name: "ble_chan_ass_en",
+            expression: "BT_CTRL_CHAN_ASS_EN",
+            value: Some(1),
+            macros: &["BT_CTRL_CHAN_ASS_EN", "CONFIG_BT_CTRL_CHAN_ASS_EN"],
+        },
+        Field {
+            name: "ble_ping_en",
+            expression: "BT_CTRL_LE_PING_EN",
+            value: Some(1),
+            macros: &["BT_CTRL_LE_PING_EN", "CONFIG_BT_CTRL_LE_PING_EN"],
+        },
+        Field {
+            name: "ble_llcp_disc_flag",
+            expression: "BT_CTRL_BLE_LLCP_DISC_FLAG",
+            value: Some(0),
+            macros: &["BT_CTRL_BLE_LLCP_DISC_FLAG", "BT_CTRL_BLE_LLCP_CONN_UPDATE", "BT_CTRL_BLE_LLCP_CHAN_MAP_UPDATE", "BT_CTRL_BLE_LLCP_PHY_UPDATE"],
+        },
+        Field {
+            name: "run_in_flash",
+            expression: "BT_CTRL_RUN_IN_FLASH_ONLY",
+            value: Some(0),
+            macros: &["BT_CTRL_RUN_IN_FLASH_ONLY"],
+        },
+        Field {
+            name: "dtm_en",
+            expression: "BT_CTRL_DTM_ENABLE",
+            value: Some(1),
+            macros: &["BT_CTRL_DTM_ENABLE", "CONFIG_BT_CTRL_DTM_ENABLE"],
+        },
+        Field {
+            name: "enc_en",
+            expression: "BLE_SECURITY_ENABLE",
+            value: Some(1),
+            macros: &["BLE_SECURITY_ENABLE", "CONFIG_BT_CTRL_BLE_SECURITY_ENABLE"],
+        },
+        Field {
+            name: "qa_test",
+            expression: "BT_CTRL_BLE_TEST",
+            value: Some(0),
+            macros: &["BT_CTRL_BLE_TEST"],
+        },
+        Field {
+            name: "connect_en",
+            expression: "BT_CTRL_BLE_MASTER",
+            value: Some(1),
+            macros: &["BT_CTRL_BLE_MASTER", "CONFIG_BT_CTRL_BLE_MASTER"],
+        },
+        Field {
+            name: "scan_en",
+            expression: "BT_CTRL_BLE_SCAN",
+            value: Some(1),
+            macros: &["BT_CTRL_BLE_SCAN", "CONFIG_BT_CTRL_BLE_SCAN"],
+        },
+        Field {
+            name: "ble_aa_check",
+            expression: "BLE_CTRL_CHECK_CONNECT_IND_ACCESS_ADDRESS_ENABLED",
+            value: Some(0),
+            macros: &["BLE_CTRL_CHECK_CONNECT_IND_ACCESS_ADDRESS_ENABLED"],
+        },
+        Field {
+            name: "ble_log_mode_en",
+            expression: "BLE_LOG_MODE_EN",
+            value: Some(0),
+            macros: &["BLE_LOG_MODE_EN"],
+        },
+        Field {
+            name: "ble_log_level",
+            expression: "BLE_LOG_LEVEL",
+            value: Some(0),
+            macros: &["BLE_LOG_LEVEL"],
+        },
+        Field {
+            name: "adv_en",
+            expression: "BT_CTRL_BLE_ADV",
+            value: Some(1),
+            macros: &["BT_CTRL_BLE_ADV", "CONFIG_BT_CTRL_BLE_ADV"],
+        },
+    ];
+}
+
+pub mod esp32c2 {
+    use super::Field;
+
+    /// The fields of `esp_bt_controller_config_t` in `include/esp32c2/esp_bt.h` order
+    pub const BT_CONTROLLER_INIT_CONFIG_DEFAULT: &[Field] = &[
+        Field {
+            name: "config_version",
+            expression: "CONFIG_VERSION",
+            value: Some(539296528),
+            macros: &["CONFIG_VERSION"],
+        },
+        Field {
+            name: "ble_ll_resolv_list_size",
+            expression: "CONFIG_BT_LE_LL_RESOLV_LIST_SIZE",
+            value: Some(4),
+            macros: &["CONFIG_BT_LE_LL_RESOLV_LIST_SIZE"],
+        },
+        Field {
+            name: "ble_hci_evt_hi_buf_count",
+            expression: "DEFAULT_BT_LE_HCI_EVT_HI_BUF_COUNT",
+            value: Some(30),
+            macros: &["DEFAULT_BT_LE_HCI_EVT_HI_BUF_COUNT", "CONFIG_BT_LE_HCI_EVT_HI_BUF_COUNT"],
+        },
+        Field {
+            name: "ble_hci_evt_lo_buf_count",
+            expression: "DEFAULT_BT_LE_HCI_EVT_LO_BUF_COUNT",
+            value: Some(8),
+            macros: &["DEFAULT_BT_LE_HCI_EVT_LO_BUF_COUNT", "CONFIG_BT_LE_HCI_EVT_LO_BUF_COUNT"],
+        },
+        Field {
+            name: "ble_ll_sync_list_cnt",
+            expression: "DEFAULT_BT_LE_MAX_PERIODIC_ADVERTISER_LIST",
+            value: Some(5),
+            macros: &["DEFAULT_BT_LE_MAX_PERIODIC_ADVERTISER_LIST", "CONFIG_BT_LE_MAX_PERIODIC_ADVERTISER_LIST"],
+        },
+        Field {
+            name: "ble_ll_sync_cnt",
+            expression: "DEFAULT_BT_LE_MAX_PERIODIC_SYNCS",
+            value: Some(1),
+            macros: &["DEFAULT_BT_LE_MAX_PERIODIC_SYNCS", "CONFIG_BT_LE_MAX_PERIODIC_SYNCS"],
+        },
+        Field {
+            name: "ble_ll_rsp_dup_list_count",
+            expression: "CONFIG_BT_LE_LL_DUP_SCAN_LIST_COUNT",
+            value: Some(20),
+            macros: &["CONFIG_BT_LE_LL_DUP_SCAN_LIST_COUNT"],
+        },
+        Field {
+            name: "ble_ll_adv_dup_list_count",
+            expression: "CONFIG_BT_LE_LL_DUP_SCAN_LIST_COUNT",
+            value: Some(20),
+            macros: &["CONFIG_BT_LE_LL_DUP_SCAN_LIST_COUNT"],
+        },
+        Field {
+            name: "ble_ll_tx_pwr_dbm",
+            expression: "BLE_LL_TX_PWR_DBM_N",
+            value: Some(9),
+            macros: &["BLE_LL_TX_PWR_DBM_N", "CONFIG_BT_LE_DFT_TX_POWER_LEVEL_DBM_EFF"],
+        },
+        Field {
+            name: "rtc_freq",
+            expression: "RTC_FREQ_N",
+            value: Some(32000),
+            macros: &["RTC_FREQ_N"],
+        },
+        Field {
+            name: "ble_ll_sca",
+            expression: "CONFIG_BT_LE_LL_SCA",
+            value: Some(60),
+            macros: &["CONFIG_BT_LE_LL_SCA"],
+        },
+        Field {
+            name: "ble_ll_scan_phy_number",
+            expression: "BLE_LL_SCAN_PHY_NUMBER_N",
+            value: Some(2),
+            macros: &["BLE_LL_SCAN_PHY_NUMBER_N"],
+        },
+        Field {
+            name: "ble_ll_conn_def_auth_pyld_tmo",
+            expression: "BLE_LL_CONN_DEF_AUTH_PYLD_TMO_N",
+            value: Some(3000),
+            macros: &["BLE_LL_CONN_DEF_AUTH_PYLD_TMO_N"],
+        },
+        Field {
+            name: "ble_ll_jitter_usecs",
+            expression: "BLE_LL_JITTER_USECS_N",
+            value: Some(16),
+            macros: &["BLE_LL_JITTER_USECS_N"],
+        },
+        Field {
+            name: "ble_ll_sched_max_adv_pdu_usecs",
+            expression: "BLE_LL_SCHED_MAX_ADV_PDU_USECS_N",
+            value: Some(376),
+            macros: &["BLE_LL_SCHED_MAX_ADV_PDU_USECS_N"],
+        },
+        Field {
+            name: "ble_ll_sched_direct_adv_max_usecs",
+            expression: "BLE_LL_SCHED_DIRECT_ADV_MAX_USECS_N",
+            value: Some(502),
+            macros: &["BLE_LL_SCHED_DIRECT_ADV_MAX_USECS_N"],
+        },
+        Field {
+            name: "ble_ll_sched_adv_max_usecs",
+            expression: "BLE_LL_SCHED_ADV_MAX_USECS_N",
+            value: Some(852),
+            macros: &["BLE_LL_SCHED_ADV_MAX_USECS_N"],
+        },
+        Field {
+            name: "ble_scan_rsp_data_max_len",
+            expression: "DEFAULT_BT_LE_SCAN_RSP_DATA_MAX_LEN_N",
+            value: Some(1650),
+            macros: &["DEFAULT_BT_LE_SCAN_RSP_DATA_MAX_LEN_N", "DEFAULT_BT_LE_EXT_ADV_MAX_SIZE", "CONFIG_BT_LE_EXT_ADV_MAX_SIZE"],
+        },
+        Field {
+            name: "ble_ll_cfg_num_hci_cmd_pkts",
+            expression: "BLE_LL_CFG_NUM_HCI_CMD_PKTS_N",
+            value: Some(1),
+            macros: &["BLE_LL_CFG_NUM_HCI_CMD_PKTS_N"],
+        },
+        Field {
+            name: "ble_ll_ctrl_proc_timeout_ms",
+            expression: "BLE_LL_CTRL_PROC_TIMEOUT_MS_N",
+            value: Some(40000),
+            macros: &["BLE_LL_CTRL_PROC_TIMEOUT_MS_N"],
+        },
+        Field {
+            name: "nimble_max_connections",
+            expression: "DEFAULT_BT_LE_MAX_CONNECTIONS",
+            value: Some(2),
+            macros: &["DEFAULT_BT_LE_MAX_CONNECTIONS", "CONFIG_BT_LE_MAX_CONNECTIONS"],
+        },
+        Field {
+            name: "ble_whitelist_size",
+            expression: "DEFAULT_BT_NIMBLE_WHITELIST_SIZE",
+            value: Some(12),
+            macros: &["DEFAULT_BT_NIMBLE_WHITELIST_SIZE", "CONFIG_BT_LE_WHITELIST_SIZE"],
+        },
+        Field {
+            name: "ble_acl_buf_size",
+            expression: "DEFAULT_BT_LE_ACL_BUF_SIZE",
+            value: Some(517),
+            macros: &["DEFAULT_BT_LE_ACL_BUF_SIZE", "CONFIG_BT_LE_ACL_BUF_SIZE"],
+        },
+        Field {
+            name: "ble_acl_buf_count",
+            expression: "DEFAULT_BT_LE_ACL_BUF_COUNT",
+            value: Some(10),
+            macros: &["DEFAULT_BT_LE_ACL_BUF_COUNT", "CONFIG_BT_LE_ACL_BUF_COUNT"],
+        },
+        Field {
+            name: "ble_hci_evt_buf_size",
+            expression: "DEFAULT_BT_LE_HCI_EVT_BUF_SIZE",
+            value: Some(257),
+            macros: &["DEFAULT_BT_LE_HCI_EVT_BUF_SIZE", "CONFIG_BT_LE_HCI_EVT_BUF_SIZE"],
+        },
+        Field {
+            name: "ble_multi_adv_instances",
+            expression: "DEFAULT_BT_LE_MAX_EXT_ADV_INSTANCES",
+            value: Some(1),
+            macros: &["DEFAULT_BT_LE_MAX_EXT_ADV_INSTANCES", "CONFIG_BT_LE_MAX_EXT_ADV_INSTANCES"],
+        },
+        Field {
+            name: "ble_ext_adv_max_size",
+            expression: "DEFAULT_BT_LE_EXT_ADV_MAX_SIZE",
+            value: Some(1650),
+            macros: &["DEFAULT_BT_LE_EXT_ADV_MAX_SIZE", "CONFIG_BT_LE_EXT_ADV_MAX_SIZE"],
+        },
+        Field {
+            name: "controller_task_stack_size",
+            expression: "NIMBLE_LL_STACK_SIZE",
+            value: Some(4096),
+            macros: &["NIMBLE_LL_STACK_SIZE", "CONFIG_BT_LE_CONTROLLER_TASK_STACK_SIZE"],
+        },
+        Field {
+            name: "controller_task_prio",
+            expression: "ESP_TASK_BT_CONTROLLER_PRIO",
+            value: None,
+            macros: &["ESP_TASK_BT_CONTROLLER_PRIO", "ESP_TASK_PRIO_MAX"],
+        },
+        Field {
+            name: "controller_run_cpu",
+            expression: "0",
+            value: Some(0),
+            macros: &[],
+        },
+        Field {
+            name: "enable_qa_test",
+            expression: "RUN_QA_TEST",
+            value: Some(0),
+            macros: &["RUN_QA_TEST"],
+        },
+        Field {
+            name: "enable_bqb_test",
+            expression: "RUN_BQB_TEST",
+            value: Some(0),
+            macros: &["RUN_BQB_TEST"],
+        },
+        Field {
+            name: "enable_tx_cca",
+            expression: "DEFAULT_BT_LE_TX_CCA_ENABLED",
+            value: Some(0),
+            macros: &["DEFAULT_BT_LE_TX_CCA_ENABLED"],
+        },
+        Field {
+            name: "cca_rssi_thresh",
+            expression: "256 - DEFAULT_BT_LE_CCA_RSSI_THRESH",
+            value: Some(206),
+            macros: &["DEFAULT_BT_LE_CCA_RSSI_THRESH"],
+        },
+        Field {
+            name: "sleep_en",
+            expression: "NIMBLE_SLEEP_ENABLE",
+            value: Some(0),
+            macros: &["NIMBLE_SLEEP_ENABLE"],
+        },
+        Field {
+            name: "coex_phy_coded_tx_rx_time_limit",
+            expression: "DEFAULT_BT_LE_COEX_PHY_CODED_TX_RX_TLIM_EFF",
+            value: Some(0),
+            macros: &["DEFAULT_BT_LE_COEX_PHY_CODED_TX_RX_TLIM_EFF", "CONFIG_BT_LE_COEX_PHY_CODED_TX_RX_TLIM_EFF"],
+        },
+        Field {
+            name: "dis_scan_backoff",
+            expression: "NIMBLE_DISABLE_SCAN_BACKOFF",
+            value: Some(0),
+            macros: &["NIMBLE_DISABLE_SCAN_BACKOFF"],
+        },
+        Field {
+            name: "ble_scan_classify_filter_enable",
+            expression: "0",
+            value: Some(0),
+            macros: &[],
+        },
+        Field {
+            name: "main_xtal_freq",
+            expression: "CONFIG_XTAL_FREQ",
+            value: Some(40),
+            macros: &["CONFIG_XTAL_FREQ"],
+        },
+        Field {
+            name: "version_num",
+            expression: "esp_ble_get_chip_rev_version()",
+            value: None,
+            macros: &[],
+        },
+        Field {
+            name: "ignore_wl_for_direct_adv",
+            expression: "0",
+            value: Some(0),
+            macros: &[],
+        },
+        Field {
+            name: "csa2_select",
+            expression: "DEFAULT_BT_LE_50_FEATURE_SUPPORT",
+            value: Some(1),
+            macros: &["DEFAULT_BT_LE_50_FEATURE_SUPPORT"],
+        },
+        Field {
+            name: "ble_aa_check",
+            expression: "DEFAULT_BT_LE_CTRL_CHECK_CONNECT_IND_ACCESS_ADDRESS",
+            value: Some(0),
+            macros: &["DEFAULT_BT_LE_CTRL_CHECK_CONNECT_IND_ACCESS_ADDRESS"],
+        },
+        Field {
+            name: "ble_llcp_disc_flag",
+            expression: "BT_LE_CTRL_LLCP_DISC_FLAG",
+            value: Some(0),
+            macros: &["BT_LE_CTRL_LLCP_DISC_FLAG", "BT_CTRL_BLE_LLCP_CONN_UPDATE", "BT_CTRL_BLE_LLCP_CHAN_MAP_UPDATE", "BT_CTRL_BLE_LLCP_PHY_UPDATE"],
+        },
+        Field {
+            name: "scan_backoff_upperlimitmax",
+            expression: "BT_CTRL_SCAN_BACKOFF_UPPERLIMITMAX",
+            value: Some(32),
+            macros: &["BT_CTRL_SCAN_BACKOFF_UPPERLIMITMAX", "CONFIG_BT_CTRL_SCAN_BACKOFF_UPPERLIMITMAX"],
+        },
+        Field {
+            name: "vhci_enabled",
+            expression: "DEFAULT_BT_LE_VHCI_ENABLED",
+            value: Some(1),
+            macros: &["DEFAULT_BT_LE_VHCI_ENABLED", "CONFIG_BT_LE_HCI_INTERFACE_USE_RAM"],
+        },
+        Field {
+            name: "config_magic",
+            expression: "CONFIG_MAGIC",
+            value: Some(1515890085),
+            macros: &["CONFIG_MAGIC"],
+        },
+    ];
+}
+
+pub mod esp32c3 {
+    use super::Field;
+
+    /// The fields of `esp_bt_controller_config_t` in `include/esp32c3/esp_bt.h` order
+    pub const BT_CONTROLLER_INIT_CONFIG_DEFAULT: &[Field] = &[
+        Field {
+            name: "magic",
+            expression: "ESP_BT_CTRL_CONFIG_MAGIC_VAL",
+            value: Some(1515890085),
+            macros: &["ESP_BT_CTRL_CONFIG_MAGIC_VAL"],
+        },
+        Field {
+            name: "version",
+            expression: "ESP_BT_CTRL_CONFIG_VERSION",
+            value: Some(38817920),
+            macros: &["ESP_BT_CTRL_CONFIG_VERSION"],
+        },
+        Field {
+            name: "controller_task_stack_size",
+            expression: "ESP_TASK_BT_CONTROLLER_STACK",
+            value: Some(4096),
+            macros: &["ESP_TASK_BT_CONTROLLER_STACK", "TASK_EXTRA_STACK_SIZE"],
+        },
+        Field {
+            name: "controller_task_prio",
+            expression: "ESP_TASK_BT_CONTROLLER_PRIO",
+            value: None,
+            macros: &["ESP_TASK_BT_CONTROLLER_PRIO", "ESP_TASK_PRIO_MAX"],
+        },
+        Field {
+            name: "controller_task_run_cpu",
+            expression: "CONFIG_BT_CTRL_PINNED_TO_CORE",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_PINNED_TO_CORE"],
+        },
+        Field {
+            name: "bluetooth_mode",
+            expression: "CONFIG_BT_CTRL_MODE_EFF",
+            value: Some(1),
+            macros: &["CONFIG_BT_CTRL_MODE_EFF"],
+        },
+        Field {
+            name: "ble_max_act",
+            expression: "CONFIG_BT_CTRL_BLE_MAX_ACT_EFF",
+            value: Some(6),
+            macros: &["CONFIG_BT_CTRL_BLE_MAX_ACT_EFF"],
+        },
+        Field {
+            name: "sleep_mode",
+            expression: "CONFIG_BT_CTRL_SLEEP_MODE_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_SLEEP_MODE_EFF"],
+        },
+        Field {
+            name: "sleep_clock",
+            expression: "CONFIG_BT_CTRL_SLEEP_CLOCK_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_SLEEP_CLOCK_EFF"],
+        },
+        Field {
+            name: "ble_st_acl_tx_buf_nb",
+            expression: "CONFIG_BT_CTRL_BLE_STATIC_ACL_TX_BUF_NB",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_BLE_STATIC_ACL_TX_BUF_NB"],
+        },
+        Field {
+            name: "ble_hw_cca_check",
+            expression: "CONFIG_BT_CTRL_HW_CCA_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_HW_CCA_EFF"],
+        },
+        Field {
+            name: "ble_adv_dup_filt_max",
+            expression: "CONFIG_BT_CTRL_ADV_DUP_FILT_MAX",
+            value: Some(30),
+            macros: &["CONFIG_BT_CTRL_ADV_DUP_FILT_MAX"],
+        },
+        Field {
+            name: "coex_param_en",
+            expression: "false",
+            value: Some(0),
+            macros: &[],
+        },
+        Field {
+            name: "ce_len_type",
+            expression: "CONFIG_BT_CTRL_CE_LENGTH_TYPE_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_CE_LENGTH_TYPE_EFF"],
+        },
+        Field {
+            name: "coex_use_hooks",
+            expression: "false",
+            value: Some(0),
+            macros: &[],
+        },
+        Field {
+            name: "hci_tl_type",
+            expression: "CONFIG_BT_CTRL_HCI_TL_EFF",
+            value: Some(1),
+            macros: &["CONFIG_BT_CTRL_HCI_TL_EFF"],
+        },
+        Field {
+            name: "hci_tl_funcs",
+            expression: "NULL",
+            value: None,
+            macros: &[],
+        },
+        Field {
+            name: "txant_dft",
+            expression: "CONFIG_BT_CTRL_TX_ANTENNA_INDEX_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_TX_ANTENNA_INDEX_EFF"],
+        },
+        Field {
+            name: "rxant_dft",
+            expression: "CONFIG_BT_CTRL_RX_ANTENNA_INDEX_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_RX_ANTENNA_INDEX_EFF"],
+        },
+        Field {
+            name: "txpwr_dft",
+            expression: "CONFIG_BT_CTRL_DFT_TX_POWER_LEVEL_EFF",
+            value: Some(11),
+            macros: &["CONFIG_BT_CTRL_DFT_TX_POWER_LEVEL_EFF"],
+        },
+        Field {
+            name: "cfg_mask",
+            expression: "CFG_MASK",
+            value: Some(1),
+            macros: &["CFG_MASK", "CFG_MASK_BIT_SCAN_DUPLICATE_OPTION"],
+        },
+        Field {
+            name: "scan_duplicate_mode",
+            expression: "SCAN_DUPLICATE_MODE",
+            value: Some(0),
+            macros: &["SCAN_DUPLICATE_MODE", "SCAN_DUPLICATE_MODE_NORMAL_ADV_ONLY"],
+        },
+        Field {
+            name: "scan_duplicate_type",
+            expression: "SCAN_DUPLICATE_TYPE_VALUE",
+            value: Some(0),
+            macros: &["SCAN_DUPLICATE_TYPE_VALUE", "CONFIG_BT_CTRL_SCAN_DUPL_TYPE"],
+        },
+        Field {
+            name: "normal_adv_size",
+            expression: "NORMAL_SCAN_DUPLICATE_CACHE_SIZE",
+            value: Some(100),
+            macros: &["NORMAL_SCAN_DUPLICATE_CACHE_SIZE", "CONFIG_BT_CTRL_SCAN_DUPL_CACHE_SIZE"],
+        },
+        Field {
+            name: "mesh_adv_size",
+            expression: "MESH_DUPLICATE_SCAN_CACHE_SIZE",
+            value: Some(0),
+            macros: &["MESH_DUPLICATE_SCAN_CACHE_SIZE"],
+        },
+        Field {
+            name: "coex_phy_coded_tx_rx_time_limit",
+            expression: "CONFIG_BT_CTRL_COEX_PHY_CODED_TX_RX_TLIM_EFF",
+            value: Some(0),
+            macros: &["CONFIG_BT_CTRL_COEX_PHY_CODED_TX_RX_TLIM_EFF"],
+        },
+        Field {
+            name: "hw_target_code",
+            expression: "BLE_HW_TARGET_CODE_CHIP_ECO0",
+            value: Some(16842752),
+            macros: &["BLE_HW_TARGET_CODE_CHIP_ECO0"],
+        },
+        Field {
+            name: "slave_ce_len_min",
+            expression: "SLAVE_CE_LEN_MIN_DEFAULT",
+            value: Some(5),
+            macros: &["SLAVE_CE_LEN_MIN_DEFAULT"],
+        },
+        Field {
+            name: "hw_recorrect_en",
+            expression: "AGC_RECORRECT_EN",
+            value: Some(0),
+            macros: &["AGC_RECORRECT_EN", "BT_CTRL_AGC_RECORRECT_EN", "BT_CTRL_CODED_AGC_RECORRECT", "BT_CTRL_AGC_RECORRECT_NEW"],
+        },
+        Field {
+            name: "cca_thresh",
+            expression: "CONFIG_BT_CTRL_HW_CCA_VAL",
+            value: Some(75),
+            macros: &["CONFIG_BT_CTRL_HW_CCA_VAL"],
+        },
+        Field {
+            name: "scan_backoff_upperlimitmax",
+            expression: "BT_CTRL_SCAN_BACKOFF_UPPERLIMITMAX",
+            value: Some(0),
+            macros: &["BT_CTRL_SCAN_BACKOFF_UPPERLIMITMAX"],
+        },
+        Field {
+            name: "dup_list_refresh_period",
+            expression: "DUPL_SCAN_CACHE_REFRESH_PERIOD",
+            value: Some(0),
+            macros: &["DUPL_SCAN_CACHE_REFRESH_PERIOD", "CONFIG_BT_CTRL_DUPL_SCAN_CACHE_REFRESH_PERIOD"],
+        },
+        Field {
+            name: "ble_50_feat_supp",
+            expression: "BT_CTRL_50_FEATURE_SUPPORT",
+            value: Some(1),
+            macros: &["BT_CTRL_50_FEATURE_SUPPORT", "CONFIG_BT_BLE_50_FEATURES_SUPPORTED"],
+        },
+        Field {
+            name: "ble_cca_mode",
+            expression: "BT_BLE_CCA_MODE",
+            value: Some(0),
+            macros: &["BT_BLE_CCA_MODE", "CONFIG_BT_BLE_CCA_MODE"],
+        },
+        Field {
+            name: "ble_data_lenth_zero_aux",
+            expression: "BT_BLE_ADV_DATA_LENGTH_ZERO_AUX",
+            value: Some(0),
+            macros: &["BT_BLE_ADV_DATA_LENGTH_ZERO_AUX"],
+        },
+        Field {
+            name: "ble_chan_ass_en",
+            expression: "BT_CTRL_CHAN_ASS_EN",
+            value: Some(1),
+            macros: &["BT_CTRL_CHAN_ASS_EN", "CONFIG_BT_CTRL_CHAN_ASS_EN"],
+        },
+        Field {
+            name: "ble_ping_en",
+            expression: "BT_CTRL_LE_PING_EN",
+            value: Some(1),
+            macros: &["BT_CTRL_LE_PING_EN", "CONFIG_BT_CTRL_LE_PING_EN"],
+        },
+        Field {
+            name: "ble_llcp_disc_flag",
+            expression: "BT_CTRL_BLE_LLCP_DISC_FLAG",
+            value: Some(0),
+            macros: &["BT_CTRL_BLE_LLCP_DISC_FLAG", "BT_CTRL_BLE_LLCP_CONN_UPDATE", "BT_CTRL_BLE_LLCP_CHAN_MAP_UPDATE", "BT_CTRL_BLE_LLCP_PHY_UPDATE"],
+        },
+        Field {
+            name: "run_in_flash",
+            expression: "BT_CTRL_RUN_IN_FLASH_ONLY",
+            value: Some(0),
+            macros: &["BT_CTRL_RUN_IN_FLASH_ONLY"],
+        },
+        Field {
+            name: "dtm_en",
+            expression: "BT_CTRL_DTM_ENABLE",
+            value: Some(1),
+            macros: &["BT_CTRL_DTM_ENABLE", "CONFIG_BT_CTRL_DTM_ENABLE"],
+        },
+        Field {
+            name: "enc_en",
+            expression: "BLE_SECURITY_ENABLE",
+            value: Some(1),
+            macros: &["BLE_SECURITY_ENABLE", "CONFIG_BT_CTRL_BLE_SECURITY_ENABLE"],
+        },
+        Field {
+            name: "qa_test",
+            expression: "BT_CTRL_BLE_TEST",
+            value: Some(0),
+            macros: &["BT_CTRL_BLE_TEST"],
+        },
+        Field {
+            name: "connect_en",
+            expression: "BT_CTRL_BLE_MASTER",
+            value: Some(1),
+            macros: &["BT_CTRL_BLE_MASTER", "CONFIG_BT_CTRL_BLE_MASTER"],
+        },
+        Field {
+            name: "scan_en",
+            expression: "BT_CTRL_BLE_SCAN",
+            value: Some(1),
+            macros: &["BT_CTRL_BLE_SCAN", "CONFIG_BT_CTRL_BLE_SCAN"],
+        },
+        Field {
+            name: "ble_aa_check",
+            expression: "BLE_CTRL_CHECK_CONNECT_IND_ACCESS_ADDRESS_ENABLED",
+            value: Some(0),
+            macros: &["BLE_CTRL_CHECK_CONNECT_IND_ACCESS_ADDRESS_ENABLED"],
+        },
+        Field {
+            name: "ble_log_mode_en",
+            expression: "BLE_LOG_MODE_EN",
+            value: Some(0),
+            macros: &["BLE_LOG_MODE_EN"],
+        },
+        Field {
+            name: "ble_log_level",
+            expression: "BLE_LOG_LEVEL",
+            value: Some(0),
+            macros: &["BLE_LOG_LEVEL"],
+        },
+        Field {
+            name: "adv_en",
+            expression: "BT_CTRL_BLE_ADV",
+            value: Some(1),
+            macros: &["BT_CTRL_BLE_ADV", "CONFIG_BT_CTRL_BLE_ADV"],
+        },
+    ];
+}
+
+pub mod esp32h2 {
+    use super::Field;
+
+    /// The fields of `esp_bt_controller_config_t` in `include/esp32h2/esp_bt.h` order
+    pub const BT_CONTROLLER_INIT_CONFIG_DEFAULT: &[Field] = &[
+        Field {
+            name: "config_version",
+            expression: "CONFIG_VERSION",
+            value: Some(539297286),
+            macros: &["CONFIG_VERSION"],
+        },
+        Field {
+            name: "ble_ll_resolv_list_size",
+            expression: "CONFIG_BT_LE_LL_RESOLV_LIST_SIZE",
+            value: Some(4),
+            macros: &["CONFIG_BT_LE_LL_RESOLV_LIST_SIZE"],
+        },
+        Field {
+            name: "ble_hci_evt_hi_buf_count",
+            expression: "DEFAULT_BT_LE_HCI_EVT_HI_BUF_COUNT",
+            value: Some(30),
+            macros: &["DEFAULT_BT_LE_HCI_EVT_HI_BUF_COUNT", "CONFIG_BT_LE_HCI_EVT_HI_BUF_COUNT"],
+        },
+        Field {
+            name: "ble_hci_evt_lo_buf_count",
+            expression: "DEFAULT_BT_LE_HCI_EVT_LO_BUF_COUNT",
+            value: Some(8),
+            macros: &["DEFAULT_BT_LE_HCI_EVT_LO_BUF_COUNT", "CONFIG_BT_LE_HCI_EVT_LO_BUF_COUNT"],
+        },
+        Field {
+            name: "ble_ll_sync_list_cnt",
+            expression: "DEFAULT_BT_LE_MAX_PERIODIC_ADVERTISER_LIST",
+            value: Some(5),
+            macros: &["DEFAULT_BT_LE_MAX_PERIODIC_ADVERTISER_LIST", "CONFIG_BT_LE_MAX_PERIODIC_ADVERTISER_LIST"],
+        },
+        Field {
+            name: "ble_ll_sync_cnt",
+            expression: "DEFAULT_BT_LE_MAX_PERIODIC_SYNCS",
+            value: Some(1),
+            macros: &["DEFAULT_BT_LE_MAX_PERIODIC_SYNCS", "CONFIG_BT_LE_MAX_PERIODIC_SYNCS"],
+        },
+        Field {
+            name: "ble_ll_rsp_dup_list_count",
+            expression: "CONFIG_BT_LE_LL_DUP_SCAN_LIST_COUNT",
+            value: Some(20),
+            macros: &["CONFIG_BT_LE_LL_DUP_SCAN_LIST_COUNT"],
+        },
+        Field {
+            name: "ble_ll_adv_dup_list_count",
+            expression: "CONFIG_BT_LE_LL_DUP_SCAN_LIST_COUNT",
+            value: Some(20),
+            macros: &["CONFIG_BT_LE_LL_DUP_SCAN_LIST_COUNT"],
+        },
+        Field {
+            name: "ble_ll_tx_pwr_dbm",
+            expression: "BLE_LL_TX_PWR_DBM_N",
+            value: Some(9),
+            macros: &["BLE_LL_TX_PWR_DBM_N", "CONFIG_BT_LE_DFT_TX_POWER_LEVEL_DBM_EFF"],
+        },
+        Field {
+            name: "rtc_freq",
+            expression: "RTC_FREQ_N",
+            value: Some(32768),
+            macros: &["RTC_FREQ_N"],
+        },
+        Field {
+            name: "ble_ll_sca",
+            expression: "CONFIG_BT_LE_LL_SCA",
+            value: Some(60),
+            macros: &["CONFIG_BT_LE_LL_SCA"],
+        },
+        Field {
+            name: "ble_ll_scan_phy_number",
+            expression: "BLE_LL_SCAN_PHY_NUMBER_N",
+            value: Some(2),
+            macros: &["BLE_LL_SCAN_PHY_NUMBER_N"],
+        },
+        Field {
+            name: "ble_ll_conn_def_auth_pyld_tmo",
+            expression: "BLE_LL_CONN_DEF_AUTH_PYLD_TMO_N",
+            value: Some(3000),
+            macros: &["BLE_LL_CONN_DEF_AUTH_PYLD_TMO_N"],
+        },
+        Field {
+            name: "ble_ll_jitter_usecs",
+            expression: "BLE_LL_JITTER_USECS_N",
+            value: Some(16),
+            macros: &["BLE_LL_JITTER_USECS_N"],
+        },
+        Field {
+            name: "ble_ll_sched_max_adv_pdu_usecs",
+            expression: "BLE_LL_SCHED_MAX_ADV_PDU_USECS_N",
+            value: Some(376),
+            macros: &["BLE_LL_SCHED_MAX_ADV_PDU_USECS_N"],
+        },
+        Field {
+            name: "ble_ll_sched_direct_adv_max_usecs",
+            expression: "BLE_LL_SCHED_DIRECT_ADV_MAX_USECS_N",
+            value: Some(502),
+            macros: &["BLE_LL_SCHED_DIRECT_ADV_MAX_USECS_N"],
+        },
+        Field {
+            name: "ble_ll_sched_adv_max_usecs",
+            expression: "BLE_LL_SCHED_ADV_MAX_USECS_N",
+            value: Some(852),
+            macros: &["BLE_LL_SCHED_ADV_MAX_USECS_N"],
+        },
+        Field {
+            name: "ble_scan_rsp_data_max_len",
+            expression: "DEFAULT_BT_LE_SCAN_RSP_DATA_MAX_LEN_N",
+            value: Some(1650),
+            macros: &["DEFAULT_BT_LE_SCAN_RSP_DATA_MAX_LEN_N", "DEFAULT_BT_LE_EXT_ADV_MAX_SIZE", "CONFIG_BT_LE_EXT_ADV_MAX_SIZE"],
+        },
+        Field {
+            name: "ble_ll_cfg_num_hci_cmd_pkts",
+            expression: "BLE_LL_CFG_NUM_HCI_CMD_PKTS_N",
+            value: Some(1),
+            macros: &["BLE_LL_CFG_NUM_HCI_CMD_PKTS_N"],
+        },
+        Field {
+            name: "ble_ll_ctrl_proc_timeout_ms",
+            expression: "BLE_LL_CTRL_PROC_TIMEOUT_MS_N",
+            value: Some(40000),
+            macros: &["BLE_LL_CTRL_PROC_TIMEOUT_MS_N"],
+        },
+        Field {
+            name: "nimble_max_connections",
+            expression: "DEFAULT_BT_LE_MAX_CONNECTIONS",
+            value: Some(3),
+            macros: &["DEFAULT_BT_LE_MAX_CONNECTIONS", "CONFIG_BT_LE_MAX_CONNECTIONS"],
+        },
+        Field {
+            name: "ble_whitelist_size",
+            expression: "DEFAULT_BT_NIMBLE_WHITELIST_SIZE",
+            value: Some(12),
+            macros: &["DEFAULT_BT_NIMBLE_WHITELIST_SIZE", "CONFIG_BT_LE_WHITELIST_SIZE"],
+        },
+        Field {
+            name: "ble_acl_buf_size",
+            expression: "DEFAULT_BT_LE_ACL_BUF_SIZE",
+            value: Some(517),
+            macros: &["DEFAULT_BT_LE_ACL_BUF_SIZE", "CONFIG_BT_LE_ACL_BUF_SIZE"],
+        },
+        Field {
+            name: "ble_acl_buf_count",
+            expression: "DEFAULT_BT_LE_ACL_BUF_COUNT",
+            value: Some(10),
+            macros: &["DEFAULT_BT_LE_ACL_BUF_COUNT", "CONFIG_BT_LE_ACL_BUF_COUNT"],
+        },
+        Field {
+            name: "ble_hci_evt_buf_size",
+            expression: "DEFAULT_BT_LE_HCI_EVT_BUF_SIZE",
+            value: Some(257),
+            macros: &["DEFAULT_BT_LE_HCI_EVT_BUF_SIZE", "CONFIG_BT_LE_HCI_EVT_BUF_SIZE"],
+        },
+        Field {
+            name: "ble_multi_adv_instances",
+            expression: "DEFAULT_BT_LE_MAX_EXT_ADV_INSTANCES",
+            value: Some(1),
+            macros: &["DEFAULT_BT_LE_MAX_EXT_ADV_INSTANCES", "CONFIG_BT_LE_MAX_EXT_ADV_INSTANCES"],
+        },
+        Field {
+            name: "ble_ext_adv_max_size",
+            expression: "DEFAULT_BT_LE_EXT_ADV_MAX_SIZE",
+            value: Some(1650),
+            macros: &["DEFAULT_BT_LE_EXT_ADV_MAX_SIZE", "CONFIG_BT_LE_EXT_ADV_MAX_SIZE"],
+        },
+        Field {
+            name: "controller_task_stack_size",
+            expression: "NIMBLE_LL_STACK_SIZE",
+            value: Some(4096),
+            macros: &["NIMBLE_LL_STACK_SIZE", "CONFIG_BT_LE_CONTROLLER_TASK_STACK_SIZE"],
+        },
+        Field {
+            name: "controller_task_prio",
+            expression: "ESP_TASK_BT_CONTROLLER_PRIO",
+            value: None,
+            macros: &["ESP_TASK_BT_CONTROLLER_PRIO", "ESP_TASK_PRIO_MAX"],
+        },
+        Field {
+            name: "controller_run_cpu",
+            expression: "0",
+            value: Some(0),
+            macros: &[],
+        },
+        Field {
+            name: "enable_qa_test",
+            expression: "RUN_QA_TEST",
+            value: Some(0),
+            macros: &["RUN_QA_TEST"],
+        },
+        Field {
+            name: "enable_bqb_test",
+            expression: "RUN_BQB_TEST",
+            value: Some(0),
+            macros: &["RUN_BQB_TEST"],
+        },
+        Field {
+            name: "enable_tx_cca",
+            expression: "DEFAULT_BT_LE_TX_CCA_ENABLED",
+            value: Some(0),
+            macros: &["DEFAULT_BT_LE_TX_CCA_ENABLED"],
+        },
+        Field {
+            name: "cca_rssi_thresh",
+            expression: "256 - DEFAULT_BT_LE_CCA_RSSI_THRESH",
+            value: Some(206),
+            macros: &["DEFAULT_BT_LE_CCA_RSSI_THRESH"],
+        },
+        Field {
+            name: "sleep_en",
+            expression: "NIMBLE_SLEEP_ENABLE",
+            value: Some(0),
+            macros: &["NIMBLE_SLEEP_ENABLE"],
+        },
+        Field {
+            name: "coex_phy_coded_tx_rx_time_limit",
+            expression: "DEFAULT_BT_LE_COEX_PHY_CODED_TX_RX_TLIM_EFF",
+            value: Some(0),
+            macros: &["DEFAULT_BT_LE_COEX_PHY_CODED_TX_RX_TLIM_EFF", "CONFIG_BT_LE_COEX_PHY_CODED_TX_RX_TLIM_EFF"],
+        },
+        Field {
+            name: "dis_scan_backoff",
+            expression: "NIMBLE_DISABLE_SCAN_BACKOFF",
+            value: Some(0),
+            macros: &["NIMBLE_DISABLE_SCAN_BACKOFF"],
+        },
+        Field {
+            name: "ble_scan_classify_filter_enable",
+            expression: "1",
+            value: Some(1),
+            macros: &[],
+        },
+        Field {
+            name: "main_xtal_freq",
+            expression: "CONFIG_XTAL_FREQ",
+            value: Some(32),
+            macros: &["CONFIG_XTAL_FREQ"],
+        },
+        Field {
+            name: "cpu_freq_mhz",
+            expression: "CONFIG_ESP_DEFAULT_CPU_FREQ_MHZ",
+            value: Some(96),
+            macros: &["CONFIG_ESP_DEFAULT_CPU_FREQ_MHZ"],
+        },
+        Field {
+            name: "ignore_wl_for_direct_adv",
+            expression: "0",
+            value: Some(0),
+            macros: &[],
+        },
+        Field {
+            name: "enable_pcl",
+            expression: "DEFAULT_BT_LE_POWER_CONTROL_ENABLED",
+            value: Some(0),
+            macros: &["DEFAULT_BT_LE_POWER_CONTROL_ENABLED"],
+        },
+        Field {
+            name: "csa2_select",
+            expression: "DEFAULT_BT_LE_50_FEATURE_SUPPORT",
+            value: Some(1),
+            macros: &["DEFAULT_BT_LE_50_FEATURE_SUPPORT"],
+        },
+        Field {
+            name: "enable_csr",
+            expression: "0",
+            value: Some(0),
+            macros: &[],
+        },
+        Field {
+            name: "ble_aa_check",
+            expression: "DEFAULT_BT_LE_CTRL_CHECK_CONNECT_IND_ACCESS_ADDRESS",
+            value: Some(0),
+            macros: &["DEFAULT_BT_LE_CTRL_CHECK_CONNECT_IND_ACCESS_ADDRESS"],
+        },
+        Field {
+            name: "ble_llcp_disc_flag",
+            expression: "BT_LE_CTRL_LLCP_DISC_FLAG",
+            value: Some(0),
+            macros: &["BT_LE_CTRL_LLCP_DISC_FLAG", "BT_CTRL_BLE_LLCP_CONN_UPDATE", "BT_CTRL_BLE_LLCP_CHAN_MAP_UPDATE", "BT_CTRL_BLE_LLCP_PHY_UPDATE"],
+        },
+        Field {
+            name: "scan_backoff_upperlimitmax",
+            expression: "BT_CTRL_SCAN_BACKOFF_UPPERLIMITMAX",
+            value: Some(32),
+            macros: &["BT_CTRL_SCAN_BACKOFF_UPPERLIMITMAX", "CONFIG_BT_CTRL_SCAN_BACKOFF_UPPERLIMITMAX"],
+        },
+        Field {
+            name: "ble_chan_ass_en",
+            expression: "DEFAULT_BT_LE_CTRL_CHAN_ASS_EN",
+            value: Some(0),
+            macros: &["DEFAULT_BT_LE_CTRL_CHAN_ASS_EN"],
+        },
+        Field {
+            name: "ble_data_lenth_zero_aux",
+            expression: "DEFAULT_BT_LE_CTRL_ADV_DATA_LENGTH_ZERO_AUX",
+            value: Some(1),
+            macros: &["DEFAULT_BT_LE_CTRL_ADV_DATA_LENGTH_ZERO_AUX", "CONFIG_BT_LE_CTRL_ADV_DATA_LENGTH_ZERO_AUX"],
+        },
+        Field {
+            name: "vhci_enabled",
+            expression: "DEFAULT_BT_LE_VHCI_ENABLED",
+            value: Some(1),
+            macros: &["DEFAULT_BT_LE_VHCI_ENABLED", "CONFIG_BT_LE_HCI_INTERFACE_USE_RAM"],
+        },
+        Field {
+            name: "ptr_check_enabled",
+            expression: "DEFAULT_BT_LE_PTR_CHECK_ENABLED",
+            value: Some(0),
+            macros: &["DEFAULT_BT_LE_PTR_CHECK_ENABLED"],
+        },
+        Field {
+            name: "ble_adv_tx_options",
+            expression: "0",
+            value: Some(0),
+            macros: &[],
+        },
+        Field {
+            name: "skip_unnecessary_checks_en",
+            expression: "0",
+            value: Some(0),
+            macros: &[],
+        },
+        Field {
+            name: "fast_conn_data_tx_en",
+            expression: "DEFAULT_BT_LE_CTRL_FAST_CONN_DATA_TX_EN",
+            value: Some(1),
+            macros: &["DEFAULT_BT_LE_CTRL_FAST_CONN_DATA_TX_EN", "CONFIG_BT_LE_CTRL_FAST_CONN_DATA_TX_EN"],
+        },
+        Field {
+            name: "ch39_txpwr",
+            expression: "BLE_LL_TX_PWR_DBM_N",
+            value: Some(9),
+            macros: &["BLE_LL_TX_PWR_DBM_N", "CONFIG_BT_LE_DFT_TX_POWER_LEVEL_DBM_EFF"],
+        },
+        Field {
+            name: "adv_rsv_cnt",
+            expression: "BLE_LL_ADV_SM_RESERVE_CNT_N",
+            value: Some(1),
+            macros: &["BLE_LL_ADV_SM_RESERVE_CNT_N", "DEFAULT_BT_LE_MAX_EXT_ADV_INSTANCES", "CONFIG_BT_LE_MAX_EXT_ADV_INSTANCES", "CONFIG_BT_LE_EXT_ADV_RESERVED_MEMORY_COUNT"],
+        },
+        Field {
+            name: "conn_rsv_cnt",
+            expression: "BLE_LL_CONN_SM_RESERVE_CNT_N",
+            value: Some(2),
+            macros: &["BLE_LL_CONN_SM_RESERVE_CNT_N", "DEFAULT_BT_LE_MAX_CONNECTIONS", "CONFIG_BT_LE_MAX_CONNECTIONS", "CONFIG_BT_LE_CONN_RESERVED_MEMORY_COUNT"],
+        },
+        Field {
+            name: "config_magic",
+            expression: "CONFIG_MAGIC",
+            value: Some(1515890085),
+            macros: &["CONFIG_MAGIC"],
+        },
+    ];
+}
@@ -0,0 +1,4988 @@
+//! SoC capabilities of ESP-IDF v5.5.1, generated by `cargo xtask caps`
+//!
+//! Every chip module defines all flags, `false` where the chip's
+//! `soc_caps.h` does not. Values are only defined for the chips having them.
+
+pub const IDF_VERSION: &str = "ESP-IDF v5.5.1";
+
+pub mod esp32 {
+    pub const SOC_ADC_ARBITER_SUPPORTED: bool = false;
+    pub const SOC_ADC_CALIBRATION_V1_SUPPORTED: bool = false;
+    pub const SOC_ADC_CALIB_CHAN_COMPENS_SUPPORTED: bool = false;
+    pub const SOC_ADC_DIG_CTRL_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_IIR_FILTER_SUPPORTED: bool = false;
+    pub const SOC_ADC_DMA_SUPPORTED: bool = true;
+    pub const SOC_ADC_MONITOR_SUPPORTED: bool = false;
+    pub const SOC_ADC_RTC_CTRL_SUPPORTED: bool = true;
+    pub const SOC_ADC_SELF_HW_CALI_SUPPORTED: bool = false;
+    pub const SOC_ADC_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORT_AES_128: bool = true;
+    pub const SOC_AES_SUPPORT_AES_192: bool = true;
+    pub const SOC_AES_SUPPORT_AES_256: bool = true;
+    pub const SOC_AES_SUPPORT_DMA: bool = false;
+    pub const SOC_AES_SUPPORT_GCM: bool = false;
+    pub const SOC_AES_SUPPORT_PSEUDO_ROUND_FUNCTION: bool = false;
+    pub const SOC_AHB_GDMA_SUPPORTED: bool = false;
+    pub const SOC_AHB_GDMA_SUPPORT_PSRAM: bool = false;
+    pub const SOC_ANA_CMPR_SUPPORTED: bool = false;
+    pub const SOC_ANA_CMPR_SUPPORT_ETM: bool = false;
+    pub const SOC_APM_CPU_APM_SUPPORTED: bool = false;
+    pub const SOC_APM_CTRL_FILTER_SUPPORTED: bool = false;
+    pub const SOC_APM_LP_APM0_SUPPORTED: bool = false;
+    pub const SOC_APM_SUPPORTED: bool = false;
+    pub const SOC_APM_SUPPORT_CTRL_CFG_LOCK: bool = false;
+    pub const SOC_APM_SUPPORT_LP_TEE_CTRL: bool = false;
+    pub const SOC_APM_SUPPORT_TEE_PERI_ACCESS_CTRL: bool = false;
+    pub const SOC_ASSIST_DEBUG_SUPPORTED: bool = false;
+    pub const SOC_ASYNC_MEMCPY_SUPPORTED: bool = false;
+    pub const SOC_BITSCRAMBLER_SUPPORTED: bool = false;
+    pub const SOC_BLE_50_SUPPORTED: bool = false;
+    pub const SOC_BLE_CTE_SUPPORTED: bool = false;
+    pub const SOC_BLE_DEVICE_PRIVACY_SUPPORTED: bool = false;
+    pub const SOC_BLE_MESH_SUPPORTED: bool = true;
+    pub const SOC_BLE_PERIODIC_ADV_ENH_SUPPORTED: bool = false;
+    pub const SOC_BLE_POWER_CONTROL_SUPPORTED: bool = false;
+    pub const SOC_BLE_SUPPORTED: bool = true;
+    pub const SOC_BLUFI_SUPPORTED: bool = true;
+    pub const SOC_BOD_SUPPORTED: bool = true;
+    pub const SOC_BOOTLOADER_ANTI_ROLLBACK_SUPPORTED: bool = false;
+    pub const SOC_BRANCH_PREDICTOR_SUPPORTED: bool = false;
+    pub const SOC_BROWNOUT_RESET_SUPPORTED: bool = false;
+    pub const SOC_BT_CLASSIC_SUPPORTED: bool = true;
+    pub const SOC_BT_H2C_ENC_KEY_CTRL_ENH_VSC_SUPPORTED: bool = true;
+    pub const SOC_BT_SUPPORTED: bool = true;
+    pub const SOC_CACHE_FREEZE_SUPPORTED: bool = false;
+    pub const SOC_CACHE_SUPPORT_WRAP: bool = false;
+    pub const SOC_CACHE_WRITEBACK_SUPPORTED: bool = false;
+    pub const SOC_CCOMP_TIMER_SUPPORTED: bool = true;
+    pub const SOC_CLK_APLL_SUPPORTED: bool = true;
+    pub const SOC_CLK_LP_FAST_SUPPORT_LP_PLL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D2: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D4: bool = true;
+    pub const SOC_CLK_OSC_SLOW_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC32K_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC_FAST_D256_SUPPORTED: bool = true;
+    pub const SOC_CLK_RC_FAST_SUPPORT_CALIBRATION: bool = true;
+    pub const SOC_CLK_TREE_SUPPORTED: bool = true;
+    pub const SOC_CLK_XTAL32K_SUPPORTED: bool = true;
+    pub const SOC_CLOCK_TREE_MANAGEMENT_SUPPORTED: bool = false;
+    pub const SOC_CONFIGURABLE_VDDSDIO_SUPPORTED: bool = true;
+    pub const SOC_CPU_SUPPORT_WFE: bool = false;
+    pub const SOC_CP_DMA_SUPPORTED: bool = false;
+    pub const SOC_CRYPTO_DPA_PROTECTION_SUPPORTED: bool = false;
+    pub const SOC_DAC_SUPPORTED: bool = true;
+    pub const SOC_DEDICATED_GPIO_SUPPORTED: bool = false;
+    pub const SOC_DEEP_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_DIG_SIGN_SUPPORTED: bool = false;
+    pub const SOC_ECC_EXTENDED_MODES_SUPPORTED: bool = false;
+    pub const SOC_ECC_SUPPORTED: bool = false;
+    pub const SOC_ECC_SUPPORT_POINT_VERIFY_QUIRK: bool = false;
+    pub const SOC_ECDSA_SUPPORTED: bool = false;
+    pub const SOC_ECDSA_SUPPORT_CURVE_P384: bool = false;
+    pub const SOC_ECDSA_SUPPORT_DETERMINISTIC_MODE: bool = false;
+    pub const SOC_ECDSA_SUPPORT_EXPORT_PUBKEY: bool = false;
+    pub const SOC_ECDSA_SUPPORT_HW_DETERMINISTIC_LOOP: bool = false;
+    pub const SOC_EFUSE_SUPPORTED: bool = true;
+    pub const SOC_EMAC_SUPPORTED: bool = true;
+    pub const SOC_ETM_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_SUPPORT_PSEUDO_ROUND: bool = false;
+    pub const SOC_FLASH_ENC_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORTED: bool = false;
+    pub const SOC_GDMA_SUPPORT_ETM: bool = false;
+    pub const SOC_GDMA_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_GPIO_FILTER_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_GPIO_SUPPORT_DEEPSLEEP_WAKEUP: bool = false;
+    pub const SOC_GPIO_SUPPORT_ETM: bool = false;
+    pub const SOC_GPIO_SUPPORT_FORCE_HOLD: bool = false;
+    pub const SOC_GPIO_SUPPORT_HOLD_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_SINGLE_IO_IN_DSLP: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_GLITCH_FILTER: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_CTRL_BY_EFUSE: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_FILTER: bool = false;
+    pub const SOC_GPIO_SUPPORT_RTC_INDEPENDENT: bool = false;
+    pub const SOC_GPSPI_SUPPORTED: bool = true;
+    pub const SOC_GPTIMER_SUPPORTED: bool = true;
+    pub const SOC_HMAC_SUPPORTED: bool = false;
+    pub const SOC_HUK_SUPPORTED: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_BROADCAST: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_I2CRAM_ACCESS: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_SLAVE_UNMATCH: bool = false;
+    pub const SOC_I2C_SUPPORTED: bool = true;
+    pub const SOC_I2C_SUPPORT_10BIT_ADDR: bool = true;
+    pub const SOC_I2C_SUPPORT_APB: bool = true;
+    pub const SOC_I2C_SUPPORT_HW_CLR_BUS: bool = false;
+    pub const SOC_I2C_SUPPORT_HW_FSM_RST: bool = false;
+    pub const SOC_I2C_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_I2C_SUPPORT_RTC: bool = false;
+    pub const SOC_I2C_SUPPORT_SLAVE: bool = true;
+    pub const SOC_I2C_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_I2C_SUPPORT_XTAL: bool = false;
+    pub const SOC_I2S_SUPPORTED: bool = true;
+    pub const SOC_I2S_SUPPORTS_ADC: bool = true;
+    pub const SOC_I2S_SUPPORTS_ADC_DAC: bool = true;
+    pub const SOC_I2S_SUPPORTS_APLL: bool = true;
+    pub const SOC_I2S_SUPPORTS_DAC: bool = true;
+    pub const SOC_I2S_SUPPORTS_DMA_EQUAL: bool = false;
+    pub const SOC_I2S_SUPPORTS_ETM: bool = false;
+    pub const SOC_I2S_SUPPORTS_LCD_CAMERA: bool = true;
+    pub const SOC_I2S_SUPPORTS_PCM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PCM2PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM2PCM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM_RX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM_TX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F120M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F160M: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F240M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F64M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F96M: bool = false;
+    pub const SOC_I2S_SUPPORTS_TDM: bool = false;
+    pub const SOC_I2S_SUPPORTS_TX_SYNC_CNT: bool = false;
+    pub const SOC_I2S_SUPPORTS_XTAL: bool = false;
+    pub const SOC_I2S_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_IEEE802154_SUPPORTED: bool = false;
+    pub const SOC_INT_CLIC_SUPPORTED: bool = false;
+    pub const SOC_INT_HW_NESTED_SUPPORTED: bool = false;
+    pub const SOC_INT_PLIC_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORT_KEY_DEPLOYMENT: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LCDCAM_I80_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_RGB_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_SUPPORTED: bool = false;
+    pub const SOC_LCD_I80_SUPPORTED: bool = true;
+    pub const SOC_LCD_RGB_SUPPORTED: bool = false;
+    pub const SOC_LCD_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_SUPPORTED: bool = false;
+    pub const SOC_LEDC_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORT_APB_CLOCK: bool = true;
+    pub const SOC_LEDC_SUPPORT_FADE_STOP: bool = false;
+    pub const SOC_LEDC_SUPPORT_HS_MODE: bool = true;
+    pub const SOC_LEDC_SUPPORT_PLL_DIV_CLOCK: bool = false;
+    pub const SOC_LEDC_SUPPORT_REF_TICK: bool = true;
+    pub const SOC_LEDC_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_LEDC_SUPPORT_XTAL_CLOCK: bool = false;
+    pub const SOC_LIGHT_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_LP_AON_SUPPORTED: bool = false;
+    pub const SOC_LP_CORE_SUPPORTED: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_ETM: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_STORE_LOAD_EXCEPTIONS: bool = false;
+    pub const SOC_LP_I2C_SUPPORTED: bool = false;
+    pub const SOC_LP_PERIPHERALS_SUPPORTED: bool = false;
+    pub const SOC_LP_TIMER_SUPPORTED: bool = false;
+    pub const SOC_MCPWM_SUPPORTED: bool = true;
+    pub const SOC_MCPWM_SUPPORT_ETM: bool = false;
+    pub const SOC_MCPWM_SUPPORT_EVENT_COMPARATOR: bool = false;
+    pub const SOC_MCPWM_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_MEMPROT_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_120M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_15M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_16M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_20M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_26M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_30M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_32M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_40M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_60M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_64M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_80M_SUPPORTED: bool = true;
+    pub const SOC_MMU_PAGE_SIZE_8KB_SUPPORTED: bool = false;
+    pub const SOC_MODEM_CLOCK_SUPPORTED: bool = false;
+    pub const SOC_MPI_SUPPORTED: bool = true;
+    pub const SOC_MPU_CONFIGURABLE_REGIONS_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_RO_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_WO_SUPPORTED: bool = false;
+    pub const SOC_MPU_SUPPORTED: bool = true;
+    pub const SOC_MWDT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_MWDT_SUPPORT_XTAL: bool = false;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_OUTPUT: bool = false;
+    pub const SOC_PARLIO_SUPPORTED: bool = false;
+    pub const SOC_PARLIO_SUPPORT_I80_LCD: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SPI_LCD: bool = false;
+    pub const SOC_PARLIO_TX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_EOF_FROM_DMA: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_LOOP_TRANSMISSION: bool = false;
+    pub const SOC_PAU_SUPPORTED: bool = false;
+    pub const SOC_PCNT_SUPPORTED: bool = true;
+    pub const SOC_PCNT_SUPPORT_CLEAR_SIGNAL: bool = false;
+    pub const SOC_PCNT_SUPPORT_RUNTIME_THRES_UPDATE: bool = false;
+    pub const SOC_PCNT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_PCNT_SUPPORT_STEP_NOTIFY: bool = false;
+    pub const SOC_PHY_SUPPORTED: bool = true;
+    pub const SOC_PMU_SUPPORTED: bool = false;
+    pub const SOC_PM_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORT_BEACON_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_BT_PD: bool = false;
+    pub const SOC_PM_SUPPORT_BT_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_CPU_PD: bool = false;
+    pub const SOC_PM_SUPPORT_DEEPSLEEP_CHECK_STUB_ONLY: bool = false;
+    pub const SOC_PM_SUPPORT_EXT0_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP_MODE_PER_PIN: bool = false;
+    pub const SOC_PM_SUPPORT_EXT_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_HP_AON_PD: bool = false;
+    pub const SOC_PM_SUPPORT_MAC_BB_PD: bool = false;
+    pub const SOC_PM_SUPPORT_MODEM_PD: bool = true;
+    pub const SOC_PM_SUPPORT_PMU_CLK_ICG: bool = false;
+    pub const SOC_PM_SUPPORT_PMU_MODEM_STATE: bool = false;
+    pub const SOC_PM_SUPPORT_RC32K_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RC_FAST_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_FAST_MEM_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_PERIPH_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_SLOW_MEM_PD: bool = true;
+    pub const SOC_PM_SUPPORT_TAGMEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOP_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOUCH_SENSOR_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_VDDSDIO_PD: bool = true;
+    pub const SOC_PM_SUPPORT_WIFI_PD: bool = false;
+    pub const SOC_PM_SUPPORT_WIFI_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_XTAL32K_PD: bool = false;
+    pub const SOC_RECOVERY_BOOTLOADER_SUPPORTED: bool = false;
+    pub const SOC_REG_I2C_SUPPORTED: bool = false;
+    pub const SOC_RISCV_COPROC_SUPPORTED: bool = false;
+    pub const SOC_RMT_SUPPORTED: bool = true;
+    pub const SOC_RMT_SUPPORT_APB: bool = true;
+    pub const SOC_RMT_SUPPORT_DMA: bool = false;
+    pub const SOC_RMT_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_RMT_SUPPORT_REF_TICK: bool = true;
+    pub const SOC_RMT_SUPPORT_RX_DEMODULATION: bool = false;
+    pub const SOC_RMT_SUPPORT_RX_PINGPONG: bool = false;
+    pub const SOC_RMT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_ASYNC_STOP: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_CARRIER_DATA_ONLY: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_AUTO_STOP: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_COUNT: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_SYNCHRO: bool = false;
+    pub const SOC_RMT_SUPPORT_XTAL: bool = false;
+    pub const SOC_RNG_SUPPORTED: bool = true;
+    pub const SOC_RSA_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_EDGE_WAKE_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_HOLD_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_INPUT_OUTPUT_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_WAKE_SUPPORTED: bool = true;
+    pub const SOC_RTC_FAST_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORT_SPEED_MODE_SWITCH: bool = false;
+    pub const SOC_RTC_SLOW_CLK_SUPPORT_RC_FAST_D256: bool = true;
+    pub const SOC_RTC_SLOW_MEM_SUPPORTED: bool = true;
+    pub const SOC_SDIO_SLAVE_SUPPORTED: bool = true;
+    pub const SOC_SDMMC_HOST_SUPPORTED: bool = true;
+    pub const SOC_SDMMC_SUPPORT_XTAL_CLOCK: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_APB: bool = true;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F48M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F80M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_XTAL: bool = false;
+    pub const SOC_SDM_SUPPORTED: bool = true;
+    pub const SOC_SECURE_BOOT_SUPPORTED: bool = true;
+    pub const SOC_SHARED_IDCACHE_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORT_DMA: bool = false;
+    pub const SOC_SHA_SUPPORT_PARALLEL_ENG: bool = true;
+    pub const SOC_SHA_SUPPORT_RESUME: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA1: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA224: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA256: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA384: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512_224: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_256: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_T: bool = false;
+    pub const SOC_SIMD_INSTRUCTION_SUPPORTED: bool = false;
+    pub const SOC_SPIRAM_SUPPORTED: bool = true;
+    pub const SOC_SPIRAM_XIP_SUPPORTED: bool = false;
+    pub const SOC_SPI_AS_CS_SUPPORTED: bool = true;
+    pub const SOC_SPI_FLASH_SUPPORTED: bool = true;
+    pub const SOC_SPI_HD_BOTH_INOUT_SUPPORTED: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_RESUME: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_SUSPEND: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_WAIT_IDLE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_CACHE_32BIT_ADDR_MAP: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_CHECK_SUS: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_CONFIG_GPIO_BY_EFUSE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_FLASH_OPI_MODE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_IDLE_INTR: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_SW_SUSPEND: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_TIMING_TUNING: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_TSUS_TRES_SEPERATE_CTR: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WB_MODE_INDEPENDENT_CONTROL: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WRAP: bool = false;
+    pub const SOC_SPI_PERIPH_SUPPORT_CONTROL_DUMMY_OUT: bool = false;
+    pub const SOC_SPI_SCT_SUPPORTED: bool = false;
+    pub const SOC_SPI_SLAVE_SUPPORT_SEG_TRANS: bool = false;
+    pub const SOC_SPI_SUPPORT_CD_SIG: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_APB: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_PLL: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F160M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F40M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F48M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F80M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_RC_FAST: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_XTAL: bool = false;
+    pub const SOC_SPI_SUPPORT_CONTINUOUS_TRANS: bool = false;
+    pub const SOC_SPI_SUPPORT_DDRCLK: bool = false;
+    pub const SOC_SPI_SUPPORT_OCT: bool = false;
+    pub const SOC_SPI_SUPPORT_SLAVE_HD_VER2: bool = false;
+    pub const SOC_SPI_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_SUPPORTS_SECURE_DL_MODE: bool = false;
+    pub const SOC_SUPPORT_COEXISTENCE: bool = true;
+    pub const SOC_SUPPORT_SECURE_BOOT_REVOKE_KEY: bool = false;
+    pub const SOC_SYSTIMER_SUPPORTED: bool = false;
+    pub const SOC_SYSTIMER_SUPPORT_ETM: bool = false;
+    pub const SOC_SYSTIMER_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_INTR_SUPPORT: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_ETM: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_FAST_RC: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_XTAL: bool = false;
+    pub const SOC_TEMP_SENSOR_SUPPORTED: bool = false;
+    pub const SOC_TIMER_GROUP_SUPPORT_APB: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_TIMER_GROUP_SUPPORT_XTAL: bool = false;
+    pub const SOC_TIMER_SUPPORT_ETM: bool = false;
+    pub const SOC_TIMER_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TOUCH_PROXIMITY_MEAS_DONE_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SENSOR_SUPPORTED: bool = true;
+    pub const SOC_TOUCH_SUPPORT_BENCHMARK: bool = false;
+    pub const SOC_TOUCH_SUPPORT_DENOISE_CHAN: bool = false;
+    pub const SOC_TOUCH_SUPPORT_PROX_SENSING: bool = false;
+    pub const SOC_TOUCH_SUPPORT_SLEEP_WAKEUP: bool = true;
+    pub const SOC_TOUCH_SUPPORT_WATERPROOF: bool = false;
+    pub const SOC_TWAI_CLK_SUPPORT_APB: bool = true;
+    pub const SOC_TWAI_CLK_SUPPORT_XTAL: bool = false;
+    pub const SOC_TWAI_SUPPORTED: bool = true;
+    pub const SOC_TWAI_SUPPORTS_RX_STATUS: bool = false;
+    pub const SOC_TWAI_SUPPORT_FD: bool = false;
+    pub const SOC_TWAI_SUPPORT_MULTI_ADDRESS_LAYOUT: bool = true;
+    pub const SOC_TWAI_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TWAI_SUPPORT_TIMESTAMP: bool = false;
+    pub const SOC_UART_SUPPORTED: bool = true;
+    pub const SOC_UART_SUPPORT_APB_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_FSM_TX_WAIT_SEND: bool = false;
+    pub const SOC_UART_SUPPORT_PLL_F40M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_PLL_F80M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_REF_TICK: bool = true;
+    pub const SOC_UART_SUPPORT_RTC_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_UART_SUPPORT_WAKEUP_INT: bool = false;
+    pub const SOC_UART_SUPPORT_XTAL_CLK: bool = false;
+    pub const SOC_UART_WAKEUP_SUPPORT_ACTIVE_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_CHAR_SEQ_MODE: bool = false;
+    pub const SOC_UART_WAKEUP_SUPPORT_FIFO_THRESH_MODE: bool = false;
+    pub const SOC_UART_WAKEUP_SUPPORT_START_BIT_MODE: bool = false;
+    pub const SOC_UHCI_SUPPORTED: bool = false;
+    pub const SOC_ULP_FSM_SUPPORTED: bool = true;
+    pub const SOC_ULP_LP_UART_SUPPORTED: bool = false;
+    pub const SOC_ULP_SUPPORTED: bool = true;
+    pub const SOC_USB_OTG_SUPPORTED: bool = false;
+    pub const SOC_USB_SERIAL_JTAG_SUPPORTED: bool = false;
+    pub const SOC_VBAT_SUPPORTED: bool = false;
+    pub const SOC_WDT_SUPPORTED: bool = true;
+    pub const SOC_WIFI_CSI_SUPPORT: bool = true;
+    pub const SOC_WIFI_FTM_SUPPORT: bool = false;
+    pub const SOC_WIFI_GCMP_SUPPORT: bool = false;
+    pub const SOC_WIFI_HE_SUPPORT: bool = false;
+    pub const SOC_WIFI_MESH_SUPPORT: bool = true;
+    pub const SOC_WIFI_NAN_SUPPORT: bool = true;
+    pub const SOC_WIFI_SUPPORTED: bool = true;
+    pub const SOC_WIFI_SUPPORT_5G: bool = false;
+    pub const SOC_WIFI_SUPPORT_VARIABLE_BEACON_WINDOW: bool = true;
+    pub const SOC_WIFI_WAPI_SUPPORT: bool = true;
+    pub const SOC_XTAL_SUPPORT_26M: bool = true;
+    pub const SOC_XTAL_SUPPORT_32M: bool = false;
+    pub const SOC_XTAL_SUPPORT_40M: bool = true;
+    pub const SOC_XTAL_SUPPORT_48M: bool = false;
+    pub const SOC_XTAL_SUPPORT_AUTO_DETECT: bool = true;
+    pub const SOC_XT_WDT_SUPPORTED: bool = false;
+    pub const SOC_ADC_ATTEN_NUM: u32 = 4;
+    pub const SOC_ADC_DIGI_CONTROLLER_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_DATA_BYTES_PER_CONV: u32 = 4;
+    pub const SOC_ADC_DIGI_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MIN_BITWIDTH: u32 = 9;
+    pub const SOC_ADC_DIGI_MONITOR_NUM: u32 = 0;
+    pub const SOC_ADC_DIGI_RESULT_BYTES: u32 = 2;
+    pub const SOC_ADC_MAX_CHANNEL_NUM: u32 = 10;
+    pub const SOC_ADC_PATT_LEN_MAX: u32 = 16;
+    pub const SOC_ADC_PERIPH_NUM: u32 = 2;
+    pub const SOC_ADC_RTC_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_RTC_MIN_BITWIDTH: u32 = 9;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_HIGH: u32 = 2000000;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_LOW: u32 = 20000;
+    pub const SOC_ADC_SHARED_POWER: u32 = 1;
+    pub const SOC_BLE_MULTI_CONN_OPTIMIZATION: u32 = 1;
+    pub const SOC_CAPS_ECO_VER: u32 = 0;
+    pub const SOC_CAPS_ECO_VER_MAX: u32 = 301;
+    pub const SOC_CPU_BREAKPOINTS_NUM: u32 = 2;
+    pub const SOC_CPU_CORES_NUM: u32 = 2;
+    pub const SOC_CPU_HAS_FPU: u32 = 1;
+    pub const SOC_CPU_INTR_NUM: u32 = 32;
+    pub const SOC_CPU_WATCHPOINTS_NUM: u32 = 2;
+    pub const SOC_CPU_WATCHPOINT_MAX_REGION_SIZE: u32 = 0x40;
+    pub const SOC_DAC_CHAN_NUM: u32 = 2;
+    pub const SOC_DAC_DMA_16BIT_ALIGN: u32 = 1;
+    pub const SOC_DAC_RESOLUTION: u32 = 8;
+    pub const SOC_DPORT_WORKAROUND: u32 = 1;
+    pub const SOC_DPORT_WORKAROUND_DIS_INTERRUPT_LVL: u32 = 5;
+    pub const SOC_EFUSE_SECURE_BOOT_KEY_DIGESTS: u32 = 1;
+    pub const SOC_EMAC_RMII_CLK_OUT_INTERNAL_LOOPBACK: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTED_XTS_AES_BLOCK_MAX: u32 = 32;
+    pub const SOC_GPIO_CLOCKOUT_BY_IO_MUX: u32 = 1;
+    pub const SOC_GPIO_CLOCKOUT_CHANNEL_NUM: u32 = 3;
+    pub const SOC_GPIO_IN_RANGE_MAX: u32 = 39;
+    pub const SOC_GPIO_OUT_RANGE_MAX: u32 = 33;
+    pub const SOC_GPIO_PIN_COUNT: u32 = 40;
+    pub const SOC_GPIO_PORT: u32 = 1;
+    pub const SOC_GPIO_VALID_DIGITAL_IO_PAD_MASK: u64 = 0xef0fea;
+    pub const SOC_GPIO_VALID_GPIO_MASK: u64 = 0xff0effffff;
+    pub const SOC_GPIO_VALID_OUTPUT_GPIO_MASK: u64 = 0x30effffff;
+    pub const SOC_HP_CPU_HAS_MULTIPLE_CORES: u32 = 1;
+    pub const SOC_HP_I2C_NUM: u32 = 2;
+    pub const SOC_I2C_CMD_REG_NUM: u32 = 16;
+    pub const SOC_I2C_FIFO_LEN: u32 = 32;
+    pub const SOC_I2C_NUM: u32 = 2;
+    pub const SOC_I2C_STOP_INDEPENDENT: u32 = 1;
+    pub const SOC_I2S_HW_VERSION_1: u32 = 1;
+    pub const SOC_I2S_LCD_I80_VARIANT: u32 = 1;
+    pub const SOC_I2S_MAX_DATA_WIDTH: u32 = 24;
+    pub const SOC_I2S_NUM: u32 = 2;
+    pub const SOC_I2S_PDM_MAX_RX_LINES: u32 = 1;
+    pub const SOC_I2S_PDM_MAX_TX_LINES: u32 = 1;
+    pub const SOC_I2S_TRANS_SIZE_ALIGN_WORD: u32 = 1;
+    pub const SOC_IDCACHE_PER_CORE: u32 = 1;
+    pub const SOC_LCD_I80_BUSES: u32 = 2;
+    pub const SOC_LCD_I80_BUS_WIDTH: u32 = 24;
+    pub const SOC_LEDC_CHANNEL_NUM: u32 = 8;
+    pub const SOC_LEDC_HAS_TIMER_SPECIFIC_MUX: u32 = 1;
+    pub const SOC_LEDC_TIMER_BIT_WIDTH: u32 = 20;
+    pub const SOC_LEDC_TIMER_NUM: u32 = 4;
+    pub const SOC_LP_PERIPH_SHARE_INTERRUPT: u32 = 1;
+    pub const SOC_LP_TIMER_BIT_WIDTH_HI: u32 = 16;
+    pub const SOC_LP_TIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_MCPWM_CAPTURE_CHANNELS_PER_TIMER: u32 = 3;
+    pub const SOC_MCPWM_CAPTURE_TIMERS_PER_GROUP: u32 = 1;
+    pub const SOC_MCPWM_COMPARATORS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MCPWM_GENERATORS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MCPWM_GPIO_FAULTS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_GPIO_SYNCHROS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_GROUPS: u32 = 2;
+    pub const SOC_MCPWM_OPERATORS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_TIMERS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_TRIGGERS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MMU_LINEAR_ADDRESS_REGION_NUM: u32 = 3;
+    pub const SOC_MMU_PERIPH_NUM: u32 = 2;
+    pub const SOC_MPI_MEM_BLOCKS_NUM: u32 = 4;
+    pub const SOC_MPI_OPERATIONS_NUM: u32 = 1;
+    pub const SOC_MPU_MIN_REGION_SIZE: u32 = 0x20000000;
+    pub const SOC_MPU_REGIONS_MAX_NUM: u32 = 8;
+    pub const SOC_PCNT_CHANNELS_PER_UNIT: u32 = 2;
+    pub const SOC_PCNT_GROUPS: u32 = 1;
+    pub const SOC_PCNT_THRES_POINT_PER_UNIT: u32 = 2;
+    pub const SOC_PCNT_UNITS_PER_GROUP: u32 = 8;
+    pub const SOC_PHY_COMBO_MODULE: u32 = 1;
+    pub const SOC_PHY_DIG_REGS_MEM_SIZE: u32 = 84;
+    pub const SOC_PM_MODEM_PD_BY_SW: u32 = 1;
+    pub const SOC_RMT_CHANNELS_PER_GROUP: u32 = 8;
+    pub const SOC_RMT_CHANNEL_CLK_INDEPENDENT: u32 = 1;
+    pub const SOC_RMT_GROUPS: u32 = 1;
+    pub const SOC_RMT_MEM_WORDS_PER_CHANNEL: u32 = 64;
+    pub const SOC_RMT_RX_CANDIDATES_PER_GROUP: u32 = 8;
+    pub const SOC_RMT_TX_CANDIDATES_PER_GROUP: u32 = 8;
+    pub const SOC_RSA_MAX_BIT_LEN: u32 = 4096;
+    pub const SOC_RTCIO_PIN_COUNT: u32 = 18;
+    pub const SOC_SDMMC_NUM_SLOTS: u32 = 2;
+    pub const SOC_SDMMC_USE_IOMUX: u32 = 1;
+    pub const SOC_SDM_CHANNELS_PER_GROUP: u32 = 8;
+    pub const SOC_SDM_GROUPS: u32 = 1;
+    pub const SOC_SECURE_BOOT_V1: u32 = 1;
+    pub const SOC_SHA_ENDIANNESS_BE: u32 = 1;
+    pub const SOC_SPI_DMA_CHAN_NUM: u32 = 2;
+    pub const SOC_SPI_MAXIMUM_BUFFER_SIZE: u32 = 64;
+    pub const SOC_SPI_MAX_CS_NUM: u32 = 3;
+    pub const SOC_SPI_MAX_PRE_DIVIDER: u32 = 8192;
+    pub const SOC_SPI_PERIPH_NUM: u32 = 3;
+    pub const SOC_TIMER_GROUPS: u32 = 2;
+    pub const SOC_TIMER_GROUP_COUNTER_BIT_WIDTH: u32 = 64;
+    pub const SOC_TIMER_GROUP_TIMERS_PER_GROUP: u32 = 2;
+    pub const SOC_TIMER_GROUP_TOTAL_TIMERS: u32 = 4;
+    pub const SOC_TOUCH_MAX_CHAN_ID: u32 = 9;
+    pub const SOC_TOUCH_MIN_CHAN_ID: u32 = 0;
+    pub const SOC_TOUCH_SAMPLE_CFG_NUM: u32 = 1;
+    pub const SOC_TOUCH_SENSOR_NUM: u32 = 10;
+    pub const SOC_TOUCH_SENSOR_VERSION: u32 = 1;
+    pub const SOC_TWAI_BRP_MAX: u32 = 128;
+    pub const SOC_TWAI_BRP_MIN: u32 = 2;
+    pub const SOC_TWAI_CONTROLLER_NUM: u32 = 1;
+    pub const SOC_TWAI_MASK_FILTER_NUM: u32 = 1;
+    pub const SOC_UART_BITRATE_MAX: u32 = 5000000;
+    pub const SOC_UART_FIFO_LEN: u32 = 128;
+    pub const SOC_UART_HP_NUM: u32 = 3;
+    pub const SOC_UART_NUM: u32 = 3;
+    pub const SOC_ULP_HAS_ADC: u32 = 1;
+}
+
+pub mod esp32s2 {
+    pub const SOC_ADC_ARBITER_SUPPORTED: bool = true;
+    pub const SOC_ADC_CALIBRATION_V1_SUPPORTED: bool = true;
+    pub const SOC_ADC_CALIB_CHAN_COMPENS_SUPPORTED: bool = false;
+    pub const SOC_ADC_DIG_CTRL_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_IIR_FILTER_SUPPORTED: bool = true;
+    pub const SOC_ADC_DMA_SUPPORTED: bool = true;
+    pub const SOC_ADC_MONITOR_SUPPORTED: bool = true;
+    pub const SOC_ADC_RTC_CTRL_SUPPORTED: bool = true;
+    pub const SOC_ADC_SELF_HW_CALI_SUPPORTED: bool = true;
+    pub const SOC_ADC_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORT_AES_128: bool = true;
+    pub const SOC_AES_SUPPORT_AES_192: bool = true;
+    pub const SOC_AES_SUPPORT_AES_256: bool = true;
+    pub const SOC_AES_SUPPORT_DMA: bool = true;
+    pub const SOC_AES_SUPPORT_GCM: bool = true;
+    pub const SOC_AES_SUPPORT_PSEUDO_ROUND_FUNCTION: bool = false;
+    pub const SOC_AHB_GDMA_SUPPORTED: bool = false;
+    pub const SOC_AHB_GDMA_SUPPORT_PSRAM: bool = false;
+    pub const SOC_ANA_CMPR_SUPPORTED: bool = false;
+    pub const SOC_ANA_CMPR_SUPPORT_ETM: bool = false;
+    pub const SOC_APM_CPU_APM_SUPPORTED: bool = false;
+    pub const SOC_APM_CTRL_FILTER_SUPPORTED: bool = false;
+    pub const SOC_APM_LP_APM0_SUPPORTED: bool = false;
+    pub const SOC_APM_SUPPORTED: bool = false;
+    pub const SOC_APM_SUPPORT_CTRL_CFG_LOCK: bool = false;
+    pub const SOC_APM_SUPPORT_LP_TEE_CTRL: bool = false;
+    pub const SOC_APM_SUPPORT_TEE_PERI_ACCESS_CTRL: bool = false;
+    pub const SOC_ASSIST_DEBUG_SUPPORTED: bool = false;
+    pub const SOC_ASYNC_MEMCPY_SUPPORTED: bool = true;
+    pub const SOC_BITSCRAMBLER_SUPPORTED: bool = false;
+    pub const SOC_BLE_50_SUPPORTED: bool = false;
+    pub const SOC_BLE_CTE_SUPPORTED: bool = false;
+    pub const SOC_BLE_DEVICE_PRIVACY_SUPPORTED: bool = false;
+    pub const SOC_BLE_MESH_SUPPORTED: bool = false;
+    pub const SOC_BLE_PERIODIC_ADV_ENH_SUPPORTED: bool = false;
+    pub const SOC_BLE_POWER_CONTROL_SUPPORTED: bool = false;
+    pub const SOC_BLE_SUPPORTED: bool = false;
+    pub const SOC_BLUFI_SUPPORTED: bool = false;
+    pub const SOC_BOD_SUPPORTED: bool = true;
+    pub const SOC_BOOTLOADER_ANTI_ROLLBACK_SUPPORTED: bool = false;
+    pub const SOC_BRANCH_PREDICTOR_SUPPORTED: bool = false;
+    pub const SOC_BROWNOUT_RESET_SUPPORTED: bool = true;
+    pub const SOC_BT_CLASSIC_SUPPORTED: bool = false;
+    pub const SOC_BT_H2C_ENC_KEY_CTRL_ENH_VSC_SUPPORTED: bool = false;
+    pub const SOC_BT_SUPPORTED: bool = false;
+    pub const SOC_CACHE_FREEZE_SUPPORTED: bool = false;
+    pub const SOC_CACHE_SUPPORT_WRAP: bool = true;
+    pub const SOC_CACHE_WRITEBACK_SUPPORTED: bool = true;
+    pub const SOC_CCOMP_TIMER_SUPPORTED: bool = true;
+    pub const SOC_CLK_APLL_SUPPORTED: bool = true;
+    pub const SOC_CLK_LP_FAST_SUPPORT_LP_PLL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D2: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D4: bool = true;
+    pub const SOC_CLK_OSC_SLOW_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC32K_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC_FAST_D256_SUPPORTED: bool = true;
+    pub const SOC_CLK_RC_FAST_SUPPORT_CALIBRATION: bool = true;
+    pub const SOC_CLK_TREE_SUPPORTED: bool = true;
+    pub const SOC_CLK_XTAL32K_SUPPORTED: bool = true;
+    pub const SOC_CLOCK_TREE_MANAGEMENT_SUPPORTED: bool = false;
+    pub const SOC_CONFIGURABLE_VDDSDIO_SUPPORTED: bool = true;
+    pub const SOC_CPU_SUPPORT_WFE: bool = false;
+    pub const SOC_CP_DMA_SUPPORTED: bool = true;
+    pub const SOC_CRYPTO_DPA_PROTECTION_SUPPORTED: bool = false;
+    pub const SOC_DAC_SUPPORTED: bool = true;
+    pub const SOC_DEDICATED_GPIO_SUPPORTED: bool = true;
+    pub const SOC_DEEP_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_DIG_SIGN_SUPPORTED: bool = true;
+    pub const SOC_ECC_EXTENDED_MODES_SUPPORTED: bool = false;
+    pub const SOC_ECC_SUPPORTED: bool = false;
+    pub const SOC_ECC_SUPPORT_POINT_VERIFY_QUIRK: bool = false;
+    pub const SOC_ECDSA_SUPPORTED: bool = false;
+    pub const SOC_ECDSA_SUPPORT_CURVE_P384: bool = false;
+    pub const SOC_ECDSA_SUPPORT_DETERMINISTIC_MODE: bool = false;
+    pub const SOC_ECDSA_SUPPORT_EXPORT_PUBKEY: bool = false;
+    pub const SOC_ECDSA_SUPPORT_HW_DETERMINISTIC_LOOP: bool = false;
+    pub const SOC_EFUSE_SUPPORTED: bool = true;
+    pub const SOC_EMAC_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_SUPPORT_PSEUDO_ROUND: bool = false;
+    pub const SOC_FLASH_ENC_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORTED: bool = false;
+    pub const SOC_GDMA_SUPPORT_ETM: bool = false;
+    pub const SOC_GDMA_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_GPIO_FILTER_CLK_SUPPORT_APB: bool = true;
+    pub const SOC_GPIO_SUPPORT_DEEPSLEEP_WAKEUP: bool = false;
+    pub const SOC_GPIO_SUPPORT_ETM: bool = false;
+    pub const SOC_GPIO_SUPPORT_FORCE_HOLD: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_SINGLE_IO_IN_DSLP: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_GLITCH_FILTER: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_CTRL_BY_EFUSE: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_FILTER: bool = false;
+    pub const SOC_GPIO_SUPPORT_RTC_INDEPENDENT: bool = true;
+    pub const SOC_GPSPI_SUPPORTED: bool = true;
+    pub const SOC_GPTIMER_SUPPORTED: bool = true;
+    pub const SOC_HMAC_SUPPORTED: bool = true;
+    pub const SOC_HUK_SUPPORTED: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_BROADCAST: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_I2CRAM_ACCESS: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_SLAVE_UNMATCH: bool = false;
+    pub const SOC_I2C_SUPPORTED: bool = true;
+    pub const SOC_I2C_SUPPORT_10BIT_ADDR: bool = false;
+    pub const SOC_I2C_SUPPORT_APB: bool = true;
+    pub const SOC_I2C_SUPPORT_HW_CLR_BUS: bool = true;
+    pub const SOC_I2C_SUPPORT_HW_FSM_RST: bool = false;
+    pub const SOC_I2C_SUPPORT_REF_TICK: bool = true;
+    pub const SOC_I2C_SUPPORT_RTC: bool = false;
+    pub const SOC_I2C_SUPPORT_SLAVE: bool = true;
+    pub const SOC_I2C_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_I2C_SUPPORT_XTAL: bool = false;
+    pub const SOC_I2S_SUPPORTED: bool = true;
+    pub const SOC_I2S_SUPPORTS_ADC: bool = false;
+    pub const SOC_I2S_SUPPORTS_ADC_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_APLL: bool = true;
+    pub const SOC_I2S_SUPPORTS_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_DMA_EQUAL: bool = true;
+    pub const SOC_I2S_SUPPORTS_ETM: bool = false;
+    pub const SOC_I2S_SUPPORTS_LCD_CAMERA: bool = true;
+    pub const SOC_I2S_SUPPORTS_PCM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PCM2PDM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM2PCM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM_RX: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM_TX: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F120M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F160M: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F240M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F64M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F96M: bool = false;
+    pub const SOC_I2S_SUPPORTS_TDM: bool = false;
+    pub const SOC_I2S_SUPPORTS_TX_SYNC_CNT: bool = false;
+    pub const SOC_I2S_SUPPORTS_XTAL: bool = false;
+    pub const SOC_I2S_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_IEEE802154_SUPPORTED: bool = false;
+    pub const SOC_INT_CLIC_SUPPORTED: bool = false;
+    pub const SOC_INT_HW_NESTED_SUPPORTED: bool = false;
+    pub const SOC_INT_PLIC_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORT_KEY_DEPLOYMENT: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LCDCAM_I80_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_RGB_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_SUPPORTED: bool = false;
+    pub const SOC_LCD_I80_SUPPORTED: bool = true;
+    pub const SOC_LCD_RGB_SUPPORTED: bool = false;
+    pub const SOC_LCD_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_SUPPORTED: bool = false;
+    pub const SOC_LEDC_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORT_APB_CLOCK: bool = true;
+    pub const SOC_LEDC_SUPPORT_FADE_STOP: bool = true;
+    pub const SOC_LEDC_SUPPORT_HS_MODE: bool = false;
+    pub const SOC_LEDC_SUPPORT_PLL_DIV_CLOCK: bool = false;
+    pub const SOC_LEDC_SUPPORT_REF_TICK: bool = true;
+    pub const SOC_LEDC_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_LEDC_SUPPORT_XTAL_CLOCK: bool = true;
+    pub const SOC_LIGHT_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_LP_AON_SUPPORTED: bool = false;
+    pub const SOC_LP_CORE_SUPPORTED: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_ETM: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_STORE_LOAD_EXCEPTIONS: bool = false;
+    pub const SOC_LP_I2C_SUPPORTED: bool = false;
+    pub const SOC_LP_PERIPHERALS_SUPPORTED: bool = false;
+    pub const SOC_LP_TIMER_SUPPORTED: bool = false;
+    pub const SOC_MCPWM_SUPPORTED: bool = false;
+    pub const SOC_MCPWM_SUPPORT_ETM: bool = false;
+    pub const SOC_MCPWM_SUPPORT_EVENT_COMPARATOR: bool = false;
+    pub const SOC_MCPWM_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_MEMPROT_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_120M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_15M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_16M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_20M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_26M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_30M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_32M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_40M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_60M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_64M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_80M_SUPPORTED: bool = true;
+    pub const SOC_MMU_PAGE_SIZE_8KB_SUPPORTED: bool = false;
+    pub const SOC_MODEM_CLOCK_SUPPORTED: bool = false;
+    pub const SOC_MPI_SUPPORTED: bool = true;
+    pub const SOC_MPU_CONFIGURABLE_REGIONS_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_RO_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_WO_SUPPORTED: bool = false;
+    pub const SOC_MPU_SUPPORTED: bool = true;
+    pub const SOC_MWDT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_MWDT_SUPPORT_XTAL: bool = false;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_OUTPUT: bool = false;
+    pub const SOC_PARLIO_SUPPORTED: bool = false;
+    pub const SOC_PARLIO_SUPPORT_I80_LCD: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SPI_LCD: bool = false;
+    pub const SOC_PARLIO_TX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_EOF_FROM_DMA: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_LOOP_TRANSMISSION: bool = false;
+    pub const SOC_PAU_SUPPORTED: bool = false;
+    pub const SOC_PCNT_SUPPORTED: bool = true;
+    pub const SOC_PCNT_SUPPORT_CLEAR_SIGNAL: bool = false;
+    pub const SOC_PCNT_SUPPORT_RUNTIME_THRES_UPDATE: bool = false;
+    pub const SOC_PCNT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_PCNT_SUPPORT_STEP_NOTIFY: bool = false;
+    pub const SOC_PHY_SUPPORTED: bool = true;
+    pub const SOC_PMU_SUPPORTED: bool = false;
+    pub const SOC_PM_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORT_BEACON_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_BT_PD: bool = false;
+    pub const SOC_PM_SUPPORT_BT_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_CPU_PD: bool = false;
+    pub const SOC_PM_SUPPORT_DEEPSLEEP_CHECK_STUB_ONLY: bool = false;
+    pub const SOC_PM_SUPPORT_EXT0_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP_MODE_PER_PIN: bool = false;
+    pub const SOC_PM_SUPPORT_EXT_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_HP_AON_PD: bool = false;
+    pub const SOC_PM_SUPPORT_MAC_BB_PD: bool = false;
+    pub const SOC_PM_SUPPORT_MODEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_PMU_CLK_ICG: bool = false;
+    pub const SOC_PM_SUPPORT_PMU_MODEM_STATE: bool = false;
+    pub const SOC_PM_SUPPORT_RC32K_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RC_FAST_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_FAST_MEM_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_PERIPH_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_SLOW_MEM_PD: bool = true;
+    pub const SOC_PM_SUPPORT_TAGMEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOP_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOUCH_SENSOR_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_VDDSDIO_PD: bool = true;
+    pub const SOC_PM_SUPPORT_WIFI_PD: bool = true;
+    pub const SOC_PM_SUPPORT_WIFI_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_XTAL32K_PD: bool = false;
+    pub const SOC_RECOVERY_BOOTLOADER_SUPPORTED: bool = false;
+    pub const SOC_REG_I2C_SUPPORTED: bool = false;
+    pub const SOC_RISCV_COPROC_SUPPORTED: bool = true;
+    pub const SOC_RMT_SUPPORTED: bool = true;
+    pub const SOC_RMT_SUPPORT_APB: bool = true;
+    pub const SOC_RMT_SUPPORT_DMA: bool = false;
+    pub const SOC_RMT_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_RMT_SUPPORT_REF_TICK: bool = true;
+    pub const SOC_RMT_SUPPORT_RX_DEMODULATION: bool = true;
+    pub const SOC_RMT_SUPPORT_RX_PINGPONG: bool = false;
+    pub const SOC_RMT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_ASYNC_STOP: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_CARRIER_DATA_ONLY: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_AUTO_STOP: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_COUNT: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_SYNCHRO: bool = true;
+    pub const SOC_RMT_SUPPORT_XTAL: bool = false;
+    pub const SOC_RNG_SUPPORTED: bool = true;
+    pub const SOC_RSA_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_EDGE_WAKE_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_HOLD_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_INPUT_OUTPUT_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_WAKE_SUPPORTED: bool = true;
+    pub const SOC_RTC_FAST_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORT_SPEED_MODE_SWITCH: bool = false;
+    pub const SOC_RTC_SLOW_CLK_SUPPORT_RC_FAST_D256: bool = true;
+    pub const SOC_RTC_SLOW_MEM_SUPPORTED: bool = true;
+    pub const SOC_SDIO_SLAVE_SUPPORTED: bool = false;
+    pub const SOC_SDMMC_HOST_SUPPORTED: bool = false;
+    pub const SOC_SDMMC_SUPPORT_XTAL_CLOCK: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_APB: bool = true;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F48M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F80M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_XTAL: bool = false;
+    pub const SOC_SDM_SUPPORTED: bool = true;
+    pub const SOC_SECURE_BOOT_SUPPORTED: bool = true;
+    pub const SOC_SHARED_IDCACHE_SUPPORTED: bool = false;
+    pub const SOC_SHA_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORT_DMA: bool = true;
+    pub const SOC_SHA_SUPPORT_PARALLEL_ENG: bool = false;
+    pub const SOC_SHA_SUPPORT_RESUME: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA1: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA224: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA256: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA384: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512_224: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512_256: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512_T: bool = true;
+    pub const SOC_SIMD_INSTRUCTION_SUPPORTED: bool = false;
+    pub const SOC_SPIRAM_SUPPORTED: bool = true;
+    pub const SOC_SPIRAM_XIP_SUPPORTED: bool = true;
+    pub const SOC_SPI_AS_CS_SUPPORTED: bool = false;
+    pub const SOC_SPI_FLASH_SUPPORTED: bool = true;
+    pub const SOC_SPI_HD_BOTH_INOUT_SUPPORTED: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_RESUME: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_SUSPEND: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_WAIT_IDLE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CACHE_32BIT_ADDR_MAP: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_CHECK_SUS: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_CONFIG_GPIO_BY_EFUSE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_FLASH_OPI_MODE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_IDLE_INTR: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_SW_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_TIMING_TUNING: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_TSUS_TRES_SEPERATE_CTR: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WB_MODE_INDEPENDENT_CONTROL: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WRAP: bool = true;
+    pub const SOC_SPI_PERIPH_SUPPORT_CONTROL_DUMMY_OUT: bool = true;
+    pub const SOC_SPI_SCT_SUPPORTED: bool = true;
+    pub const SOC_SPI_SLAVE_SUPPORT_SEG_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_CD_SIG: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_APB: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_PLL: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F160M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F40M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F48M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F80M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_RC_FAST: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_XTAL: bool = false;
+    pub const SOC_SPI_SUPPORT_CONTINUOUS_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_DDRCLK: bool = true;
+    pub const SOC_SPI_SUPPORT_OCT: bool = true;
+    pub const SOC_SPI_SUPPORT_SLAVE_HD_VER2: bool = true;
+    pub const SOC_SPI_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_SUPPORTS_SECURE_DL_MODE: bool = true;
+    pub const SOC_SUPPORT_COEXISTENCE: bool = false;
+    pub const SOC_SUPPORT_SECURE_BOOT_REVOKE_KEY: bool = true;
+    pub const SOC_SYSTIMER_SUPPORTED: bool = true;
+    pub const SOC_SYSTIMER_SUPPORT_ETM: bool = false;
+    pub const SOC_SYSTIMER_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_INTR_SUPPORT: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_ETM: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_FAST_RC: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_XTAL: bool = false;
+    pub const SOC_TEMP_SENSOR_SUPPORTED: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_APB: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_TIMER_GROUP_SUPPORT_XTAL: bool = true;
+    pub const SOC_TIMER_SUPPORT_ETM: bool = false;
+    pub const SOC_TIMER_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TOUCH_PROXIMITY_MEAS_DONE_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SENSOR_SUPPORTED: bool = true;
+    pub const SOC_TOUCH_SUPPORT_BENCHMARK: bool = true;
+    pub const SOC_TOUCH_SUPPORT_DENOISE_CHAN: bool = true;
+    pub const SOC_TOUCH_SUPPORT_PROX_SENSING: bool = true;
+    pub const SOC_TOUCH_SUPPORT_SLEEP_WAKEUP: bool = true;
+    pub const SOC_TOUCH_SUPPORT_WATERPROOF: bool = true;
+    pub const SOC_TWAI_CLK_SUPPORT_APB: bool = true;
+    pub const SOC_TWAI_CLK_SUPPORT_XTAL: bool = false;
+    pub const SOC_TWAI_SUPPORTED: bool = true;
+    pub const SOC_TWAI_SUPPORTS_RX_STATUS: bool = true;
+    pub const SOC_TWAI_SUPPORT_FD: bool = false;
+    pub const SOC_TWAI_SUPPORT_MULTI_ADDRESS_LAYOUT: bool = false;
+    pub const SOC_TWAI_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TWAI_SUPPORT_TIMESTAMP: bool = false;
+    pub const SOC_UART_SUPPORTED: bool = true;
+    pub const SOC_UART_SUPPORT_APB_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_FSM_TX_WAIT_SEND: bool = false;
+    pub const SOC_UART_SUPPORT_PLL_F40M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_PLL_F80M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_REF_TICK: bool = true;
+    pub const SOC_UART_SUPPORT_RTC_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_UART_SUPPORT_WAKEUP_INT: bool = true;
+    pub const SOC_UART_SUPPORT_XTAL_CLK: bool = false;
+    pub const SOC_UART_WAKEUP_SUPPORT_ACTIVE_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_CHAR_SEQ_MODE: bool = false;
+    pub const SOC_UART_WAKEUP_SUPPORT_FIFO_THRESH_MODE: bool = false;
+    pub const SOC_UART_WAKEUP_SUPPORT_START_BIT_MODE: bool = false;
+    pub const SOC_UHCI_SUPPORTED: bool = false;
+    pub const SOC_ULP_FSM_SUPPORTED: bool = true;
+    pub const SOC_ULP_LP_UART_SUPPORTED: bool = false;
+    pub const SOC_ULP_SUPPORTED: bool = true;
+    pub const SOC_USB_OTG_SUPPORTED: bool = true;
+    pub const SOC_USB_SERIAL_JTAG_SUPPORTED: bool = false;
+    pub const SOC_VBAT_SUPPORTED: bool = false;
+    pub const SOC_WDT_SUPPORTED: bool = true;
+    pub const SOC_WIFI_CSI_SUPPORT: bool = true;
+    pub const SOC_WIFI_FTM_SUPPORT: bool = true;
+    pub const SOC_WIFI_GCMP_SUPPORT: bool = false;
+    pub const SOC_WIFI_HE_SUPPORT: bool = false;
+    pub const SOC_WIFI_MESH_SUPPORT: bool = true;
+    pub const SOC_WIFI_NAN_SUPPORT: bool = true;
+    pub const SOC_WIFI_SUPPORTED: bool = true;
+    pub const SOC_WIFI_SUPPORT_5G: bool = false;
+    pub const SOC_WIFI_SUPPORT_VARIABLE_BEACON_WINDOW: bool = true;
+    pub const SOC_WIFI_WAPI_SUPPORT: bool = true;
+    pub const SOC_XTAL_SUPPORT_26M: bool = false;
+    pub const SOC_XTAL_SUPPORT_32M: bool = false;
+    pub const SOC_XTAL_SUPPORT_40M: bool = true;
+    pub const SOC_XTAL_SUPPORT_48M: bool = false;
+    pub const SOC_XTAL_SUPPORT_AUTO_DETECT: bool = false;
+    pub const SOC_XT_WDT_SUPPORTED: bool = true;
+    pub const SOC_ADC_ATTEN_NUM: u32 = 4;
+    pub const SOC_ADC_DIGI_CONTROLLER_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_DATA_BYTES_PER_CONV: u32 = 2;
+    pub const SOC_ADC_DIGI_IIR_FILTER_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MONITOR_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_RESULT_BYTES: u32 = 2;
+    pub const SOC_ADC_DIG_IIR_FILTER_UNIT_BINDED: u32 = 1;
+    pub const SOC_ADC_MAX_CHANNEL_NUM: u32 = 10;
+    pub const SOC_ADC_PATT_LEN_MAX: u32 = 32;
+    pub const SOC_ADC_PERIPH_NUM: u32 = 2;
+    pub const SOC_ADC_RTC_MAX_BITWIDTH: u32 = 13;
+    pub const SOC_ADC_RTC_MIN_BITWIDTH: u32 = 13;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_HIGH: u32 = 83333;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_LOW: u32 = 611;
+    pub const SOC_ADC_SHARED_POWER: u32 = 1;
+    pub const SOC_AES_CRYPTO_DMA: u32 = 1;
+    pub const SOC_CAPS_ECO_VER: u32 = 0;
+    pub const SOC_COEX_HW_PTI: u32 = 1;
+    pub const SOC_CPU_BREAKPOINTS_NUM: u32 = 2;
+    pub const SOC_CPU_CORES_NUM: u32 = 1;
+    pub const SOC_CPU_INTR_NUM: u32 = 32;
+    pub const SOC_CPU_WATCHPOINTS_NUM: u32 = 2;
+    pub const SOC_CPU_WATCHPOINT_MAX_REGION_SIZE: u32 = 0x40;
+    pub const SOC_CP_DMA_MAX_BUFFER_SIZE: u32 = 4095;
+    pub const SOC_DAC_CHAN_NUM: u32 = 2;
+    pub const SOC_DAC_RESOLUTION: u32 = 8;
+    pub const SOC_DEDIC_GPIO_ALLOW_REG_ACCESS: u32 = 1;
+    pub const SOC_DEDIC_GPIO_HAS_INTERRUPT: u32 = 1;
+    pub const SOC_DEDIC_GPIO_IN_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_GPIO_OUT_AUTO_ENABLE: u32 = 1;
+    pub const SOC_DEDIC_GPIO_OUT_CHANNELS_NUM: u32 = 8;
+    pub const SOC_EFUSE_DIS_BOOT_REMAP: u32 = 1;
+    pub const SOC_EFUSE_DIS_DOWNLOAD_DCACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_DOWNLOAD_ICACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_ICACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_LEGACY_SPI_BOOT: u32 = 1;
+    pub const SOC_EFUSE_HARD_DIS_JTAG: u32 = 1;
+    pub const SOC_EFUSE_KEY_PURPOSE_FIELD: u32 = 1;
+    pub const SOC_EFUSE_REVOKE_BOOT_KEY_DIGESTS: u32 = 1;
+    pub const SOC_EFUSE_SECURE_BOOT_KEY_DIGESTS: u32 = 3;
+    pub const SOC_EFUSE_SOFT_DIS_JTAG: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_ADVANCE: u32 = 0;
+    pub const SOC_EXTERNAL_COEX_LEADER_TX_LINE: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTED_XTS_AES_BLOCK_MAX: u32 = 64;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_128: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_256: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_OPTIONS: u32 = 1;
+    pub const SOC_GPIO_CLOCKOUT_BY_IO_MUX: u32 = 1;
+    pub const SOC_GPIO_CLOCKOUT_CHANNEL_NUM: u32 = 3;
+    pub const SOC_GPIO_IN_RANGE_MAX: u32 = 46;
+    pub const SOC_GPIO_OUT_RANGE_MAX: u32 = 45;
+    pub const SOC_GPIO_PIN_COUNT: u32 = 47;
+    pub const SOC_GPIO_PORT: u32 = 1;
+    pub const SOC_GPIO_VALID_DIGITAL_IO_PAD_MASK: u64 = 0x7ffffc000000;
+    pub const SOC_GPIO_VALID_GPIO_MASK: u64 = 0x7ffffc3fffff;
+    pub const SOC_GPIO_VALID_OUTPUT_GPIO_MASK: u64 = 0x3ffffc3fffff;
+    pub const SOC_HP_I2C_NUM: u32 = 2;
+    pub const SOC_I2C_CMD_REG_NUM: u32 = 16;
+    pub const SOC_I2C_FIFO_LEN: u32 = 32;
+    pub const SOC_I2C_NUM: u32 = 2;
+    pub const SOC_I2C_SLAVE_CAN_GET_STRETCH_CAUSE: u32 = 1;
+    pub const SOC_I2S_APLL_MAX_FREQ: u32 = 500000000;
+    pub const SOC_I2S_APLL_MIN_FREQ: u32 = 250000000;
+    pub const SOC_I2S_APLL_MIN_RATE: u32 = 10675;
+    pub const SOC_I2S_HW_VERSION_1: u32 = 1;
+    pub const SOC_I2S_LCD_I80_VARIANT: u32 = 1;
+    pub const SOC_I2S_MAX_DATA_WIDTH: u32 = 24;
+    pub const SOC_I2S_NUM: u32 = 1;
+    pub const SOC_LCD_I80_BUSES: u32 = 1;
+    pub const SOC_LCD_I80_BUS_WIDTH: u32 = 24;
+    pub const SOC_LEDC_CHANNEL_NUM: u32 = 8;
+    pub const SOC_LEDC_HAS_TIMER_SPECIFIC_MUX: u32 = 1;
+    pub const SOC_LEDC_TIMER_BIT_WIDTH: u32 = 14;
+    pub const SOC_LEDC_TIMER_NUM: u32 = 4;
+    pub const SOC_LP_IO_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_LP_PERIPH_SHARE_INTERRUPT: u32 = 1;
+    pub const SOC_LP_TIMER_BIT_WIDTH_HI: u32 = 16;
+    pub const SOC_LP_TIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_MEMPROT_CPU_PREFETCH_PAD_SIZE: u32 = 16;
+    pub const SOC_MEMPROT_MEM_ALIGN_SIZE: u32 = 4;
+    pub const SOC_MEMSPI_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_MMU_LINEAR_ADDRESS_REGION_NUM: u32 = 5;
+    pub const SOC_MMU_PERIPH_NUM: u32 = 1;
+    pub const SOC_MPI_MEM_BLOCKS_NUM: u32 = 4;
+    pub const SOC_MPI_OPERATIONS_NUM: u32 = 3;
+    pub const SOC_MPU_MIN_REGION_SIZE: u32 = 0x20000000;
+    pub const SOC_MPU_REGIONS_MAX_NUM: u32 = 8;
+    pub const SOC_PCNT_CHANNELS_PER_UNIT: u32 = 2;
+    pub const SOC_PCNT_GROUPS: u32 = 1;
+    pub const SOC_PCNT_THRES_POINT_PER_UNIT: u32 = 2;
+    pub const SOC_PCNT_UNITS_PER_GROUP: u32 = 4;
+    pub const SOC_PHY_COMBO_MODULE: u32 = 0;
+    pub const SOC_PHY_DIG_REGS_MEM_SIZE: u32 = 84;
+    pub const SOC_PM_MODEM_PD_BY_SW: u32 = 1;
+    pub const SOC_PSRAM_DMA_CAPABLE: u32 = 1;
+    pub const SOC_RMT_CHANNELS_PER_GROUP: u32 = 4;
+    pub const SOC_RMT_CHANNEL_CLK_INDEPENDENT: u32 = 1;
+    pub const SOC_RMT_GROUPS: u32 = 1;
+    pub const SOC_RMT_MEM_WORDS_PER_CHANNEL: u32 = 64;
+    pub const SOC_RMT_RX_CANDIDATES_PER_GROUP: u32 = 4;
+    pub const SOC_RMT_TX_CANDIDATES_PER_GROUP: u32 = 4;
+    pub const SOC_RSA_MAX_BIT_LEN: u32 = 4096;
+    pub const SOC_RTCIO_PIN_COUNT: u32 = 22;
+    pub const SOC_SDM_CHANNELS_PER_GROUP: u32 = 8;
+    pub const SOC_SDM_GROUPS: u32 = 1;
+    pub const SOC_SECURE_BOOT_V2_RSA: u32 = 1;
+    pub const SOC_SHA_CRYPTO_DMA: u32 = 1;
+    pub const SOC_SHA_DMA_MAX_BUFFER_SIZE: u32 = 3968;
+    pub const SOC_SPI_DMA_CHAN_NUM: u32 = 3;
+    pub const SOC_SPI_MAXIMUM_BUFFER_SIZE: u32 = 72;
+    pub const SOC_SPI_MAX_CS_NUM: u32 = 6;
+    pub const SOC_SPI_MAX_PRE_DIVIDER: u32 = 8192;
+    pub const SOC_SPI_PERIPH_NUM: u32 = 3;
+    pub const SOC_SPI_SCT_BUFFER_NUM_MAX: u32 = 28;
+    pub const SOC_SPI_SCT_CONF_BITLEN_MAX: u32 = 0x7ffffd;
+    pub const SOC_SPI_SCT_REG_NUM: u32 = 27;
+    pub const SOC_SYSTIMER_ALARM_NUM: u32 = 3;
+    pub const SOC_SYSTIMER_BIT_WIDTH_HI: u32 = 32;
+    pub const SOC_SYSTIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_SYSTIMER_COUNTER_NUM: u32 = 1;
+    pub const SOC_TIMER_GROUPS: u32 = 2;
+    pub const SOC_TIMER_GROUP_COUNTER_BIT_WIDTH: u32 = 64;
+    pub const SOC_TIMER_GROUP_TIMERS_PER_GROUP: u32 = 2;
+    pub const SOC_TIMER_GROUP_TOTAL_TIMERS: u32 = 4;
+    pub const SOC_TOUCH_MAX_CHAN_ID: u32 = 14;
+    pub const SOC_TOUCH_MIN_CHAN_ID: u32 = 1;
+    pub const SOC_TOUCH_PROXIMITY_CHANNEL_NUM: u32 = 3;
+    pub const SOC_TOUCH_SAMPLE_CFG_NUM: u32 = 1;
+    pub const SOC_TOUCH_SENSOR_NUM: u32 = 15;
+    pub const SOC_TOUCH_SENSOR_VERSION: u32 = 2;
+    pub const SOC_TWAI_BRP_MAX: u32 = 32768;
+    pub const SOC_TWAI_BRP_MIN: u32 = 2;
+    pub const SOC_TWAI_CONTROLLER_NUM: u32 = 1;
+    pub const SOC_TWAI_MASK_FILTER_NUM: u32 = 1;
+    pub const SOC_UART_BITRATE_MAX: u32 = 5000000;
+    pub const SOC_UART_FIFO_LEN: u32 = 128;
+    pub const SOC_UART_HP_NUM: u32 = 2;
+    pub const SOC_UART_NUM: u32 = 2;
+    pub const SOC_ULP_HAS_ADC: u32 = 1;
+    pub const SOC_USB_OTG_PERIPH_NUM: u32 = 1;
+    pub const SOC_WIFI_HW_TSF: u32 = 1;
+    pub const SOC_WIFI_LIGHT_SLEEP_CLK_WIDTH: u32 = 12;
+}
+
+pub mod esp32s3 {
+    pub const SOC_ADC_ARBITER_SUPPORTED: bool = true;
+    pub const SOC_ADC_CALIBRATION_V1_SUPPORTED: bool = true;
+    pub const SOC_ADC_CALIB_CHAN_COMPENS_SUPPORTED: bool = false;
+    pub const SOC_ADC_DIG_CTRL_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_IIR_FILTER_SUPPORTED: bool = true;
+    pub const SOC_ADC_DMA_SUPPORTED: bool = true;
+    pub const SOC_ADC_MONITOR_SUPPORTED: bool = true;
+    pub const SOC_ADC_RTC_CTRL_SUPPORTED: bool = true;
+    pub const SOC_ADC_SELF_HW_CALI_SUPPORTED: bool = true;
+    pub const SOC_ADC_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORT_AES_128: bool = true;
+    pub const SOC_AES_SUPPORT_AES_192: bool = false;
+    pub const SOC_AES_SUPPORT_AES_256: bool = true;
+    pub const SOC_AES_SUPPORT_DMA: bool = true;
+    pub const SOC_AES_SUPPORT_GCM: bool = false;
+    pub const SOC_AES_SUPPORT_PSEUDO_ROUND_FUNCTION: bool = false;
+    pub const SOC_AHB_GDMA_SUPPORTED: bool = true;
+    pub const SOC_AHB_GDMA_SUPPORT_PSRAM: bool = true;
+    pub const SOC_ANA_CMPR_SUPPORTED: bool = false;
+    pub const SOC_ANA_CMPR_SUPPORT_ETM: bool = false;
+    pub const SOC_APM_CPU_APM_SUPPORTED: bool = false;
+    pub const SOC_APM_CTRL_FILTER_SUPPORTED: bool = false;
+    pub const SOC_APM_LP_APM0_SUPPORTED: bool = false;
+    pub const SOC_APM_SUPPORTED: bool = false;
+    pub const SOC_APM_SUPPORT_CTRL_CFG_LOCK: bool = false;
+    pub const SOC_APM_SUPPORT_LP_TEE_CTRL: bool = false;
+    pub const SOC_APM_SUPPORT_TEE_PERI_ACCESS_CTRL: bool = false;
+    pub const SOC_ASSIST_DEBUG_SUPPORTED: bool = false;
+    pub const SOC_ASYNC_MEMCPY_SUPPORTED: bool = true;
+    pub const SOC_BITSCRAMBLER_SUPPORTED: bool = false;
+    pub const SOC_BLE_50_SUPPORTED: bool = true;
+    pub const SOC_BLE_CTE_SUPPORTED: bool = false;
+    pub const SOC_BLE_DEVICE_PRIVACY_SUPPORTED: bool = true;
+    pub const SOC_BLE_MESH_SUPPORTED: bool = true;
+    pub const SOC_BLE_PERIODIC_ADV_ENH_SUPPORTED: bool = false;
+    pub const SOC_BLE_POWER_CONTROL_SUPPORTED: bool = false;
+    pub const SOC_BLE_SUPPORTED: bool = true;
+    pub const SOC_BLUFI_SUPPORTED: bool = true;
+    pub const SOC_BOD_SUPPORTED: bool = true;
+    pub const SOC_BOOTLOADER_ANTI_ROLLBACK_SUPPORTED: bool = false;
+    pub const SOC_BRANCH_PREDICTOR_SUPPORTED: bool = false;
+    pub const SOC_BROWNOUT_RESET_SUPPORTED: bool = true;
+    pub const SOC_BT_CLASSIC_SUPPORTED: bool = false;
+    pub const SOC_BT_H2C_ENC_KEY_CTRL_ENH_VSC_SUPPORTED: bool = false;
+    pub const SOC_BT_SUPPORTED: bool = true;
+    pub const SOC_CACHE_FREEZE_SUPPORTED: bool = true;
+    pub const SOC_CACHE_SUPPORT_WRAP: bool = true;
+    pub const SOC_CACHE_WRITEBACK_SUPPORTED: bool = true;
+    pub const SOC_CCOMP_TIMER_SUPPORTED: bool = true;
+    pub const SOC_CLK_APLL_SUPPORTED: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_LP_PLL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D2: bool = true;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D4: bool = false;
+    pub const SOC_CLK_OSC_SLOW_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC32K_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC_FAST_D256_SUPPORTED: bool = true;
+    pub const SOC_CLK_RC_FAST_SUPPORT_CALIBRATION: bool = true;
+    pub const SOC_CLK_TREE_SUPPORTED: bool = true;
+    pub const SOC_CLK_XTAL32K_SUPPORTED: bool = true;
+    pub const SOC_CLOCK_TREE_MANAGEMENT_SUPPORTED: bool = false;
+    pub const SOC_CONFIGURABLE_VDDSDIO_SUPPORTED: bool = true;
+    pub const SOC_CPU_SUPPORT_WFE: bool = false;
+    pub const SOC_CP_DMA_SUPPORTED: bool = false;
+    pub const SOC_CRYPTO_DPA_PROTECTION_SUPPORTED: bool = false;
+    pub const SOC_DAC_SUPPORTED: bool = false;
+    pub const SOC_DEDICATED_GPIO_SUPPORTED: bool = true;
+    pub const SOC_DEEP_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_DIG_SIGN_SUPPORTED: bool = true;
+    pub const SOC_ECC_EXTENDED_MODES_SUPPORTED: bool = false;
+    pub const SOC_ECC_SUPPORTED: bool = false;
+    pub const SOC_ECC_SUPPORT_POINT_VERIFY_QUIRK: bool = false;
+    pub const SOC_ECDSA_SUPPORTED: bool = false;
+    pub const SOC_ECDSA_SUPPORT_CURVE_P384: bool = false;
+    pub const SOC_ECDSA_SUPPORT_DETERMINISTIC_MODE: bool = false;
+    pub const SOC_ECDSA_SUPPORT_EXPORT_PUBKEY: bool = false;
+    pub const SOC_ECDSA_SUPPORT_HW_DETERMINISTIC_LOOP: bool = false;
+    pub const SOC_EFUSE_SUPPORTED: bool = true;
+    pub const SOC_EMAC_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_SUPPORT_PSEUDO_ROUND: bool = false;
+    pub const SOC_FLASH_ENC_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORT_ETM: bool = false;
+    pub const SOC_GDMA_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_GPIO_FILTER_CLK_SUPPORT_APB: bool = true;
+    pub const SOC_GPIO_SUPPORT_DEEPSLEEP_WAKEUP: bool = false;
+    pub const SOC_GPIO_SUPPORT_ETM: bool = false;
+    pub const SOC_GPIO_SUPPORT_FORCE_HOLD: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_SINGLE_IO_IN_DSLP: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_GLITCH_FILTER: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_CTRL_BY_EFUSE: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_FILTER: bool = false;
+    pub const SOC_GPIO_SUPPORT_RTC_INDEPENDENT: bool = true;
+    pub const SOC_GPSPI_SUPPORTED: bool = true;
+    pub const SOC_GPTIMER_SUPPORTED: bool = true;
+    pub const SOC_HMAC_SUPPORTED: bool = true;
+    pub const SOC_HUK_SUPPORTED: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_BROADCAST: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_I2CRAM_ACCESS: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_SLAVE_UNMATCH: bool = false;
+    pub const SOC_I2C_SUPPORTED: bool = true;
+    pub const SOC_I2C_SUPPORT_10BIT_ADDR: bool = true;
+    pub const SOC_I2C_SUPPORT_APB: bool = false;
+    pub const SOC_I2C_SUPPORT_HW_CLR_BUS: bool = true;
+    pub const SOC_I2C_SUPPORT_HW_FSM_RST: bool = false;
+    pub const SOC_I2C_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_I2C_SUPPORT_RTC: bool = true;
+    pub const SOC_I2C_SUPPORT_SLAVE: bool = true;
+    pub const SOC_I2C_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_I2C_SUPPORT_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORTED: bool = true;
+    pub const SOC_I2S_SUPPORTS_ADC: bool = false;
+    pub const SOC_I2S_SUPPORTS_ADC_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_APLL: bool = false;
+    pub const SOC_I2S_SUPPORTS_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_DMA_EQUAL: bool = false;
+    pub const SOC_I2S_SUPPORTS_ETM: bool = false;
+    pub const SOC_I2S_SUPPORTS_LCD_CAMERA: bool = false;
+    pub const SOC_I2S_SUPPORTS_PCM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PCM2PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM2PCM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM_RX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM_TX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F120M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F160M: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F240M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F64M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F96M: bool = false;
+    pub const SOC_I2S_SUPPORTS_TDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_TX_SYNC_CNT: bool = false;
+    pub const SOC_I2S_SUPPORTS_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_IEEE802154_SUPPORTED: bool = false;
+    pub const SOC_INT_CLIC_SUPPORTED: bool = false;
+    pub const SOC_INT_HW_NESTED_SUPPORTED: bool = false;
+    pub const SOC_INT_PLIC_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORT_KEY_DEPLOYMENT: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORTED: bool = true;
+    pub const SOC_LCDCAM_CAM_SUPPORT_RGB_YUV_CONV: bool = true;
+    pub const SOC_LCDCAM_I80_LCD_SUPPORTED: bool = true;
+    pub const SOC_LCDCAM_RGB_LCD_SUPPORTED: bool = true;
+    pub const SOC_LCDCAM_SUPPORTED: bool = true;
+    pub const SOC_LCD_I80_SUPPORTED: bool = true;
+    pub const SOC_LCD_RGB_SUPPORTED: bool = true;
+    pub const SOC_LCD_SUPPORT_RGB_YUV_CONV: bool = true;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_SUPPORTED: bool = false;
+    pub const SOC_LEDC_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORT_APB_CLOCK: bool = true;
+    pub const SOC_LEDC_SUPPORT_FADE_STOP: bool = true;
+    pub const SOC_LEDC_SUPPORT_HS_MODE: bool = false;
+    pub const SOC_LEDC_SUPPORT_PLL_DIV_CLOCK: bool = false;
+    pub const SOC_LEDC_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_LEDC_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_LEDC_SUPPORT_XTAL_CLOCK: bool = true;
+    pub const SOC_LIGHT_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_LP_AON_SUPPORTED: bool = false;
+    pub const SOC_LP_CORE_SUPPORTED: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_ETM: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_STORE_LOAD_EXCEPTIONS: bool = false;
+    pub const SOC_LP_I2C_SUPPORTED: bool = false;
+    pub const SOC_LP_PERIPHERALS_SUPPORTED: bool = false;
+    pub const SOC_LP_TIMER_SUPPORTED: bool = false;
+    pub const SOC_MCPWM_SUPPORTED: bool = true;
+    pub const SOC_MCPWM_SUPPORT_ETM: bool = false;
+    pub const SOC_MCPWM_SUPPORT_EVENT_COMPARATOR: bool = false;
+    pub const SOC_MCPWM_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_MEMPROT_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_120M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_15M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_16M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_20M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_26M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_30M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_32M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_40M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_60M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_64M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_80M_SUPPORTED: bool = true;
+    pub const SOC_MMU_PAGE_SIZE_8KB_SUPPORTED: bool = false;
+    pub const SOC_MODEM_CLOCK_SUPPORTED: bool = false;
+    pub const SOC_MPI_SUPPORTED: bool = true;
+    pub const SOC_MPU_CONFIGURABLE_REGIONS_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_RO_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_WO_SUPPORTED: bool = false;
+    pub const SOC_MPU_SUPPORTED: bool = true;
+    pub const SOC_MWDT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_MWDT_SUPPORT_XTAL: bool = false;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_OUTPUT: bool = false;
+    pub const SOC_PARLIO_SUPPORTED: bool = false;
+    pub const SOC_PARLIO_SUPPORT_I80_LCD: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SPI_LCD: bool = false;
+    pub const SOC_PARLIO_TX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_EOF_FROM_DMA: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_LOOP_TRANSMISSION: bool = false;
+    pub const SOC_PAU_SUPPORTED: bool = false;
+    pub const SOC_PCNT_SUPPORTED: bool = true;
+    pub const SOC_PCNT_SUPPORT_CLEAR_SIGNAL: bool = false;
+    pub const SOC_PCNT_SUPPORT_RUNTIME_THRES_UPDATE: bool = false;
+    pub const SOC_PCNT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_PCNT_SUPPORT_STEP_NOTIFY: bool = false;
+    pub const SOC_PHY_SUPPORTED: bool = true;
+    pub const SOC_PMU_SUPPORTED: bool = false;
+    pub const SOC_PM_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORT_BEACON_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_BT_PD: bool = false;
+    pub const SOC_PM_SUPPORT_BT_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_CPU_PD: bool = true;
+    pub const SOC_PM_SUPPORT_DEEPSLEEP_CHECK_STUB_ONLY: bool = true;
+    pub const SOC_PM_SUPPORT_EXT0_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP_MODE_PER_PIN: bool = false;
+    pub const SOC_PM_SUPPORT_EXT_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_HP_AON_PD: bool = false;
+    pub const SOC_PM_SUPPORT_MAC_BB_PD: bool = true;
+    pub const SOC_PM_SUPPORT_MODEM_PD: bool = true;
+    pub const SOC_PM_SUPPORT_PMU_CLK_ICG: bool = false;
+    pub const SOC_PM_SUPPORT_PMU_MODEM_STATE: bool = false;
+    pub const SOC_PM_SUPPORT_RC32K_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RC_FAST_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_FAST_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RTC_PERIPH_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_SLOW_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TAGMEM_PD: bool = true;
+    pub const SOC_PM_SUPPORT_TOP_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOUCH_SENSOR_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_VDDSDIO_PD: bool = true;
+    pub const SOC_PM_SUPPORT_WIFI_PD: bool = false;
+    pub const SOC_PM_SUPPORT_WIFI_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_XTAL32K_PD: bool = false;
+    pub const SOC_RECOVERY_BOOTLOADER_SUPPORTED: bool = false;
+    pub const SOC_REG_I2C_SUPPORTED: bool = false;
+    pub const SOC_RISCV_COPROC_SUPPORTED: bool = true;
+    pub const SOC_RMT_SUPPORTED: bool = true;
+    pub const SOC_RMT_SUPPORT_APB: bool = true;
+    pub const SOC_RMT_SUPPORT_DMA: bool = true;
+    pub const SOC_RMT_SUPPORT_RC_FAST: bool = true;
+    pub const SOC_RMT_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_RMT_SUPPORT_RX_DEMODULATION: bool = true;
+    pub const SOC_RMT_SUPPORT_RX_PINGPONG: bool = true;
+    pub const SOC_RMT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_ASYNC_STOP: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_CARRIER_DATA_ONLY: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_AUTO_STOP: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_COUNT: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_SYNCHRO: bool = true;
+    pub const SOC_RMT_SUPPORT_XTAL: bool = true;
+    pub const SOC_RNG_SUPPORTED: bool = true;
+    pub const SOC_RSA_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_EDGE_WAKE_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_HOLD_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_INPUT_OUTPUT_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_WAKE_SUPPORTED: bool = true;
+    pub const SOC_RTC_FAST_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORT_SPEED_MODE_SWITCH: bool = false;
+    pub const SOC_RTC_SLOW_CLK_SUPPORT_RC_FAST_D256: bool = true;
+    pub const SOC_RTC_SLOW_MEM_SUPPORTED: bool = true;
+    pub const SOC_SDIO_SLAVE_SUPPORTED: bool = false;
+    pub const SOC_SDMMC_HOST_SUPPORTED: bool = true;
+    pub const SOC_SDMMC_SUPPORT_XTAL_CLOCK: bool = true;
+    pub const SOC_SDM_CLK_SUPPORT_APB: bool = true;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F48M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F80M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_XTAL: bool = false;
+    pub const SOC_SDM_SUPPORTED: bool = true;
+    pub const SOC_SECURE_BOOT_SUPPORTED: bool = true;
+    pub const SOC_SHARED_IDCACHE_SUPPORTED: bool = false;
+    pub const SOC_SHA_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORT_DMA: bool = true;
+    pub const SOC_SHA_SUPPORT_PARALLEL_ENG: bool = false;
+    pub const SOC_SHA_SUPPORT_RESUME: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA1: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA224: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA256: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA384: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512_224: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512_256: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512_T: bool = true;
+    pub const SOC_SIMD_INSTRUCTION_SUPPORTED: bool = true;
+    pub const SOC_SPIRAM_SUPPORTED: bool = true;
+    pub const SOC_SPIRAM_XIP_SUPPORTED: bool = true;
+    pub const SOC_SPI_AS_CS_SUPPORTED: bool = false;
+    pub const SOC_SPI_FLASH_SUPPORTED: bool = true;
+    pub const SOC_SPI_HD_BOTH_INOUT_SUPPORTED: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_RESUME: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_WAIT_IDLE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CACHE_32BIT_ADDR_MAP: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CHECK_SUS: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_CONFIG_GPIO_BY_EFUSE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_FLASH_OPI_MODE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_IDLE_INTR: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_SW_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_TIMING_TUNING: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_TSUS_TRES_SEPERATE_CTR: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WB_MODE_INDEPENDENT_CONTROL: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WRAP: bool = true;
+    pub const SOC_SPI_PERIPH_SUPPORT_CONTROL_DUMMY_OUT: bool = true;
+    pub const SOC_SPI_SCT_SUPPORTED: bool = true;
+    pub const SOC_SPI_SLAVE_SUPPORT_SEG_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_CD_SIG: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_APB: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_PLL: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F160M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F40M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F48M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F80M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_RC_FAST: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_XTAL: bool = true;
+    pub const SOC_SPI_SUPPORT_CONTINUOUS_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_DDRCLK: bool = true;
+    pub const SOC_SPI_SUPPORT_OCT: bool = true;
+    pub const SOC_SPI_SUPPORT_SLAVE_HD_VER2: bool = true;
+    pub const SOC_SPI_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_SUPPORTS_SECURE_DL_MODE: bool = true;
+    pub const SOC_SUPPORT_COEXISTENCE: bool = true;
+    pub const SOC_SUPPORT_SECURE_BOOT_REVOKE_KEY: bool = true;
+    pub const SOC_SYSTIMER_SUPPORTED: bool = true;
+    pub const SOC_SYSTIMER_SUPPORT_ETM: bool = false;
+    pub const SOC_SYSTIMER_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_INTR_SUPPORT: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_ETM: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_FAST_RC: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_XTAL: bool = false;
+    pub const SOC_TEMP_SENSOR_SUPPORTED: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_APB: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_TIMER_GROUP_SUPPORT_XTAL: bool = true;
+    pub const SOC_TIMER_SUPPORT_ETM: bool = false;
+    pub const SOC_TIMER_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TOUCH_PROXIMITY_MEAS_DONE_SUPPORTED: bool = true;
+    pub const SOC_TOUCH_SENSOR_SUPPORTED: bool = true;
+    pub const SOC_TOUCH_SUPPORT_BENCHMARK: bool = true;
+    pub const SOC_TOUCH_SUPPORT_DENOISE_CHAN: bool = true;
+    pub const SOC_TOUCH_SUPPORT_PROX_SENSING: bool = true;
+    pub const SOC_TOUCH_SUPPORT_SLEEP_WAKEUP: bool = true;
+    pub const SOC_TOUCH_SUPPORT_WATERPROOF: bool = true;
+    pub const SOC_TWAI_CLK_SUPPORT_APB: bool = true;
+    pub const SOC_TWAI_CLK_SUPPORT_XTAL: bool = false;
+    pub const SOC_TWAI_SUPPORTED: bool = true;
+    pub const SOC_TWAI_SUPPORTS_RX_STATUS: bool = true;
+    pub const SOC_TWAI_SUPPORT_FD: bool = false;
+    pub const SOC_TWAI_SUPPORT_MULTI_ADDRESS_LAYOUT: bool = false;
+    pub const SOC_TWAI_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TWAI_SUPPORT_TIMESTAMP: bool = false;
+    pub const SOC_UART_SUPPORTED: bool = true;
+    pub const SOC_UART_SUPPORT_APB_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_FSM_TX_WAIT_SEND: bool = true;
+    pub const SOC_UART_SUPPORT_PLL_F40M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_PLL_F80M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_UART_SUPPORT_RTC_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_UART_SUPPORT_WAKEUP_INT: bool = true;
+    pub const SOC_UART_SUPPORT_XTAL_CLK: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_ACTIVE_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_CHAR_SEQ_MODE: bool = false;
+    pub const SOC_UART_WAKEUP_SUPPORT_FIFO_THRESH_MODE: bool = false;
+    pub const SOC_UART_WAKEUP_SUPPORT_START_BIT_MODE: bool = false;
+    pub const SOC_UHCI_SUPPORTED: bool = true;
+    pub const SOC_ULP_FSM_SUPPORTED: bool = true;
+    pub const SOC_ULP_LP_UART_SUPPORTED: bool = false;
+    pub const SOC_ULP_SUPPORTED: bool = true;
+    pub const SOC_USB_OTG_SUPPORTED: bool = true;
+    pub const SOC_USB_SERIAL_JTAG_SUPPORTED: bool = true;
+    pub const SOC_VBAT_SUPPORTED: bool = false;
+    pub const SOC_WDT_SUPPORTED: bool = true;
+    pub const SOC_WIFI_CSI_SUPPORT: bool = true;
+    pub const SOC_WIFI_FTM_SUPPORT: bool = true;
+    pub const SOC_WIFI_GCMP_SUPPORT: bool = true;
+    pub const SOC_WIFI_HE_SUPPORT: bool = false;
+    pub const SOC_WIFI_MESH_SUPPORT: bool = true;
+    pub const SOC_WIFI_NAN_SUPPORT: bool = false;
+    pub const SOC_WIFI_SUPPORTED: bool = true;
+    pub const SOC_WIFI_SUPPORT_5G: bool = false;
+    pub const SOC_WIFI_SUPPORT_VARIABLE_BEACON_WINDOW: bool = true;
+    pub const SOC_WIFI_WAPI_SUPPORT: bool = true;
+    pub const SOC_XTAL_SUPPORT_26M: bool = false;
+    pub const SOC_XTAL_SUPPORT_32M: bool = false;
+    pub const SOC_XTAL_SUPPORT_40M: bool = true;
+    pub const SOC_XTAL_SUPPORT_48M: bool = false;
+    pub const SOC_XTAL_SUPPORT_AUTO_DETECT: bool = false;
+    pub const SOC_XT_WDT_SUPPORTED: bool = true;
+    pub const SOC_ADC_ATTEN_NUM: u32 = 4;
+    pub const SOC_ADC_DIGI_CONTROLLER_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_DATA_BYTES_PER_CONV: u32 = 4;
+    pub const SOC_ADC_DIGI_IIR_FILTER_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MONITOR_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_RESULT_BYTES: u32 = 4;
+    pub const SOC_ADC_MAX_CHANNEL_NUM: u32 = 10;
+    pub const SOC_ADC_PATT_LEN_MAX: u32 = 24;
+    pub const SOC_ADC_PERIPH_NUM: u32 = 2;
+    pub const SOC_ADC_RTC_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_RTC_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_HIGH: u32 = 83333;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_LOW: u32 = 611;
+    pub const SOC_ADC_SHARED_POWER: u32 = 1;
+    pub const SOC_AES_GDMA: u32 = 1;
+    pub const SOC_AHB_GDMA_VERSION: u32 = 1;
+    pub const SOC_APB_BACKUP_DMA: u32 = 1;
+    pub const SOC_APPCPU_HAS_CLOCK_GATING_BUG: u32 = 1;
+    pub const SOC_CACHE_ACS_INVALID_STATE_ON_PANIC: u32 = 1;
+    pub const SOC_CAPS_ECO_VER: u32 = 0;
+    pub const SOC_COEX_HW_PTI: u32 = 1;
+    pub const SOC_CPU_BREAKPOINTS_NUM: u32 = 2;
+    pub const SOC_CPU_CORES_NUM: u32 = 2;
+    pub const SOC_CPU_HAS_FPU: u32 = 1;
+    pub const SOC_CPU_INTR_NUM: u32 = 32;
+    pub const SOC_CPU_WATCHPOINTS_NUM: u32 = 2;
+    pub const SOC_CPU_WATCHPOINT_MAX_REGION_SIZE: u32 = 0x40;
+    pub const SOC_DEDIC_GPIO_IN_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_GPIO_OUT_AUTO_ENABLE: u32 = 1;
+    pub const SOC_DEDIC_GPIO_OUT_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DS_KEY_CHECK_MAX_WAIT_US: u32 = 1100;
+    pub const SOC_DS_KEY_PARAM_MD_IV_LENGTH: u32 = 16;
+    pub const SOC_DS_SIGNATURE_MAX_BIT_LEN: u32 = 4096;
+    pub const SOC_EFUSE_BLOCK9_KEY_PURPOSE_QUIRK: u32 = 1;
+    pub const SOC_EFUSE_DIS_DIRECT_BOOT: u32 = 1;
+    pub const SOC_EFUSE_DIS_DOWNLOAD_DCACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_DOWNLOAD_ICACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_ICACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_USB_JTAG: u32 = 1;
+    pub const SOC_EFUSE_HARD_DIS_JTAG: u32 = 1;
+    pub const SOC_EFUSE_KEY_PURPOSE_FIELD: u32 = 1;
+    pub const SOC_EFUSE_REVOKE_BOOT_KEY_DIGESTS: u32 = 1;
+    pub const SOC_EFUSE_SECURE_BOOT_KEY_DIGESTS: u32 = 3;
+    pub const SOC_EFUSE_SOFT_DIS_JTAG: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_ADVANCE: u32 = 0;
+    pub const SOC_EXTERNAL_COEX_LEADER_TX_LINE: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTED_XTS_AES_BLOCK_MAX: u32 = 64;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_128: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_256: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_OPTIONS: u32 = 1;
+    pub const SOC_GDMA_NUM_GROUPS_MAX: u32 = 1;
+    pub const SOC_GDMA_PAIRS_PER_GROUP: u32 = 5;
+    pub const SOC_GDMA_PAIRS_PER_GROUP_MAX: u32 = 5;
+    pub const SOC_GPIO_CLOCKOUT_BY_IO_MUX: u32 = 1;
+    pub const SOC_GPIO_CLOCKOUT_CHANNEL_NUM: u32 = 3;
+    pub const SOC_GPIO_IN_RANGE_MAX: u32 = 48;
+    pub const SOC_GPIO_OUT_RANGE_MAX: u32 = 48;
+    pub const SOC_GPIO_PIN_COUNT: u32 = 49;
+    pub const SOC_GPIO_PORT: u32 = 1;
+    pub const SOC_GPIO_VALID_DIGITAL_IO_PAD_MASK: u64 = 0x1fffffc000000;
+    pub const SOC_GPIO_VALID_GPIO_MASK: u64 = 0x1fffffc3fffff;
+    pub const SOC_GPIO_VALID_OUTPUT_GPIO_MASK: u64 = 0x1fffffc3fffff;
+    pub const SOC_HP_CPU_HAS_MULTIPLE_CORES: u32 = 1;
+    pub const SOC_HP_I2C_NUM: u32 = 2;
+    pub const SOC_I2C_CMD_REG_NUM: u32 = 8;
+    pub const SOC_I2C_FIFO_LEN: u32 = 32;
+    pub const SOC_I2C_NUM: u32 = 2;
+    pub const SOC_I2C_SLAVE_CAN_GET_STRETCH_CAUSE: u32 = 1;
+    pub const SOC_I2S_HW_VERSION_2: u32 = 1;
+    pub const SOC_I2S_NUM: u32 = 2;
+    pub const SOC_I2S_PDM_MAX_RX_LINES: u32 = 4;
+    pub const SOC_I2S_PDM_MAX_TX_LINES: u32 = 2;
+    pub const SOC_LCDCAM_CAM_DATA_WIDTH_MAX: u32 = 16;
+    pub const SOC_LCDCAM_CAM_PERIPH_NUM: u32 = 1;
+    pub const SOC_LCDCAM_I80_BUS_WIDTH: u32 = 16;
+    pub const SOC_LCDCAM_I80_NUM_BUSES: u32 = 1;
+    pub const SOC_LCDCAM_RGB_DATA_WIDTH: u32 = 16;
+    pub const SOC_LCDCAM_RGB_NUM_PANELS: u32 = 1;
+    pub const SOC_LCD_I80_BUSES: u32 = 1;
+    pub const SOC_LCD_I80_BUS_WIDTH: u32 = 16;
+    pub const SOC_LCD_RGB_DATA_WIDTH: u32 = 16;
+    pub const SOC_LCD_RGB_PANELS: u32 = 1;
+    pub const SOC_LEDC_CHANNEL_NUM: u32 = 8;
+    pub const SOC_LEDC_TIMER_BIT_WIDTH: u32 = 14;
+    pub const SOC_LEDC_TIMER_NUM: u32 = 4;
+    pub const SOC_LP_IO_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_LP_PERIPH_SHARE_INTERRUPT: u32 = 1;
+    pub const SOC_LP_TIMER_BIT_WIDTH_HI: u32 = 16;
+    pub const SOC_LP_TIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_MAC_BB_PD_MEM_SIZE: u32 = 768;
+    pub const SOC_MCPWM_CAPTURE_CHANNELS_PER_TIMER: u32 = 3;
+    pub const SOC_MCPWM_CAPTURE_TIMERS_PER_GROUP: u32 = 1;
+    pub const SOC_MCPWM_COMPARATORS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MCPWM_GENERATORS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MCPWM_GPIO_FAULTS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_GPIO_SYNCHROS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_GROUPS: u32 = 2;
+    pub const SOC_MCPWM_OPERATORS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_SWSYNC_CAN_PROPAGATE: u32 = 1;
+    pub const SOC_MCPWM_TIMERS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_TRIGGERS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MEMPROT_CPU_PREFETCH_PAD_SIZE: u32 = 16;
+    pub const SOC_MEMPROT_MEM_ALIGN_SIZE: u32 = 256;
+    pub const SOC_MEMSPI_CORE_CLK_SHARED_WITH_PSRAM: u32 = 1;
+    pub const SOC_MEMSPI_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_MEMSPI_TIMING_TUNING_BY_MSPI_DELAY: u32 = 1;
+    pub const SOC_MMU_LINEAR_ADDRESS_REGION_NUM: u32 = 1;
+    pub const SOC_MMU_PERIPH_NUM: u32 = 1;
+    pub const SOC_MPI_MEM_BLOCKS_NUM: u32 = 4;
+    pub const SOC_MPI_OPERATIONS_NUM: u32 = 3;
+    pub const SOC_MPU_MIN_REGION_SIZE: u32 = 0x20000000;
+    pub const SOC_MPU_REGIONS_MAX_NUM: u32 = 8;
+    pub const SOC_PCNT_CHANNELS_PER_UNIT: u32 = 2;
+    pub const SOC_PCNT_GROUPS: u32 = 1;
+    pub const SOC_PCNT_THRES_POINT_PER_UNIT: u32 = 2;
+    pub const SOC_PCNT_UNITS_PER_GROUP: u32 = 4;
+    pub const SOC_PHY_COMBO_MODULE: u32 = 1;
+    pub const SOC_PHY_DIG_REGS_MEM_SIZE: u32 = 84;
+    pub const SOC_PM_CPU_RETENTION_BY_RTCCNTL: u32 = 1;
+    pub const SOC_PM_MODEM_PD_BY_SW: u32 = 1;
+    pub const SOC_PM_MODEM_RETENTION_BY_BACKUPDMA: u32 = 1;
+    pub const SOC_PSRAM_DMA_CAPABLE: u32 = 1;
+    pub const SOC_RMT_CHANNELS_PER_GROUP: u32 = 8;
+    pub const SOC_RMT_GROUPS: u32 = 1;
+    pub const SOC_RMT_MEM_WORDS_PER_CHANNEL: u32 = 48;
+    pub const SOC_RMT_RX_CANDIDATES_PER_GROUP: u32 = 4;
+    pub const SOC_RMT_TX_CANDIDATES_PER_GROUP: u32 = 4;
+    pub const SOC_RSA_MAX_BIT_LEN: u32 = 4096;
+    pub const SOC_RTCIO_PIN_COUNT: u32 = 22;
+    pub const SOC_RTC_CNTL_CPU_PD_DMA_ADDR_ALIGN: u32 = 16;
+    pub const SOC_RTC_CNTL_CPU_PD_DMA_BLOCK_SIZE: u32 = 16;
+    pub const SOC_RTC_CNTL_CPU_PD_DMA_BUS_WIDTH: u32 = 128;
+    pub const SOC_RTC_CNTL_CPU_PD_REG_FILE_NUM: u32 = 549;
+    pub const SOC_RTC_CNTL_CPU_PD_RETENTION_MEM_SIZE: u32 = 8784;
+    pub const SOC_RTC_CNTL_TAGMEM_PD_DMA_ADDR_ALIGN: u32 = 16;
+    pub const SOC_RTC_CNTL_TAGMEM_PD_DMA_BUS_WIDTH: u32 = 128;
+    pub const SOC_SDMMC_DELAY_PHASE_NUM: u32 = 4;
+    pub const SOC_SDMMC_NUM_SLOTS: u32 = 2;
+    pub const SOC_SDMMC_USE_GPIO_MATRIX: u32 = 1;
+    pub const SOC_SDM_CHANNELS_PER_GROUP: u32 = 8;
+    pub const SOC_SDM_GROUPS: u32 = 1;
+    pub const SOC_SECURE_BOOT_V2_RSA: u32 = 1;
+    pub const SOC_SHA_DMA_MAX_BUFFER_SIZE: u32 = 3968;
+    pub const SOC_SHA_GDMA: u32 = 1;
+    pub const SOC_SIMD_PREFERRED_DATA_ALIGNMENT: u32 = 16;
+    pub const SOC_SPI_MAXIMUM_BUFFER_SIZE: u32 = 64;
+    pub const SOC_SPI_MAX_CS_NUM: u32 = 6;
+    pub const SOC_SPI_MAX_PRE_DIVIDER: u32 = 16;
+    pub const SOC_SPI_PERIPH_NUM: u32 = 3;
+    pub const SOC_SPI_SCT_BUFFER_NUM_MAX: u32 = 15;
+    pub const SOC_SPI_SCT_CONF_BITLEN_MAX: u32 = 0x3fffa;
+    pub const SOC_SPI_SCT_REG_NUM: u32 = 14;
+    pub const SOC_SYSTIMER_ALARM_MISS_COMPENSATE: u32 = 1;
+    pub const SOC_SYSTIMER_ALARM_NUM: u32 = 3;
+    pub const SOC_SYSTIMER_BIT_WIDTH_HI: u32 = 20;
+    pub const SOC_SYSTIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_SYSTIMER_COUNTER_NUM: u32 = 2;
+    pub const SOC_SYSTIMER_FIXED_DIVIDER: u32 = 1;
+    pub const SOC_SYSTIMER_INT_LEVEL: u32 = 1;
+    pub const SOC_TIMER_GROUPS: u32 = 2;
+    pub const SOC_TIMER_GROUP_COUNTER_BIT_WIDTH: u32 = 54;
+    pub const SOC_TIMER_GROUP_TIMERS_PER_GROUP: u32 = 2;
+    pub const SOC_TIMER_GROUP_TOTAL_TIMERS: u32 = 4;
+    pub const SOC_TOUCH_MAX_CHAN_ID: u32 = 14;
+    pub const SOC_TOUCH_MIN_CHAN_ID: u32 = 1;
+    pub const SOC_TOUCH_PROXIMITY_CHANNEL_NUM: u32 = 3;
+    pub const SOC_TOUCH_SAMPLE_CFG_NUM: u32 = 1;
+    pub const SOC_TOUCH_SENSOR_NUM: u32 = 15;
+    pub const SOC_TOUCH_SENSOR_VERSION: u32 = 2;
+    pub const SOC_TWAI_BRP_MAX: u32 = 16384;
+    pub const SOC_TWAI_BRP_MIN: u32 = 2;
+    pub const SOC_TWAI_CONTROLLER_NUM: u32 = 1;
+    pub const SOC_TWAI_MASK_FILTER_NUM: u32 = 1;
+    pub const SOC_UART_BITRATE_MAX: u32 = 5000000;
+    pub const SOC_UART_FIFO_LEN: u32 = 128;
+    pub const SOC_UART_HP_NUM: u32 = 3;
+    pub const SOC_UART_NUM: u32 = 3;
+    pub const SOC_UHCI_NUM: u32 = 1;
+    pub const SOC_ULP_HAS_ADC: u32 = 1;
+    pub const SOC_USB_OTG_PERIPH_NUM: u32 = 1;
+    pub const SOC_WIFI_HW_TSF: u32 = 1;
+    pub const SOC_WIFI_LIGHT_SLEEP_CLK_WIDTH: u32 = 12;
+    pub const SOC_WIFI_PHY_NEEDS_USB_WORKAROUND: u32 = 1;
+}
+
+pub mod esp32c2 {
+    pub const SOC_ADC_ARBITER_SUPPORTED: bool = false;
+    pub const SOC_ADC_CALIBRATION_V1_SUPPORTED: bool = true;
+    pub const SOC_ADC_CALIB_CHAN_COMPENS_SUPPORTED: bool = false;
+    pub const SOC_ADC_DIG_CTRL_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_IIR_FILTER_SUPPORTED: bool = true;
+    pub const SOC_ADC_DMA_SUPPORTED: bool = false;
+    pub const SOC_ADC_MONITOR_SUPPORTED: bool = true;
+    pub const SOC_ADC_RTC_CTRL_SUPPORTED: bool = false;
+    pub const SOC_ADC_SELF_HW_CALI_SUPPORTED: bool = true;
+    pub const SOC_ADC_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORTED: bool = false;
+    pub const SOC_AES_SUPPORT_AES_128: bool = false;
+    pub const SOC_AES_SUPPORT_AES_192: bool = false;
+    pub const SOC_AES_SUPPORT_AES_256: bool = false;
+    pub const SOC_AES_SUPPORT_DMA: bool = false;
+    pub const SOC_AES_SUPPORT_GCM: bool = false;
+    pub const SOC_AES_SUPPORT_PSEUDO_ROUND_FUNCTION: bool = false;
+    pub const SOC_AHB_GDMA_SUPPORTED: bool = true;
+    pub const SOC_AHB_GDMA_SUPPORT_PSRAM: bool = false;
+    pub const SOC_ANA_CMPR_SUPPORTED: bool = false;
+    pub const SOC_ANA_CMPR_SUPPORT_ETM: bool = false;
+    pub const SOC_APM_CPU_APM_SUPPORTED: bool = false;
+    pub const SOC_APM_CTRL_FILTER_SUPPORTED: bool = false;
+    pub const SOC_APM_LP_APM0_SUPPORTED: bool = false;
+    pub const SOC_APM_SUPPORTED: bool = false;
+    pub const SOC_APM_SUPPORT_CTRL_CFG_LOCK: bool = false;
+    pub const SOC_APM_SUPPORT_LP_TEE_CTRL: bool = false;
+    pub const SOC_APM_SUPPORT_TEE_PERI_ACCESS_CTRL: bool = false;
+    pub const SOC_ASSIST_DEBUG_SUPPORTED: bool = true;
+    pub const SOC_ASYNC_MEMCPY_SUPPORTED: bool = true;
+    pub const SOC_BITSCRAMBLER_SUPPORTED: bool = false;
+    pub const SOC_BLE_50_SUPPORTED: bool = true;
+    pub const SOC_BLE_CTE_SUPPORTED: bool = false;
+    pub const SOC_BLE_DEVICE_PRIVACY_SUPPORTED: bool = true;
+    pub const SOC_BLE_MESH_SUPPORTED: bool = false;
+    pub const SOC_BLE_PERIODIC_ADV_ENH_SUPPORTED: bool = true;
+    pub const SOC_BLE_POWER_CONTROL_SUPPORTED: bool = false;
+    pub const SOC_BLE_SUPPORTED: bool = true;
+    pub const SOC_BLUFI_SUPPORTED: bool = true;
+    pub const SOC_BOD_SUPPORTED: bool = true;
+    pub const SOC_BOOTLOADER_ANTI_ROLLBACK_SUPPORTED: bool = false;
+    pub const SOC_BRANCH_PREDICTOR_SUPPORTED: bool = false;
+    pub const SOC_BROWNOUT_RESET_SUPPORTED: bool = true;
+    pub const SOC_BT_CLASSIC_SUPPORTED: bool = false;
+    pub const SOC_BT_H2C_ENC_KEY_CTRL_ENH_VSC_SUPPORTED: bool = false;
+    pub const SOC_BT_SUPPORTED: bool = true;
+    pub const SOC_CACHE_FREEZE_SUPPORTED: bool = true;
+    pub const SOC_CACHE_SUPPORT_WRAP: bool = false;
+    pub const SOC_CACHE_WRITEBACK_SUPPORTED: bool = false;
+    pub const SOC_CCOMP_TIMER_SUPPORTED: bool = false;
+    pub const SOC_CLK_APLL_SUPPORTED: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_LP_PLL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D2: bool = true;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D4: bool = false;
+    pub const SOC_CLK_OSC_SLOW_SUPPORTED: bool = true;
+    pub const SOC_CLK_RC32K_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC_FAST_D256_SUPPORTED: bool = true;
+    pub const SOC_CLK_RC_FAST_SUPPORT_CALIBRATION: bool = true;
+    pub const SOC_CLK_TREE_SUPPORTED: bool = true;
+    pub const SOC_CLK_XTAL32K_SUPPORTED: bool = false;
+    pub const SOC_CLOCK_TREE_MANAGEMENT_SUPPORTED: bool = false;
+    pub const SOC_CONFIGURABLE_VDDSDIO_SUPPORTED: bool = false;
+    pub const SOC_CPU_SUPPORT_WFE: bool = false;
+    pub const SOC_CP_DMA_SUPPORTED: bool = false;
+    pub const SOC_CRYPTO_DPA_PROTECTION_SUPPORTED: bool = false;
+    pub const SOC_DAC_SUPPORTED: bool = false;
+    pub const SOC_DEDICATED_GPIO_SUPPORTED: bool = true;
+    pub const SOC_DEEP_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_DIG_SIGN_SUPPORTED: bool = false;
+    pub const SOC_ECC_EXTENDED_MODES_SUPPORTED: bool = false;
+    pub const SOC_ECC_SUPPORTED: bool = true;
+    pub const SOC_ECC_SUPPORT_POINT_VERIFY_QUIRK: bool = true;
+    pub const SOC_ECDSA_SUPPORTED: bool = false;
+    pub const SOC_ECDSA_SUPPORT_CURVE_P384: bool = false;
+    pub const SOC_ECDSA_SUPPORT_DETERMINISTIC_MODE: bool = false;
+    pub const SOC_ECDSA_SUPPORT_EXPORT_PUBKEY: bool = false;
+    pub const SOC_ECDSA_SUPPORT_HW_DETERMINISTIC_LOOP: bool = false;
+    pub const SOC_EFUSE_SUPPORTED: bool = true;
+    pub const SOC_EMAC_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_SUPPORT_PSEUDO_ROUND: bool = false;
+    pub const SOC_FLASH_ENC_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORT_ETM: bool = false;
+    pub const SOC_GDMA_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_GPIO_FILTER_CLK_SUPPORT_APB: bool = true;
+    pub const SOC_GPIO_SUPPORT_DEEPSLEEP_WAKEUP: bool = true;
+    pub const SOC_GPIO_SUPPORT_ETM: bool = false;
+    pub const SOC_GPIO_SUPPORT_FORCE_HOLD: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_SINGLE_IO_IN_DSLP: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_GLITCH_FILTER: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_CTRL_BY_EFUSE: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_FILTER: bool = false;
+    pub const SOC_GPIO_SUPPORT_RTC_INDEPENDENT: bool = false;
+    pub const SOC_GPSPI_SUPPORTED: bool = true;
+    pub const SOC_GPTIMER_SUPPORTED: bool = true;
+    pub const SOC_HMAC_SUPPORTED: bool = false;
+    pub const SOC_HUK_SUPPORTED: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_BROADCAST: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_I2CRAM_ACCESS: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_SLAVE_UNMATCH: bool = false;
+    pub const SOC_I2C_SUPPORTED: bool = true;
+    pub const SOC_I2C_SUPPORT_10BIT_ADDR: bool = true;
+    pub const SOC_I2C_SUPPORT_APB: bool = false;
+    pub const SOC_I2C_SUPPORT_HW_CLR_BUS: bool = true;
+    pub const SOC_I2C_SUPPORT_HW_FSM_RST: bool = false;
+    pub const SOC_I2C_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_I2C_SUPPORT_RTC: bool = true;
+    pub const SOC_I2C_SUPPORT_SLAVE: bool = false;
+    pub const SOC_I2C_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_I2C_SUPPORT_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORTED: bool = false;
+    pub const SOC_I2S_SUPPORTS_ADC: bool = false;
+    pub const SOC_I2S_SUPPORTS_ADC_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_APLL: bool = false;
+    pub const SOC_I2S_SUPPORTS_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_DMA_EQUAL: bool = false;
+    pub const SOC_I2S_SUPPORTS_ETM: bool = false;
+    pub const SOC_I2S_SUPPORTS_LCD_CAMERA: bool = false;
+    pub const SOC_I2S_SUPPORTS_PCM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PCM2PDM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM2PCM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM_RX: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM_TX: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F120M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F160M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F240M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F64M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F96M: bool = false;
+    pub const SOC_I2S_SUPPORTS_TDM: bool = false;
+    pub const SOC_I2S_SUPPORTS_TX_SYNC_CNT: bool = false;
+    pub const SOC_I2S_SUPPORTS_XTAL: bool = false;
+    pub const SOC_I2S_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_IEEE802154_SUPPORTED: bool = false;
+    pub const SOC_INT_CLIC_SUPPORTED: bool = false;
+    pub const SOC_INT_HW_NESTED_SUPPORTED: bool = false;
+    pub const SOC_INT_PLIC_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORT_KEY_DEPLOYMENT: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LCDCAM_I80_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_RGB_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_SUPPORTED: bool = false;
+    pub const SOC_LCD_I80_SUPPORTED: bool = false;
+    pub const SOC_LCD_RGB_SUPPORTED: bool = false;
+    pub const SOC_LCD_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_SUPPORTED: bool = false;
+    pub const SOC_LEDC_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORT_APB_CLOCK: bool = false;
+    pub const SOC_LEDC_SUPPORT_FADE_STOP: bool = true;
+    pub const SOC_LEDC_SUPPORT_HS_MODE: bool = false;
+    pub const SOC_LEDC_SUPPORT_PLL_DIV_CLOCK: bool = true;
+    pub const SOC_LEDC_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_LEDC_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_LEDC_SUPPORT_XTAL_CLOCK: bool = true;
+    pub const SOC_LIGHT_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_LP_AON_SUPPORTED: bool = false;
+    pub const SOC_LP_CORE_SUPPORTED: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_ETM: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_STORE_LOAD_EXCEPTIONS: bool = false;
+    pub const SOC_LP_I2C_SUPPORTED: bool = false;
+    pub const SOC_LP_PERIPHERALS_SUPPORTED: bool = false;
+    pub const SOC_LP_TIMER_SUPPORTED: bool = false;
+    pub const SOC_MCPWM_SUPPORTED: bool = false;
+    pub const SOC_MCPWM_SUPPORT_ETM: bool = false;
+    pub const SOC_MCPWM_SUPPORT_EVENT_COMPARATOR: bool = false;
+    pub const SOC_MCPWM_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_MEMPROT_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_120M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_15M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_16M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_20M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_26M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_30M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_32M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_40M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_60M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_64M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_80M_SUPPORTED: bool = false;
+    pub const SOC_MMU_PAGE_SIZE_8KB_SUPPORTED: bool = false;
+    pub const SOC_MODEM_CLOCK_SUPPORTED: bool = false;
+    pub const SOC_MPI_SUPPORTED: bool = false;
+    pub const SOC_MPU_CONFIGURABLE_REGIONS_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_RO_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_WO_SUPPORTED: bool = false;
+    pub const SOC_MPU_SUPPORTED: bool = false;
+    pub const SOC_MWDT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_MWDT_SUPPORT_XTAL: bool = true;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_OUTPUT: bool = false;
+    pub const SOC_PARLIO_SUPPORTED: bool = false;
+    pub const SOC_PARLIO_SUPPORT_I80_LCD: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SPI_LCD: bool = false;
+    pub const SOC_PARLIO_TX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_EOF_FROM_DMA: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_LOOP_TRANSMISSION: bool = false;
+    pub const SOC_PAU_SUPPORTED: bool = false;
+    pub const SOC_PCNT_SUPPORTED: bool = false;
+    pub const SOC_PCNT_SUPPORT_CLEAR_SIGNAL: bool = false;
+    pub const SOC_PCNT_SUPPORT_RUNTIME_THRES_UPDATE: bool = false;
+    pub const SOC_PCNT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_PCNT_SUPPORT_STEP_NOTIFY: bool = false;
+    pub const SOC_PHY_SUPPORTED: bool = true;
+    pub const SOC_PMU_SUPPORTED: bool = false;
+    pub const SOC_PM_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORT_BEACON_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_BT_PD: bool = false;
+    pub const SOC_PM_SUPPORT_BT_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_CPU_PD: bool = false;
+    pub const SOC_PM_SUPPORT_DEEPSLEEP_CHECK_STUB_ONLY: bool = false;
+    pub const SOC_PM_SUPPORT_EXT0_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP_MODE_PER_PIN: bool = false;
+    pub const SOC_PM_SUPPORT_EXT_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_HP_AON_PD: bool = false;
+    pub const SOC_PM_SUPPORT_MAC_BB_PD: bool = false;
+    pub const SOC_PM_SUPPORT_MODEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_PMU_CLK_ICG: bool = false;
+    pub const SOC_PM_SUPPORT_PMU_MODEM_STATE: bool = false;
+    pub const SOC_PM_SUPPORT_RC32K_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RC_FAST_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_FAST_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RTC_PERIPH_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RTC_SLOW_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TAGMEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOP_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOUCH_SENSOR_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_VDDSDIO_PD: bool = true;
+    pub const SOC_PM_SUPPORT_WIFI_PD: bool = false;
+    pub const SOC_PM_SUPPORT_WIFI_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_XTAL32K_PD: bool = false;
+    pub const SOC_RECOVERY_BOOTLOADER_SUPPORTED: bool = false;
+    pub const SOC_REG_I2C_SUPPORTED: bool = false;
+    pub const SOC_RISCV_COPROC_SUPPORTED: bool = false;
+    pub const SOC_RMT_SUPPORTED: bool = false;
+    pub const SOC_RMT_SUPPORT_APB: bool = false;
+    pub const SOC_RMT_SUPPORT_DMA: bool = false;
+    pub const SOC_RMT_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_RMT_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_RMT_SUPPORT_RX_DEMODULATION: bool = false;
+    pub const SOC_RMT_SUPPORT_RX_PINGPONG: bool = false;
+    pub const SOC_RMT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_ASYNC_STOP: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_CARRIER_DATA_ONLY: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_AUTO_STOP: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_COUNT: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_SYNCHRO: bool = false;
+    pub const SOC_RMT_SUPPORT_XTAL: bool = false;
+    pub const SOC_RNG_SUPPORTED: bool = true;
+    pub const SOC_RSA_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_EDGE_WAKE_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_HOLD_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_INPUT_OUTPUT_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_WAKE_SUPPORTED: bool = false;
+    pub const SOC_RTC_FAST_MEM_SUPPORTED: bool = false;
+    pub const SOC_RTC_MEM_SUPPORTED: bool = false;
+    pub const SOC_RTC_MEM_SUPPORT_SPEED_MODE_SWITCH: bool = false;
+    pub const SOC_RTC_SLOW_CLK_SUPPORT_RC_FAST_D256: bool = true;
+    pub const SOC_RTC_SLOW_MEM_SUPPORTED: bool = false;
+    pub const SOC_SDIO_SLAVE_SUPPORTED: bool = false;
+    pub const SOC_SDMMC_HOST_SUPPORTED: bool = false;
+    pub const SOC_SDMMC_SUPPORT_XTAL_CLOCK: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F48M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F80M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_XTAL: bool = false;
+    pub const SOC_SDM_SUPPORTED: bool = false;
+    pub const SOC_SECURE_BOOT_SUPPORTED: bool = true;
+    pub const SOC_SHARED_IDCACHE_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORT_DMA: bool = false;
+    pub const SOC_SHA_SUPPORT_PARALLEL_ENG: bool = false;
+    pub const SOC_SHA_SUPPORT_RESUME: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA1: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA224: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA256: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA384: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_224: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_256: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_T: bool = false;
+    pub const SOC_SIMD_INSTRUCTION_SUPPORTED: bool = false;
+    pub const SOC_SPIRAM_SUPPORTED: bool = false;
+    pub const SOC_SPIRAM_XIP_SUPPORTED: bool = false;
+    pub const SOC_SPI_AS_CS_SUPPORTED: bool = false;
+    pub const SOC_SPI_FLASH_SUPPORTED: bool = true;
+    pub const SOC_SPI_HD_BOTH_INOUT_SUPPORTED: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_RESUME: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_WAIT_IDLE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CACHE_32BIT_ADDR_MAP: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_CHECK_SUS: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CONFIG_GPIO_BY_EFUSE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_FLASH_OPI_MODE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_IDLE_INTR: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_SW_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_TIMING_TUNING: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_TSUS_TRES_SEPERATE_CTR: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WB_MODE_INDEPENDENT_CONTROL: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WRAP: bool = true;
+    pub const SOC_SPI_PERIPH_SUPPORT_CONTROL_DUMMY_OUT: bool = true;
+    pub const SOC_SPI_SCT_SUPPORTED: bool = true;
+    pub const SOC_SPI_SLAVE_SUPPORT_SEG_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_CD_SIG: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_APB: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F160M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F40M: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F48M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F80M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_RC_FAST: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_XTAL: bool = true;
+    pub const SOC_SPI_SUPPORT_CONTINUOUS_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_DDRCLK: bool = true;
+    pub const SOC_SPI_SUPPORT_OCT: bool = false;
+    pub const SOC_SPI_SUPPORT_SLAVE_HD_VER2: bool = true;
+    pub const SOC_SPI_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_SUPPORTS_SECURE_DL_MODE: bool = true;
+    pub const SOC_SUPPORT_COEXISTENCE: bool = true;
+    pub const SOC_SUPPORT_SECURE_BOOT_REVOKE_KEY: bool = false;
+    pub const SOC_SYSTIMER_SUPPORTED: bool = true;
+    pub const SOC_SYSTIMER_SUPPORT_ETM: bool = false;
+    pub const SOC_SYSTIMER_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_INTR_SUPPORT: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_ETM: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_FAST_RC: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_XTAL: bool = false;
+    pub const SOC_TEMP_SENSOR_SUPPORTED: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_APB: bool = false;
+    pub const SOC_TIMER_GROUP_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_TIMER_GROUP_SUPPORT_XTAL: bool = true;
+    pub const SOC_TIMER_SUPPORT_ETM: bool = false;
+    pub const SOC_TIMER_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TOUCH_PROXIMITY_MEAS_DONE_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SENSOR_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SUPPORT_BENCHMARK: bool = false;
+    pub const SOC_TOUCH_SUPPORT_DENOISE_CHAN: bool = false;
+    pub const SOC_TOUCH_SUPPORT_PROX_SENSING: bool = false;
+    pub const SOC_TOUCH_SUPPORT_SLEEP_WAKEUP: bool = false;
+    pub const SOC_TOUCH_SUPPORT_WATERPROOF: bool = false;
+    pub const SOC_TWAI_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_TWAI_CLK_SUPPORT_XTAL: bool = false;
+    pub const SOC_TWAI_SUPPORTED: bool = false;
+    pub const SOC_TWAI_SUPPORTS_RX_STATUS: bool = false;
+    pub const SOC_TWAI_SUPPORT_FD: bool = false;
+    pub const SOC_TWAI_SUPPORT_MULTI_ADDRESS_LAYOUT: bool = false;
+    pub const SOC_TWAI_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TWAI_SUPPORT_TIMESTAMP: bool = false;
+    pub const SOC_UART_SUPPORTED: bool = true;
+    pub const SOC_UART_SUPPORT_APB_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_FSM_TX_WAIT_SEND: bool = true;
+    pub const SOC_UART_SUPPORT_PLL_F40M_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_PLL_F80M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_UART_SUPPORT_RTC_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_UART_SUPPORT_WAKEUP_INT: bool = true;
+    pub const SOC_UART_SUPPORT_XTAL_CLK: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_ACTIVE_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_CHAR_SEQ_MODE: bool = false;
+    pub const SOC_UART_WAKEUP_SUPPORT_FIFO_THRESH_MODE: bool = false;
+    pub const SOC_UART_WAKEUP_SUPPORT_START_BIT_MODE: bool = false;
+    pub const SOC_UHCI_SUPPORTED: bool = false;
+    pub const SOC_ULP_FSM_SUPPORTED: bool = false;
+    pub const SOC_ULP_LP_UART_SUPPORTED: bool = false;
+    pub const SOC_ULP_SUPPORTED: bool = false;
+    pub const SOC_USB_OTG_SUPPORTED: bool = false;
+    pub const SOC_USB_SERIAL_JTAG_SUPPORTED: bool = false;
+    pub const SOC_VBAT_SUPPORTED: bool = false;
+    pub const SOC_WDT_SUPPORTED: bool = true;
+    pub const SOC_WIFI_CSI_SUPPORT: bool = false;
+    pub const SOC_WIFI_FTM_SUPPORT: bool = true;
+    pub const SOC_WIFI_GCMP_SUPPORT: bool = false;
+    pub const SOC_WIFI_HE_SUPPORT: bool = false;
+    pub const SOC_WIFI_MESH_SUPPORT: bool = false;
+    pub const SOC_WIFI_NAN_SUPPORT: bool = false;
+    pub const SOC_WIFI_SUPPORTED: bool = true;
+    pub const SOC_WIFI_SUPPORT_5G: bool = false;
+    pub const SOC_WIFI_SUPPORT_VARIABLE_BEACON_WINDOW: bool = true;
+    pub const SOC_WIFI_WAPI_SUPPORT: bool = false;
+    pub const SOC_XTAL_SUPPORT_26M: bool = true;
+    pub const SOC_XTAL_SUPPORT_32M: bool = false;
+    pub const SOC_XTAL_SUPPORT_40M: bool = true;
+    pub const SOC_XTAL_SUPPORT_48M: bool = false;
+    pub const SOC_XTAL_SUPPORT_AUTO_DETECT: bool = false;
+    pub const SOC_XT_WDT_SUPPORTED: bool = false;
+    pub const SOC_ADC_ATTEN_NUM: u32 = 4;
+    pub const SOC_ADC_DIGI_CONTROLLER_NUM: u32 = 1;
+    pub const SOC_ADC_DIGI_IIR_FILTER_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MONITOR_NUM: u32 = 2;
+    pub const SOC_ADC_MAX_CHANNEL_NUM: u32 = 5;
+    pub const SOC_ADC_PATT_LEN_MAX: u32 = 8;
+    pub const SOC_ADC_PERIPH_NUM: u32 = 1;
+    pub const SOC_ADC_RTC_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_RTC_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_HIGH: u32 = 83333;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_LOW: u32 = 611;
+    pub const SOC_ADC_SHARED_POWER: u32 = 1;
+    pub const SOC_AHB_GDMA_VERSION: u32 = 1;
+    pub const SOC_CAPS_ECO_VER: u32 = 100;
+    pub const SOC_COEX_HW_PTI: u32 = 1;
+    pub const SOC_CPU_BREAKPOINTS_NUM: u32 = 2;
+    pub const SOC_CPU_CORES_NUM: u32 = 1;
+    pub const SOC_CPU_HAS_CSR_PC: u32 = 1;
+    pub const SOC_CPU_HAS_FLEXIBLE_INTC: u32 = 1;
+    pub const SOC_CPU_IDRAM_SPLIT_USING_PMP: u32 = 1;
+    pub const SOC_CPU_INTR_NUM: u32 = 32;
+    pub const SOC_CPU_WATCHPOINTS_NUM: u32 = 2;
+    pub const SOC_CPU_WATCHPOINT_MAX_REGION_SIZE: u32 = 0x80000000;
+    pub const SOC_DEDIC_GPIO_IN_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_GPIO_OUT_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_PERIPH_ALWAYS_ENABLE: u32 = 1;
+    pub const SOC_EFUSE_CONSISTS_OF_ONE_KEY_BLOCK: u32 = 1;
+    pub const SOC_EFUSE_DIS_DIRECT_BOOT: u32 = 1;
+    pub const SOC_EFUSE_DIS_DOWNLOAD_ICACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_PAD_JTAG: u32 = 1;
+    pub const SOC_EFUSE_KEY_PURPOSE_FIELD: u32 = 0;
+    pub const SOC_EFUSE_SECURE_BOOT_KEY_DIGESTS: u32 = 1;
+    pub const SOC_ESP_NIMBLE_CONTROLLER: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_ADVANCE: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_LEADER_TX_LINE: u32 = 0;
+    pub const SOC_FLASH_ENCRYPTED_XTS_AES_BLOCK_MAX: u32 = 32;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_128: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_128_DERIVED: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_OPTIONS: u32 = 1;
+    pub const SOC_GDMA_NUM_GROUPS_MAX: u32 = 1;
+    pub const SOC_GDMA_PAIRS_PER_GROUP_MAX: u32 = 1;
+    pub const SOC_GPIO_CLOCKOUT_BY_GPIO_MATRIX: u32 = 1;
+    pub const SOC_GPIO_CLOCKOUT_CHANNEL_NUM: u32 = 3;
+    pub const SOC_GPIO_DEEP_SLEEP_WAKE_SUPPORTED_PIN_CNT: u32 = 6;
+    pub const SOC_GPIO_DEEP_SLEEP_WAKE_VALID_GPIO_MASK: u32 = 0x3f;
+    pub const SOC_GPIO_IN_RANGE_MAX: u32 = 20;
+    pub const SOC_GPIO_OUT_RANGE_MAX: u32 = 20;
+    pub const SOC_GPIO_PIN_COUNT: u32 = 21;
+    pub const SOC_GPIO_PORT: u32 = 1;
+    pub const SOC_GPIO_VALID_DIGITAL_IO_PAD_MASK: u64 = 0x1fffc0;
+    pub const SOC_GPIO_VALID_GPIO_MASK: u64 = 0x1fffff;
+    pub const SOC_GPIO_VALID_OUTPUT_GPIO_MASK: u64 = 0x1fffff;
+    pub const SOC_HP_I2C_NUM: u32 = 1;
+    pub const SOC_I2C_CMD_REG_NUM: u32 = 8;
+    pub const SOC_I2C_FIFO_LEN: u32 = 16;
+    pub const SOC_I2C_NUM: u32 = 1;
+    pub const SOC_LEDC_CHANNEL_NUM: u32 = 6;
+    pub const SOC_LEDC_TIMER_BIT_WIDTH: u32 = 14;
+    pub const SOC_LEDC_TIMER_NUM: u32 = 4;
+    pub const SOC_LP_PERIPH_SHARE_INTERRUPT: u32 = 1;
+    pub const SOC_LP_TIMER_BIT_WIDTH_HI: u32 = 16;
+    pub const SOC_LP_TIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_MEMSPI_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_MMU_LINEAR_ADDRESS_REGION_NUM: u32 = 1;
+    pub const SOC_MMU_PAGE_SIZE_CONFIGURABLE: u32 = 1;
+    pub const SOC_MMU_PERIPH_NUM: u32 = 1;
+    pub const SOC_MPU_MIN_REGION_SIZE: u32 = 0x20000000;
+    pub const SOC_MPU_REGIONS_MAX_NUM: u32 = 8;
+    pub const SOC_PHY_COMBO_MODULE: u32 = 1;
+    pub const SOC_PHY_DIG_REGS_MEM_SIZE: u32 = 84;
+    pub const SOC_PHY_IMPROVE_RX_11B: u32 = 1;
+    pub const SOC_RTCIO_PIN_COUNT: u32 = 0;
+    pub const SOC_RTC_CNTL_CPU_PD_DMA_ADDR_ALIGN: u32 = 16;
+    pub const SOC_RTC_CNTL_CPU_PD_DMA_BLOCK_SIZE: u32 = 16;
+    pub const SOC_RTC_CNTL_CPU_PD_DMA_BUS_WIDTH: u32 = 128;
+    pub const SOC_RTC_CNTL_CPU_PD_REG_FILE_NUM: u32 = 108;
+    pub const SOC_RTC_CNTL_CPU_PD_RETENTION_MEM_SIZE: u32 = 1728;
+    pub const SOC_SECURE_BOOT_V2_ECC: u32 = 1;
+    pub const SOC_SPI_MAXIMUM_BUFFER_SIZE: u32 = 64;
+    pub const SOC_SPI_MAX_CS_NUM: u32 = 6;
+    pub const SOC_SPI_MAX_PRE_DIVIDER: u32 = 16;
+    pub const SOC_SPI_PERIPH_NUM: u32 = 2;
+    pub const SOC_SPI_SCT_BUFFER_NUM_MAX: u32 = 15;
+    pub const SOC_SPI_SCT_CONF_BITLEN_MAX: u32 = 0x3fffa;
+    pub const SOC_SPI_SCT_REG_NUM: u32 = 14;
+    pub const SOC_SYSTIMER_ALARM_MISS_COMPENSATE: u32 = 1;
+    pub const SOC_SYSTIMER_ALARM_NUM: u32 = 3;
+    pub const SOC_SYSTIMER_BIT_WIDTH_HI: u32 = 20;
+    pub const SOC_SYSTIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_SYSTIMER_COUNTER_NUM: u32 = 2;
+    pub const SOC_SYSTIMER_FIXED_DIVIDER: u32 = 1;
+    pub const SOC_SYSTIMER_INT_LEVEL: u32 = 1;
+    pub const SOC_TIMER_GROUPS: u32 = 1;
+    pub const SOC_TIMER_GROUP_COUNTER_BIT_WIDTH: u32 = 54;
+    pub const SOC_TIMER_GROUP_TIMERS_PER_GROUP: u32 = 1;
+    pub const SOC_TIMER_GROUP_TOTAL_TIMERS: u32 = 1;
+    pub const SOC_UART_BITRATE_MAX: u32 = 2500000;
+    pub const SOC_UART_FIFO_LEN: u32 = 128;
+    pub const SOC_UART_HP_NUM: u32 = 2;
+    pub const SOC_UART_NUM: u32 = 2;
+    pub const SOC_WIFI_HW_TSF: u32 = 1;
+    pub const SOC_WIFI_LIGHT_SLEEP_CLK_WIDTH: u32 = 12;
+    pub const SOC_WIFI_PHY_NEEDS_USB_WORKAROUND: u32 = 1;
+}
+
+pub mod esp32c3 {
+    pub const SOC_ADC_ARBITER_SUPPORTED: bool = true;
+    pub const SOC_ADC_CALIBRATION_V1_SUPPORTED: bool = true;
+    pub const SOC_ADC_CALIB_CHAN_COMPENS_SUPPORTED: bool = false;
+    pub const SOC_ADC_DIG_CTRL_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_IIR_FILTER_SUPPORTED: bool = true;
+    pub const SOC_ADC_DMA_SUPPORTED: bool = true;
+    pub const SOC_ADC_MONITOR_SUPPORTED: bool = true;
+    pub const SOC_ADC_RTC_CTRL_SUPPORTED: bool = false;
+    pub const SOC_ADC_SELF_HW_CALI_SUPPORTED: bool = true;
+    pub const SOC_ADC_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORT_AES_128: bool = true;
+    pub const SOC_AES_SUPPORT_AES_192: bool = false;
+    pub const SOC_AES_SUPPORT_AES_256: bool = true;
+    pub const SOC_AES_SUPPORT_DMA: bool = true;
+    pub const SOC_AES_SUPPORT_GCM: bool = false;
+    pub const SOC_AES_SUPPORT_PSEUDO_ROUND_FUNCTION: bool = false;
+    pub const SOC_AHB_GDMA_SUPPORTED: bool = true;
+    pub const SOC_AHB_GDMA_SUPPORT_PSRAM: bool = false;
+    pub const SOC_ANA_CMPR_SUPPORTED: bool = false;
+    pub const SOC_ANA_CMPR_SUPPORT_ETM: bool = false;
+    pub const SOC_APM_CPU_APM_SUPPORTED: bool = false;
+    pub const SOC_APM_CTRL_FILTER_SUPPORTED: bool = false;
+    pub const SOC_APM_LP_APM0_SUPPORTED: bool = false;
+    pub const SOC_APM_SUPPORTED: bool = false;
+    pub const SOC_APM_SUPPORT_CTRL_CFG_LOCK: bool = false;
+    pub const SOC_APM_SUPPORT_LP_TEE_CTRL: bool = false;
+    pub const SOC_APM_SUPPORT_TEE_PERI_ACCESS_CTRL: bool = false;
+    pub const SOC_ASSIST_DEBUG_SUPPORTED: bool = true;
+    pub const SOC_ASYNC_MEMCPY_SUPPORTED: bool = true;
+    pub const SOC_BITSCRAMBLER_SUPPORTED: bool = false;
+    pub const SOC_BLE_50_SUPPORTED: bool = true;
+    pub const SOC_BLE_CTE_SUPPORTED: bool = false;
+    pub const SOC_BLE_DEVICE_PRIVACY_SUPPORTED: bool = true;
+    pub const SOC_BLE_MESH_SUPPORTED: bool = true;
+    pub const SOC_BLE_PERIODIC_ADV_ENH_SUPPORTED: bool = false;
+    pub const SOC_BLE_POWER_CONTROL_SUPPORTED: bool = false;
+    pub const SOC_BLE_SUPPORTED: bool = true;
+    pub const SOC_BLUFI_SUPPORTED: bool = true;
+    pub const SOC_BOD_SUPPORTED: bool = true;
+    pub const SOC_BOOTLOADER_ANTI_ROLLBACK_SUPPORTED: bool = false;
+    pub const SOC_BRANCH_PREDICTOR_SUPPORTED: bool = false;
+    pub const SOC_BROWNOUT_RESET_SUPPORTED: bool = true;
+    pub const SOC_BT_CLASSIC_SUPPORTED: bool = false;
+    pub const SOC_BT_H2C_ENC_KEY_CTRL_ENH_VSC_SUPPORTED: bool = false;
+    pub const SOC_BT_SUPPORTED: bool = true;
+    pub const SOC_CACHE_FREEZE_SUPPORTED: bool = true;
+    pub const SOC_CACHE_SUPPORT_WRAP: bool = false;
+    pub const SOC_CACHE_WRITEBACK_SUPPORTED: bool = false;
+    pub const SOC_CCOMP_TIMER_SUPPORTED: bool = false;
+    pub const SOC_CLK_APLL_SUPPORTED: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_LP_PLL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D2: bool = true;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D4: bool = false;
+    pub const SOC_CLK_OSC_SLOW_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC32K_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC_FAST_D256_SUPPORTED: bool = true;
+    pub const SOC_CLK_RC_FAST_SUPPORT_CALIBRATION: bool = true;
+    pub const SOC_CLK_TREE_SUPPORTED: bool = true;
+    pub const SOC_CLK_XTAL32K_SUPPORTED: bool = true;
+    pub const SOC_CLOCK_TREE_MANAGEMENT_SUPPORTED: bool = false;
+    pub const SOC_CONFIGURABLE_VDDSDIO_SUPPORTED: bool = false;
+    pub const SOC_CPU_SUPPORT_WFE: bool = false;
+    pub const SOC_CP_DMA_SUPPORTED: bool = false;
+    pub const SOC_CRYPTO_DPA_PROTECTION_SUPPORTED: bool = false;
+    pub const SOC_DAC_SUPPORTED: bool = false;
+    pub const SOC_DEDICATED_GPIO_SUPPORTED: bool = true;
+    pub const SOC_DEEP_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_DIG_SIGN_SUPPORTED: bool = true;
+    pub const SOC_ECC_EXTENDED_MODES_SUPPORTED: bool = false;
+    pub const SOC_ECC_SUPPORTED: bool = false;
+    pub const SOC_ECC_SUPPORT_POINT_VERIFY_QUIRK: bool = false;
+    pub const SOC_ECDSA_SUPPORTED: bool = false;
+    pub const SOC_ECDSA_SUPPORT_CURVE_P384: bool = false;
+    pub const SOC_ECDSA_SUPPORT_DETERMINISTIC_MODE: bool = false;
+    pub const SOC_ECDSA_SUPPORT_EXPORT_PUBKEY: bool = false;
+    pub const SOC_ECDSA_SUPPORT_HW_DETERMINISTIC_LOOP: bool = false;
+    pub const SOC_EFUSE_SUPPORTED: bool = true;
+    pub const SOC_EMAC_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_SUPPORT_PSEUDO_ROUND: bool = false;
+    pub const SOC_FLASH_ENC_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORT_ETM: bool = false;
+    pub const SOC_GDMA_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_GPIO_FILTER_CLK_SUPPORT_APB: bool = true;
+    pub const SOC_GPIO_SUPPORT_DEEPSLEEP_WAKEUP: bool = true;
+    pub const SOC_GPIO_SUPPORT_ETM: bool = false;
+    pub const SOC_GPIO_SUPPORT_FORCE_HOLD: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_SINGLE_IO_IN_DSLP: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_GLITCH_FILTER: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_CTRL_BY_EFUSE: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_FILTER: bool = false;
+    pub const SOC_GPIO_SUPPORT_RTC_INDEPENDENT: bool = false;
+    pub const SOC_GPSPI_SUPPORTED: bool = true;
+    pub const SOC_GPTIMER_SUPPORTED: bool = true;
+    pub const SOC_HMAC_SUPPORTED: bool = true;
+    pub const SOC_HUK_SUPPORTED: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_BROADCAST: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_I2CRAM_ACCESS: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_SLAVE_UNMATCH: bool = false;
+    pub const SOC_I2C_SUPPORTED: bool = true;
+    pub const SOC_I2C_SUPPORT_10BIT_ADDR: bool = true;
+    pub const SOC_I2C_SUPPORT_APB: bool = false;
+    pub const SOC_I2C_SUPPORT_HW_CLR_BUS: bool = true;
+    pub const SOC_I2C_SUPPORT_HW_FSM_RST: bool = false;
+    pub const SOC_I2C_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_I2C_SUPPORT_RTC: bool = true;
+    pub const SOC_I2C_SUPPORT_SLAVE: bool = true;
+    pub const SOC_I2C_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_I2C_SUPPORT_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORTED: bool = true;
+    pub const SOC_I2S_SUPPORTS_ADC: bool = false;
+    pub const SOC_I2S_SUPPORTS_ADC_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_APLL: bool = false;
+    pub const SOC_I2S_SUPPORTS_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_DMA_EQUAL: bool = false;
+    pub const SOC_I2S_SUPPORTS_ETM: bool = false;
+    pub const SOC_I2S_SUPPORTS_LCD_CAMERA: bool = false;
+    pub const SOC_I2S_SUPPORTS_PCM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PCM2PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM2PCM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM_RX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM_TX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F120M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F160M: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F240M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F64M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F96M: bool = false;
+    pub const SOC_I2S_SUPPORTS_TDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_TX_SYNC_CNT: bool = false;
+    pub const SOC_I2S_SUPPORTS_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_IEEE802154_SUPPORTED: bool = false;
+    pub const SOC_INT_CLIC_SUPPORTED: bool = false;
+    pub const SOC_INT_HW_NESTED_SUPPORTED: bool = false;
+    pub const SOC_INT_PLIC_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORT_KEY_DEPLOYMENT: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LCDCAM_I80_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_RGB_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_SUPPORTED: bool = false;
+    pub const SOC_LCD_I80_SUPPORTED: bool = false;
+    pub const SOC_LCD_RGB_SUPPORTED: bool = false;
+    pub const SOC_LCD_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_SUPPORTED: bool = false;
+    pub const SOC_LEDC_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORT_APB_CLOCK: bool = true;
+    pub const SOC_LEDC_SUPPORT_FADE_STOP: bool = true;
+    pub const SOC_LEDC_SUPPORT_HS_MODE: bool = false;
+    pub const SOC_LEDC_SUPPORT_PLL_DIV_CLOCK: bool = false;
+    pub const SOC_LEDC_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_LEDC_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_LEDC_SUPPORT_XTAL_CLOCK: bool = true;
+    pub const SOC_LIGHT_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_LP_AON_SUPPORTED: bool = false;
+    pub const SOC_LP_CORE_SUPPORTED: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_ETM: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_STORE_LOAD_EXCEPTIONS: bool = false;
+    pub const SOC_LP_I2C_SUPPORTED: bool = false;
+    pub const SOC_LP_PERIPHERALS_SUPPORTED: bool = false;
+    pub const SOC_LP_TIMER_SUPPORTED: bool = false;
+    pub const SOC_MCPWM_SUPPORTED: bool = false;
+    pub const SOC_MCPWM_SUPPORT_ETM: bool = false;
+    pub const SOC_MCPWM_SUPPORT_EVENT_COMPARATOR: bool = false;
+    pub const SOC_MCPWM_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_MEMPROT_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_120M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_15M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_16M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_20M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_26M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_30M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_32M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_40M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_60M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_64M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_80M_SUPPORTED: bool = true;
+    pub const SOC_MMU_PAGE_SIZE_8KB_SUPPORTED: bool = false;
+    pub const SOC_MODEM_CLOCK_SUPPORTED: bool = false;
+    pub const SOC_MPI_SUPPORTED: bool = true;
+    pub const SOC_MPU_CONFIGURABLE_REGIONS_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_RO_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_WO_SUPPORTED: bool = false;
+    pub const SOC_MPU_SUPPORTED: bool = false;
+    pub const SOC_MWDT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_MWDT_SUPPORT_XTAL: bool = true;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_OUTPUT: bool = false;
+    pub const SOC_PARLIO_SUPPORTED: bool = false;
+    pub const SOC_PARLIO_SUPPORT_I80_LCD: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SPI_LCD: bool = false;
+    pub const SOC_PARLIO_TX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_EOF_FROM_DMA: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_LOOP_TRANSMISSION: bool = false;
+    pub const SOC_PAU_SUPPORTED: bool = false;
+    pub const SOC_PCNT_SUPPORTED: bool = false;
+    pub const SOC_PCNT_SUPPORT_CLEAR_SIGNAL: bool = false;
+    pub const SOC_PCNT_SUPPORT_RUNTIME_THRES_UPDATE: bool = false;
+    pub const SOC_PCNT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_PCNT_SUPPORT_STEP_NOTIFY: bool = false;
+    pub const SOC_PHY_SUPPORTED: bool = true;
+    pub const SOC_PMU_SUPPORTED: bool = false;
+    pub const SOC_PM_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORT_BEACON_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_BT_PD: bool = true;
+    pub const SOC_PM_SUPPORT_BT_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_CPU_PD: bool = true;
+    pub const SOC_PM_SUPPORT_DEEPSLEEP_CHECK_STUB_ONLY: bool = false;
+    pub const SOC_PM_SUPPORT_EXT0_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP_MODE_PER_PIN: bool = false;
+    pub const SOC_PM_SUPPORT_EXT_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_HP_AON_PD: bool = false;
+    pub const SOC_PM_SUPPORT_MAC_BB_PD: bool = true;
+    pub const SOC_PM_SUPPORT_MODEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_PMU_CLK_ICG: bool = false;
+    pub const SOC_PM_SUPPORT_PMU_MODEM_STATE: bool = false;
+    pub const SOC_PM_SUPPORT_RC32K_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RC_FAST_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_FAST_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RTC_PERIPH_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RTC_SLOW_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TAGMEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOP_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOUCH_SENSOR_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_VDDSDIO_PD: bool = true;
+    pub const SOC_PM_SUPPORT_WIFI_PD: bool = true;
+    pub const SOC_PM_SUPPORT_WIFI_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_XTAL32K_PD: bool = false;
+    pub const SOC_RECOVERY_BOOTLOADER_SUPPORTED: bool = false;
+    pub const SOC_REG_I2C_SUPPORTED: bool = false;
+    pub const SOC_RISCV_COPROC_SUPPORTED: bool = false;
+    pub const SOC_RMT_SUPPORTED: bool = true;
+    pub const SOC_RMT_SUPPORT_APB: bool = true;
+    pub const SOC_RMT_SUPPORT_DMA: bool = false;
+    pub const SOC_RMT_SUPPORT_RC_FAST: bool = true;
+    pub const SOC_RMT_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_RMT_SUPPORT_RX_DEMODULATION: bool = true;
+    pub const SOC_RMT_SUPPORT_RX_PINGPONG: bool = true;
+    pub const SOC_RMT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_ASYNC_STOP: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_CARRIER_DATA_ONLY: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_AUTO_STOP: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_COUNT: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_SYNCHRO: bool = true;
+    pub const SOC_RMT_SUPPORT_XTAL: bool = true;
+    pub const SOC_RNG_SUPPORTED: bool = true;
+    pub const SOC_RSA_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_EDGE_WAKE_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_HOLD_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_INPUT_OUTPUT_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_WAKE_SUPPORTED: bool = false;
+    pub const SOC_RTC_FAST_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORT_SPEED_MODE_SWITCH: bool = false;
+    pub const SOC_RTC_SLOW_CLK_SUPPORT_RC_FAST_D256: bool = true;
+    pub const SOC_RTC_SLOW_MEM_SUPPORTED: bool = false;
+    pub const SOC_SDIO_SLAVE_SUPPORTED: bool = false;
+    pub const SOC_SDMMC_HOST_SUPPORTED: bool = false;
+    pub const SOC_SDMMC_SUPPORT_XTAL_CLOCK: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_APB: bool = true;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F48M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F80M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_XTAL: bool = false;
+    pub const SOC_SDM_SUPPORTED: bool = true;
+    pub const SOC_SECURE_BOOT_SUPPORTED: bool = true;
+    pub const SOC_SHARED_IDCACHE_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORT_DMA: bool = true;
+    pub const SOC_SHA_SUPPORT_PARALLEL_ENG: bool = false;
+    pub const SOC_SHA_SUPPORT_RESUME: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA1: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA224: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA256: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA384: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_224: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_256: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_T: bool = false;
+    pub const SOC_SIMD_INSTRUCTION_SUPPORTED: bool = false;
+    pub const SOC_SPIRAM_SUPPORTED: bool = false;
+    pub const SOC_SPIRAM_XIP_SUPPORTED: bool = false;
+    pub const SOC_SPI_AS_CS_SUPPORTED: bool = false;
+    pub const SOC_SPI_FLASH_SUPPORTED: bool = true;
+    pub const SOC_SPI_HD_BOTH_INOUT_SUPPORTED: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_RESUME: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_WAIT_IDLE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CACHE_32BIT_ADDR_MAP: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_CHECK_SUS: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CONFIG_GPIO_BY_EFUSE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_FLASH_OPI_MODE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_IDLE_INTR: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_SW_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_TIMING_TUNING: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_TSUS_TRES_SEPERATE_CTR: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WB_MODE_INDEPENDENT_CONTROL: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WRAP: bool = true;
+    pub const SOC_SPI_PERIPH_SUPPORT_CONTROL_DUMMY_OUT: bool = true;
+    pub const SOC_SPI_SCT_SUPPORTED: bool = true;
+    pub const SOC_SPI_SLAVE_SUPPORT_SEG_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_CD_SIG: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_APB: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_PLL: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F160M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F40M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F48M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F80M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_RC_FAST: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_XTAL: bool = true;
+    pub const SOC_SPI_SUPPORT_CONTINUOUS_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_DDRCLK: bool = true;
+    pub const SOC_SPI_SUPPORT_OCT: bool = false;
+    pub const SOC_SPI_SUPPORT_SLAVE_HD_VER2: bool = true;
+    pub const SOC_SPI_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_SUPPORTS_SECURE_DL_MODE: bool = true;
+    pub const SOC_SUPPORT_COEXISTENCE: bool = true;
+    pub const SOC_SUPPORT_SECURE_BOOT_REVOKE_KEY: bool = true;
+    pub const SOC_SYSTIMER_SUPPORTED: bool = true;
+    pub const SOC_SYSTIMER_SUPPORT_ETM: bool = false;
+    pub const SOC_SYSTIMER_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_INTR_SUPPORT: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_ETM: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_FAST_RC: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_XTAL: bool = true;
+    pub const SOC_TEMP_SENSOR_SUPPORTED: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_APB: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_TIMER_GROUP_SUPPORT_XTAL: bool = true;
+    pub const SOC_TIMER_SUPPORT_ETM: bool = false;
+    pub const SOC_TIMER_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TOUCH_PROXIMITY_MEAS_DONE_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SENSOR_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SUPPORT_BENCHMARK: bool = false;
+    pub const SOC_TOUCH_SUPPORT_DENOISE_CHAN: bool = false;
+    pub const SOC_TOUCH_SUPPORT_PROX_SENSING: bool = false;
+    pub const SOC_TOUCH_SUPPORT_SLEEP_WAKEUP: bool = false;
+    pub const SOC_TOUCH_SUPPORT_WATERPROOF: bool = false;
+    pub const SOC_TWAI_CLK_SUPPORT_APB: bool = true;
+    pub const SOC_TWAI_CLK_SUPPORT_XTAL: bool = false;
+    pub const SOC_TWAI_SUPPORTED: bool = true;
+    pub const SOC_TWAI_SUPPORTS_RX_STATUS: bool = true;
+    pub const SOC_TWAI_SUPPORT_FD: bool = false;
+    pub const SOC_TWAI_SUPPORT_MULTI_ADDRESS_LAYOUT: bool = false;
+    pub const SOC_TWAI_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TWAI_SUPPORT_TIMESTAMP: bool = false;
+    pub const SOC_UART_SUPPORTED: bool = true;
+    pub const SOC_UART_SUPPORT_APB_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_FSM_TX_WAIT_SEND: bool = true;
+    pub const SOC_UART_SUPPORT_PLL_F40M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_PLL_F80M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_UART_SUPPORT_RTC_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_UART_SUPPORT_WAKEUP_INT: bool = true;
+    pub const SOC_UART_SUPPORT_XTAL_CLK: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_ACTIVE_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_CHAR_SEQ_MODE: bool = false;
+    pub const SOC_UART_WAKEUP_SUPPORT_FIFO_THRESH_MODE: bool = false;
+    pub const SOC_UART_WAKEUP_SUPPORT_START_BIT_MODE: bool = false;
+    pub const SOC_UHCI_SUPPORTED: bool = true;
+    pub const SOC_ULP_FSM_SUPPORTED: bool = false;
+    pub const SOC_ULP_LP_UART_SUPPORTED: bool = false;
+    pub const SOC_ULP_SUPPORTED: bool = false;
+    pub const SOC_USB_OTG_SUPPORTED: bool = false;
+    pub const SOC_USB_SERIAL_JTAG_SUPPORTED: bool = true;
+    pub const SOC_VBAT_SUPPORTED: bool = false;
+    pub const SOC_WDT_SUPPORTED: bool = true;
+    pub const SOC_WIFI_CSI_SUPPORT: bool = true;
+    pub const SOC_WIFI_FTM_SUPPORT: bool = true;
+    pub const SOC_WIFI_GCMP_SUPPORT: bool = true;
+    pub const SOC_WIFI_HE_SUPPORT: bool = false;
+    pub const SOC_WIFI_MESH_SUPPORT: bool = true;
+    pub const SOC_WIFI_NAN_SUPPORT: bool = false;
+    pub const SOC_WIFI_SUPPORTED: bool = true;
+    pub const SOC_WIFI_SUPPORT_5G: bool = false;
+    pub const SOC_WIFI_SUPPORT_VARIABLE_BEACON_WINDOW: bool = true;
+    pub const SOC_WIFI_WAPI_SUPPORT: bool = true;
+    pub const SOC_XTAL_SUPPORT_26M: bool = false;
+    pub const SOC_XTAL_SUPPORT_32M: bool = false;
+    pub const SOC_XTAL_SUPPORT_40M: bool = true;
+    pub const SOC_XTAL_SUPPORT_48M: bool = false;
+    pub const SOC_XTAL_SUPPORT_AUTO_DETECT: bool = false;
+    pub const SOC_XT_WDT_SUPPORTED: bool = true;
+    pub const SOC_ADC_ATTEN_NUM: u32 = 4;
+    pub const SOC_ADC_DIGI_CONTROLLER_NUM: u32 = 1;
+    pub const SOC_ADC_DIGI_DATA_BYTES_PER_CONV: u32 = 4;
+    pub const SOC_ADC_DIGI_IIR_FILTER_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MONITOR_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_RESULT_BYTES: u32 = 4;
+    pub const SOC_ADC_MAX_CHANNEL_NUM: u32 = 5;
+    pub const SOC_ADC_PATT_LEN_MAX: u32 = 8;
+    pub const SOC_ADC_PERIPH_NUM: u32 = 2;
+    pub const SOC_ADC_RTC_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_RTC_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_HIGH: u32 = 83333;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_LOW: u32 = 611;
+    pub const SOC_ADC_SHARED_POWER: u32 = 1;
+    pub const SOC_AES_GDMA: u32 = 1;
+    pub const SOC_AHB_GDMA_VERSION: u32 = 1;
+    pub const SOC_APB_BACKUP_DMA: u32 = 1;
+    pub const SOC_CACHE_MEMORY_IBANK_SIZE: u32 = 0x4000;
+    pub const SOC_CAPS_ECO_VER: u32 = 3;
+    pub const SOC_COEX_HW_PTI: u32 = 1;
+    pub const SOC_CPU_BREAKPOINTS_NUM: u32 = 8;
+    pub const SOC_CPU_CORES_NUM: u32 = 1;
+    pub const SOC_CPU_HAS_CSR_PC: u32 = 1;
+    pub const SOC_CPU_HAS_FLEXIBLE_INTC: u32 = 1;
+    pub const SOC_CPU_INTR_NUM: u32 = 32;
+    pub const SOC_CPU_WATCHPOINTS_NUM: u32 = 8;
+    pub const SOC_CPU_WATCHPOINT_MAX_REGION_SIZE: u32 = 0x80000000;
+    pub const SOC_DEDIC_GPIO_IN_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_GPIO_OUT_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_PERIPH_ALWAYS_ENABLE: u32 = 1;
+    pub const SOC_DS_KEY_CHECK_MAX_WAIT_US: u32 = 1100;
+    pub const SOC_DS_KEY_PARAM_MD_IV_LENGTH: u32 = 16;
+    pub const SOC_DS_SIGNATURE_MAX_BIT_LEN: u32 = 3072;
+    pub const SOC_EFUSE_BLOCK9_KEY_PURPOSE_QUIRK: u32 = 1;
+    pub const SOC_EFUSE_DIS_DIRECT_BOOT: u32 = 1;
+    pub const SOC_EFUSE_DIS_DOWNLOAD_ICACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_ICACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_PAD_JTAG: u32 = 1;
+    pub const SOC_EFUSE_DIS_USB_JTAG: u32 = 1;
+    pub const SOC_EFUSE_HAS_EFUSE_RST_BUG: u32 = 1;
+    pub const SOC_EFUSE_KEY_PURPOSE_FIELD: u32 = 1;
+    pub const SOC_EFUSE_REVOKE_BOOT_KEY_DIGESTS: u32 = 1;
+    pub const SOC_EFUSE_SECURE_BOOT_KEY_DIGESTS: u32 = 3;
+    pub const SOC_EFUSE_SOFT_DIS_JTAG: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_ADVANCE: u32 = 0;
+    pub const SOC_EXTERNAL_COEX_LEADER_TX_LINE: u32 = 0;
+    pub const SOC_FLASH_ENCRYPTED_XTS_AES_BLOCK_MAX: u32 = 32;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_128: u32 = 1;
+    pub const SOC_GDMA_NUM_GROUPS_MAX: u32 = 1;
+    pub const SOC_GDMA_PAIRS_PER_GROUP_MAX: u32 = 3;
+    pub const SOC_GPIO_CLOCKOUT_BY_GPIO_MATRIX: u32 = 1;
+    pub const SOC_GPIO_CLOCKOUT_CHANNEL_NUM: u32 = 3;
+    pub const SOC_GPIO_DEEP_SLEEP_WAKE_SUPPORTED_PIN_CNT: u32 = 6;
+    pub const SOC_GPIO_DEEP_SLEEP_WAKE_VALID_GPIO_MASK: u32 = 0x3f;
+    pub const SOC_GPIO_IN_RANGE_MAX: u32 = 21;
+    pub const SOC_GPIO_OUT_RANGE_MAX: u32 = 21;
+    pub const SOC_GPIO_PIN_COUNT: u32 = 22;
+    pub const SOC_GPIO_PORT: u32 = 1;
+    pub const SOC_GPIO_VALID_DIGITAL_IO_PAD_MASK: u64 = 0x3fffc0;
+    pub const SOC_GPIO_VALID_GPIO_MASK: u64 = 0x3fffff;
+    pub const SOC_GPIO_VALID_OUTPUT_GPIO_MASK: u64 = 0x3fffff;
+    pub const SOC_HP_I2C_NUM: u32 = 1;
+    pub const SOC_I2C_CMD_REG_NUM: u32 = 8;
+    pub const SOC_I2C_FIFO_LEN: u32 = 32;
+    pub const SOC_I2C_NUM: u32 = 1;
+    pub const SOC_I2C_SLAVE_CAN_GET_STRETCH_CAUSE: u32 = 1;
+    pub const SOC_I2S_HW_VERSION_2: u32 = 1;
+    pub const SOC_I2S_NUM: u32 = 1;
+    pub const SOC_I2S_PDM_MAX_RX_LINES: u32 = 1;
+    pub const SOC_I2S_PDM_MAX_TX_LINES: u32 = 2;
+    pub const SOC_LEDC_CHANNEL_NUM: u32 = 6;
+    pub const SOC_LEDC_TIMER_BIT_WIDTH: u32 = 14;
+    pub const SOC_LEDC_TIMER_NUM: u32 = 4;
+    pub const SOC_LP_PERIPH_SHARE_INTERRUPT: u32 = 1;
+    pub const SOC_LP_TIMER_BIT_WIDTH_HI: u32 = 16;
+    pub const SOC_LP_TIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_MAC_BB_PD_MEM_SIZE: u32 = 768;
+    pub const SOC_MEMPROT_CPU_PREFETCH_PAD_SIZE: u32 = 16;
+    pub const SOC_MEMPROT_MEM_ALIGN_SIZE: u32 = 512;
+    pub const SOC_MEMSPI_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_MMU_LINEAR_ADDRESS_REGION_NUM: u32 = 1;
+    pub const SOC_MMU_PERIPH_NUM: u32 = 1;
+    pub const SOC_MPI_MEM_BLOCKS_NUM: u32 = 4;
+    pub const SOC_MPI_OPERATIONS_NUM: u32 = 3;
+    pub const SOC_MPU_MIN_REGION_SIZE: u32 = 0x20000000;
+    pub const SOC_MPU_REGIONS_MAX_NUM: u32 = 8;
+    pub const SOC_PHY_COMBO_MODULE: u32 = 1;
+    pub const SOC_PHY_DIG_REGS_MEM_SIZE: u32 = 84;
+    pub const SOC_PM_CPU_RETENTION_BY_RTCCNTL: u32 = 1;
+    pub const SOC_PM_MODEM_PD_BY_SW: u32 = 1;
+    pub const SOC_PM_MODEM_RETENTION_BY_BACKUPDMA: u32 = 1;
+    pub const SOC_RMT_CHANNELS_PER_GROUP: u32 = 4;
+    pub const SOC_RMT_GROUPS: u32 = 1;
+    pub const SOC_RMT_MEM_WORDS_PER_CHANNEL: u32 = 48;
+    pub const SOC_RMT_RX_CANDIDATES_PER_GROUP: u32 = 2;
+    pub const SOC_RMT_TX_CANDIDATES_PER_GROUP: u32 = 2;
+    pub const SOC_RSA_MAX_BIT_LEN: u32 = 3072;
+    pub const SOC_RTCIO_PIN_COUNT: u32 = 0;
+    pub const SOC_RTC_CNTL_CPU_PD_DMA_ADDR_ALIGN: u32 = 16;
+    pub const SOC_RTC_CNTL_CPU_PD_DMA_BLOCK_SIZE: u32 = 16;
+    pub const SOC_RTC_CNTL_CPU_PD_DMA_BUS_WIDTH: u32 = 128;
+    pub const SOC_RTC_CNTL_CPU_PD_REG_FILE_NUM: u32 = 108;
+    pub const SOC_RTC_CNTL_CPU_PD_RETENTION_MEM_SIZE: u32 = 1728;
+    pub const SOC_SDM_CHANNELS_PER_GROUP: u32 = 4;
+    pub const SOC_SDM_GROUPS: u32 = 1;
+    pub const SOC_SECURE_BOOT_V2_RSA: u32 = 1;
+    pub const SOC_SHA_DMA_MAX_BUFFER_SIZE: u32 = 3968;
+    pub const SOC_SHA_GDMA: u32 = 1;
+    pub const SOC_SLEEP_SYSTIMER_STALL_WORKAROUND: u32 = 1;
+    pub const SOC_SLEEP_TGWDT_STOP_WORKAROUND: u32 = 1;
+    pub const SOC_SPI_MAXIMUM_BUFFER_SIZE: u32 = 64;
+    pub const SOC_SPI_MAX_CS_NUM: u32 = 6;
+    pub const SOC_SPI_MAX_PRE_DIVIDER: u32 = 16;
+    pub const SOC_SPI_PERIPH_NUM: u32 = 2;
+    pub const SOC_SPI_SCT_BUFFER_NUM_MAX: u32 = 15;
+    pub const SOC_SPI_SCT_CONF_BITLEN_MAX: u32 = 0x3fffa;
+    pub const SOC_SPI_SCT_REG_NUM: u32 = 14;
+    pub const SOC_SYSTIMER_ALARM_MISS_COMPENSATE: u32 = 1;
+    pub const SOC_SYSTIMER_ALARM_NUM: u32 = 3;
+    pub const SOC_SYSTIMER_BIT_WIDTH_HI: u32 = 20;
+    pub const SOC_SYSTIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_SYSTIMER_COUNTER_NUM: u32 = 2;
+    pub const SOC_SYSTIMER_FIXED_DIVIDER: u32 = 1;
+    pub const SOC_SYSTIMER_INT_LEVEL: u32 = 1;
+    pub const SOC_TIMER_GROUPS: u32 = 2;
+    pub const SOC_TIMER_GROUP_COUNTER_BIT_WIDTH: u32 = 54;
+    pub const SOC_TIMER_GROUP_TIMERS_PER_GROUP: u32 = 1;
+    pub const SOC_TIMER_GROUP_TOTAL_TIMERS: u32 = 2;
+    pub const SOC_TWAI_BRP_MAX: u32 = 16384;
+    pub const SOC_TWAI_BRP_MIN: u32 = 2;
+    pub const SOC_TWAI_CONTROLLER_NUM: u32 = 1;
+    pub const SOC_TWAI_MASK_FILTER_NUM: u32 = 1;
+    pub const SOC_UART_BITRATE_MAX: u32 = 5000000;
+    pub const SOC_UART_FIFO_LEN: u32 = 128;
+    pub const SOC_UART_HP_NUM: u32 = 2;
+    pub const SOC_UART_NUM: u32 = 2;
+    pub const SOC_UHCI_NUM: u32 = 1;
+    pub const SOC_WIFI_HW_TSF: u32 = 1;
+    pub const SOC_WIFI_LIGHT_SLEEP_CLK_WIDTH: u32 = 12;
+    pub const SOC_WIFI_PHY_NEEDS_USB_WORKAROUND: u32 = 1;
+}
+
+pub mod esp32c6 {
+    pub const SOC_ADC_ARBITER_SUPPORTED: bool = false;
+    pub const SOC_ADC_CALIBRATION_V1_SUPPORTED: bool = true;
+    pub const SOC_ADC_CALIB_CHAN_COMPENS_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_CTRL_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_IIR_FILTER_SUPPORTED: bool = true;
+    pub const SOC_ADC_DMA_SUPPORTED: bool = true;
+    pub const SOC_ADC_MONITOR_SUPPORTED: bool = true;
+    pub const SOC_ADC_RTC_CTRL_SUPPORTED: bool = false;
+    pub const SOC_ADC_SELF_HW_CALI_SUPPORTED: bool = true;
+    pub const SOC_ADC_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORT_AES_128: bool = true;
+    pub const SOC_AES_SUPPORT_AES_192: bool = false;
+    pub const SOC_AES_SUPPORT_AES_256: bool = true;
+    pub const SOC_AES_SUPPORT_DMA: bool = true;
+    pub const SOC_AES_SUPPORT_GCM: bool = false;
+    pub const SOC_AES_SUPPORT_PSEUDO_ROUND_FUNCTION: bool = false;
+    pub const SOC_AHB_GDMA_SUPPORTED: bool = true;
+    pub const SOC_AHB_GDMA_SUPPORT_PSRAM: bool = false;
+    pub const SOC_ANA_CMPR_SUPPORTED: bool = false;
+    pub const SOC_ANA_CMPR_SUPPORT_ETM: bool = false;
+    pub const SOC_APM_CPU_APM_SUPPORTED: bool = false;
+    pub const SOC_APM_CTRL_FILTER_SUPPORTED: bool = true;
+    pub const SOC_APM_LP_APM0_SUPPORTED: bool = true;
+    pub const SOC_APM_SUPPORTED: bool = true;
+    pub const SOC_APM_SUPPORT_CTRL_CFG_LOCK: bool = false;
+    pub const SOC_APM_SUPPORT_LP_TEE_CTRL: bool = true;
+    pub const SOC_APM_SUPPORT_TEE_PERI_ACCESS_CTRL: bool = false;
+    pub const SOC_ASSIST_DEBUG_SUPPORTED: bool = true;
+    pub const SOC_ASYNC_MEMCPY_SUPPORTED: bool = true;
+    pub const SOC_BITSCRAMBLER_SUPPORTED: bool = false;
+    pub const SOC_BLE_50_SUPPORTED: bool = true;
+    pub const SOC_BLE_CTE_SUPPORTED: bool = false;
+    pub const SOC_BLE_DEVICE_PRIVACY_SUPPORTED: bool = true;
+    pub const SOC_BLE_MESH_SUPPORTED: bool = true;
+    pub const SOC_BLE_PERIODIC_ADV_ENH_SUPPORTED: bool = true;
+    pub const SOC_BLE_POWER_CONTROL_SUPPORTED: bool = true;
+    pub const SOC_BLE_SUPPORTED: bool = true;
+    pub const SOC_BLUFI_SUPPORTED: bool = true;
+    pub const SOC_BOD_SUPPORTED: bool = true;
+    pub const SOC_BOOTLOADER_ANTI_ROLLBACK_SUPPORTED: bool = false;
+    pub const SOC_BRANCH_PREDICTOR_SUPPORTED: bool = false;
+    pub const SOC_BROWNOUT_RESET_SUPPORTED: bool = true;
+    pub const SOC_BT_CLASSIC_SUPPORTED: bool = false;
+    pub const SOC_BT_H2C_ENC_KEY_CTRL_ENH_VSC_SUPPORTED: bool = false;
+    pub const SOC_BT_SUPPORTED: bool = true;
+    pub const SOC_CACHE_FREEZE_SUPPORTED: bool = true;
+    pub const SOC_CACHE_SUPPORT_WRAP: bool = false;
+    pub const SOC_CACHE_WRITEBACK_SUPPORTED: bool = false;
+    pub const SOC_CCOMP_TIMER_SUPPORTED: bool = false;
+    pub const SOC_CLK_APLL_SUPPORTED: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_LP_PLL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D2: bool = true;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D4: bool = false;
+    pub const SOC_CLK_OSC_SLOW_SUPPORTED: bool = true;
+    pub const SOC_CLK_RC32K_SUPPORTED: bool = true;
+    pub const SOC_CLK_RC_FAST_D256_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC_FAST_SUPPORT_CALIBRATION: bool = true;
+    pub const SOC_CLK_TREE_SUPPORTED: bool = true;
+    pub const SOC_CLK_XTAL32K_SUPPORTED: bool = true;
+    pub const SOC_CLOCK_TREE_MANAGEMENT_SUPPORTED: bool = false;
+    pub const SOC_CONFIGURABLE_VDDSDIO_SUPPORTED: bool = false;
+    pub const SOC_CPU_SUPPORT_WFE: bool = false;
+    pub const SOC_CP_DMA_SUPPORTED: bool = false;
+    pub const SOC_CRYPTO_DPA_PROTECTION_SUPPORTED: bool = true;
+    pub const SOC_DAC_SUPPORTED: bool = false;
+    pub const SOC_DEDICATED_GPIO_SUPPORTED: bool = true;
+    pub const SOC_DEEP_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_DIG_SIGN_SUPPORTED: bool = true;
+    pub const SOC_ECC_EXTENDED_MODES_SUPPORTED: bool = false;
+    pub const SOC_ECC_SUPPORTED: bool = true;
+    pub const SOC_ECC_SUPPORT_POINT_VERIFY_QUIRK: bool = false;
+    pub const SOC_ECDSA_SUPPORTED: bool = false;
+    pub const SOC_ECDSA_SUPPORT_CURVE_P384: bool = false;
+    pub const SOC_ECDSA_SUPPORT_DETERMINISTIC_MODE: bool = false;
+    pub const SOC_ECDSA_SUPPORT_EXPORT_PUBKEY: bool = false;
+    pub const SOC_ECDSA_SUPPORT_HW_DETERMINISTIC_LOOP: bool = false;
+    pub const SOC_EFUSE_SUPPORTED: bool = true;
+    pub const SOC_EMAC_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORTED: bool = true;
+    pub const SOC_ETM_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_SUPPORT_PSEUDO_ROUND: bool = false;
+    pub const SOC_FLASH_ENC_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORT_ETM: bool = true;
+    pub const SOC_GDMA_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_GPIO_FILTER_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_GPIO_SUPPORT_DEEPSLEEP_WAKEUP: bool = true;
+    pub const SOC_GPIO_SUPPORT_ETM: bool = true;
+    pub const SOC_GPIO_SUPPORT_FORCE_HOLD: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_SINGLE_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_GLITCH_FILTER: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_CTRL_BY_EFUSE: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_FILTER: bool = false;
+    pub const SOC_GPIO_SUPPORT_RTC_INDEPENDENT: bool = true;
+    pub const SOC_GPSPI_SUPPORTED: bool = true;
+    pub const SOC_GPTIMER_SUPPORTED: bool = true;
+    pub const SOC_HMAC_SUPPORTED: bool = true;
+    pub const SOC_HUK_SUPPORTED: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_BROADCAST: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_I2CRAM_ACCESS: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_SLAVE_UNMATCH: bool = true;
+    pub const SOC_I2C_SUPPORTED: bool = true;
+    pub const SOC_I2C_SUPPORT_10BIT_ADDR: bool = true;
+    pub const SOC_I2C_SUPPORT_APB: bool = false;
+    pub const SOC_I2C_SUPPORT_HW_CLR_BUS: bool = true;
+    pub const SOC_I2C_SUPPORT_HW_FSM_RST: bool = true;
+    pub const SOC_I2C_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_I2C_SUPPORT_RTC: bool = true;
+    pub const SOC_I2C_SUPPORT_SLAVE: bool = true;
+    pub const SOC_I2C_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_I2C_SUPPORT_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORTED: bool = true;
+    pub const SOC_I2S_SUPPORTS_ADC: bool = false;
+    pub const SOC_I2S_SUPPORTS_ADC_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_APLL: bool = false;
+    pub const SOC_I2S_SUPPORTS_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_DMA_EQUAL: bool = false;
+    pub const SOC_I2S_SUPPORTS_ETM: bool = true;
+    pub const SOC_I2S_SUPPORTS_LCD_CAMERA: bool = false;
+    pub const SOC_I2S_SUPPORTS_PCM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PCM2PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM2PCM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM_RX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM_TX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F120M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F160M: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F240M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F64M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F96M: bool = false;
+    pub const SOC_I2S_SUPPORTS_TDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_TX_SYNC_CNT: bool = false;
+    pub const SOC_I2S_SUPPORTS_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_IEEE802154_SUPPORTED: bool = true;
+    pub const SOC_INT_CLIC_SUPPORTED: bool = false;
+    pub const SOC_INT_HW_NESTED_SUPPORTED: bool = false;
+    pub const SOC_INT_PLIC_SUPPORTED: bool = true;
+    pub const SOC_KEY_MANAGER_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORT_KEY_DEPLOYMENT: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LCDCAM_I80_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_RGB_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_SUPPORTED: bool = false;
+    pub const SOC_LCD_I80_SUPPORTED: bool = false;
+    pub const SOC_LCD_RGB_SUPPORTED: bool = false;
+    pub const SOC_LCD_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORT_APB_CLOCK: bool = false;
+    pub const SOC_LEDC_SUPPORT_FADE_STOP: bool = true;
+    pub const SOC_LEDC_SUPPORT_HS_MODE: bool = false;
+    pub const SOC_LEDC_SUPPORT_PLL_DIV_CLOCK: bool = true;
+    pub const SOC_LEDC_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_LEDC_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_LEDC_SUPPORT_XTAL_CLOCK: bool = true;
+    pub const SOC_LIGHT_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_LP_AON_SUPPORTED: bool = true;
+    pub const SOC_LP_CORE_SUPPORTED: bool = true;
+    pub const SOC_LP_CORE_SUPPORT_ETM: bool = true;
+    pub const SOC_LP_CORE_SUPPORT_STORE_LOAD_EXCEPTIONS: bool = false;
+    pub const SOC_LP_I2C_SUPPORTED: bool = true;
+    pub const SOC_LP_PERIPHERALS_SUPPORTED: bool = true;
+    pub const SOC_LP_TIMER_SUPPORTED: bool = true;
+    pub const SOC_MCPWM_SUPPORTED: bool = true;
+    pub const SOC_MCPWM_SUPPORT_ETM: bool = true;
+    pub const SOC_MCPWM_SUPPORT_EVENT_COMPARATOR: bool = false;
+    pub const SOC_MCPWM_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_MEMPROT_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_120M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_15M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_16M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_20M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_26M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_30M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_32M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_40M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_60M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_64M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_80M_SUPPORTED: bool = true;
+    pub const SOC_MMU_PAGE_SIZE_8KB_SUPPORTED: bool = true;
+    pub const SOC_MODEM_CLOCK_SUPPORTED: bool = true;
+    pub const SOC_MPI_SUPPORTED: bool = true;
+    pub const SOC_MPU_CONFIGURABLE_REGIONS_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_RO_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_WO_SUPPORTED: bool = false;
+    pub const SOC_MPU_SUPPORTED: bool = false;
+    pub const SOC_MWDT_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_MWDT_SUPPORT_XTAL: bool = true;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_OUTPUT: bool = false;
+    pub const SOC_PARLIO_SUPPORTED: bool = true;
+    pub const SOC_PARLIO_SUPPORT_I80_LCD: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_PARLIO_SUPPORT_SPI_LCD: bool = false;
+    pub const SOC_PARLIO_TX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_EOF_FROM_DMA: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_LOOP_TRANSMISSION: bool = false;
+    pub const SOC_PAU_SUPPORTED: bool = true;
+    pub const SOC_PCNT_SUPPORTED: bool = true;
+    pub const SOC_PCNT_SUPPORT_CLEAR_SIGNAL: bool = false;
+    pub const SOC_PCNT_SUPPORT_RUNTIME_THRES_UPDATE: bool = true;
+    pub const SOC_PCNT_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_PCNT_SUPPORT_STEP_NOTIFY: bool = false;
+    pub const SOC_PHY_SUPPORTED: bool = true;
+    pub const SOC_PMU_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORT_BEACON_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_BT_PD: bool = false;
+    pub const SOC_PM_SUPPORT_BT_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_CPU_PD: bool = true;
+    pub const SOC_PM_SUPPORT_DEEPSLEEP_CHECK_STUB_ONLY: bool = true;
+    pub const SOC_PM_SUPPORT_EXT0_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP_MODE_PER_PIN: bool = true;
+    pub const SOC_PM_SUPPORT_EXT_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_HP_AON_PD: bool = true;
+    pub const SOC_PM_SUPPORT_MAC_BB_PD: bool = true;
+    pub const SOC_PM_SUPPORT_MODEM_PD: bool = true;
+    pub const SOC_PM_SUPPORT_PMU_CLK_ICG: bool = true;
+    pub const SOC_PM_SUPPORT_PMU_MODEM_STATE: bool = true;
+    pub const SOC_PM_SUPPORT_RC32K_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RC_FAST_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_FAST_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RTC_PERIPH_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_SLOW_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TAGMEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOP_PD: bool = true;
+    pub const SOC_PM_SUPPORT_TOUCH_SENSOR_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_VDDSDIO_PD: bool = true;
+    pub const SOC_PM_SUPPORT_WIFI_PD: bool = false;
+    pub const SOC_PM_SUPPORT_WIFI_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_XTAL32K_PD: bool = true;
+    pub const SOC_RECOVERY_BOOTLOADER_SUPPORTED: bool = false;
+    pub const SOC_REG_I2C_SUPPORTED: bool = false;
+    pub const SOC_RISCV_COPROC_SUPPORTED: bool = false;
+    pub const SOC_RMT_SUPPORTED: bool = true;
+    pub const SOC_RMT_SUPPORT_APB: bool = false;
+    pub const SOC_RMT_SUPPORT_DMA: bool = false;
+    pub const SOC_RMT_SUPPORT_RC_FAST: bool = true;
+    pub const SOC_RMT_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_RMT_SUPPORT_RX_DEMODULATION: bool = true;
+    pub const SOC_RMT_SUPPORT_RX_PINGPONG: bool = true;
+    pub const SOC_RMT_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_ASYNC_STOP: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_CARRIER_DATA_ONLY: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_AUTO_STOP: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_COUNT: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_SYNCHRO: bool = true;
+    pub const SOC_RMT_SUPPORT_XTAL: bool = true;
+    pub const SOC_RNG_SUPPORTED: bool = true;
+    pub const SOC_RSA_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_EDGE_WAKE_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_HOLD_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_INPUT_OUTPUT_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_WAKE_SUPPORTED: bool = true;
+    pub const SOC_RTC_FAST_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORT_SPEED_MODE_SWITCH: bool = true;
+    pub const SOC_RTC_SLOW_CLK_SUPPORT_RC_FAST_D256: bool = false;
+    pub const SOC_RTC_SLOW_MEM_SUPPORTED: bool = false;
+    pub const SOC_SDIO_SLAVE_SUPPORTED: bool = true;
+    pub const SOC_SDMMC_HOST_SUPPORTED: bool = false;
+    pub const SOC_SDMMC_SUPPORT_XTAL_CLOCK: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F48M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F80M: bool = true;
+    pub const SOC_SDM_CLK_SUPPORT_XTAL: bool = true;
+    pub const SOC_SDM_SUPPORTED: bool = true;
+    pub const SOC_SECURE_BOOT_SUPPORTED: bool = true;
+    pub const SOC_SHARED_IDCACHE_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORT_DMA: bool = true;
+    pub const SOC_SHA_SUPPORT_PARALLEL_ENG: bool = false;
+    pub const SOC_SHA_SUPPORT_RESUME: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA1: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA224: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA256: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA384: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_224: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_256: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_T: bool = false;
+    pub const SOC_SIMD_INSTRUCTION_SUPPORTED: bool = false;
+    pub const SOC_SPIRAM_SUPPORTED: bool = false;
+    pub const SOC_SPIRAM_XIP_SUPPORTED: bool = false;
+    pub const SOC_SPI_AS_CS_SUPPORTED: bool = false;
+    pub const SOC_SPI_FLASH_SUPPORTED: bool = true;
+    pub const SOC_SPI_HD_BOTH_INOUT_SUPPORTED: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_RESUME: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_WAIT_IDLE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CACHE_32BIT_ADDR_MAP: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_CHECK_SUS: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CONFIG_GPIO_BY_EFUSE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_FLASH_OPI_MODE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_IDLE_INTR: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_SW_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_TIMING_TUNING: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_TSUS_TRES_SEPERATE_CTR: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WB_MODE_INDEPENDENT_CONTROL: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WRAP: bool = true;
+    pub const SOC_SPI_PERIPH_SUPPORT_CONTROL_DUMMY_OUT: bool = false;
+    pub const SOC_SPI_SCT_SUPPORTED: bool = true;
+    pub const SOC_SPI_SLAVE_SUPPORT_SEG_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_CD_SIG: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_APB: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F160M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F40M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F48M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F80M: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_RC_FAST: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_XTAL: bool = true;
+    pub const SOC_SPI_SUPPORT_CONTINUOUS_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_DDRCLK: bool = true;
+    pub const SOC_SPI_SUPPORT_OCT: bool = false;
+    pub const SOC_SPI_SUPPORT_SLAVE_HD_VER2: bool = true;
+    pub const SOC_SPI_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_SUPPORTS_SECURE_DL_MODE: bool = true;
+    pub const SOC_SUPPORT_COEXISTENCE: bool = true;
+    pub const SOC_SUPPORT_SECURE_BOOT_REVOKE_KEY: bool = true;
+    pub const SOC_SYSTIMER_SUPPORTED: bool = true;
+    pub const SOC_SYSTIMER_SUPPORT_ETM: bool = true;
+    pub const SOC_SYSTIMER_SUPPORT_RC_FAST: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_INTR_SUPPORT: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_ETM: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_FAST_RC: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_XTAL: bool = true;
+    pub const SOC_TEMP_SENSOR_SUPPORTED: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_APB: bool = false;
+    pub const SOC_TIMER_GROUP_SUPPORT_RC_FAST: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_XTAL: bool = true;
+    pub const SOC_TIMER_SUPPORT_ETM: bool = true;
+    pub const SOC_TIMER_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_TOUCH_PROXIMITY_MEAS_DONE_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SENSOR_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SUPPORT_BENCHMARK: bool = false;
+    pub const SOC_TOUCH_SUPPORT_DENOISE_CHAN: bool = false;
+    pub const SOC_TOUCH_SUPPORT_PROX_SENSING: bool = false;
+    pub const SOC_TOUCH_SUPPORT_SLEEP_WAKEUP: bool = false;
+    pub const SOC_TOUCH_SUPPORT_WATERPROOF: bool = false;
+    pub const SOC_TWAI_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_TWAI_CLK_SUPPORT_XTAL: bool = true;
+    pub const SOC_TWAI_SUPPORTED: bool = true;
+    pub const SOC_TWAI_SUPPORTS_RX_STATUS: bool = true;
+    pub const SOC_TWAI_SUPPORT_FD: bool = false;
+    pub const SOC_TWAI_SUPPORT_MULTI_ADDRESS_LAYOUT: bool = false;
+    pub const SOC_TWAI_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_TWAI_SUPPORT_TIMESTAMP: bool = false;
+    pub const SOC_UART_SUPPORTED: bool = true;
+    pub const SOC_UART_SUPPORT_APB_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_FSM_TX_WAIT_SEND: bool = true;
+    pub const SOC_UART_SUPPORT_PLL_F40M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_PLL_F80M_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_UART_SUPPORT_RTC_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_UART_SUPPORT_WAKEUP_INT: bool = true;
+    pub const SOC_UART_SUPPORT_XTAL_CLK: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_ACTIVE_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_CHAR_SEQ_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_FIFO_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_START_BIT_MODE: bool = true;
+    pub const SOC_UHCI_SUPPORTED: bool = true;
+    pub const SOC_ULP_FSM_SUPPORTED: bool = false;
+    pub const SOC_ULP_LP_UART_SUPPORTED: bool = true;
+    pub const SOC_ULP_SUPPORTED: bool = true;
+    pub const SOC_USB_OTG_SUPPORTED: bool = false;
+    pub const SOC_USB_SERIAL_JTAG_SUPPORTED: bool = true;
+    pub const SOC_VBAT_SUPPORTED: bool = false;
+    pub const SOC_WDT_SUPPORTED: bool = true;
+    pub const SOC_WIFI_CSI_SUPPORT: bool = true;
+    pub const SOC_WIFI_FTM_SUPPORT: bool = true;
+    pub const SOC_WIFI_GCMP_SUPPORT: bool = true;
+    pub const SOC_WIFI_HE_SUPPORT: bool = true;
+    pub const SOC_WIFI_MESH_SUPPORT: bool = true;
+    pub const SOC_WIFI_NAN_SUPPORT: bool = false;
+    pub const SOC_WIFI_SUPPORTED: bool = true;
+    pub const SOC_WIFI_SUPPORT_5G: bool = false;
+    pub const SOC_WIFI_SUPPORT_VARIABLE_BEACON_WINDOW: bool = false;
+    pub const SOC_WIFI_WAPI_SUPPORT: bool = true;
+    pub const SOC_XTAL_SUPPORT_26M: bool = false;
+    pub const SOC_XTAL_SUPPORT_32M: bool = false;
+    pub const SOC_XTAL_SUPPORT_40M: bool = true;
+    pub const SOC_XTAL_SUPPORT_48M: bool = false;
+    pub const SOC_XTAL_SUPPORT_AUTO_DETECT: bool = false;
+    pub const SOC_XT_WDT_SUPPORTED: bool = false;
+    pub const SOC_ADC_ATTEN_NUM: u32 = 4;
+    pub const SOC_ADC_DIGI_CONTROLLER_NUM: u32 = 1;
+    pub const SOC_ADC_DIGI_DATA_BYTES_PER_CONV: u32 = 4;
+    pub const SOC_ADC_DIGI_IIR_FILTER_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MONITOR_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_RESULT_BYTES: u32 = 4;
+    pub const SOC_ADC_MAX_CHANNEL_NUM: u32 = 7;
+    pub const SOC_ADC_PATT_LEN_MAX: u32 = 8;
+    pub const SOC_ADC_PERIPH_NUM: u32 = 1;
+    pub const SOC_ADC_RTC_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_RTC_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_HIGH: u32 = 83333;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_LOW: u32 = 611;
+    pub const SOC_ADC_SHARED_POWER: u32 = 1;
+    pub const SOC_ADC_TEMPERATURE_SHARE_INTR: u32 = 1;
+    pub const SOC_AES_GDMA: u32 = 1;
+    pub const SOC_AHB_GDMA_VERSION: u32 = 1;
+    pub const SOC_APB_BACKUP_DMA: u32 = 0;
+    pub const SOC_BLE_MULTI_CONN_OPTIMIZATION: u32 = 1;
+    pub const SOC_BLE_USE_WIFI_PWR_CLK_WORKAROUND: u32 = 1;
+    pub const SOC_CAPS_ECO_VER: u32 = 0;
+    pub const SOC_CAPS_NO_RESET_BY_ANA_BOD: u32 = 1;
+    pub const SOC_CLK_ANA_I2C_MST_HAS_ROOT_GATE: u32 = 1;
+    pub const SOC_CLOCKOUT_HAS_SOURCE_GATE: u32 = 1;
+    pub const SOC_COEX_HW_PTI: u32 = 1;
+    pub const SOC_CPU_BREAKPOINTS_NUM: u32 = 4;
+    pub const SOC_CPU_CORES_NUM: u32 = 1;
+    pub const SOC_CPU_HAS_CSR_PC: u32 = 1;
+    pub const SOC_CPU_HAS_FLEXIBLE_INTC: u32 = 1;
+    pub const SOC_CPU_HAS_PMA: u32 = 1;
+    pub const SOC_CPU_IDRAM_SPLIT_USING_PMP: u32 = 1;
+    pub const SOC_CPU_INTR_NUM: u32 = 32;
+    pub const SOC_CPU_PMP_REGION_GRANULARITY: u32 = 4;
+    pub const SOC_CPU_WATCHPOINTS_NUM: u32 = 4;
+    pub const SOC_CPU_WATCHPOINT_MAX_REGION_SIZE: u32 = 0x80000000;
+    pub const SOC_DEBUG_HAVE_OCD_STUB_BINS: u32 = 1;
+    pub const SOC_DEDIC_GPIO_IN_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_GPIO_OUT_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_PERIPH_ALWAYS_ENABLE: u32 = 1;
+    pub const SOC_DS_KEY_CHECK_MAX_WAIT_US: u32 = 1100;
+    pub const SOC_DS_KEY_PARAM_MD_IV_LENGTH: u32 = 16;
+    pub const SOC_DS_SIGNATURE_MAX_BIT_LEN: u32 = 3072;
+    pub const SOC_EFUSE_BLOCK9_KEY_PURPOSE_QUIRK: u32 = 1;
+    pub const SOC_EFUSE_DIS_DIRECT_BOOT: u32 = 1;
+    pub const SOC_EFUSE_DIS_DOWNLOAD_ICACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_ICACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_PAD_JTAG: u32 = 1;
+    pub const SOC_EFUSE_DIS_USB_JTAG: u32 = 1;
+    pub const SOC_EFUSE_KEY_PURPOSE_FIELD: u32 = 1;
+    pub const SOC_EFUSE_REVOKE_BOOT_KEY_DIGESTS: u32 = 1;
+    pub const SOC_EFUSE_SECURE_BOOT_KEY_DIGESTS: u32 = 3;
+    pub const SOC_EFUSE_SOFT_DIS_JTAG: u32 = 1;
+    pub const SOC_ESP_NIMBLE_CONTROLLER: u32 = 1;
+    pub const SOC_ETM_CHANNELS_PER_GROUP: u32 = 50;
+    pub const SOC_ETM_GROUPS: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_ADVANCE: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_LEADER_TX_LINE: u32 = 0;
+    pub const SOC_EXT_MEM_CACHE_TAG_IN_CPU_DOMAIN: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTED_XTS_AES_BLOCK_MAX: u32 = 64;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_128: u32 = 1;
+    pub const SOC_GDMA_NUM_GROUPS_MAX: u32 = 1;
+    pub const SOC_GDMA_PAIRS_PER_GROUP_MAX: u32 = 3;
+    pub const SOC_GPIO_CLOCKOUT_BY_GPIO_MATRIX: u32 = 1;
+    pub const SOC_GPIO_CLOCKOUT_CHANNEL_NUM: u32 = 3;
+    pub const SOC_GPIO_DEEP_SLEEP_WAKE_SUPPORTED_PIN_CNT: u32 = 8;
+    pub const SOC_GPIO_DEEP_SLEEP_WAKE_VALID_GPIO_MASK: u32 = 0xff;
+    pub const SOC_GPIO_FLEX_GLITCH_FILTER_NUM: u32 = 8;
+    pub const SOC_GPIO_IN_RANGE_MAX: u32 = 30;
+    pub const SOC_GPIO_OUT_RANGE_MAX: u32 = 30;
+    pub const SOC_GPIO_PIN_COUNT: u32 = 31;
+    pub const SOC_GPIO_PORT: u32 = 1;
+    pub const SOC_GPIO_VALID_DIGITAL_IO_PAD_MASK: u64 = 0x7fffff00;
+    pub const SOC_GPIO_VALID_GPIO_MASK: u64 = 0x7fffffff;
+    pub const SOC_GPIO_VALID_OUTPUT_GPIO_MASK: u64 = 0x7fffffff;
+    pub const SOC_HP_I2C_NUM: u32 = 1;
+    pub const SOC_I2C_CMD_REG_NUM: u32 = 8;
+    pub const SOC_I2C_FIFO_LEN: u32 = 32;
+    pub const SOC_I2C_NUM: u32 = 2;
+    pub const SOC_I2C_SLAVE_CAN_GET_STRETCH_CAUSE: u32 = 1;
+    pub const SOC_I2S_HW_VERSION_2: u32 = 1;
+    pub const SOC_I2S_NUM: u32 = 1;
+    pub const SOC_I2S_PDM_MAX_RX_LINES: u32 = 1;
+    pub const SOC_I2S_PDM_MAX_TX_LINES: u32 = 2;
+    pub const SOC_LEDC_CHANNEL_NUM: u32 = 6;
+    pub const SOC_LEDC_FADE_PARAMS_BIT_WIDTH: u32 = 10;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_RANGE_MAX: u32 = 16;
+    pub const SOC_LEDC_TIMER_BIT_WIDTH: u32 = 20;
+    pub const SOC_LEDC_TIMER_NUM: u32 = 4;
+    pub const SOC_LP_CORE_SINGLE_INTERRUPT_VECTOR: u32 = 1;
+    pub const SOC_LP_I2C_FIFO_LEN: u32 = 16;
+    pub const SOC_LP_I2C_NUM: u32 = 1;
+    pub const SOC_LP_IO_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_LP_TIMER_BIT_WIDTH_HI: u32 = 16;
+    pub const SOC_LP_TIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_LP_UART_FIFO_LEN: u32 = 16;
+    pub const SOC_MCPWM_CAPTURE_CHANNELS_PER_TIMER: u32 = 3;
+    pub const SOC_MCPWM_CAPTURE_CLK_FROM_GROUP: u32 = 1;
+    pub const SOC_MCPWM_CAPTURE_TIMERS_PER_GROUP: u32 = 1;
+    pub const SOC_MCPWM_COMPARATORS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MCPWM_GENERATORS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MCPWM_GPIO_FAULTS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_GPIO_SYNCHROS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_GROUPS: u32 = 1;
+    pub const SOC_MCPWM_OPERATORS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_SWSYNC_CAN_PROPAGATE: u32 = 1;
+    pub const SOC_MCPWM_TIMERS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_TRIGGERS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MEMSPI_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_MMU_DI_VADDR_SHARED: u32 = 1;
+    pub const SOC_MMU_LINEAR_ADDRESS_REGION_NUM: u32 = 1;
+    pub const SOC_MMU_PAGE_SIZE_CONFIGURABLE: u32 = 1;
+    pub const SOC_MMU_PERIPH_NUM: u32 = 1;
+    pub const SOC_MODEM_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_MPI_MEM_BLOCKS_NUM: u32 = 4;
+    pub const SOC_MPI_OPERATIONS_NUM: u32 = 3;
+    pub const SOC_MPU_MIN_REGION_SIZE: u32 = 0x20000000;
+    pub const SOC_MPU_REGIONS_MAX_NUM: u32 = 8;
+    pub const SOC_PARLIO_GROUPS: u32 = 1;
+    pub const SOC_PARLIO_RX_UNITS_PER_GROUP: u32 = 1;
+    pub const SOC_PARLIO_RX_UNIT_MAX_DATA_WIDTH: u32 = 16;
+    pub const SOC_PARLIO_TX_RX_SHARE_INTERRUPT: u32 = 1;
+    pub const SOC_PARLIO_TX_UNITS_PER_GROUP: u32 = 1;
+    pub const SOC_PARLIO_TX_UNIT_MAX_DATA_WIDTH: u32 = 16;
+    pub const SOC_PCNT_CHANNELS_PER_UNIT: u32 = 2;
+    pub const SOC_PCNT_GROUPS: u32 = 1;
+    pub const SOC_PCNT_THRES_POINT_PER_UNIT: u32 = 2;
+    pub const SOC_PCNT_UNITS_PER_GROUP: u32 = 4;
+    pub const SOC_PHY_COMBO_MODULE: u32 = 1;
+    pub const SOC_PHY_DIG_REGS_MEM_SIZE: u32 = 84;
+    pub const SOC_PM_CPU_RETENTION_BY_SW: u32 = 1;
+    pub const SOC_PM_MODEM_RETENTION_BY_REGDMA: u32 = 1;
+    pub const SOC_PM_PAU_LINK_NUM: u32 = 4;
+    pub const SOC_PM_PAU_REGDMA_LINK_MULTI_ADDR: u32 = 1;
+    pub const SOC_PM_PAU_REGDMA_LINK_WIFIMAC: u32 = 1;
+    pub const SOC_PM_PAU_REGDMA_UPDATE_CACHE_BEFORE_WAIT_COMPARE: u32 = 1;
+    pub const SOC_PM_PMU_MIN_SLP_SLOW_CLK_CYCLE_FIXED: u32 = 1;
+    pub const SOC_PM_RETENTION_HAS_CLOCK_BUG: u32 = 1;
+    pub const SOC_PM_RETENTION_MODULE_NUM: u32 = 32;
+    pub const SOC_RCC_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_RMT_CHANNELS_PER_GROUP: u32 = 4;
+    pub const SOC_RMT_GROUPS: u32 = 1;
+    pub const SOC_RMT_MEM_WORDS_PER_CHANNEL: u32 = 48;
+    pub const SOC_RMT_RX_CANDIDATES_PER_GROUP: u32 = 2;
+    pub const SOC_RMT_TX_CANDIDATES_PER_GROUP: u32 = 2;
+    pub const SOC_RNG_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_RSA_MAX_BIT_LEN: u32 = 3072;
+    pub const SOC_RTCIO_PIN_COUNT: u32 = 8;
+    pub const SOC_SDM_CHANNELS_PER_GROUP: u32 = 4;
+    pub const SOC_SDM_GROUPS: u32 = 1;
+    pub const SOC_SECURE_BOOT_V2_ECC: u32 = 1;
+    pub const SOC_SECURE_BOOT_V2_RSA: u32 = 1;
+    pub const SOC_SHA_DMA_MAX_BUFFER_SIZE: u32 = 3968;
+    pub const SOC_SHA_GDMA: u32 = 1;
+    pub const SOC_SPI_MAXIMUM_BUFFER_SIZE: u32 = 64;
+    pub const SOC_SPI_MAX_CS_NUM: u32 = 6;
+    pub const SOC_SPI_MAX_PRE_DIVIDER: u32 = 16;
+    pub const SOC_SPI_PERIPH_NUM: u32 = 2;
+    pub const SOC_SPI_SCT_BUFFER_NUM_MAX: u32 = 15;
+    pub const SOC_SPI_SCT_CONF_BITLEN_MAX: u32 = 0x3fffa;
+    pub const SOC_SPI_SCT_REG_NUM: u32 = 14;
+    pub const SOC_SYSTIMER_ALARM_MISS_COMPENSATE: u32 = 1;
+    pub const SOC_SYSTIMER_ALARM_NUM: u32 = 3;
+    pub const SOC_SYSTIMER_BIT_WIDTH_HI: u32 = 20;
+    pub const SOC_SYSTIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_SYSTIMER_COUNTER_NUM: u32 = 2;
+    pub const SOC_SYSTIMER_FIXED_DIVIDER: u32 = 1;
+    pub const SOC_SYSTIMER_INT_LEVEL: u32 = 1;
+    pub const SOC_TEMPERATURE_SENSOR_UNDER_PD_TOP_DOMAIN: u32 = 1;
+    pub const SOC_TIMER_GROUPS: u32 = 2;
+    pub const SOC_TIMER_GROUP_COUNTER_BIT_WIDTH: u32 = 54;
+    pub const SOC_TIMER_GROUP_TIMERS_PER_GROUP: u32 = 1;
+    pub const SOC_TIMER_GROUP_TOTAL_TIMERS: u32 = 2;
+    pub const SOC_TWAI_BRP_MAX: u32 = 32768;
+    pub const SOC_TWAI_BRP_MIN: u32 = 2;
+    pub const SOC_TWAI_CONTROLLER_NUM: u32 = 2;
+    pub const SOC_TWAI_MASK_FILTER_NUM: u32 = 1;
+    pub const SOC_UART_BITRATE_MAX: u32 = 5000000;
+    pub const SOC_UART_FIFO_LEN: u32 = 128;
+    pub const SOC_UART_HAS_LP_UART: u32 = 1;
+    pub const SOC_UART_HP_NUM: u32 = 2;
+    pub const SOC_UART_LP_NUM: u32 = 1;
+    pub const SOC_UART_NUM: u32 = 3;
+    pub const SOC_UART_WAKEUP_CHARS_SEQ_MAX_LEN: u32 = 5;
+    pub const SOC_UHCI_NUM: u32 = 1;
+    pub const SOC_WIFI_HW_TSF: u32 = 1;
+    pub const SOC_WIFI_LIGHT_SLEEP_CLK_WIDTH: u32 = 12;
+    pub const SOC_WIFI_MAC_VERSION_NUM: u32 = 2;
+    pub const SOC_XTAL_CLOCK_PATH_DEPENDS_ON_TOP_DOMAIN: u32 = 1;
+}
+
+pub mod esp32h2 {
+    pub const SOC_ADC_ARBITER_SUPPORTED: bool = false;
+    pub const SOC_ADC_CALIBRATION_V1_SUPPORTED: bool = true;
+    pub const SOC_ADC_CALIB_CHAN_COMPENS_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_CTRL_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_IIR_FILTER_SUPPORTED: bool = true;
+    pub const SOC_ADC_DMA_SUPPORTED: bool = true;
+    pub const SOC_ADC_MONITOR_SUPPORTED: bool = true;
+    pub const SOC_ADC_RTC_CTRL_SUPPORTED: bool = false;
+    pub const SOC_ADC_SELF_HW_CALI_SUPPORTED: bool = true;
+    pub const SOC_ADC_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORT_AES_128: bool = true;
+    pub const SOC_AES_SUPPORT_AES_192: bool = false;
+    pub const SOC_AES_SUPPORT_AES_256: bool = true;
+    pub const SOC_AES_SUPPORT_DMA: bool = true;
+    pub const SOC_AES_SUPPORT_GCM: bool = false;
+    pub const SOC_AES_SUPPORT_PSEUDO_ROUND_FUNCTION: bool = true;
+    pub const SOC_AHB_GDMA_SUPPORTED: bool = true;
+    pub const SOC_AHB_GDMA_SUPPORT_PSRAM: bool = false;
+    pub const SOC_ANA_CMPR_SUPPORTED: bool = true;
+    pub const SOC_ANA_CMPR_SUPPORT_ETM: bool = false;
+    pub const SOC_APM_CPU_APM_SUPPORTED: bool = false;
+    pub const SOC_APM_CTRL_FILTER_SUPPORTED: bool = true;
+    pub const SOC_APM_LP_APM0_SUPPORTED: bool = false;
+    pub const SOC_APM_SUPPORTED: bool = true;
+    pub const SOC_APM_SUPPORT_CTRL_CFG_LOCK: bool = false;
+    pub const SOC_APM_SUPPORT_LP_TEE_CTRL: bool = false;
+    pub const SOC_APM_SUPPORT_TEE_PERI_ACCESS_CTRL: bool = false;
+    pub const SOC_ASSIST_DEBUG_SUPPORTED: bool = true;
+    pub const SOC_ASYNC_MEMCPY_SUPPORTED: bool = true;
+    pub const SOC_BITSCRAMBLER_SUPPORTED: bool = false;
+    pub const SOC_BLE_50_SUPPORTED: bool = true;
+    pub const SOC_BLE_CTE_SUPPORTED: bool = true;
+    pub const SOC_BLE_DEVICE_PRIVACY_SUPPORTED: bool = true;
+    pub const SOC_BLE_MESH_SUPPORTED: bool = true;
+    pub const SOC_BLE_PERIODIC_ADV_ENH_SUPPORTED: bool = true;
+    pub const SOC_BLE_POWER_CONTROL_SUPPORTED: bool = true;
+    pub const SOC_BLE_SUPPORTED: bool = true;
+    pub const SOC_BLUFI_SUPPORTED: bool = false;
+    pub const SOC_BOD_SUPPORTED: bool = true;
+    pub const SOC_BOOTLOADER_ANTI_ROLLBACK_SUPPORTED: bool = false;
+    pub const SOC_BRANCH_PREDICTOR_SUPPORTED: bool = false;
+    pub const SOC_BROWNOUT_RESET_SUPPORTED: bool = true;
+    pub const SOC_BT_CLASSIC_SUPPORTED: bool = false;
+    pub const SOC_BT_H2C_ENC_KEY_CTRL_ENH_VSC_SUPPORTED: bool = false;
+    pub const SOC_BT_SUPPORTED: bool = true;
+    pub const SOC_CACHE_FREEZE_SUPPORTED: bool = true;
+    pub const SOC_CACHE_SUPPORT_WRAP: bool = false;
+    pub const SOC_CACHE_WRITEBACK_SUPPORTED: bool = false;
+    pub const SOC_CCOMP_TIMER_SUPPORTED: bool = false;
+    pub const SOC_CLK_APLL_SUPPORTED: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_LP_PLL: bool = true;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D2: bool = true;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D4: bool = false;
+    pub const SOC_CLK_OSC_SLOW_SUPPORTED: bool = true;
+    pub const SOC_CLK_RC32K_SUPPORTED: bool = true;
+    pub const SOC_CLK_RC_FAST_D256_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC_FAST_SUPPORT_CALIBRATION: bool = true;
+    pub const SOC_CLK_TREE_SUPPORTED: bool = true;
+    pub const SOC_CLK_XTAL32K_SUPPORTED: bool = true;
+    pub const SOC_CLOCK_TREE_MANAGEMENT_SUPPORTED: bool = false;
+    pub const SOC_CONFIGURABLE_VDDSDIO_SUPPORTED: bool = false;
+    pub const SOC_CPU_SUPPORT_WFE: bool = false;
+    pub const SOC_CP_DMA_SUPPORTED: bool = false;
+    pub const SOC_CRYPTO_DPA_PROTECTION_SUPPORTED: bool = true;
+    pub const SOC_DAC_SUPPORTED: bool = false;
+    pub const SOC_DEDICATED_GPIO_SUPPORTED: bool = true;
+    pub const SOC_DEEP_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_DIG_SIGN_SUPPORTED: bool = true;
+    pub const SOC_ECC_EXTENDED_MODES_SUPPORTED: bool = true;
+    pub const SOC_ECC_SUPPORTED: bool = true;
+    pub const SOC_ECC_SUPPORT_POINT_VERIFY_QUIRK: bool = false;
+    pub const SOC_ECDSA_SUPPORTED: bool = true;
+    pub const SOC_ECDSA_SUPPORT_CURVE_P384: bool = false;
+    pub const SOC_ECDSA_SUPPORT_DETERMINISTIC_MODE: bool = true;
+    pub const SOC_ECDSA_SUPPORT_EXPORT_PUBKEY: bool = false;
+    pub const SOC_ECDSA_SUPPORT_HW_DETERMINISTIC_LOOP: bool = true;
+    pub const SOC_EFUSE_SUPPORTED: bool = true;
+    pub const SOC_EMAC_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORTED: bool = true;
+    pub const SOC_ETM_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_SUPPORT_PSEUDO_ROUND: bool = true;
+    pub const SOC_FLASH_ENC_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORT_ETM: bool = true;
+    pub const SOC_GDMA_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_GPIO_FILTER_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_GPIO_SUPPORT_DEEPSLEEP_WAKEUP: bool = false;
+    pub const SOC_GPIO_SUPPORT_ETM: bool = true;
+    pub const SOC_GPIO_SUPPORT_FORCE_HOLD: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_SINGLE_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_GLITCH_FILTER: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_CTRL_BY_EFUSE: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_FILTER: bool = true;
+    pub const SOC_GPIO_SUPPORT_RTC_INDEPENDENT: bool = true;
+    pub const SOC_GPSPI_SUPPORTED: bool = true;
+    pub const SOC_GPTIMER_SUPPORTED: bool = true;
+    pub const SOC_HMAC_SUPPORTED: bool = true;
+    pub const SOC_HUK_SUPPORTED: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_BROADCAST: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_I2CRAM_ACCESS: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_SLAVE_UNMATCH: bool = true;
+    pub const SOC_I2C_SUPPORTED: bool = true;
+    pub const SOC_I2C_SUPPORT_10BIT_ADDR: bool = true;
+    pub const SOC_I2C_SUPPORT_APB: bool = false;
+    pub const SOC_I2C_SUPPORT_HW_CLR_BUS: bool = true;
+    pub const SOC_I2C_SUPPORT_HW_FSM_RST: bool = true;
+    pub const SOC_I2C_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_I2C_SUPPORT_RTC: bool = true;
+    pub const SOC_I2C_SUPPORT_SLAVE: bool = true;
+    pub const SOC_I2C_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_I2C_SUPPORT_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORTED: bool = true;
+    pub const SOC_I2S_SUPPORTS_ADC: bool = false;
+    pub const SOC_I2S_SUPPORTS_ADC_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_APLL: bool = false;
+    pub const SOC_I2S_SUPPORTS_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_DMA_EQUAL: bool = false;
+    pub const SOC_I2S_SUPPORTS_ETM: bool = true;
+    pub const SOC_I2S_SUPPORTS_LCD_CAMERA: bool = false;
+    pub const SOC_I2S_SUPPORTS_PCM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PCM2PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM2PCM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM_RX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM_TX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F120M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F160M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F240M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F64M: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F96M: bool = true;
+    pub const SOC_I2S_SUPPORTS_TDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_TX_SYNC_CNT: bool = false;
+    pub const SOC_I2S_SUPPORTS_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_IEEE802154_SUPPORTED: bool = true;
+    pub const SOC_INT_CLIC_SUPPORTED: bool = false;
+    pub const SOC_INT_HW_NESTED_SUPPORTED: bool = false;
+    pub const SOC_INT_PLIC_SUPPORTED: bool = true;
+    pub const SOC_KEY_MANAGER_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORT_KEY_DEPLOYMENT: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LCDCAM_I80_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_RGB_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_SUPPORTED: bool = false;
+    pub const SOC_LCD_I80_SUPPORTED: bool = false;
+    pub const SOC_LCD_RGB_SUPPORTED: bool = false;
+    pub const SOC_LCD_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORT_APB_CLOCK: bool = false;
+    pub const SOC_LEDC_SUPPORT_FADE_STOP: bool = true;
+    pub const SOC_LEDC_SUPPORT_HS_MODE: bool = false;
+    pub const SOC_LEDC_SUPPORT_PLL_DIV_CLOCK: bool = true;
+    pub const SOC_LEDC_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_LEDC_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_LEDC_SUPPORT_XTAL_CLOCK: bool = true;
+    pub const SOC_LIGHT_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_LP_AON_SUPPORTED: bool = true;
+    pub const SOC_LP_CORE_SUPPORTED: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_ETM: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_STORE_LOAD_EXCEPTIONS: bool = false;
+    pub const SOC_LP_I2C_SUPPORTED: bool = false;
+    pub const SOC_LP_PERIPHERALS_SUPPORTED: bool = false;
+    pub const SOC_LP_TIMER_SUPPORTED: bool = true;
+    pub const SOC_MCPWM_SUPPORTED: bool = true;
+    pub const SOC_MCPWM_SUPPORT_ETM: bool = true;
+    pub const SOC_MCPWM_SUPPORT_EVENT_COMPARATOR: bool = false;
+    pub const SOC_MCPWM_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_MEMPROT_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_120M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_15M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_16M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_20M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_26M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_30M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_32M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_40M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_60M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_64M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_80M_SUPPORTED: bool = false;
+    pub const SOC_MMU_PAGE_SIZE_8KB_SUPPORTED: bool = true;
+    pub const SOC_MODEM_CLOCK_SUPPORTED: bool = true;
+    pub const SOC_MPI_SUPPORTED: bool = true;
+    pub const SOC_MPU_CONFIGURABLE_REGIONS_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_RO_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_WO_SUPPORTED: bool = false;
+    pub const SOC_MPU_SUPPORTED: bool = false;
+    pub const SOC_MWDT_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_MWDT_SUPPORT_XTAL: bool = true;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_GATING: bool = true;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_OUTPUT: bool = true;
+    pub const SOC_PARLIO_SUPPORTED: bool = true;
+    pub const SOC_PARLIO_SUPPORT_I80_LCD: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_PARLIO_SUPPORT_SPI_LCD: bool = true;
+    pub const SOC_PARLIO_TX_CLK_SUPPORT_GATING: bool = true;
+    pub const SOC_PARLIO_TX_SUPPORT_EOF_FROM_DMA: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_LOOP_TRANSMISSION: bool = true;
+    pub const SOC_PAU_SUPPORTED: bool = true;
+    pub const SOC_PCNT_SUPPORTED: bool = true;
+    pub const SOC_PCNT_SUPPORT_CLEAR_SIGNAL: bool = false;
+    pub const SOC_PCNT_SUPPORT_RUNTIME_THRES_UPDATE: bool = true;
+    pub const SOC_PCNT_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_PCNT_SUPPORT_STEP_NOTIFY: bool = true;
+    pub const SOC_PHY_SUPPORTED: bool = true;
+    pub const SOC_PMU_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORT_BEACON_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_BT_PD: bool = false;
+    pub const SOC_PM_SUPPORT_BT_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_CPU_PD: bool = true;
+    pub const SOC_PM_SUPPORT_DEEPSLEEP_CHECK_STUB_ONLY: bool = true;
+    pub const SOC_PM_SUPPORT_EXT0_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP_MODE_PER_PIN: bool = true;
+    pub const SOC_PM_SUPPORT_EXT_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_HP_AON_PD: bool = false;
+    pub const SOC_PM_SUPPORT_MAC_BB_PD: bool = false;
+    pub const SOC_PM_SUPPORT_MODEM_PD: bool = true;
+    pub const SOC_PM_SUPPORT_PMU_CLK_ICG: bool = true;
+    pub const SOC_PM_SUPPORT_PMU_MODEM_STATE: bool = false;
+    pub const SOC_PM_SUPPORT_RC32K_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RC_FAST_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_FAST_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RTC_PERIPH_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RTC_SLOW_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TAGMEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOP_PD: bool = true;
+    pub const SOC_PM_SUPPORT_TOUCH_SENSOR_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_VDDSDIO_PD: bool = true;
+    pub const SOC_PM_SUPPORT_WIFI_PD: bool = false;
+    pub const SOC_PM_SUPPORT_WIFI_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_XTAL32K_PD: bool = true;
+    pub const SOC_RECOVERY_BOOTLOADER_SUPPORTED: bool = false;
+    pub const SOC_REG_I2C_SUPPORTED: bool = false;
+    pub const SOC_RISCV_COPROC_SUPPORTED: bool = false;
+    pub const SOC_RMT_SUPPORTED: bool = true;
+    pub const SOC_RMT_SUPPORT_APB: bool = false;
+    pub const SOC_RMT_SUPPORT_DMA: bool = false;
+    pub const SOC_RMT_SUPPORT_RC_FAST: bool = true;
+    pub const SOC_RMT_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_RMT_SUPPORT_RX_DEMODULATION: bool = true;
+    pub const SOC_RMT_SUPPORT_RX_PINGPONG: bool = true;
+    pub const SOC_RMT_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_ASYNC_STOP: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_CARRIER_DATA_ONLY: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_AUTO_STOP: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_COUNT: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_SYNCHRO: bool = true;
+    pub const SOC_RMT_SUPPORT_XTAL: bool = true;
+    pub const SOC_RNG_SUPPORTED: bool = true;
+    pub const SOC_RSA_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_EDGE_WAKE_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_HOLD_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_INPUT_OUTPUT_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_WAKE_SUPPORTED: bool = false;
+    pub const SOC_RTC_FAST_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORT_SPEED_MODE_SWITCH: bool = false;
+    pub const SOC_RTC_SLOW_CLK_SUPPORT_RC_FAST_D256: bool = false;
+    pub const SOC_RTC_SLOW_MEM_SUPPORTED: bool = false;
+    pub const SOC_SDIO_SLAVE_SUPPORTED: bool = false;
+    pub const SOC_SDMMC_HOST_SUPPORTED: bool = false;
+    pub const SOC_SDMMC_SUPPORT_XTAL_CLOCK: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F48M: bool = true;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F80M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_XTAL: bool = true;
+    pub const SOC_SDM_SUPPORTED: bool = true;
+    pub const SOC_SECURE_BOOT_SUPPORTED: bool = true;
+    pub const SOC_SHARED_IDCACHE_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORT_DMA: bool = true;
+    pub const SOC_SHA_SUPPORT_PARALLEL_ENG: bool = false;
+    pub const SOC_SHA_SUPPORT_RESUME: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA1: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA224: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA256: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA384: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_224: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_256: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_T: bool = false;
+    pub const SOC_SIMD_INSTRUCTION_SUPPORTED: bool = false;
+    pub const SOC_SPIRAM_SUPPORTED: bool = false;
+    pub const SOC_SPIRAM_XIP_SUPPORTED: bool = false;
+    pub const SOC_SPI_AS_CS_SUPPORTED: bool = false;
+    pub const SOC_SPI_FLASH_SUPPORTED: bool = true;
+    pub const SOC_SPI_HD_BOTH_INOUT_SUPPORTED: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_RESUME: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_WAIT_IDLE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CACHE_32BIT_ADDR_MAP: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_CHECK_SUS: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CONFIG_GPIO_BY_EFUSE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_FLASH_OPI_MODE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_IDLE_INTR: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_SW_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_TIMING_TUNING: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_TSUS_TRES_SEPERATE_CTR: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WB_MODE_INDEPENDENT_CONTROL: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WRAP: bool = true;
+    pub const SOC_SPI_PERIPH_SUPPORT_CONTROL_DUMMY_OUT: bool = false;
+    pub const SOC_SPI_SCT_SUPPORTED: bool = true;
+    pub const SOC_SPI_SLAVE_SUPPORT_SEG_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_CD_SIG: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_APB: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F160M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F40M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F48M: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F80M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_RC_FAST: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_XTAL: bool = true;
+    pub const SOC_SPI_SUPPORT_CONTINUOUS_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_DDRCLK: bool = true;
+    pub const SOC_SPI_SUPPORT_OCT: bool = false;
+    pub const SOC_SPI_SUPPORT_SLAVE_HD_VER2: bool = true;
+    pub const SOC_SPI_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_SUPPORTS_SECURE_DL_MODE: bool = true;
+    pub const SOC_SUPPORT_COEXISTENCE: bool = true;
+    pub const SOC_SUPPORT_SECURE_BOOT_REVOKE_KEY: bool = true;
+    pub const SOC_SYSTIMER_SUPPORTED: bool = true;
+    pub const SOC_SYSTIMER_SUPPORT_ETM: bool = true;
+    pub const SOC_SYSTIMER_SUPPORT_RC_FAST: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_INTR_SUPPORT: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_ETM: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_FAST_RC: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_XTAL: bool = true;
+    pub const SOC_TEMP_SENSOR_SUPPORTED: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_APB: bool = false;
+    pub const SOC_TIMER_GROUP_SUPPORT_RC_FAST: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_XTAL: bool = true;
+    pub const SOC_TIMER_SUPPORT_ETM: bool = true;
+    pub const SOC_TIMER_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_TOUCH_PROXIMITY_MEAS_DONE_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SENSOR_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SUPPORT_BENCHMARK: bool = false;
+    pub const SOC_TOUCH_SUPPORT_DENOISE_CHAN: bool = false;
+    pub const SOC_TOUCH_SUPPORT_PROX_SENSING: bool = false;
+    pub const SOC_TOUCH_SUPPORT_SLEEP_WAKEUP: bool = false;
+    pub const SOC_TOUCH_SUPPORT_WATERPROOF: bool = false;
+    pub const SOC_TWAI_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_TWAI_CLK_SUPPORT_XTAL: bool = true;
+    pub const SOC_TWAI_SUPPORTED: bool = true;
+    pub const SOC_TWAI_SUPPORTS_RX_STATUS: bool = true;
+    pub const SOC_TWAI_SUPPORT_FD: bool = false;
+    pub const SOC_TWAI_SUPPORT_MULTI_ADDRESS_LAYOUT: bool = false;
+    pub const SOC_TWAI_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_TWAI_SUPPORT_TIMESTAMP: bool = false;
+    pub const SOC_UART_SUPPORTED: bool = true;
+    pub const SOC_UART_SUPPORT_APB_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_FSM_TX_WAIT_SEND: bool = true;
+    pub const SOC_UART_SUPPORT_PLL_F40M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_PLL_F80M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_UART_SUPPORT_RTC_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_UART_SUPPORT_WAKEUP_INT: bool = true;
+    pub const SOC_UART_SUPPORT_XTAL_CLK: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_ACTIVE_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_CHAR_SEQ_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_FIFO_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_START_BIT_MODE: bool = true;
+    pub const SOC_UHCI_SUPPORTED: bool = true;
+    pub const SOC_ULP_FSM_SUPPORTED: bool = false;
+    pub const SOC_ULP_LP_UART_SUPPORTED: bool = false;
+    pub const SOC_ULP_SUPPORTED: bool = false;
+    pub const SOC_USB_OTG_SUPPORTED: bool = false;
+    pub const SOC_USB_SERIAL_JTAG_SUPPORTED: bool = true;
+    pub const SOC_VBAT_SUPPORTED: bool = true;
+    pub const SOC_WDT_SUPPORTED: bool = true;
+    pub const SOC_WIFI_CSI_SUPPORT: bool = false;
+    pub const SOC_WIFI_FTM_SUPPORT: bool = false;
+    pub const SOC_WIFI_GCMP_SUPPORT: bool = false;
+    pub const SOC_WIFI_HE_SUPPORT: bool = false;
+    pub const SOC_WIFI_MESH_SUPPORT: bool = false;
+    pub const SOC_WIFI_NAN_SUPPORT: bool = false;
+    pub const SOC_WIFI_SUPPORTED: bool = false;
+    pub const SOC_WIFI_SUPPORT_5G: bool = false;
+    pub const SOC_WIFI_SUPPORT_VARIABLE_BEACON_WINDOW: bool = false;
+    pub const SOC_WIFI_WAPI_SUPPORT: bool = false;
+    pub const SOC_XTAL_SUPPORT_26M: bool = false;
+    pub const SOC_XTAL_SUPPORT_32M: bool = true;
+    pub const SOC_XTAL_SUPPORT_40M: bool = false;
+    pub const SOC_XTAL_SUPPORT_48M: bool = false;
+    pub const SOC_XTAL_SUPPORT_AUTO_DETECT: bool = false;
+    pub const SOC_XT_WDT_SUPPORTED: bool = false;
+    pub const SOC_ADC_ATTEN_NUM: u32 = 4;
+    pub const SOC_ADC_DIGI_CONTROLLER_NUM: u32 = 1;
+    pub const SOC_ADC_DIGI_DATA_BYTES_PER_CONV: u32 = 4;
+    pub const SOC_ADC_DIGI_IIR_FILTER_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MONITOR_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_RESULT_BYTES: u32 = 4;
+    pub const SOC_ADC_MAX_CHANNEL_NUM: u32 = 5;
+    pub const SOC_ADC_PATT_LEN_MAX: u32 = 8;
+    pub const SOC_ADC_PERIPH_NUM: u32 = 1;
+    pub const SOC_ADC_RTC_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_RTC_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_HIGH: u32 = 83333;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_LOW: u32 = 611;
+    pub const SOC_ADC_SHARED_POWER: u32 = 1;
+    pub const SOC_ADC_TEMPERATURE_SHARE_INTR: u32 = 1;
+    pub const SOC_AES_GDMA: u32 = 1;
+    pub const SOC_AHB_GDMA_VERSION: u32 = 1;
+    pub const SOC_ANA_CMPR_INTR_SHARE_WITH_GPIO: u32 = 1;
+    pub const SOC_ANA_CMPR_NUM: u32 = 1;
+    pub const SOC_APB_BACKUP_DMA: u32 = 0;
+    pub const SOC_BLE_MULTI_CONN_OPTIMIZATION: u32 = 1;
+    pub const SOC_CAPS_ECO_VER: u32 = 0;
+    pub const SOC_CAPS_ECO_VER_MAX: u32 = 102;
+    pub const SOC_CLK_ANA_I2C_MST_HAS_ROOT_GATE: u32 = 1;
+    pub const SOC_CLOCKOUT_HAS_SOURCE_GATE: u32 = 1;
+    pub const SOC_COEX_HW_PTI: u32 = 1;
+    pub const SOC_CPU_BREAKPOINTS_NUM: u32 = 4;
+    pub const SOC_CPU_CORES_NUM: u32 = 1;
+    pub const SOC_CPU_HAS_CSR_PC: u32 = 1;
+    pub const SOC_CPU_HAS_FLEXIBLE_INTC: u32 = 1;
+    pub const SOC_CPU_HAS_PMA: u32 = 1;
+    pub const SOC_CPU_IDRAM_SPLIT_USING_PMP: u32 = 1;
+    pub const SOC_CPU_INTR_NUM: u32 = 32;
+    pub const SOC_CPU_PMP_REGION_GRANULARITY: u32 = 4;
+    pub const SOC_CPU_WATCHPOINTS_NUM: u32 = 4;
+    pub const SOC_CPU_WATCHPOINT_MAX_REGION_SIZE: u32 = 0x80000000;
+    pub const SOC_DEBUG_HAVE_OCD_STUB_BINS: u32 = 1;
+    pub const SOC_DEDIC_GPIO_IN_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_GPIO_OUT_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_PERIPH_ALWAYS_ENABLE: u32 = 1;
+    pub const SOC_DS_KEY_CHECK_MAX_WAIT_US: u32 = 1100;
+    pub const SOC_DS_KEY_PARAM_MD_IV_LENGTH: u32 = 16;
+    pub const SOC_DS_SIGNATURE_MAX_BIT_LEN: u32 = 3072;
+    pub const SOC_ECC_CONSTANT_TIME_POINT_MUL: u32 = 1;
+    pub const SOC_ECDSA_P192_CURVE_DEFAULT_DISABLED: u32 = 1;
+    pub const SOC_ECDSA_USES_MPI: u32 = 1;
+    pub const SOC_EFUSE_BLOCK9_KEY_PURPOSE_QUIRK: u32 = 1;
+    pub const SOC_EFUSE_DIS_DIRECT_BOOT: u32 = 1;
+    pub const SOC_EFUSE_DIS_ICACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_PAD_JTAG: u32 = 1;
+    pub const SOC_EFUSE_DIS_USB_JTAG: u32 = 1;
+    pub const SOC_EFUSE_ECDSA_KEY: u32 = 1;
+    pub const SOC_EFUSE_ECDSA_USE_HARDWARE_K: u32 = 1;
+    pub const SOC_EFUSE_KEY_PURPOSE_FIELD: u32 = 1;
+    pub const SOC_EFUSE_REVOKE_BOOT_KEY_DIGESTS: u32 = 1;
+    pub const SOC_EFUSE_SECURE_BOOT_KEY_DIGESTS: u32 = 3;
+    pub const SOC_EFUSE_SOFT_DIS_JTAG: u32 = 1;
+    pub const SOC_ESP_NIMBLE_CONTROLLER: u32 = 1;
+    pub const SOC_ETM_CHANNELS_PER_GROUP: u32 = 50;
+    pub const SOC_ETM_GROUPS: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_ADVANCE: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_LEADER_TX_LINE: u32 = 0;
+    pub const SOC_EXT_MEM_CACHE_TAG_IN_CPU_DOMAIN: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTED_XTS_AES_BLOCK_MAX: u32 = 64;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_128: u32 = 1;
+    pub const SOC_GDMA_NUM_GROUPS_MAX: u32 = 1;
+    pub const SOC_GDMA_PAIRS_PER_GROUP_MAX: u32 = 3;
+    pub const SOC_GPIO_CLOCKOUT_BY_GPIO_MATRIX: u32 = 1;
+    pub const SOC_GPIO_CLOCKOUT_CHANNEL_NUM: u32 = 3;
+    pub const SOC_GPIO_FLEX_GLITCH_FILTER_NUM: u32 = 8;
+    pub const SOC_GPIO_IN_RANGE_MAX: u32 = 27;
+    pub const SOC_GPIO_OUT_RANGE_MAX: u32 = 27;
+    pub const SOC_GPIO_PIN_COUNT: u32 = 28;
+    pub const SOC_GPIO_PORT: u32 = 1;
+    pub const SOC_GPIO_VALID_DIGITAL_IO_PAD_MASK: u64 = 0xfff807f;
+    pub const SOC_GPIO_VALID_GPIO_MASK: u64 = 0xfffffff;
+    pub const SOC_GPIO_VALID_OUTPUT_GPIO_MASK: u64 = 0xfffffff;
+    pub const SOC_HP_I2C_NUM: u32 = 2;
+    pub const SOC_I2C_CMD_REG_NUM: u32 = 8;
+    pub const SOC_I2C_FIFO_LEN: u32 = 32;
+    pub const SOC_I2C_NUM: u32 = 2;
+    pub const SOC_I2C_SLAVE_CAN_GET_STRETCH_CAUSE: u32 = 1;
+    pub const SOC_I2S_HW_VERSION_2: u32 = 1;
+    pub const SOC_I2S_NUM: u32 = 1;
+    pub const SOC_I2S_PDM_MAX_RX_LINES: u32 = 1;
+    pub const SOC_I2S_PDM_MAX_TX_LINES: u32 = 2;
+    pub const SOC_I2S_TDM_FULL_DATA_WIDTH: u32 = 1;
+    pub const SOC_IEEE802154_BLE_ONLY: u32 = 1;
+    pub const SOC_LEDC_CHANNEL_NUM: u32 = 6;
+    pub const SOC_LEDC_FADE_PARAMS_BIT_WIDTH: u32 = 10;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_RANGE_MAX: u32 = 16;
+    pub const SOC_LEDC_TIMER_BIT_WIDTH: u32 = 20;
+    pub const SOC_LEDC_TIMER_NUM: u32 = 4;
+    pub const SOC_LP_IO_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_LP_TIMER_BIT_WIDTH_HI: u32 = 16;
+    pub const SOC_LP_TIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_MCPWM_CAPTURE_CHANNELS_PER_TIMER: u32 = 3;
+    pub const SOC_MCPWM_CAPTURE_CLK_FROM_GROUP: u32 = 1;
+    pub const SOC_MCPWM_CAPTURE_TIMERS_PER_GROUP: u32 = 1;
+    pub const SOC_MCPWM_COMPARATORS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MCPWM_GENERATORS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MCPWM_GPIO_FAULTS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_GPIO_SYNCHROS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_GROUPS: u32 = 1;
+    pub const SOC_MCPWM_OPERATORS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_SWSYNC_CAN_PROPAGATE: u32 = 1;
+    pub const SOC_MCPWM_TIMERS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_TRIGGERS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MEMSPI_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_MMU_DI_VADDR_SHARED: u32 = 1;
+    pub const SOC_MMU_LINEAR_ADDRESS_REGION_NUM: u32 = 1;
+    pub const SOC_MMU_PAGE_SIZE_CONFIGURABLE: u32 = 1;
+    pub const SOC_MMU_PERIPH_NUM: u32 = 1;
+    pub const SOC_MODEM_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_MPI_MEM_BLOCKS_NUM: u32 = 4;
+    pub const SOC_MPI_OPERATIONS_NUM: u32 = 3;
+    pub const SOC_MPU_MIN_REGION_SIZE: u32 = 0x20000000;
+    pub const SOC_MPU_REGIONS_MAX_NUM: u32 = 8;
+    pub const SOC_PARLIO_GROUPS: u32 = 1;
+    pub const SOC_PARLIO_RX_UNITS_PER_GROUP: u32 = 1;
+    pub const SOC_PARLIO_RX_UNIT_MAX_DATA_WIDTH: u32 = 8;
+    pub const SOC_PARLIO_TRANS_BIT_ALIGN: u32 = 1;
+    pub const SOC_PARLIO_TX_UNITS_PER_GROUP: u32 = 1;
+    pub const SOC_PARLIO_TX_UNIT_MAX_DATA_WIDTH: u32 = 8;
+    pub const SOC_PCNT_CHANNELS_PER_UNIT: u32 = 2;
+    pub const SOC_PCNT_GROUPS: u32 = 1;
+    pub const SOC_PCNT_THRES_POINT_PER_UNIT: u32 = 2;
+    pub const SOC_PCNT_UNITS_PER_GROUP: u32 = 4;
+    pub const SOC_PHY_DIG_REGS_MEM_SIZE: u32 = 84;
+    pub const SOC_PM_CPU_RETENTION_BY_SW: u32 = 1;
+    pub const SOC_PM_MODEM_RETENTION_BY_REGDMA: u32 = 1;
+    pub const SOC_PM_PAU_LINK_NUM: u32 = 4;
+    pub const SOC_PM_PAU_REGDMA_LINK_MULTI_ADDR: u32 = 1;
+    pub const SOC_PM_PAU_REGDMA_LINK_WIFIMAC: u32 = 1;
+    pub const SOC_PM_PAU_REGDMA_UPDATE_CACHE_BEFORE_WAIT_COMPARE: u32 = 1;
+    pub const SOC_PM_RETENTION_MODULE_NUM: u32 = 32;
+    pub const SOC_PM_RETENTION_SW_TRIGGER_REGDMA: u32 = 1;
+    pub const SOC_RCC_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_RMT_CHANNELS_PER_GROUP: u32 = 4;
+    pub const SOC_RMT_GROUPS: u32 = 1;
+    pub const SOC_RMT_MEM_WORDS_PER_CHANNEL: u32 = 48;
+    pub const SOC_RMT_RX_CANDIDATES_PER_GROUP: u32 = 2;
+    pub const SOC_RMT_TX_CANDIDATES_PER_GROUP: u32 = 2;
+    pub const SOC_RNG_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_RSA_MAX_BIT_LEN: u32 = 3072;
+    pub const SOC_RTCIO_PIN_COUNT: u32 = 8;
+    pub const SOC_SDM_CHANNELS_PER_GROUP: u32 = 4;
+    pub const SOC_SDM_GROUPS: u32 = 1;
+    pub const SOC_SECURE_BOOT_V2_ECC: u32 = 1;
+    pub const SOC_SECURE_BOOT_V2_RSA: u32 = 1;
+    pub const SOC_SHA_DMA_MAX_BUFFER_SIZE: u32 = 3968;
+    pub const SOC_SHA_GDMA: u32 = 1;
+    pub const SOC_SPI_MAXIMUM_BUFFER_SIZE: u32 = 64;
+    pub const SOC_SPI_MAX_CS_NUM: u32 = 6;
+    pub const SOC_SPI_MAX_PRE_DIVIDER: u32 = 16;
+    pub const SOC_SPI_PERIPH_NUM: u32 = 2;
+    pub const SOC_SPI_SCT_BUFFER_NUM_MAX: u32 = 15;
+    pub const SOC_SPI_SCT_CONF_BITLEN_MAX: u32 = 0x3fffa;
+    pub const SOC_SPI_SCT_REG_NUM: u32 = 14;
+    pub const SOC_SYSTIMER_ALARM_MISS_COMPENSATE: u32 = 1;
+    pub const SOC_SYSTIMER_ALARM_NUM: u32 = 3;
+    pub const SOC_SYSTIMER_BIT_WIDTH_HI: u32 = 20;
+    pub const SOC_SYSTIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_SYSTIMER_COUNTER_NUM: u32 = 2;
+    pub const SOC_SYSTIMER_FIXED_DIVIDER: u32 = 1;
+    pub const SOC_SYSTIMER_INT_LEVEL: u32 = 1;
+    pub const SOC_TEMPERATURE_SENSOR_UNDER_PD_TOP_DOMAIN: u32 = 1;
+    pub const SOC_TIMER_GROUPS: u32 = 2;
+    pub const SOC_TIMER_GROUP_COUNTER_BIT_WIDTH: u32 = 54;
+    pub const SOC_TIMER_GROUP_TIMERS_PER_GROUP: u32 = 1;
+    pub const SOC_TIMER_GROUP_TOTAL_TIMERS: u32 = 2;
+    pub const SOC_TWAI_BRP_MAX: u32 = 32768;
+    pub const SOC_TWAI_BRP_MIN: u32 = 2;
+    pub const SOC_TWAI_CONTROLLER_NUM: u32 = 1;
+    pub const SOC_TWAI_MASK_FILTER_NUM: u32 = 1;
+    pub const SOC_UART_BITRATE_MAX: u32 = 5000000;
+    pub const SOC_UART_FIFO_LEN: u32 = 128;
+    pub const SOC_UART_HP_NUM: u32 = 2;
+    pub const SOC_UART_NUM: u32 = 2;
+    pub const SOC_UART_WAKEUP_CHARS_SEQ_MAX_LEN: u32 = 5;
+    pub const SOC_UHCI_NUM: u32 = 1;
+    pub const SOC_XTAL_CLOCK_PATH_DEPENDS_ON_TOP_DOMAIN: u32 = 1;
+}
+
+pub mod esp32c5 {
+    pub const SOC_ADC_ARBITER_SUPPORTED: bool = false;
+    pub const SOC_ADC_CALIBRATION_V1_SUPPORTED: bool = true;
+    pub const SOC_ADC_CALIB_CHAN_COMPENS_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_CTRL_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_IIR_FILTER_SUPPORTED: bool = true;
+    pub const SOC_ADC_DMA_SUPPORTED: bool = true;
+    pub const SOC_ADC_MONITOR_SUPPORTED: bool = true;
+    pub const SOC_ADC_RTC_CTRL_SUPPORTED: bool = false;
+    pub const SOC_ADC_SELF_HW_CALI_SUPPORTED: bool = false;
+    pub const SOC_ADC_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORT_AES_128: bool = true;
+    pub const SOC_AES_SUPPORT_AES_192: bool = false;
+    pub const SOC_AES_SUPPORT_AES_256: bool = true;
+    pub const SOC_AES_SUPPORT_DMA: bool = true;
+    pub const SOC_AES_SUPPORT_GCM: bool = false;
+    pub const SOC_AES_SUPPORT_PSEUDO_ROUND_FUNCTION: bool = true;
+    pub const SOC_AHB_GDMA_SUPPORTED: bool = true;
+    pub const SOC_AHB_GDMA_SUPPORT_PSRAM: bool = true;
+    pub const SOC_ANA_CMPR_SUPPORTED: bool = true;
+    pub const SOC_ANA_CMPR_SUPPORT_ETM: bool = true;
+    pub const SOC_APM_CPU_APM_SUPPORTED: bool = true;
+    pub const SOC_APM_CTRL_FILTER_SUPPORTED: bool = true;
+    pub const SOC_APM_LP_APM0_SUPPORTED: bool = true;
+    pub const SOC_APM_SUPPORTED: bool = true;
+    pub const SOC_APM_SUPPORT_CTRL_CFG_LOCK: bool = true;
+    pub const SOC_APM_SUPPORT_LP_TEE_CTRL: bool = true;
+    pub const SOC_APM_SUPPORT_TEE_PERI_ACCESS_CTRL: bool = true;
+    pub const SOC_ASSIST_DEBUG_SUPPORTED: bool = true;
+    pub const SOC_ASYNC_MEMCPY_SUPPORTED: bool = true;
+    pub const SOC_BITSCRAMBLER_SUPPORTED: bool = true;
+    pub const SOC_BLE_50_SUPPORTED: bool = true;
+    pub const SOC_BLE_CTE_SUPPORTED: bool = true;
+    pub const SOC_BLE_DEVICE_PRIVACY_SUPPORTED: bool = true;
+    pub const SOC_BLE_MESH_SUPPORTED: bool = false;
+    pub const SOC_BLE_PERIODIC_ADV_ENH_SUPPORTED: bool = true;
+    pub const SOC_BLE_POWER_CONTROL_SUPPORTED: bool = true;
+    pub const SOC_BLE_SUPPORTED: bool = true;
+    pub const SOC_BLUFI_SUPPORTED: bool = false;
+    pub const SOC_BOD_SUPPORTED: bool = true;
+    pub const SOC_BOOTLOADER_ANTI_ROLLBACK_SUPPORTED: bool = true;
+    pub const SOC_BRANCH_PREDICTOR_SUPPORTED: bool = true;
+    pub const SOC_BROWNOUT_RESET_SUPPORTED: bool = true;
+    pub const SOC_BT_CLASSIC_SUPPORTED: bool = false;
+    pub const SOC_BT_H2C_ENC_KEY_CTRL_ENH_VSC_SUPPORTED: bool = false;
+    pub const SOC_BT_SUPPORTED: bool = true;
+    pub const SOC_CACHE_FREEZE_SUPPORTED: bool = true;
+    pub const SOC_CACHE_SUPPORT_WRAP: bool = false;
+    pub const SOC_CACHE_WRITEBACK_SUPPORTED: bool = true;
+    pub const SOC_CCOMP_TIMER_SUPPORTED: bool = false;
+    pub const SOC_CLK_APLL_SUPPORTED: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_LP_PLL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL: bool = true;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D2: bool = true;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D4: bool = false;
+    pub const SOC_CLK_OSC_SLOW_SUPPORTED: bool = true;
+    pub const SOC_CLK_RC32K_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC_FAST_D256_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC_FAST_SUPPORT_CALIBRATION: bool = true;
+    pub const SOC_CLK_TREE_SUPPORTED: bool = true;
+    pub const SOC_CLK_XTAL32K_SUPPORTED: bool = true;
+    pub const SOC_CLOCK_TREE_MANAGEMENT_SUPPORTED: bool = true;
+    pub const SOC_CONFIGURABLE_VDDSDIO_SUPPORTED: bool = false;
+    pub const SOC_CPU_SUPPORT_WFE: bool = true;
+    pub const SOC_CP_DMA_SUPPORTED: bool = false;
+    pub const SOC_CRYPTO_DPA_PROTECTION_SUPPORTED: bool = true;
+    pub const SOC_DAC_SUPPORTED: bool = false;
+    pub const SOC_DEDICATED_GPIO_SUPPORTED: bool = true;
+    pub const SOC_DEEP_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_DIG_SIGN_SUPPORTED: bool = true;
+    pub const SOC_ECC_EXTENDED_MODES_SUPPORTED: bool = true;
+    pub const SOC_ECC_SUPPORTED: bool = true;
+    pub const SOC_ECC_SUPPORT_POINT_VERIFY_QUIRK: bool = false;
+    pub const SOC_ECDSA_SUPPORTED: bool = true;
+    pub const SOC_ECDSA_SUPPORT_CURVE_P384: bool = true;
+    pub const SOC_ECDSA_SUPPORT_DETERMINISTIC_MODE: bool = true;
+    pub const SOC_ECDSA_SUPPORT_EXPORT_PUBKEY: bool = true;
+    pub const SOC_ECDSA_SUPPORT_HW_DETERMINISTIC_LOOP: bool = true;
+    pub const SOC_EFUSE_SUPPORTED: bool = true;
+    pub const SOC_EMAC_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORTED: bool = true;
+    pub const SOC_ETM_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_SUPPORT_PSEUDO_ROUND: bool = true;
+    pub const SOC_FLASH_ENC_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORT_ETM: bool = true;
+    pub const SOC_GDMA_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_GPIO_FILTER_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_GPIO_SUPPORT_DEEPSLEEP_WAKEUP: bool = true;
+    pub const SOC_GPIO_SUPPORT_ETM: bool = true;
+    pub const SOC_GPIO_SUPPORT_FORCE_HOLD: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_SINGLE_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_GLITCH_FILTER: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_CTRL_BY_EFUSE: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_FILTER: bool = true;
+    pub const SOC_GPIO_SUPPORT_RTC_INDEPENDENT: bool = true;
+    pub const SOC_GPSPI_SUPPORTED: bool = true;
+    pub const SOC_GPTIMER_SUPPORTED: bool = true;
+    pub const SOC_HMAC_SUPPORTED: bool = true;
+    pub const SOC_HUK_SUPPORTED: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_BROADCAST: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_I2CRAM_ACCESS: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_SLAVE_UNMATCH: bool = true;
+    pub const SOC_I2C_SUPPORTED: bool = true;
+    pub const SOC_I2C_SUPPORT_10BIT_ADDR: bool = true;
+    pub const SOC_I2C_SUPPORT_APB: bool = false;
+    pub const SOC_I2C_SUPPORT_HW_CLR_BUS: bool = false;
+    pub const SOC_I2C_SUPPORT_HW_FSM_RST: bool = true;
+    pub const SOC_I2C_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_I2C_SUPPORT_RTC: bool = true;
+    pub const SOC_I2C_SUPPORT_SLAVE: bool = true;
+    pub const SOC_I2C_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_I2C_SUPPORT_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORTED: bool = true;
+    pub const SOC_I2S_SUPPORTS_ADC: bool = false;
+    pub const SOC_I2S_SUPPORTS_ADC_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_APLL: bool = false;
+    pub const SOC_I2S_SUPPORTS_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_DMA_EQUAL: bool = false;
+    pub const SOC_I2S_SUPPORTS_ETM: bool = true;
+    pub const SOC_I2S_SUPPORTS_LCD_CAMERA: bool = false;
+    pub const SOC_I2S_SUPPORTS_PCM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PCM2PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM2PCM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM_RX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM_TX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F120M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F160M: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F240M: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F64M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F96M: bool = false;
+    pub const SOC_I2S_SUPPORTS_TDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_TX_SYNC_CNT: bool = true;
+    pub const SOC_I2S_SUPPORTS_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_IEEE802154_SUPPORTED: bool = true;
+    pub const SOC_INT_CLIC_SUPPORTED: bool = true;
+    pub const SOC_INT_HW_NESTED_SUPPORTED: bool = true;
+    pub const SOC_INT_PLIC_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORTED: bool = true;
+    pub const SOC_KEY_MANAGER_SUPPORT_KEY_DEPLOYMENT: bool = true;
+    pub const SOC_LCDCAM_CAM_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LCDCAM_I80_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_RGB_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_SUPPORTED: bool = false;
+    pub const SOC_LCD_I80_SUPPORTED: bool = false;
+    pub const SOC_LCD_RGB_SUPPORTED: bool = false;
+    pub const SOC_LCD_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORT_APB_CLOCK: bool = false;
+    pub const SOC_LEDC_SUPPORT_FADE_STOP: bool = true;
+    pub const SOC_LEDC_SUPPORT_HS_MODE: bool = false;
+    pub const SOC_LEDC_SUPPORT_PLL_DIV_CLOCK: bool = true;
+    pub const SOC_LEDC_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_LEDC_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_LEDC_SUPPORT_XTAL_CLOCK: bool = true;
+    pub const SOC_LIGHT_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_LP_AON_SUPPORTED: bool = true;
+    pub const SOC_LP_CORE_SUPPORTED: bool = true;
+    pub const SOC_LP_CORE_SUPPORT_ETM: bool = true;
+    pub const SOC_LP_CORE_SUPPORT_STORE_LOAD_EXCEPTIONS: bool = true;
+    pub const SOC_LP_I2C_SUPPORTED: bool = true;
+    pub const SOC_LP_PERIPHERALS_SUPPORTED: bool = true;
+    pub const SOC_LP_TIMER_SUPPORTED: bool = true;
+    pub const SOC_MCPWM_SUPPORTED: bool = true;
+    pub const SOC_MCPWM_SUPPORT_ETM: bool = true;
+    pub const SOC_MCPWM_SUPPORT_EVENT_COMPARATOR: bool = true;
+    pub const SOC_MCPWM_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_MEMPROT_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_120M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_15M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_16M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_20M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_26M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_30M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_32M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_40M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_60M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_64M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_80M_SUPPORTED: bool = true;
+    pub const SOC_MMU_PAGE_SIZE_8KB_SUPPORTED: bool = false;
+    pub const SOC_MODEM_CLOCK_SUPPORTED: bool = true;
+    pub const SOC_MPI_SUPPORTED: bool = true;
+    pub const SOC_MPU_CONFIGURABLE_REGIONS_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_RO_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_WO_SUPPORTED: bool = false;
+    pub const SOC_MPU_SUPPORTED: bool = false;
+    pub const SOC_MWDT_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_MWDT_SUPPORT_XTAL: bool = false;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_GATING: bool = true;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_OUTPUT: bool = true;
+    pub const SOC_PARLIO_SUPPORTED: bool = true;
+    pub const SOC_PARLIO_SUPPORT_I80_LCD: bool = true;
+    pub const SOC_PARLIO_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_PARLIO_SUPPORT_SPI_LCD: bool = true;
+    pub const SOC_PARLIO_TX_CLK_SUPPORT_GATING: bool = true;
+    pub const SOC_PARLIO_TX_SUPPORT_EOF_FROM_DMA: bool = true;
+    pub const SOC_PARLIO_TX_SUPPORT_LOOP_TRANSMISSION: bool = true;
+    pub const SOC_PAU_SUPPORTED: bool = true;
+    pub const SOC_PCNT_SUPPORTED: bool = true;
+    pub const SOC_PCNT_SUPPORT_CLEAR_SIGNAL: bool = true;
+    pub const SOC_PCNT_SUPPORT_RUNTIME_THRES_UPDATE: bool = true;
+    pub const SOC_PCNT_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_PCNT_SUPPORT_STEP_NOTIFY: bool = true;
+    pub const SOC_PHY_SUPPORTED: bool = true;
+    pub const SOC_PMU_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORT_BEACON_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_BT_PD: bool = false;
+    pub const SOC_PM_SUPPORT_BT_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_CPU_PD: bool = true;
+    pub const SOC_PM_SUPPORT_DEEPSLEEP_CHECK_STUB_ONLY: bool = true;
+    pub const SOC_PM_SUPPORT_EXT0_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP_MODE_PER_PIN: bool = true;
+    pub const SOC_PM_SUPPORT_EXT_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_HP_AON_PD: bool = true;
+    pub const SOC_PM_SUPPORT_MAC_BB_PD: bool = true;
+    pub const SOC_PM_SUPPORT_MODEM_PD: bool = true;
+    pub const SOC_PM_SUPPORT_PMU_CLK_ICG: bool = true;
+    pub const SOC_PM_SUPPORT_PMU_MODEM_STATE: bool = true;
+    pub const SOC_PM_SUPPORT_RC32K_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RC_FAST_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_FAST_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RTC_PERIPH_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_SLOW_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TAGMEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOP_PD: bool = true;
+    pub const SOC_PM_SUPPORT_TOUCH_SENSOR_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_VDDSDIO_PD: bool = true;
+    pub const SOC_PM_SUPPORT_WIFI_PD: bool = false;
+    pub const SOC_PM_SUPPORT_WIFI_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_XTAL32K_PD: bool = true;
+    pub const SOC_RECOVERY_BOOTLOADER_SUPPORTED: bool = true;
+    pub const SOC_REG_I2C_SUPPORTED: bool = false;
+    pub const SOC_RISCV_COPROC_SUPPORTED: bool = false;
+    pub const SOC_RMT_SUPPORTED: bool = true;
+    pub const SOC_RMT_SUPPORT_APB: bool = false;
+    pub const SOC_RMT_SUPPORT_DMA: bool = false;
+    pub const SOC_RMT_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_RMT_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_RMT_SUPPORT_RX_DEMODULATION: bool = true;
+    pub const SOC_RMT_SUPPORT_RX_PINGPONG: bool = true;
+    pub const SOC_RMT_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_ASYNC_STOP: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_CARRIER_DATA_ONLY: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_AUTO_STOP: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_COUNT: bool = true;
+    pub const SOC_RMT_SUPPORT_TX_SYNCHRO: bool = true;
+    pub const SOC_RMT_SUPPORT_XTAL: bool = true;
+    pub const SOC_RNG_SUPPORTED: bool = true;
+    pub const SOC_RSA_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_EDGE_WAKE_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_HOLD_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_INPUT_OUTPUT_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_WAKE_SUPPORTED: bool = true;
+    pub const SOC_RTC_FAST_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORTED: bool = true;
+    pub const SOC_RTC_MEM_SUPPORT_SPEED_MODE_SWITCH: bool = true;
+    pub const SOC_RTC_SLOW_CLK_SUPPORT_RC_FAST_D256: bool = false;
+    pub const SOC_RTC_SLOW_MEM_SUPPORTED: bool = false;
+    pub const SOC_SDIO_SLAVE_SUPPORTED: bool = true;
+    pub const SOC_SDMMC_HOST_SUPPORTED: bool = false;
+    pub const SOC_SDMMC_SUPPORT_XTAL_CLOCK: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F48M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F80M: bool = true;
+    pub const SOC_SDM_CLK_SUPPORT_XTAL: bool = true;
+    pub const SOC_SDM_SUPPORTED: bool = true;
+    pub const SOC_SECURE_BOOT_SUPPORTED: bool = true;
+    pub const SOC_SHARED_IDCACHE_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORT_DMA: bool = true;
+    pub const SOC_SHA_SUPPORT_PARALLEL_ENG: bool = false;
+    pub const SOC_SHA_SUPPORT_RESUME: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA1: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA224: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA256: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA384: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512_224: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512_256: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA512_T: bool = true;
+    pub const SOC_SIMD_INSTRUCTION_SUPPORTED: bool = false;
+    pub const SOC_SPIRAM_SUPPORTED: bool = true;
+    pub const SOC_SPIRAM_XIP_SUPPORTED: bool = true;
+    pub const SOC_SPI_AS_CS_SUPPORTED: bool = false;
+    pub const SOC_SPI_FLASH_SUPPORTED: bool = true;
+    pub const SOC_SPI_HD_BOTH_INOUT_SUPPORTED: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_RESUME: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_WAIT_IDLE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CACHE_32BIT_ADDR_MAP: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CHECK_SUS: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CONFIG_GPIO_BY_EFUSE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_FLASH_OPI_MODE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_IDLE_INTR: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_SW_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_TIMING_TUNING: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_TSUS_TRES_SEPERATE_CTR: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_WB_MODE_INDEPENDENT_CONTROL: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_WRAP: bool = true;
+    pub const SOC_SPI_PERIPH_SUPPORT_CONTROL_DUMMY_OUT: bool = false;
+    pub const SOC_SPI_SCT_SUPPORTED: bool = false;
+    pub const SOC_SPI_SLAVE_SUPPORT_SEG_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_CD_SIG: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_APB: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F160M: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F40M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F48M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F80M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_RC_FAST: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_XTAL: bool = true;
+    pub const SOC_SPI_SUPPORT_CONTINUOUS_TRANS: bool = true;
+    pub const SOC_SPI_SUPPORT_DDRCLK: bool = true;
+    pub const SOC_SPI_SUPPORT_OCT: bool = false;
+    pub const SOC_SPI_SUPPORT_SLAVE_HD_VER2: bool = true;
+    pub const SOC_SPI_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_SUPPORTS_SECURE_DL_MODE: bool = true;
+    pub const SOC_SUPPORT_COEXISTENCE: bool = true;
+    pub const SOC_SUPPORT_SECURE_BOOT_REVOKE_KEY: bool = true;
+    pub const SOC_SYSTIMER_SUPPORTED: bool = true;
+    pub const SOC_SYSTIMER_SUPPORT_ETM: bool = true;
+    pub const SOC_SYSTIMER_SUPPORT_RC_FAST: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_INTR_SUPPORT: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_ETM: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_FAST_RC: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_XTAL: bool = true;
+    pub const SOC_TEMP_SENSOR_SUPPORTED: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_APB: bool = false;
+    pub const SOC_TIMER_GROUP_SUPPORT_RC_FAST: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_XTAL: bool = true;
+    pub const SOC_TIMER_SUPPORT_ETM: bool = true;
+    pub const SOC_TIMER_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_TOUCH_PROXIMITY_MEAS_DONE_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SENSOR_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SUPPORT_BENCHMARK: bool = false;
+    pub const SOC_TOUCH_SUPPORT_DENOISE_CHAN: bool = false;
+    pub const SOC_TOUCH_SUPPORT_PROX_SENSING: bool = false;
+    pub const SOC_TOUCH_SUPPORT_SLEEP_WAKEUP: bool = false;
+    pub const SOC_TOUCH_SUPPORT_WATERPROOF: bool = false;
+    pub const SOC_TWAI_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_TWAI_CLK_SUPPORT_XTAL: bool = true;
+    pub const SOC_TWAI_SUPPORTED: bool = true;
+    pub const SOC_TWAI_SUPPORTS_RX_STATUS: bool = true;
+    pub const SOC_TWAI_SUPPORT_FD: bool = true;
+    pub const SOC_TWAI_SUPPORT_MULTI_ADDRESS_LAYOUT: bool = false;
+    pub const SOC_TWAI_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TWAI_SUPPORT_TIMESTAMP: bool = true;
+    pub const SOC_UART_SUPPORTED: bool = true;
+    pub const SOC_UART_SUPPORT_APB_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_FSM_TX_WAIT_SEND: bool = true;
+    pub const SOC_UART_SUPPORT_PLL_F40M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_PLL_F80M_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_UART_SUPPORT_RTC_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_UART_SUPPORT_WAKEUP_INT: bool = true;
+    pub const SOC_UART_SUPPORT_XTAL_CLK: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_ACTIVE_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_CHAR_SEQ_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_FIFO_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_START_BIT_MODE: bool = true;
+    pub const SOC_UHCI_SUPPORTED: bool = true;
+    pub const SOC_ULP_FSM_SUPPORTED: bool = false;
+    pub const SOC_ULP_LP_UART_SUPPORTED: bool = true;
+    pub const SOC_ULP_SUPPORTED: bool = true;
+    pub const SOC_USB_OTG_SUPPORTED: bool = false;
+    pub const SOC_USB_SERIAL_JTAG_SUPPORTED: bool = true;
+    pub const SOC_VBAT_SUPPORTED: bool = false;
+    pub const SOC_WDT_SUPPORTED: bool = true;
+    pub const SOC_WIFI_CSI_SUPPORT: bool = true;
+    pub const SOC_WIFI_FTM_SUPPORT: bool = true;
+    pub const SOC_WIFI_GCMP_SUPPORT: bool = true;
+    pub const SOC_WIFI_HE_SUPPORT: bool = true;
+    pub const SOC_WIFI_MESH_SUPPORT: bool = true;
+    pub const SOC_WIFI_NAN_SUPPORT: bool = true;
+    pub const SOC_WIFI_SUPPORTED: bool = true;
+    pub const SOC_WIFI_SUPPORT_5G: bool = true;
+    pub const SOC_WIFI_SUPPORT_VARIABLE_BEACON_WINDOW: bool = false;
+    pub const SOC_WIFI_WAPI_SUPPORT: bool = true;
+    pub const SOC_XTAL_SUPPORT_26M: bool = false;
+    pub const SOC_XTAL_SUPPORT_32M: bool = false;
+    pub const SOC_XTAL_SUPPORT_40M: bool = true;
+    pub const SOC_XTAL_SUPPORT_48M: bool = true;
+    pub const SOC_XTAL_SUPPORT_AUTO_DETECT: bool = false;
+    pub const SOC_XT_WDT_SUPPORTED: bool = false;
+    pub const SOC_ADC_ATTEN_NUM: u32 = 4;
+    pub const SOC_ADC_DIGI_CONTROLLER_NUM: u32 = 1;
+    pub const SOC_ADC_DIGI_DATA_BYTES_PER_CONV: u32 = 4;
+    pub const SOC_ADC_DIGI_IIR_FILTER_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MONITOR_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_RESULT_BYTES: u32 = 4;
+    pub const SOC_ADC_MAX_CHANNEL_NUM: u32 = 6;
+    pub const SOC_ADC_PATT_LEN_MAX: u32 = 8;
+    pub const SOC_ADC_PERIPH_NUM: u32 = 1;
+    pub const SOC_ADC_RTC_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_RTC_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_HIGH: u32 = 83333;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_LOW: u32 = 611;
+    pub const SOC_ADC_SHARED_POWER: u32 = 1;
+    pub const SOC_ADC_TEMPERATURE_SHARE_INTR: u32 = 1;
+    pub const SOC_AES_GDMA: u32 = 1;
+    pub const SOC_AHB_GDMA_VERSION: u32 = 2;
+    pub const SOC_ANA_CMPR_CAN_DISTINGUISH_EDGE: u32 = 1;
+    pub const SOC_ANA_CMPR_NUM: u32 = 1;
+    pub const SOC_BLE_MULTI_CONN_OPTIMIZATION: u32 = 1;
+    pub const SOC_CAPS_ECO_VER: u32 = 100;
+    pub const SOC_CLK_ANA_I2C_MST_HAS_ROOT_GATE: u32 = 1;
+    pub const SOC_COEX_HW_PTI: u32 = 1;
+    pub const SOC_CPU_BREAKPOINTS_NUM: u32 = 4;
+    pub const SOC_CPU_CORES_NUM: u32 = 1;
+    pub const SOC_CPU_HAS_FLEXIBLE_INTC: u32 = 1;
+    pub const SOC_CPU_HAS_LOCKUP_RESET: u32 = 1;
+    pub const SOC_CPU_HAS_PMA: u32 = 1;
+    pub const SOC_CPU_IDRAM_SPLIT_USING_PMP: u32 = 1;
+    pub const SOC_CPU_INTR_NUM: u32 = 32;
+    pub const SOC_CPU_PMP_REGION_GRANULARITY: u32 = 128;
+    pub const SOC_CPU_WATCHPOINTS_NUM: u32 = 4;
+    pub const SOC_CPU_WATCHPOINT_MAX_REGION_SIZE: u32 = 0x100;
+    pub const SOC_DEDIC_GPIO_IN_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_GPIO_OUT_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_PERIPH_ALWAYS_ENABLE: u32 = 1;
+    pub const SOC_DMA_CAN_ACCESS_FLASH: u32 = 1;
+    pub const SOC_DS_KEY_CHECK_MAX_WAIT_US: u32 = 1100;
+    pub const SOC_DS_KEY_PARAM_MD_IV_LENGTH: u32 = 16;
+    pub const SOC_DS_SIGNATURE_MAX_BIT_LEN: u32 = 3072;
+    pub const SOC_ECC_CONSTANT_TIME_POINT_MUL: u32 = 1;
+    pub const SOC_EFUSE_DIS_DIRECT_BOOT: u32 = 1;
+    pub const SOC_EFUSE_DIS_ICACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_PAD_JTAG: u32 = 1;
+    pub const SOC_EFUSE_DIS_USB_JTAG: u32 = 1;
+    pub const SOC_EFUSE_ECDSA_KEY: u32 = 1;
+    pub const SOC_EFUSE_ECDSA_KEY_P192: u32 = 1;
+    pub const SOC_EFUSE_ECDSA_KEY_P384: u32 = 1;
+    pub const SOC_EFUSE_KEY_PURPOSE_FIELD: u32 = 1;
+    pub const SOC_EFUSE_REVOKE_BOOT_KEY_DIGESTS: u32 = 1;
+    pub const SOC_EFUSE_SECURE_BOOT_KEY_DIGESTS: u32 = 3;
+    pub const SOC_EFUSE_SOFT_DIS_JTAG: u32 = 1;
+    pub const SOC_ESP_NIMBLE_CONTROLLER: u32 = 1;
+    pub const SOC_ETM_CHANNELS_PER_GROUP: u32 = 50;
+    pub const SOC_ETM_GROUPS: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_ADVANCE: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_LEADER_TX_LINE: u32 = 0;
+    pub const SOC_EXT_MEM_CACHE_TAG_IN_CPU_DOMAIN: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTED_XTS_AES_BLOCK_MAX: u32 = 64;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_128: u32 = 1;
+    pub const SOC_GDMA_NUM_GROUPS_MAX: u32 = 1;
+    pub const SOC_GDMA_PAIRS_PER_GROUP_MAX: u32 = 3;
+    pub const SOC_GPIO_CLOCKOUT_CHANNEL_NUM: u32 = 3;
+    pub const SOC_GPIO_DEEP_SLEEP_WAKE_SUPPORTED_PIN_CNT: u32 = 7;
+    pub const SOC_GPIO_DEEP_SLEEP_WAKE_VALID_GPIO_MASK: u32 = 0x7f;
+    pub const SOC_GPIO_FLEX_GLITCH_FILTER_NUM: u32 = 8;
+    pub const SOC_GPIO_IN_RANGE_MAX: u32 = 28;
+    pub const SOC_GPIO_OUT_RANGE_MAX: u32 = 28;
+    pub const SOC_GPIO_PIN_COUNT: u32 = 29;
+    pub const SOC_GPIO_PORT: u32 = 1;
+    pub const SOC_GPIO_VALID_DIGITAL_IO_PAD_MASK: u64 = 0x1ffff80;
+    pub const SOC_GPIO_VALID_GPIO_MASK: u64 = 0x1fffffff;
+    pub const SOC_GPIO_VALID_OUTPUT_GPIO_MASK: u64 = 0x1fffffff;
+    pub const SOC_HP_I2C_NUM: u32 = 1;
+    pub const SOC_HUK_MEM_NEEDS_RECHARGE: u32 = 1;
+    pub const SOC_I2C_CMD_REG_NUM: u32 = 8;
+    pub const SOC_I2C_FIFO_LEN: u32 = 32;
+    pub const SOC_I2C_NUM: u32 = 2;
+    pub const SOC_I2C_SLAVE_CAN_GET_STRETCH_CAUSE: u32 = 1;
+    pub const SOC_I2S_HW_VERSION_2: u32 = 1;
+    pub const SOC_I2S_NUM: u32 = 1;
+    pub const SOC_I2S_PDM_MAX_RX_LINES: u32 = 1;
+    pub const SOC_I2S_PDM_MAX_TX_LINES: u32 = 2;
+    pub const SOC_I2S_TDM_FULL_DATA_WIDTH: u32 = 1;
+    pub const SOC_KEY_MANAGER_DS_KEY_DEPLOY: u32 = 1;
+    pub const SOC_KEY_MANAGER_ECDSA_KEY_DEPLOY: u32 = 1;
+    pub const SOC_KEY_MANAGER_FE_KEY_DEPLOY: u32 = 1;
+    pub const SOC_KEY_MANAGER_FE_KEY_DEPLOY_XTS_AES_128: u32 = 1;
+    pub const SOC_KEY_MANAGER_FE_KEY_DEPLOY_XTS_AES_256: u32 = 1;
+    pub const SOC_KEY_MANAGER_HMAC_KEY_DEPLOY: u32 = 1;
+    pub const SOC_LEDC_CHANNEL_NUM: u32 = 6;
+    pub const SOC_LEDC_FADE_PARAMS_BIT_WIDTH: u32 = 10;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_RANGE_MAX: u32 = 16;
+    pub const SOC_LEDC_TIMER_BIT_WIDTH: u32 = 20;
+    pub const SOC_LEDC_TIMER_NUM: u32 = 4;
+    pub const SOC_LP_CORE_SINGLE_INTERRUPT_VECTOR: u32 = 1;
+    pub const SOC_LP_I2C_FIFO_LEN: u32 = 16;
+    pub const SOC_LP_I2C_NUM: u32 = 1;
+    pub const SOC_LP_IO_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_LP_TIMER_BIT_WIDTH_HI: u32 = 16;
+    pub const SOC_LP_TIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_LP_UART_FIFO_LEN: u32 = 16;
+    pub const SOC_MCPWM_CAPTURE_CHANNELS_PER_TIMER: u32 = 3;
+    pub const SOC_MCPWM_CAPTURE_CLK_FROM_GROUP: u32 = 1;
+    pub const SOC_MCPWM_CAPTURE_TIMERS_PER_GROUP: u32 = 1;
+    pub const SOC_MCPWM_COMPARATORS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MCPWM_EVENT_COMPARATORS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MCPWM_GENERATORS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MCPWM_GPIO_FAULTS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_GPIO_SYNCHROS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_GROUPS: u32 = 1;
+    pub const SOC_MCPWM_OPERATORS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_SWSYNC_CAN_PROPAGATE: u32 = 1;
+    pub const SOC_MCPWM_TIMERS_PER_GROUP: u32 = 3;
+    pub const SOC_MCPWM_TRIGGERS_PER_OPERATOR: u32 = 2;
+    pub const SOC_MEMSPI_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_MEMSPI_TIMING_TUNING_BY_MSPI_DELAY: u32 = 1;
+    pub const SOC_MMU_DI_VADDR_SHARED: u32 = 1;
+    pub const SOC_MMU_LINEAR_ADDRESS_REGION_NUM: u32 = 1;
+    pub const SOC_MMU_PERIPH_NUM: u32 = 1;
+    pub const SOC_MODEM_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_MPI_MEM_BLOCKS_NUM: u32 = 4;
+    pub const SOC_MPI_OPERATIONS_NUM: u32 = 3;
+    pub const SOC_PARLIO_GROUPS: u32 = 1;
+    pub const SOC_PARLIO_RX_UNITS_PER_GROUP: u32 = 1;
+    pub const SOC_PARLIO_RX_UNIT_MAX_DATA_WIDTH: u32 = 8;
+    pub const SOC_PARLIO_TRANS_BIT_ALIGN: u32 = 1;
+    pub const SOC_PARLIO_TX_UNITS_PER_GROUP: u32 = 1;
+    pub const SOC_PARLIO_TX_UNIT_MAX_DATA_WIDTH: u32 = 8;
+    pub const SOC_PCNT_CHANNELS_PER_UNIT: u32 = 2;
+    pub const SOC_PCNT_GROUPS: u32 = 1;
+    pub const SOC_PCNT_THRES_POINT_PER_UNIT: u32 = 2;
+    pub const SOC_PCNT_UNITS_PER_GROUP: u32 = 4;
+    pub const SOC_PHY_CALIBRATION_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_PM_CPU_RETENTION_BY_SW: u32 = 1;
+    pub const SOC_PM_MODEM_RETENTION_BY_REGDMA: u32 = 1;
+    pub const SOC_PM_PAU_LINK_NUM: u32 = 5;
+    pub const SOC_PM_PAU_REGDMA_LINK_CONFIGURABLE: u32 = 1;
+    pub const SOC_PM_PAU_REGDMA_LINK_IDX_WIFIMAC: u32 = 4;
+    pub const SOC_PM_PMU_MIN_SLP_SLOW_CLK_CYCLE_FIXED: u32 = 1;
+    pub const SOC_PM_RETENTION_MODULE_NUM: u32 = 32;
+    pub const SOC_PM_TOP_PD_NOT_ALLOWED: u32 = 1;
+    pub const SOC_PSRAM_DMA_CAPABLE: u32 = 1;
+    pub const SOC_PSRAM_ENCRYPTION_XTS_AES_128: u32 = 1;
+    pub const SOC_RCC_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_RMT_CHANNELS_PER_GROUP: u32 = 4;
+    pub const SOC_RMT_GROUPS: u32 = 1;
+    pub const SOC_RMT_MEM_WORDS_PER_CHANNEL: u32 = 48;
+    pub const SOC_RMT_RX_CANDIDATES_PER_GROUP: u32 = 2;
+    pub const SOC_RMT_TX_CANDIDATES_PER_GROUP: u32 = 2;
+    pub const SOC_RNG_BUF_CHAIN_ENTROPY_SOURCE: u32 = 1;
+    pub const SOC_RNG_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_RNG_RTC_TIMER_ENTROPY_SOURCE: u32 = 1;
+    pub const SOC_RSA_MAX_BIT_LEN: u32 = 3072;
+    pub const SOC_RTCIO_PIN_COUNT: u32 = 7;
+    pub const SOC_SDM_CHANNELS_PER_GROUP: u32 = 4;
+    pub const SOC_SDM_GROUPS: u32 = 1;
+    pub const SOC_SECURE_BOOT_V2_ECC: u32 = 1;
+    pub const SOC_SECURE_BOOT_V2_RSA: u32 = 1;
+    pub const SOC_SHA_DMA_MAX_BUFFER_SIZE: u32 = 3968;
+    pub const SOC_SHA_GDMA: u32 = 1;
+    pub const SOC_SPI_MAXIMUM_BUFFER_SIZE: u32 = 64;
+    pub const SOC_SPI_MAX_CS_NUM: u32 = 6;
+    pub const SOC_SPI_MAX_PRE_DIVIDER: u32 = 16;
+    pub const SOC_SPI_PERIPH_NUM: u32 = 2;
+    pub const SOC_SYSTIMER_ALARM_MISS_COMPENSATE: u32 = 1;
+    pub const SOC_SYSTIMER_ALARM_NUM: u32 = 3;
+    pub const SOC_SYSTIMER_BIT_WIDTH_HI: u32 = 20;
+    pub const SOC_SYSTIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_SYSTIMER_COUNTER_NUM: u32 = 2;
+    pub const SOC_SYSTIMER_FIXED_DIVIDER: u32 = 1;
+    pub const SOC_SYSTIMER_INT_LEVEL: u32 = 1;
+    pub const SOC_TEMPERATURE_SENSOR_UNDER_PD_TOP_DOMAIN: u32 = 1;
+    pub const SOC_TIMER_GROUPS: u32 = 2;
+    pub const SOC_TIMER_GROUP_COUNTER_BIT_WIDTH: u32 = 54;
+    pub const SOC_TIMER_GROUP_TIMERS_PER_GROUP: u32 = 1;
+    pub const SOC_TIMER_GROUP_TOTAL_TIMERS: u32 = 2;
+    pub const SOC_TWAI_BRP_MAX: u32 = 255;
+    pub const SOC_TWAI_BRP_MIN: u32 = 1;
+    pub const SOC_TWAI_CONTROLLER_NUM: u32 = 2;
+    pub const SOC_TWAI_MASK_FILTER_NUM: u32 = 3;
+    pub const SOC_TWAI_RANGE_FILTER_NUM: u32 = 1;
+    pub const SOC_UART_BITRATE_MAX: u32 = 5000000;
+    pub const SOC_UART_FIFO_LEN: u32 = 128;
+    pub const SOC_UART_HAS_LP_UART: u32 = 1;
+    pub const SOC_UART_HP_NUM: u32 = 2;
+    pub const SOC_UART_LP_NUM: u32 = 1;
+    pub const SOC_UART_NUM: u32 = 3;
+    pub const SOC_UART_WAKEUP_CHARS_SEQ_MAX_LEN: u32 = 5;
+    pub const SOC_UHCI_NUM: u32 = 1;
+    pub const SOC_WIFI_HW_TSF: u32 = 1;
+    pub const SOC_WIFI_LIGHT_SLEEP_CLK_WIDTH: u32 = 12;
+    pub const SOC_WIFI_MAC_VERSION_NUM: u32 = 3;
+    pub const SOC_XTAL_CLOCK_PATH_DEPENDS_ON_TOP_DOMAIN: u32 = 1;
+}
+
+pub mod esp32c61 {
+    pub const SOC_ADC_ARBITER_SUPPORTED: bool = false;
+    pub const SOC_ADC_CALIBRATION_V1_SUPPORTED: bool = true;
+    pub const SOC_ADC_CALIB_CHAN_COMPENS_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_CTRL_SUPPORTED: bool = true;
+    pub const SOC_ADC_DIG_IIR_FILTER_SUPPORTED: bool = true;
+    pub const SOC_ADC_DMA_SUPPORTED: bool = true;
+    pub const SOC_ADC_MONITOR_SUPPORTED: bool = true;
+    pub const SOC_ADC_RTC_CTRL_SUPPORTED: bool = false;
+    pub const SOC_ADC_SELF_HW_CALI_SUPPORTED: bool = true;
+    pub const SOC_ADC_SUPPORTED: bool = true;
+    pub const SOC_AES_SUPPORTED: bool = false;
+    pub const SOC_AES_SUPPORT_AES_128: bool = false;
+    pub const SOC_AES_SUPPORT_AES_192: bool = false;
+    pub const SOC_AES_SUPPORT_AES_256: bool = false;
+    pub const SOC_AES_SUPPORT_DMA: bool = false;
+    pub const SOC_AES_SUPPORT_GCM: bool = false;
+    pub const SOC_AES_SUPPORT_PSEUDO_ROUND_FUNCTION: bool = false;
+    pub const SOC_AHB_GDMA_SUPPORTED: bool = true;
+    pub const SOC_AHB_GDMA_SUPPORT_PSRAM: bool = true;
+    pub const SOC_ANA_CMPR_SUPPORTED: bool = true;
+    pub const SOC_ANA_CMPR_SUPPORT_ETM: bool = true;
+    pub const SOC_APM_CPU_APM_SUPPORTED: bool = true;
+    pub const SOC_APM_CTRL_FILTER_SUPPORTED: bool = true;
+    pub const SOC_APM_LP_APM0_SUPPORTED: bool = false;
+    pub const SOC_APM_SUPPORTED: bool = true;
+    pub const SOC_APM_SUPPORT_CTRL_CFG_LOCK: bool = true;
+    pub const SOC_APM_SUPPORT_LP_TEE_CTRL: bool = false;
+    pub const SOC_APM_SUPPORT_TEE_PERI_ACCESS_CTRL: bool = false;
+    pub const SOC_ASSIST_DEBUG_SUPPORTED: bool = true;
+    pub const SOC_ASYNC_MEMCPY_SUPPORTED: bool = true;
+    pub const SOC_BITSCRAMBLER_SUPPORTED: bool = false;
+    pub const SOC_BLE_50_SUPPORTED: bool = true;
+    pub const SOC_BLE_CTE_SUPPORTED: bool = true;
+    pub const SOC_BLE_DEVICE_PRIVACY_SUPPORTED: bool = true;
+    pub const SOC_BLE_MESH_SUPPORTED: bool = false;
+    pub const SOC_BLE_PERIODIC_ADV_ENH_SUPPORTED: bool = true;
+    pub const SOC_BLE_POWER_CONTROL_SUPPORTED: bool = true;
+    pub const SOC_BLE_SUPPORTED: bool = true;
+    pub const SOC_BLUFI_SUPPORTED: bool = true;
+    pub const SOC_BOD_SUPPORTED: bool = true;
+    pub const SOC_BOOTLOADER_ANTI_ROLLBACK_SUPPORTED: bool = true;
+    pub const SOC_BRANCH_PREDICTOR_SUPPORTED: bool = true;
+    pub const SOC_BROWNOUT_RESET_SUPPORTED: bool = true;
+    pub const SOC_BT_CLASSIC_SUPPORTED: bool = false;
+    pub const SOC_BT_H2C_ENC_KEY_CTRL_ENH_VSC_SUPPORTED: bool = false;
+    pub const SOC_BT_SUPPORTED: bool = true;
+    pub const SOC_CACHE_FREEZE_SUPPORTED: bool = true;
+    pub const SOC_CACHE_SUPPORT_WRAP: bool = false;
+    pub const SOC_CACHE_WRITEBACK_SUPPORTED: bool = true;
+    pub const SOC_CCOMP_TIMER_SUPPORTED: bool = false;
+    pub const SOC_CLK_APLL_SUPPORTED: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_LP_PLL: bool = false;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL: bool = true;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D2: bool = true;
+    pub const SOC_CLK_LP_FAST_SUPPORT_XTAL_D4: bool = false;
+    pub const SOC_CLK_OSC_SLOW_SUPPORTED: bool = true;
+    pub const SOC_CLK_RC32K_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC_FAST_D256_SUPPORTED: bool = false;
+    pub const SOC_CLK_RC_FAST_SUPPORT_CALIBRATION: bool = true;
+    pub const SOC_CLK_TREE_SUPPORTED: bool = true;
+    pub const SOC_CLK_XTAL32K_SUPPORTED: bool = true;
+    pub const SOC_CLOCK_TREE_MANAGEMENT_SUPPORTED: bool = false;
+    pub const SOC_CONFIGURABLE_VDDSDIO_SUPPORTED: bool = false;
+    pub const SOC_CPU_SUPPORT_WFE: bool = true;
+    pub const SOC_CP_DMA_SUPPORTED: bool = false;
+    pub const SOC_CRYPTO_DPA_PROTECTION_SUPPORTED: bool = true;
+    pub const SOC_DAC_SUPPORTED: bool = false;
+    pub const SOC_DEDICATED_GPIO_SUPPORTED: bool = true;
+    pub const SOC_DEEP_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_DIG_SIGN_SUPPORTED: bool = false;
+    pub const SOC_ECC_EXTENDED_MODES_SUPPORTED: bool = true;
+    pub const SOC_ECC_SUPPORTED: bool = true;
+    pub const SOC_ECC_SUPPORT_POINT_VERIFY_QUIRK: bool = false;
+    pub const SOC_ECDSA_SUPPORTED: bool = true;
+    pub const SOC_ECDSA_SUPPORT_CURVE_P384: bool = false;
+    pub const SOC_ECDSA_SUPPORT_DETERMINISTIC_MODE: bool = true;
+    pub const SOC_ECDSA_SUPPORT_EXPORT_PUBKEY: bool = true;
+    pub const SOC_ECDSA_SUPPORT_HW_DETERMINISTIC_LOOP: bool = false;
+    pub const SOC_EFUSE_SUPPORTED: bool = true;
+    pub const SOC_EMAC_SUPPORTED: bool = false;
+    pub const SOC_ETM_SUPPORTED: bool = true;
+    pub const SOC_ETM_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_SUPPORT_PSEUDO_ROUND: bool = true;
+    pub const SOC_FLASH_ENC_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORTED: bool = true;
+    pub const SOC_GDMA_SUPPORT_ETM: bool = true;
+    pub const SOC_GDMA_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_GPIO_FILTER_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_GPIO_SUPPORT_DEEPSLEEP_WAKEUP: bool = true;
+    pub const SOC_GPIO_SUPPORT_ETM: bool = true;
+    pub const SOC_GPIO_SUPPORT_FORCE_HOLD: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_HOLD_SINGLE_IO_IN_DSLP: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_GLITCH_FILTER: bool = true;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_CTRL_BY_EFUSE: bool = false;
+    pub const SOC_GPIO_SUPPORT_PIN_HYS_FILTER: bool = true;
+    pub const SOC_GPIO_SUPPORT_RTC_INDEPENDENT: bool = true;
+    pub const SOC_GPSPI_SUPPORTED: bool = true;
+    pub const SOC_GPTIMER_SUPPORTED: bool = true;
+    pub const SOC_HMAC_SUPPORTED: bool = false;
+    pub const SOC_HUK_SUPPORTED: bool = false;
+    pub const SOC_I2C_SLAVE_SUPPORT_BROADCAST: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_I2CRAM_ACCESS: bool = true;
+    pub const SOC_I2C_SLAVE_SUPPORT_SLAVE_UNMATCH: bool = true;
+    pub const SOC_I2C_SUPPORTED: bool = true;
+    pub const SOC_I2C_SUPPORT_10BIT_ADDR: bool = true;
+    pub const SOC_I2C_SUPPORT_APB: bool = false;
+    pub const SOC_I2C_SUPPORT_HW_CLR_BUS: bool = false;
+    pub const SOC_I2C_SUPPORT_HW_FSM_RST: bool = true;
+    pub const SOC_I2C_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_I2C_SUPPORT_RTC: bool = true;
+    pub const SOC_I2C_SUPPORT_SLAVE: bool = true;
+    pub const SOC_I2C_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_I2C_SUPPORT_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORTED: bool = true;
+    pub const SOC_I2S_SUPPORTS_ADC: bool = false;
+    pub const SOC_I2S_SUPPORTS_ADC_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_APLL: bool = false;
+    pub const SOC_I2S_SUPPORTS_DAC: bool = false;
+    pub const SOC_I2S_SUPPORTS_DMA_EQUAL: bool = false;
+    pub const SOC_I2S_SUPPORTS_ETM: bool = true;
+    pub const SOC_I2S_SUPPORTS_LCD_CAMERA: bool = false;
+    pub const SOC_I2S_SUPPORTS_PCM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PCM2PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM2PCM: bool = false;
+    pub const SOC_I2S_SUPPORTS_PDM_RX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PDM_TX: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F120M: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F160M: bool = true;
+    pub const SOC_I2S_SUPPORTS_PLL_F240M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F64M: bool = false;
+    pub const SOC_I2S_SUPPORTS_PLL_F96M: bool = false;
+    pub const SOC_I2S_SUPPORTS_TDM: bool = true;
+    pub const SOC_I2S_SUPPORTS_TX_SYNC_CNT: bool = true;
+    pub const SOC_I2S_SUPPORTS_XTAL: bool = true;
+    pub const SOC_I2S_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_IEEE802154_SUPPORTED: bool = false;
+    pub const SOC_INT_CLIC_SUPPORTED: bool = true;
+    pub const SOC_INT_HW_NESTED_SUPPORTED: bool = true;
+    pub const SOC_INT_PLIC_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORTED: bool = false;
+    pub const SOC_KEY_MANAGER_SUPPORT_KEY_DEPLOYMENT: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_CAM_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LCDCAM_I80_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_RGB_LCD_SUPPORTED: bool = false;
+    pub const SOC_LCDCAM_SUPPORTED: bool = false;
+    pub const SOC_LCD_I80_SUPPORTED: bool = false;
+    pub const SOC_LCD_RGB_SUPPORTED: bool = false;
+    pub const SOC_LCD_SUPPORT_RGB_YUV_CONV: bool = false;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORTED: bool = true;
+    pub const SOC_LEDC_SUPPORT_APB_CLOCK: bool = false;
+    pub const SOC_LEDC_SUPPORT_FADE_STOP: bool = true;
+    pub const SOC_LEDC_SUPPORT_HS_MODE: bool = false;
+    pub const SOC_LEDC_SUPPORT_PLL_DIV_CLOCK: bool = true;
+    pub const SOC_LEDC_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_LEDC_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_LEDC_SUPPORT_XTAL_CLOCK: bool = true;
+    pub const SOC_LIGHT_SLEEP_SUPPORTED: bool = true;
+    pub const SOC_LP_AON_SUPPORTED: bool = true;
+    pub const SOC_LP_CORE_SUPPORTED: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_ETM: bool = false;
+    pub const SOC_LP_CORE_SUPPORT_STORE_LOAD_EXCEPTIONS: bool = false;
+    pub const SOC_LP_I2C_SUPPORTED: bool = false;
+    pub const SOC_LP_PERIPHERALS_SUPPORTED: bool = false;
+    pub const SOC_LP_TIMER_SUPPORTED: bool = true;
+    pub const SOC_MCPWM_SUPPORTED: bool = false;
+    pub const SOC_MCPWM_SUPPORT_ETM: bool = false;
+    pub const SOC_MCPWM_SUPPORT_EVENT_COMPARATOR: bool = false;
+    pub const SOC_MCPWM_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_MEMPROT_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_120M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_15M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_16M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_20M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_26M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_30M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_32M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_40M_SUPPORTED: bool = true;
+    pub const SOC_MEMSPI_SRC_FREQ_60M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_64M_SUPPORTED: bool = false;
+    pub const SOC_MEMSPI_SRC_FREQ_80M_SUPPORTED: bool = true;
+    pub const SOC_MMU_PAGE_SIZE_8KB_SUPPORTED: bool = true;
+    pub const SOC_MODEM_CLOCK_SUPPORTED: bool = true;
+    pub const SOC_MPI_SUPPORTED: bool = false;
+    pub const SOC_MPU_CONFIGURABLE_REGIONS_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_RO_SUPPORTED: bool = false;
+    pub const SOC_MPU_REGION_WO_SUPPORTED: bool = false;
+    pub const SOC_MPU_SUPPORTED: bool = false;
+    pub const SOC_MWDT_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_MWDT_SUPPORT_XTAL: bool = false;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_RX_CLK_SUPPORT_OUTPUT: bool = false;
+    pub const SOC_PARLIO_SUPPORTED: bool = false;
+    pub const SOC_PARLIO_SUPPORT_I80_LCD: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_PARLIO_SUPPORT_SPI_LCD: bool = false;
+    pub const SOC_PARLIO_TX_CLK_SUPPORT_GATING: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_EOF_FROM_DMA: bool = false;
+    pub const SOC_PARLIO_TX_SUPPORT_LOOP_TRANSMISSION: bool = false;
+    pub const SOC_PAU_SUPPORTED: bool = true;
+    pub const SOC_PCNT_SUPPORTED: bool = false;
+    pub const SOC_PCNT_SUPPORT_CLEAR_SIGNAL: bool = false;
+    pub const SOC_PCNT_SUPPORT_RUNTIME_THRES_UPDATE: bool = false;
+    pub const SOC_PCNT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_PCNT_SUPPORT_STEP_NOTIFY: bool = false;
+    pub const SOC_PHY_SUPPORTED: bool = true;
+    pub const SOC_PMU_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORTED: bool = true;
+    pub const SOC_PM_SUPPORT_BEACON_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_BT_PD: bool = false;
+    pub const SOC_PM_SUPPORT_BT_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_CPU_PD: bool = true;
+    pub const SOC_PM_SUPPORT_DEEPSLEEP_CHECK_STUB_ONLY: bool = false;
+    pub const SOC_PM_SUPPORT_EXT0_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_EXT1_WAKEUP_MODE_PER_PIN: bool = true;
+    pub const SOC_PM_SUPPORT_EXT_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_HP_AON_PD: bool = true;
+    pub const SOC_PM_SUPPORT_MAC_BB_PD: bool = true;
+    pub const SOC_PM_SUPPORT_MODEM_PD: bool = true;
+    pub const SOC_PM_SUPPORT_PMU_CLK_ICG: bool = true;
+    pub const SOC_PM_SUPPORT_PMU_MODEM_STATE: bool = true;
+    pub const SOC_PM_SUPPORT_RC32K_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RC_FAST_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_FAST_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_RTC_PERIPH_PD: bool = true;
+    pub const SOC_PM_SUPPORT_RTC_SLOW_MEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TAGMEM_PD: bool = false;
+    pub const SOC_PM_SUPPORT_TOP_PD: bool = true;
+    pub const SOC_PM_SUPPORT_TOUCH_SENSOR_WAKEUP: bool = false;
+    pub const SOC_PM_SUPPORT_VDDSDIO_PD: bool = true;
+    pub const SOC_PM_SUPPORT_WIFI_PD: bool = false;
+    pub const SOC_PM_SUPPORT_WIFI_WAKEUP: bool = true;
+    pub const SOC_PM_SUPPORT_XTAL32K_PD: bool = true;
+    pub const SOC_RECOVERY_BOOTLOADER_SUPPORTED: bool = true;
+    pub const SOC_REG_I2C_SUPPORTED: bool = true;
+    pub const SOC_RISCV_COPROC_SUPPORTED: bool = false;
+    pub const SOC_RMT_SUPPORTED: bool = false;
+    pub const SOC_RMT_SUPPORT_APB: bool = false;
+    pub const SOC_RMT_SUPPORT_DMA: bool = false;
+    pub const SOC_RMT_SUPPORT_RC_FAST: bool = false;
+    pub const SOC_RMT_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_RMT_SUPPORT_RX_DEMODULATION: bool = false;
+    pub const SOC_RMT_SUPPORT_RX_PINGPONG: bool = false;
+    pub const SOC_RMT_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_ASYNC_STOP: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_CARRIER_DATA_ONLY: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_AUTO_STOP: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_LOOP_COUNT: bool = false;
+    pub const SOC_RMT_SUPPORT_TX_SYNCHRO: bool = false;
+    pub const SOC_RMT_SUPPORT_XTAL: bool = false;
+    pub const SOC_RNG_SUPPORTED: bool = true;
+    pub const SOC_RSA_SUPPORTED: bool = false;
+    pub const SOC_RTCIO_EDGE_WAKE_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_HOLD_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_INPUT_OUTPUT_SUPPORTED: bool = true;
+    pub const SOC_RTCIO_WAKE_SUPPORTED: bool = true;
+    pub const SOC_RTC_FAST_MEM_SUPPORTED: bool = false;
+    pub const SOC_RTC_MEM_SUPPORTED: bool = false;
+    pub const SOC_RTC_MEM_SUPPORT_SPEED_MODE_SWITCH: bool = false;
+    pub const SOC_RTC_SLOW_CLK_SUPPORT_RC_FAST_D256: bool = false;
+    pub const SOC_RTC_SLOW_MEM_SUPPORTED: bool = false;
+    pub const SOC_SDIO_SLAVE_SUPPORTED: bool = true;
+    pub const SOC_SDMMC_HOST_SUPPORTED: bool = false;
+    pub const SOC_SDMMC_SUPPORT_XTAL_CLOCK: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F48M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_PLL_F80M: bool = false;
+    pub const SOC_SDM_CLK_SUPPORT_XTAL: bool = false;
+    pub const SOC_SDM_SUPPORTED: bool = false;
+    pub const SOC_SECURE_BOOT_SUPPORTED: bool = true;
+    pub const SOC_SHARED_IDCACHE_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORTED: bool = true;
+    pub const SOC_SHA_SUPPORT_DMA: bool = true;
+    pub const SOC_SHA_SUPPORT_PARALLEL_ENG: bool = false;
+    pub const SOC_SHA_SUPPORT_RESUME: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA1: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA224: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA256: bool = true;
+    pub const SOC_SHA_SUPPORT_SHA384: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_224: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_256: bool = false;
+    pub const SOC_SHA_SUPPORT_SHA512_T: bool = false;
+    pub const SOC_SIMD_INSTRUCTION_SUPPORTED: bool = false;
+    pub const SOC_SPIRAM_SUPPORTED: bool = true;
+    pub const SOC_SPIRAM_XIP_SUPPORTED: bool = true;
+    pub const SOC_SPI_AS_CS_SUPPORTED: bool = false;
+    pub const SOC_SPI_FLASH_SUPPORTED: bool = true;
+    pub const SOC_SPI_HD_BOTH_INOUT_SUPPORTED: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_RESUME: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_AUTO_WAIT_IDLE: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CACHE_32BIT_ADDR_MAP: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_CHECK_SUS: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_CONFIG_GPIO_BY_EFUSE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_FLASH_OPI_MODE: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_IDLE_INTR: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_SW_SUSPEND: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_TIMING_TUNING: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_TSUS_TRES_SEPERATE_CTR: bool = true;
+    pub const SOC_SPI_MEM_SUPPORT_WB_MODE_INDEPENDENT_CONTROL: bool = false;
+    pub const SOC_SPI_MEM_SUPPORT_WRAP: bool = true;
+    pub const SOC_SPI_PERIPH_SUPPORT_CONTROL_DUMMY_OUT: bool = false;
+    pub const SOC_SPI_SCT_SUPPORTED: bool = false;
+    pub const SOC_SPI_SLAVE_SUPPORT_SEG_TRANS: bool = false;
+    pub const SOC_SPI_SUPPORT_CD_SIG: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_APB: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F160M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F40M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F48M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_PLL_F80M: bool = false;
+    pub const SOC_SPI_SUPPORT_CLK_RC_FAST: bool = true;
+    pub const SOC_SPI_SUPPORT_CLK_XTAL: bool = true;
+    pub const SOC_SPI_SUPPORT_CONTINUOUS_TRANS: bool = false;
+    pub const SOC_SPI_SUPPORT_DDRCLK: bool = false;
+    pub const SOC_SPI_SUPPORT_OCT: bool = false;
+    pub const SOC_SPI_SUPPORT_SLAVE_HD_VER2: bool = true;
+    pub const SOC_SPI_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_SUPPORTS_SECURE_DL_MODE: bool = true;
+    pub const SOC_SUPPORT_COEXISTENCE: bool = false;
+    pub const SOC_SUPPORT_SECURE_BOOT_REVOKE_KEY: bool = true;
+    pub const SOC_SYSTIMER_SUPPORTED: bool = true;
+    pub const SOC_SYSTIMER_SUPPORT_ETM: bool = true;
+    pub const SOC_SYSTIMER_SUPPORT_RC_FAST: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_INTR_SUPPORT: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_ETM: bool = false;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_FAST_RC: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_TEMPERATURE_SENSOR_SUPPORT_XTAL: bool = true;
+    pub const SOC_TEMP_SENSOR_SUPPORTED: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_APB: bool = false;
+    pub const SOC_TIMER_GROUP_SUPPORT_RC_FAST: bool = true;
+    pub const SOC_TIMER_GROUP_SUPPORT_XTAL: bool = true;
+    pub const SOC_TIMER_SUPPORT_ETM: bool = true;
+    pub const SOC_TIMER_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_TOUCH_PROXIMITY_MEAS_DONE_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SENSOR_SUPPORTED: bool = false;
+    pub const SOC_TOUCH_SUPPORT_BENCHMARK: bool = false;
+    pub const SOC_TOUCH_SUPPORT_DENOISE_CHAN: bool = false;
+    pub const SOC_TOUCH_SUPPORT_PROX_SENSING: bool = false;
+    pub const SOC_TOUCH_SUPPORT_SLEEP_WAKEUP: bool = false;
+    pub const SOC_TOUCH_SUPPORT_WATERPROOF: bool = false;
+    pub const SOC_TWAI_CLK_SUPPORT_APB: bool = false;
+    pub const SOC_TWAI_CLK_SUPPORT_XTAL: bool = false;
+    pub const SOC_TWAI_SUPPORTED: bool = false;
+    pub const SOC_TWAI_SUPPORTS_RX_STATUS: bool = false;
+    pub const SOC_TWAI_SUPPORT_FD: bool = false;
+    pub const SOC_TWAI_SUPPORT_MULTI_ADDRESS_LAYOUT: bool = false;
+    pub const SOC_TWAI_SUPPORT_SLEEP_RETENTION: bool = false;
+    pub const SOC_TWAI_SUPPORT_TIMESTAMP: bool = false;
+    pub const SOC_UART_SUPPORTED: bool = true;
+    pub const SOC_UART_SUPPORT_APB_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_FSM_TX_WAIT_SEND: bool = true;
+    pub const SOC_UART_SUPPORT_PLL_F40M_CLK: bool = false;
+    pub const SOC_UART_SUPPORT_PLL_F80M_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_REF_TICK: bool = false;
+    pub const SOC_UART_SUPPORT_RTC_CLK: bool = true;
+    pub const SOC_UART_SUPPORT_SLEEP_RETENTION: bool = true;
+    pub const SOC_UART_SUPPORT_WAKEUP_INT: bool = true;
+    pub const SOC_UART_SUPPORT_XTAL_CLK: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_ACTIVE_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_CHAR_SEQ_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_FIFO_THRESH_MODE: bool = true;
+    pub const SOC_UART_WAKEUP_SUPPORT_START_BIT_MODE: bool = true;
+    pub const SOC_UHCI_SUPPORTED: bool = false;
+    pub const SOC_ULP_FSM_SUPPORTED: bool = false;
+    pub const SOC_ULP_LP_UART_SUPPORTED: bool = false;
+    pub const SOC_ULP_SUPPORTED: bool = false;
+    pub const SOC_USB_OTG_SUPPORTED: bool = false;
+    pub const SOC_USB_SERIAL_JTAG_SUPPORTED: bool = true;
+    pub const SOC_VBAT_SUPPORTED: bool = false;
+    pub const SOC_WDT_SUPPORTED: bool = true;
+    pub const SOC_WIFI_CSI_SUPPORT: bool = true;
+    pub const SOC_WIFI_FTM_SUPPORT: bool = true;
+    pub const SOC_WIFI_GCMP_SUPPORT: bool = true;
+    pub const SOC_WIFI_HE_SUPPORT: bool = true;
+    pub const SOC_WIFI_MESH_SUPPORT: bool = true;
+    pub const SOC_WIFI_NAN_SUPPORT: bool = true;
+    pub const SOC_WIFI_SUPPORTED: bool = true;
+    pub const SOC_WIFI_SUPPORT_5G: bool = false;
+    pub const SOC_WIFI_SUPPORT_VARIABLE_BEACON_WINDOW: bool = false;
+    pub const SOC_WIFI_WAPI_SUPPORT: bool = true;
+    pub const SOC_XTAL_SUPPORT_26M: bool = false;
+    pub const SOC_XTAL_SUPPORT_32M: bool = false;
+    pub const SOC_XTAL_SUPPORT_40M: bool = true;
+    pub const SOC_XTAL_SUPPORT_48M: bool = false;
+    pub const SOC_XTAL_SUPPORT_AUTO_DETECT: bool = false;
+    pub const SOC_XT_WDT_SUPPORTED: bool = false;
+    pub const SOC_ADC_ATTEN_NUM: u32 = 4;
+    pub const SOC_ADC_DIGI_CONTROLLER_NUM: u32 = 1;
+    pub const SOC_ADC_DIGI_DATA_BYTES_PER_CONV: u32 = 4;
+    pub const SOC_ADC_DIGI_IIR_FILTER_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_DIGI_MONITOR_NUM: u32 = 2;
+    pub const SOC_ADC_DIGI_RESULT_BYTES: u32 = 4;
+    pub const SOC_ADC_MAX_CHANNEL_NUM: u32 = 4;
+    pub const SOC_ADC_PATT_LEN_MAX: u32 = 8;
+    pub const SOC_ADC_PERIPH_NUM: u32 = 1;
+    pub const SOC_ADC_RTC_MAX_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_RTC_MIN_BITWIDTH: u32 = 12;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_HIGH: u32 = 83333;
+    pub const SOC_ADC_SAMPLE_FREQ_THRES_LOW: u32 = 611;
+    pub const SOC_ADC_SHARED_POWER: u32 = 1;
+    pub const SOC_ADC_TEMPERATURE_SHARE_INTR: u32 = 1;
+    pub const SOC_AHB_GDMA_VERSION: u32 = 2;
+    pub const SOC_ANA_CMPR_CAN_DISTINGUISH_EDGE: u32 = 1;
+    pub const SOC_ANA_CMPR_NUM: u32 = 1;
+    pub const SOC_APB_BACKUP_DMA: u32 = 0;
+    pub const SOC_BLE_MULTI_CONN_OPTIMIZATION: u32 = 1;
+    pub const SOC_CAPS_ECO_VER: u32 = 100;
+    pub const SOC_CLK_ANA_I2C_MST_HAS_ROOT_GATE: u32 = 1;
+    pub const SOC_COEX_HW_PTI: u32 = 1;
+    pub const SOC_CPU_BREAKPOINTS_NUM: u32 = 4;
+    pub const SOC_CPU_CORES_NUM: u32 = 1;
+    pub const SOC_CPU_HAS_FLEXIBLE_INTC: u32 = 1;
+    pub const SOC_CPU_HAS_LOCKUP_RESET: u32 = 1;
+    pub const SOC_CPU_HAS_PMA: u32 = 1;
+    pub const SOC_CPU_IDRAM_SPLIT_USING_PMP: u32 = 1;
+    pub const SOC_CPU_INTR_NUM: u32 = 32;
+    pub const SOC_CPU_PMP_REGION_GRANULARITY: u32 = 128;
+    pub const SOC_CPU_WATCHPOINTS_NUM: u32 = 4;
+    pub const SOC_CPU_WATCHPOINT_MAX_REGION_SIZE: u32 = 0x80000000;
+    pub const SOC_DEDIC_GPIO_IN_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_GPIO_OUT_CHANNELS_NUM: u32 = 8;
+    pub const SOC_DEDIC_PERIPH_ALWAYS_ENABLE: u32 = 1;
+    pub const SOC_DMA_CAN_ACCESS_FLASH: u32 = 1;
+    pub const SOC_ECC_CONSTANT_TIME_POINT_MUL: u32 = 1;
+    pub const SOC_EFUSE_DIS_DIRECT_BOOT: u32 = 1;
+    pub const SOC_EFUSE_DIS_DOWNLOAD_ICACHE: u32 = 0;
+    pub const SOC_EFUSE_DIS_ICACHE: u32 = 1;
+    pub const SOC_EFUSE_DIS_PAD_JTAG: u32 = 1;
+    pub const SOC_EFUSE_DIS_USB_JTAG: u32 = 1;
+    pub const SOC_EFUSE_ECDSA_KEY: u32 = 1;
+    pub const SOC_EFUSE_KEY_PURPOSE_FIELD: u32 = 1;
+    pub const SOC_EFUSE_REVOKE_BOOT_KEY_DIGESTS: u32 = 1;
+    pub const SOC_EFUSE_SECURE_BOOT_KEY_DIGESTS: u32 = 3;
+    pub const SOC_EFUSE_SOFT_DIS_JTAG: u32 = 0;
+    pub const SOC_ESP_NIMBLE_CONTROLLER: u32 = 1;
+    pub const SOC_ETM_CHANNELS_PER_GROUP: u32 = 50;
+    pub const SOC_ETM_GROUPS: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_ADVANCE: u32 = 1;
+    pub const SOC_EXTERNAL_COEX_LEADER_TX_LINE: u32 = 0;
+    pub const SOC_EXT_MEM_CACHE_TAG_IN_CPU_DOMAIN: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTED_XTS_AES_BLOCK_MAX: u32 = 64;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES: u32 = 1;
+    pub const SOC_FLASH_ENCRYPTION_XTS_AES_128: u32 = 1;
+    pub const SOC_GDMA_NUM_GROUPS_MAX: u32 = 1;
+    pub const SOC_GDMA_PAIRS_PER_GROUP_MAX: u32 = 2;
+    pub const SOC_GPIO_CLOCKOUT_CHANNEL_NUM: u32 = 3;
+    pub const SOC_GPIO_DEEP_SLEEP_WAKE_SUPPORTED_PIN_CNT: u32 = 7;
+    pub const SOC_GPIO_DEEP_SLEEP_WAKE_VALID_GPIO_MASK: u32 = 0x7f;
+    pub const SOC_GPIO_IN_RANGE_MAX: u32 = 29;
+    pub const SOC_GPIO_OUT_RANGE_MAX: u32 = 29;
+    pub const SOC_GPIO_PIN_COUNT: u32 = 30;
+    pub const SOC_GPIO_PORT: u32 = 1;
+    pub const SOC_GPIO_VALID_DIGITAL_IO_PAD_MASK: u64 = 0x3fffff80;
+    pub const SOC_GPIO_VALID_GPIO_MASK: u64 = 0x3fffffff;
+    pub const SOC_GPIO_VALID_OUTPUT_GPIO_MASK: u64 = 0x3fffffff;
+    pub const SOC_HP_I2C_NUM: u32 = 1;
+    pub const SOC_I2C_CMD_REG_NUM: u32 = 8;
+    pub const SOC_I2C_FIFO_LEN: u32 = 32;
+    pub const SOC_I2C_NUM: u32 = 1;
+    pub const SOC_I2C_SLAVE_CAN_GET_STRETCH_CAUSE: u32 = 1;
+    pub const SOC_I2S_HW_VERSION_2: u32 = 1;
+    pub const SOC_I2S_NUM: u32 = 1;
+    pub const SOC_I2S_PDM_MAX_RX_LINES: u32 = 1;
+    pub const SOC_I2S_PDM_MAX_TX_LINES: u32 = 2;
+    pub const SOC_I2S_TDM_FULL_DATA_WIDTH: u32 = 1;
+    pub const SOC_LEDC_CHANNEL_NUM: u32 = 6;
+    pub const SOC_LEDC_FADE_PARAMS_BIT_WIDTH: u32 = 10;
+    pub const SOC_LEDC_GAMMA_CURVE_FADE_RANGE_MAX: u32 = 16;
+    pub const SOC_LEDC_TIMER_BIT_WIDTH: u32 = 20;
+    pub const SOC_LEDC_TIMER_NUM: u32 = 4;
+    pub const SOC_LP_IO_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_LP_TIMER_BIT_WIDTH_HI: u32 = 16;
+    pub const SOC_LP_TIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_MEMSPI_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_MEMSPI_TIMING_TUNING_BY_MSPI_DELAY: u32 = 1;
+    pub const SOC_MMU_DI_VADDR_SHARED: u32 = 1;
+    pub const SOC_MMU_LINEAR_ADDRESS_REGION_NUM: u32 = 1;
+    pub const SOC_MMU_PAGE_SIZE_CONFIGURABLE: u32 = 1;
+    pub const SOC_MMU_PERIPH_NUM: u32 = 1;
+    pub const SOC_MODEM_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_MPU_MIN_REGION_SIZE: u32 = 0x20000000;
+    pub const SOC_MPU_REGIONS_MAX_NUM: u32 = 8;
+    pub const SOC_PHY_CALIBRATION_CLOCK_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_PHY_COMBO_MODULE: u32 = 1;
+    pub const SOC_PHY_DIG_REGS_MEM_SIZE: u32 = 84;
+    pub const SOC_PM_CPU_RETENTION_BY_SW: u32 = 1;
+    pub const SOC_PM_MODEM_RETENTION_BY_REGDMA: u32 = 1;
+    pub const SOC_PM_PAU_LINK_NUM: u32 = 4;
+    pub const SOC_PM_PAU_REGDMA_LINK_MULTI_ADDR: u32 = 1;
+    pub const SOC_PM_PAU_REGDMA_LINK_WIFIMAC: u32 = 1;
+    pub const SOC_PM_PAU_REGDMA_UPDATE_CACHE_BEFORE_WAIT_COMPARE: u32 = 1;
+    pub const SOC_PM_PMU_MIN_SLP_SLOW_CLK_CYCLE_FIXED: u32 = 1;
+    pub const SOC_PM_RETENTION_MODULE_NUM: u32 = 32;
+    pub const SOC_PSRAM_DMA_CAPABLE: u32 = 1;
+    pub const SOC_RCC_IS_INDEPENDENT: u32 = 1;
+    pub const SOC_RTCIO_PIN_COUNT: u32 = 7;
+    pub const SOC_SECURE_BOOT_V2_ECC: u32 = 1;
+    pub const SOC_SECURE_BOOT_V2_RSA: u32 = 0;
+    pub const SOC_SHA_DMA_MAX_BUFFER_SIZE: u32 = 3968;
+    pub const SOC_SHA_GDMA: u32 = 1;
+    pub const SOC_SPI_MAXIMUM_BUFFER_SIZE: u32 = 64;
+    pub const SOC_SPI_MAX_CS_NUM: u32 = 6;
+    pub const SOC_SPI_MAX_PRE_DIVIDER: u32 = 16;
+    pub const SOC_SPI_PERIPH_NUM: u32 = 2;
+    pub const SOC_SYSTIMER_ALARM_MISS_COMPENSATE: u32 = 1;
+    pub const SOC_SYSTIMER_ALARM_NUM: u32 = 3;
+    pub const SOC_SYSTIMER_BIT_WIDTH_HI: u32 = 20;
+    pub const SOC_SYSTIMER_BIT_WIDTH_LO: u32 = 32;
+    pub const SOC_SYSTIMER_COUNTER_NUM: u32 = 2;
+    pub const SOC_SYSTIMER_FIXED_DIVIDER: u32 = 1;
+    pub const SOC_SYSTIMER_INT_LEVEL: u32 = 1;
+    pub const SOC_TEMPERATURE_SENSOR_UNDER_PD_TOP_DOMAIN: u32 = 1;
+    pub const SOC_TIMER_GROUPS: u32 = 2;
+    pub const SOC_TIMER_GROUP_COUNTER_BIT_WIDTH: u32 = 54;
+    pub const SOC_TIMER_GROUP_TIMERS_PER_GROUP: u32 = 1;
+    pub const SOC_TIMER_GROUP_TOTAL_TIMERS: u32 = 2;
+    pub const SOC_UART_BITRATE_MAX: u32 = 5000000;
+    pub const SOC_UART_FIFO_LEN: u32 = 128;
+    pub const SOC_UART_HP_NUM: u32 = 3;
+    pub const SOC_UART_NUM: u32 = 3;
+    pub const SOC_UART_WAKEUP_CHARS_SEQ_MAX_LEN: u32 = 5;
+    pub const SOC_WIFI_HW_TSF: u32 = 1;
+    pub const SOC_WIFI_LIGHT_SLEEP_CLK_WIDTH: u32 = 12;
+    pub const SOC_WIFI_MAC_VERSION_NUM: u32 = 3;
+    pub const SOC_XTAL_CLOCK_PATH_DEPENDS_ON_TOP_DOMAIN: u32 = 1;
+}
@@ -0,0 +1,2297 @@
+//! Interrupt matrix sources of ESP-IDF v5.5.1, generated by `cargo xtask interrupts`
+//!
+//! `InterruptSource` of a chip module is the `intr_source` argument of
+//! `wifi_osi_funcs_t::_set_intr` and friends.
+
+pub const IDF_VERSION: &str = "ESP-IDF v5.5.1";
+
+pub mod esp32 {
+    /// The `periph_interrupt_t` of `include/esp32/soc/interrupts.h`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    pub enum InterruptSource {
+        /// `ETS_WIFI_MAC_INTR_SOURCE`
+        WifiMac = 0,
+        /// `ETS_WIFI_MAC_NMI_SOURCE`
+        WifiMacNmi = 1,
+        /// `ETS_WIFI_BB_INTR_SOURCE`
+        WifiBb = 2,
+        /// `ETS_BT_MAC_INTR_SOURCE`
+        BtMac = 3,
+        /// `ETS_BT_BB_INTR_SOURCE`
+        BtBb = 4,
+        /// `ETS_BT_BB_NMI_SOURCE`
+        BtBbNmi = 5,
+        /// `ETS_RWBT_INTR_SOURCE`
+        Rwbt = 6,
+        /// `ETS_RWBLE_INTR_SOURCE`
+        Rwble = 7,
+        /// `ETS_RWBT_NMI_SOURCE`
+        RwbtNmi = 8,
+        /// `ETS_RWBLE_NMI_SOURCE`
+        RwbleNmi = 9,
+        /// `ETS_SLC0_INTR_SOURCE`
+        Slc0 = 10,
+        /// `ETS_SLC1_INTR_SOURCE`
+        Slc1 = 11,
+        /// `ETS_UHCI0_INTR_SOURCE`
+        Uhci0 = 12,
+        /// `ETS_UHCI1_INTR_SOURCE`
+        Uhci1 = 13,
+        /// `ETS_TG0_T0_LEVEL_INTR_SOURCE`
+        Tg0T0Level = 14,
+        /// `ETS_TG0_T1_LEVEL_INTR_SOURCE`
+        Tg0T1Level = 15,
+        /// `ETS_TG0_WDT_LEVEL_INTR_SOURCE`
+        Tg0WdtLevel = 16,
+        /// `ETS_TG0_LACT_LEVEL_INTR_SOURCE`
+        Tg0LactLevel = 17,
+        /// `ETS_TG1_T0_LEVEL_INTR_SOURCE`
+        Tg1T0Level = 18,
+        /// `ETS_TG1_T1_LEVEL_INTR_SOURCE`
+        Tg1T1Level = 19,
+        /// `ETS_TG1_WDT_LEVEL_INTR_SOURCE`
+        Tg1WdtLevel = 20,
+        /// `ETS_TG1_LACT_LEVEL_INTR_SOURCE`
+        Tg1LactLevel = 21,
+        /// `ETS_GPIO_INTR_SOURCE`
+        Gpio = 22,
+        /// `ETS_GPIO_NMI_SOURCE`
+        GpioNmi = 23,
+        /// `ETS_FROM_CPU_INTR0_SOURCE`
+        FromCpuIntr0 = 24,
+        /// `ETS_FROM_CPU_INTR1_SOURCE`
+        FromCpuIntr1 = 25,
+        /// `ETS_FROM_CPU_INTR2_SOURCE`
+        FromCpuIntr2 = 26,
+        /// `ETS_FROM_CPU_INTR3_SOURCE`
+        FromCpuIntr3 = 27,
+        /// `ETS_SPI0_INTR_SOURCE`
+        Spi0 = 28,
+        /// `ETS_SPI1_INTR_SOURCE`
+        Spi1 = 29,
+        /// `ETS_SPI2_INTR_SOURCE`
+        Spi2 = 30,
+        /// `ETS_SPI3_INTR_SOURCE`
+        Spi3 = 31,
+        /// `ETS_I2S0_INTR_SOURCE`
+        I2s0 = 32,
+        /// `ETS_I2S1_INTR_SOURCE`
+        I2s1 = 33,
+        /// `ETS_UART0_INTR_SOURCE`
+        Uart0 = 34,
+        /// `ETS_UART1_INTR_SOURCE`
+        Uart1 = 35,
+        /// `ETS_UART2_INTR_SOURCE`
+        Uart2 = 36,
+        /// `ETS_SDIO_HOST_INTR_SOURCE`
+        SdioHost = 37,
+        /// `ETS_ETH_MAC_INTR_SOURCE`
+        EthMac = 38,
+        /// `ETS_PWM0_INTR_SOURCE`
+        Pwm0 = 39,
+        /// `ETS_PWM1_INTR_SOURCE`
+        Pwm1 = 40,
+        /// `ETS_LEDC_INTR_SOURCE`
+        Ledc = 43,
+        /// `ETS_EFUSE_INTR_SOURCE`
+        Efuse = 44,
+        /// `ETS_TWAI_INTR_SOURCE`
+        Twai = 45,
+        /// `ETS_RTC_CORE_INTR_SOURCE`
+        RtcCore = 46,
+        /// `ETS_RMT_INTR_SOURCE`
+        Rmt = 47,
+        /// `ETS_PCNT_INTR_SOURCE`
+        Pcnt = 48,
+        /// `ETS_I2C_EXT0_INTR_SOURCE`
+        I2cExt0 = 49,
+        /// `ETS_I2C_EXT1_INTR_SOURCE`
+        I2cExt1 = 50,
+        /// `ETS_RSA_INTR_SOURCE`
+        Rsa = 51,
+        /// `ETS_SPI1_DMA_INTR_SOURCE`
+        Spi1Dma = 52,
+        /// `ETS_SPI2_DMA_INTR_SOURCE`
+        Spi2Dma = 53,
+        /// `ETS_SPI3_DMA_INTR_SOURCE`
+        Spi3Dma = 54,
+        /// `ETS_WDT_INTR_SOURCE`
+        Wdt = 55,
+        /// `ETS_TIMER1_INTR_SOURCE`
+        Timer1 = 56,
+        /// `ETS_TIMER2_INTR_SOURCE`
+        Timer2 = 57,
+        /// `ETS_TG0_T0_EDGE_INTR_SOURCE`
+        Tg0T0Edge = 58,
+        /// `ETS_TG0_T1_EDGE_INTR_SOURCE`
+        Tg0T1Edge = 59,
+        /// `ETS_TG0_WDT_EDGE_INTR_SOURCE`
+        Tg0WdtEdge = 60,
+        /// `ETS_TG0_LACT_EDGE_INTR_SOURCE`
+        Tg0LactEdge = 61,
+        /// `ETS_TG1_T0_EDGE_INTR_SOURCE`
+        Tg1T0Edge = 62,
+        /// `ETS_TG1_T1_EDGE_INTR_SOURCE`
+        Tg1T1Edge = 63,
+        /// `ETS_TG1_WDT_EDGE_INTR_SOURCE`
+        Tg1WdtEdge = 64,
+        /// `ETS_TG1_LACT_EDGE_INTR_SOURCE`
+        Tg1LactEdge = 65,
+        /// `ETS_MMU_IA_INTR_SOURCE`
+        MmuIa = 66,
+        /// `ETS_MPU_IA_INTR_SOURCE`
+        MpuIa = 67,
+        /// `ETS_CACHE_IA_INTR_SOURCE`
+        CacheIa = 68,
+    }
+
+    impl InterruptSource {
+        /// The sources of the Wi-Fi, BT, IEEE 802.15.4 and coex hardware
+        pub const WIRELESS: &'static [Self] = &[Self::WifiMac, Self::WifiMacNmi, Self::WifiBb, Self::BtMac, Self::BtBb, Self::BtBbNmi, Self::Rwbt, Self::Rwble, Self::RwbtNmi, Self::RwbleNmi];
+        /// One more than the highest source, `ETS_MAX_INTR_SOURCE`
+        pub const COUNT: u32 = 69;
+    }
+
+    impl TryFrom<u32> for InterruptSource {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(Self::WifiMac),
+                1 => Ok(Self::WifiMacNmi),
+                2 => Ok(Self::WifiBb),
+                3 => Ok(Self::BtMac),
+                4 => Ok(Self::BtBb),
+                5 => Ok(Self::BtBbNmi),
+                6 => Ok(Self::Rwbt),
+                7 => Ok(Self::Rwble),
+                8 => Ok(Self::RwbtNmi),
+                9 => Ok(Self::RwbleNmi),
+                10 => Ok(Self::Slc0),
+                11 => Ok(Self::Slc1),
+                12 => Ok(Self::Uhci0),
+                13 => Ok(Self::Uhci1),
+                14 => Ok(Self::Tg0T0Level),
+                15 => Ok(Self::Tg0T1Level),
+                16 => Ok(Self::Tg0WdtLevel),
+                17 => Ok(Self::Tg0LactLevel),
+                18 => Ok(Self::Tg1T0Level),
+                19 => Ok(Self::Tg1T1Level),
+                20 => Ok(Self::Tg1WdtLevel),
+                21 => Ok(Self::Tg1LactLevel),
+                22 => Ok(Self::Gpio),
+                23 => Ok(Self::GpioNmi),
+                24 => Ok(Self::FromCpuIntr0),
+                25 => Ok(Self::FromCpuIntr1),
+                26 => Ok(Self::FromCpuIntr2),
+                27 => Ok(Self::FromCpuIntr3),
+                28 => Ok(Self::Spi0),
+                29 => Ok(Self::Spi1),
+                30 => Ok(Self::Spi2),
+                31 => Ok(Self::Spi3),
+                32 => Ok(Self::I2s0),
+                33 => Ok(Self::I2s1),
+                34 => Ok(Self::Uart0),
+                35 => Ok(Self::Uart1),
+                36 => Ok(Self::Uart2),
+                37 => Ok(Self::SdioHost),
+                38 => Ok(Self::EthMac),
+                39 => Ok(Self::Pwm0),
+                40 => Ok(Self::Pwm1),
+                43 => Ok(Self::Ledc),
+                44 => Ok(Self::Efuse),
+                45 => Ok(Self::Twai),
+                46 => Ok(Self::RtcCore),
+                47 => Ok(Self::Rmt),
+                48 => Ok(Self::Pcnt),
+                49 => Ok(Self::I2cExt0),
+                50 => Ok(Self::I2cExt1),
+                51 => Ok(Self::Rsa),
+                52 => Ok(Self::Spi1Dma),
+                53 => Ok(Self::Spi2Dma),
+                54 => Ok(Self::Spi3Dma),
+                55 => Ok(Self::Wdt),
+                56 => Ok(Self::Timer1),
+                57 => Ok(Self::Timer2),
+                58 => Ok(Self::Tg0T0Edge),
+                59 => Ok(Self::Tg0T1Edge),
+                60 => Ok(Self::Tg0WdtEdge),
+                61 => Ok(Self::Tg0LactEdge),
+                62 => Ok(Self::Tg1T0Edge),
+                63 => Ok(Self::Tg1T1Edge),
+                64 => Ok(Self::Tg1WdtEdge),
+                65 => Ok(Self::Tg1LactEdge),
+                66 => Ok(Self::MmuIa),
+                67 => Ok(Self::MpuIa),
+                68 => Ok(Self::CacheIa),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl From<InterruptSource> for u32 {
+        fn from(source: InterruptSource) -> u32 {
+            source as u32
+        }
+    }
+}
+
+pub mod esp32s2 {
+    /// The `periph_interrupt_t` of `include/esp32s2/soc/interrupts.h`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    pub enum InterruptSource {
+        /// `ETS_WIFI_MAC_INTR_SOURCE`
+        WifiMac = 0,
+        /// `ETS_WIFI_MAC_NMI_SOURCE`
+        WifiMacNmi = 1,
+        /// `ETS_WIFI_PWR_INTR_SOURCE`
+        WifiPwr = 2,
+        /// `ETS_WIFI_BB_INTR_SOURCE`
+        WifiBb = 3,
+        /// `ETS_BT_MAC_INTR_SOURCE`
+        BtMac = 4,
+        /// `ETS_BT_BB_INTR_SOURCE`
+        BtBb = 5,
+        /// `ETS_BT_BB_NMI_SOURCE`
+        BtBbNmi = 6,
+        /// `ETS_RWBT_INTR_SOURCE`
+        Rwbt = 7,
+        /// `ETS_RWBLE_INTR_SOURCE`
+        Rwble = 8,
+        /// `ETS_RWBT_NMI_SOURCE`
+        RwbtNmi = 9,
+        /// `ETS_RWBLE_NMI_SOURCE`
+        RwbleNmi = 10,
+        /// `ETS_SLC0_INTR_SOURCE`
+        Slc0 = 11,
+        /// `ETS_SLC1_INTR_SOURCE`
+        Slc1 = 12,
+        /// `ETS_UHCI0_INTR_SOURCE`
+        Uhci0 = 13,
+        /// `ETS_UHCI1_INTR_SOURCE`
+        Uhci1 = 14,
+        /// `ETS_TG0_T0_LEVEL_INTR_SOURCE`
+        Tg0T0Level = 15,
+        /// `ETS_TG0_T1_LEVEL_INTR_SOURCE`
+        Tg0T1Level = 16,
+        /// `ETS_TG0_WDT_LEVEL_INTR_SOURCE`
+        Tg0WdtLevel = 17,
+        /// `ETS_TG0_LACT_LEVEL_INTR_SOURCE`
+        Tg0LactLevel = 18,
+        /// `ETS_TG1_T0_LEVEL_INTR_SOURCE`
+        Tg1T0Level = 19,
+        /// `ETS_TG1_T1_LEVEL_INTR_SOURCE`
+        Tg1T1Level = 20,
+        /// `ETS_TG1_WDT_LEVEL_INTR_SOURCE`
+        Tg1WdtLevel = 21,
+        /// `ETS_TG1_LACT_LEVEL_INTR_SOURCE`
+        Tg1LactLevel = 22,
+        /// `ETS_GPIO_INTR_SOURCE`
+        Gpio = 23,
+        /// `ETS_GPIO_NMI_SOURCE`
+        GpioNmi = 24,
+        /// `ETS_GPIO_INTR_SOURCE2`
+        GpioIntrSource2 = 25,
+        /// `ETS_GPIO_NMI_SOURCE2`
+        GpioNmiSource2 = 26,
+        /// `ETS_DEDICATED_GPIO_INTR_SOURCE`
+        DedicatedGpio = 27,
+        /// `ETS_FROM_CPU_INTR0_SOURCE`
+        FromCpuIntr0 = 28,
+        /// `ETS_FROM_CPU_INTR1_SOURCE`
+        FromCpuIntr1 = 29,
+        /// `ETS_FROM_CPU_INTR2_SOURCE`
+        FromCpuIntr2 = 30,
+        /// `ETS_FROM_CPU_INTR3_SOURCE`
+        FromCpuIntr3 = 31,
+        /// `ETS_SPI1_INTR_SOURCE`
+        Spi1 = 32,
+        /// `ETS_SPI2_INTR_SOURCE`
+        Spi2 = 33,
+        /// `ETS_SPI3_INTR_SOURCE`
+        Spi3 = 34,
+        /// `ETS_I2S0_INTR_SOURCE`
+        I2s0 = 35,
+        /// `ETS_UART0_INTR_SOURCE`
+        Uart0 = 37,
+        /// `ETS_UART1_INTR_SOURCE`
+        Uart1 = 38,
+        /// `ETS_UART2_INTR_SOURCE`
+        Uart2 = 39,
+        /// `ETS_SDIO_HOST_INTR_SOURCE`
+        SdioHost = 40,
+        /// `ETS_LEDC_INTR_SOURCE`
+        Ledc = 45,
+        /// `ETS_EFUSE_INTR_SOURCE`
+        Efuse = 46,
+        /// `ETS_TWAI_INTR_SOURCE`
+        Twai = 47,
+        /// `ETS_USB_INTR_SOURCE`
+        Usb = 48,
+        /// `ETS_RTC_CORE_INTR_SOURCE`
+        RtcCore = 49,
+        /// `ETS_RMT_INTR_SOURCE`
+        Rmt = 50,
+        /// `ETS_PCNT_INTR_SOURCE`
+        Pcnt = 51,
+        /// `ETS_I2C_EXT0_INTR_SOURCE`
+        I2cExt0 = 52,
+        /// `ETS_I2C_EXT1_INTR_SOURCE`
+        I2cExt1 = 53,
+        /// `ETS_RSA_INTR_SOURCE`
+        Rsa = 54,
+        /// `ETS_SHA_INTR_SOURCE`
+        Sha = 55,
+        /// `ETS_AES_INTR_SOURCE`
+        Aes = 56,
+        /// `ETS_SPI2_DMA_INTR_SOURCE`
+        Spi2Dma = 57,
+        /// `ETS_SPI3_DMA_INTR_SOURCE`
+        Spi3Dma = 58,
+        /// `ETS_WDT_INTR_SOURCE`
+        Wdt = 59,
+        /// `ETS_TIMER1_INTR_SOURCE`
+        Timer1 = 60,
+        /// `ETS_TIMER2_INTR_SOURCE`
+        Timer2 = 61,
+        /// `ETS_TG0_T0_EDGE_INTR_SOURCE`
+        Tg0T0Edge = 62,
+        /// `ETS_TG0_T1_EDGE_INTR_SOURCE`
+        Tg0T1Edge = 63,
+        /// `ETS_TG0_WDT_EDGE_INTR_SOURCE`
+        Tg0WdtEdge = 64,
+        /// `ETS_TG0_LACT_EDGE_INTR_SOURCE`
+        Tg0LactEdge = 65,
+        /// `ETS_TG1_T0_EDGE_INTR_SOURCE`
+        Tg1T0Edge = 66,
+        /// `ETS_TG1_T1_EDGE_INTR_SOURCE`
+        Tg1T1Edge = 67,
+        /// `ETS_TG1_WDT_EDGE_INTR_SOURCE`
+        Tg1WdtEdge = 68,
+        /// `ETS_TG1_LACT_EDGE_INTR_SOURCE`
+        Tg1LactEdge = 69,
+        /// `ETS_CACHE_IA_INTR_SOURCE`
+        CacheIa = 70,
+        /// `ETS_SYSTIMER_TARGET0_INTR_SOURCE`
+        SystimerTarget0 = 71,
+        /// `ETS_SYSTIMER_TARGET1_INTR_SOURCE`
+        SystimerTarget1 = 72,
+        /// `ETS_SYSTIMER_TARGET2_INTR_SOURCE`
+        SystimerTarget2 = 73,
+        /// `ETS_ASSIST_DEBUG_INTR_SOURCE`
+        AssistDebug = 74,
+        /// `ETS_PMS_PRO_IRAM0_ILG_INTR_SOURCE`
+        PmsProIram0Ilg = 75,
+        /// `ETS_PMS_PRO_DRAM0_ILG_INTR_SOURCE`
+        PmsProDram0Ilg = 76,
+        /// `ETS_PMS_PRO_DPORT_ILG_INTR_SOURCE`
+        PmsProDportIlg = 77,
+        /// `ETS_PMS_PRO_AHB_ILG_INTR_SOURCE`
+        PmsProAhbIlg = 78,
+        /// `ETS_PMS_PRO_CACHE_ILG_INTR_SOURCE`
+        PmsProCacheIlg = 79,
+        /// `ETS_PMS_DMA_APB_I_ILG_INTR_SOURCE`
+        PmsDmaApbIIlg = 80,
+        /// `ETS_PMS_DMA_RX_I_ILG_INTR_SOURCE`
+        PmsDmaRxIIlg = 81,
+        /// `ETS_PMS_DMA_TX_I_ILG_INTR_SOURCE`
+        PmsDmaTxIIlg = 82,
+        /// `ETS_SPI_MEM_REJECT_CACHE_INTR_SOURCE`
+        SpiMemRejectCache = 83,
+        /// `ETS_DMA_COPY_INTR_SOURCE`
+        DmaCopy = 84,
+        /// `ETS_SPI4_DMA_INTR_SOURCE`
+        Spi4Dma = 85,
+        /// `ETS_SPI4_INTR_SOURCE`
+        Spi4 = 86,
+        /// `ETS_ICACHE_PRELOAD_INTR_SOURCE`
+        IcachePreload = 87,
+        /// `ETS_DCACHE_PRELOAD_INTR_SOURCE`
+        DcachePreload = 88,
+        /// `ETS_APB_ADC_INTR_SOURCE`
+        ApbAdc = 89,
+        /// `ETS_CRYPTO_DMA_INTR_SOURCE`
+        CryptoDma = 90,
+        /// `ETS_CPU_PERI_ERROR_INTR_SOURCE`
+        CpuPeriError = 91,
+        /// `ETS_APB_PERI_ERROR_INTR_SOURCE`
+        ApbPeriError = 92,
+        /// `ETS_DCACHE_SYNC_INTR_SOURCE`
+        DcacheSync = 93,
+        /// `ETS_ICACHE_SYNC_INTR_SOURCE`
+        IcacheSync = 94,
+    }
+
+    impl InterruptSource {
+        /// `ETS_SYSTIMER_TARGET0_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET0_EDGE: Self = Self::SystimerTarget0;
+        /// `ETS_SYSTIMER_TARGET1_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET1_EDGE: Self = Self::SystimerTarget1;
+        /// `ETS_SYSTIMER_TARGET2_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET2_EDGE: Self = Self::SystimerTarget2;
+        /// The sources of the Wi-Fi, BT, IEEE 802.15.4 and coex hardware
+        pub const WIRELESS: &'static [Self] = &[Self::WifiMac, Self::WifiMacNmi, Self::WifiPwr, Self::WifiBb, Self::BtMac, Self::BtBb, Self::BtBbNmi, Self::Rwbt, Self::Rwble, Self::RwbtNmi, Self::RwbleNmi];
+        /// One more than the highest source, `ETS_MAX_INTR_SOURCE`
+        pub const COUNT: u32 = 95;
+    }
+
+    impl TryFrom<u32> for InterruptSource {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(Self::WifiMac),
+                1 => Ok(Self::WifiMacNmi),
+                2 => Ok(Self::WifiPwr),
+                3 => Ok(Self::WifiBb),
+                4 => Ok(Self::BtMac),
+                5 => Ok(Self::BtBb),
+                6 => Ok(Self::BtBbNmi),
+                7 => Ok(Self::Rwbt),
+                8 => Ok(Self::Rwble),
+                9 => Ok(Self::RwbtNmi),
+                10 => Ok(Self::RwbleNmi),
+                11 => Ok(Self::Slc0),
+                12 => Ok(Self::Slc1),
+                13 => Ok(Self::Uhci0),
+                14 => Ok(Self::Uhci1),
+                15 => Ok(Self::Tg0T0Level),
+                16 => Ok(Self::Tg0T1Level),
+                17 => Ok(Self::Tg0WdtLevel),
+                18 => Ok(Self::Tg0LactLevel),
+                19 => Ok(Self::Tg1T0Level),
+                20 => Ok(Self::Tg1T1Level),
+                21 => Ok(Self::Tg1WdtLevel),
+                22 => Ok(Self::Tg1LactLevel),
+                23 => Ok(Self::Gpio),
+                24 => Ok(Self::GpioNmi),
+                25 => Ok(Self::GpioIntrSource2),
+                26 => Ok(Self::GpioNmiSource2),
+                27 => Ok(Self::DedicatedGpio),
+                28 => Ok(Self::FromCpuIntr0),
+                29 => Ok(Self::FromCpuIntr1),
+                30 => Ok(Self::FromCpuIntr2),
+                31 => Ok(Self::FromCpuIntr3),
+                32 => Ok(Self::Spi1),
+                33 => Ok(Self::Spi2),
+                34 => Ok(Self::Spi3),
+                35 => Ok(Self::I2s0),
+                37 => Ok(Self::Uart0),
+                38 => Ok(Self::Uart1),
+                39 => Ok(Self::Uart2),
+                40 => Ok(Self::SdioHost),
+                45 => Ok(Self::Ledc),
+                46 => Ok(Self::Efuse),
+                47 => Ok(Self::Twai),
+                48 => Ok(Self::Usb),
+                49 => Ok(Self::RtcCore),
+                50 => Ok(Self::Rmt),
+                51 => Ok(Self::Pcnt),
+                52 => Ok(Self::I2cExt0),
+                53 => Ok(Self::I2cExt1),
+                54 => Ok(Self::Rsa),
+                55 => Ok(Self::Sha),
+                56 => Ok(Self::Aes),
+                57 => Ok(Self::Spi2Dma),
+                58 => Ok(Self::Spi3Dma),
+                59 => Ok(Self::Wdt),
+                60 => Ok(Self::Timer1),
+                61 => Ok(Self::Timer2),
+                62 => Ok(Self::Tg0T0Edge),
+                63 => Ok(Self::Tg0T1Edge),
+                64 => Ok(Self::Tg0WdtEdge),
+                65 => Ok(Self::Tg0LactEdge),
+                66 => Ok(Self::Tg1T0Edge),
+                67 => Ok(Self::Tg1T1Edge),
+                68 => Ok(Self::Tg1WdtEdge),
+                69 => Ok(Self::Tg1LactEdge),
+                70 => Ok(Self::CacheIa),
+                71 => Ok(Self::SystimerTarget0),
+                72 => Ok(Self::SystimerTarget1),
+                73 => Ok(Self::SystimerTarget2),
+                74 => Ok(Self::AssistDebug),
+                75 => Ok(Self::PmsProIram0Ilg),
+                76 => Ok(Self::PmsProDram0Ilg),
+                77 => Ok(Self::PmsProDportIlg),
+                78 => Ok(Self::PmsProAhbIlg),
+                79 => Ok(Self::PmsProCacheIlg),
+                80 => Ok(Self::PmsDmaApbIIlg),
+                81 => Ok(Self::PmsDmaRxIIlg),
+                82 => Ok(Self::PmsDmaTxIIlg),
+                83 => Ok(Self::SpiMemRejectCache),
+                84 => Ok(Self::DmaCopy),
+                85 => Ok(Self::Spi4Dma),
+                86 => Ok(Self::Spi4),
+                87 => Ok(Self::IcachePreload),
+                88 => Ok(Self::DcachePreload),
+                89 => Ok(Self::ApbAdc),
+                90 => Ok(Self::CryptoDma),
+                91 => Ok(Self::CpuPeriError),
+                92 => Ok(Self::ApbPeriError),
+                93 => Ok(Self::DcacheSync),
+                94 => Ok(Self::IcacheSync),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl From<InterruptSource> for u32 {
+        fn from(source: InterruptSource) -> u32 {
+            source as u32
+        }
+    }
+}
+
+pub mod esp32s3 {
+    /// The `periph_interrupt_t` of `include/esp32s3/soc/interrupts.h`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    pub enum InterruptSource {
+        /// `ETS_WIFI_MAC_INTR_SOURCE`
+        WifiMac = 0,
+        /// `ETS_WIFI_MAC_NMI_SOURCE`
+        WifiMacNmi = 1,
+        /// `ETS_WIFI_PWR_INTR_SOURCE`
+        WifiPwr = 2,
+        /// `ETS_WIFI_BB_INTR_SOURCE`
+        WifiBb = 3,
+        /// `ETS_BT_MAC_INTR_SOURCE`
+        BtMac = 4,
+        /// `ETS_BT_BB_INTR_SOURCE`
+        BtBb = 5,
+        /// `ETS_BT_BB_NMI_SOURCE`
+        BtBbNmi = 6,
+        /// `ETS_RWBT_INTR_SOURCE`
+        Rwbt = 7,
+        /// `ETS_RWBLE_INTR_SOURCE`
+        Rwble = 8,
+        /// `ETS_RWBT_NMI_SOURCE`
+        RwbtNmi = 9,
+        /// `ETS_RWBLE_NMI_SOURCE`
+        RwbleNmi = 10,
+        /// `ETS_I2C_MASTER_SOURCE`
+        I2cMaster = 11,
+        /// `ETS_SLC0_INTR_SOURCE`
+        Slc0 = 12,
+        /// `ETS_SLC1_INTR_SOURCE`
+        Slc1 = 13,
+        /// `ETS_UHCI0_INTR_SOURCE`
+        Uhci0 = 14,
+        /// `ETS_UHCI1_INTR_SOURCE`
+        Uhci1 = 15,
+        /// `ETS_GPIO_INTR_SOURCE`
+        Gpio = 16,
+        /// `ETS_GPIO_NMI_SOURCE`
+        GpioNmi = 17,
+        /// `ETS_GPIO_INTR_SOURCE2`
+        GpioIntrSource2 = 18,
+        /// `ETS_GPIO_NMI_SOURCE2`
+        GpioNmiSource2 = 19,
+        /// `ETS_SPI1_INTR_SOURCE`
+        Spi1 = 20,
+        /// `ETS_SPI2_INTR_SOURCE`
+        Spi2 = 21,
+        /// `ETS_SPI3_INTR_SOURCE`
+        Spi3 = 22,
+        /// `ETS_LCD_CAM_INTR_SOURCE`
+        LcdCam = 24,
+        /// `ETS_I2S0_INTR_SOURCE`
+        I2s0 = 25,
+        /// `ETS_I2S1_INTR_SOURCE`
+        I2s1 = 26,
+        /// `ETS_UART0_INTR_SOURCE`
+        Uart0 = 27,
+        /// `ETS_UART1_INTR_SOURCE`
+        Uart1 = 28,
+        /// `ETS_UART2_INTR_SOURCE`
+        Uart2 = 29,
+        /// `ETS_SDIO_HOST_INTR_SOURCE`
+        SdioHost = 30,
+        /// `ETS_PWM0_INTR_SOURCE`
+        Pwm0 = 31,
+        /// `ETS_PWM1_INTR_SOURCE`
+        Pwm1 = 32,
+        /// `ETS_LEDC_INTR_SOURCE`
+        Ledc = 35,
+        /// `ETS_EFUSE_INTR_SOURCE`
+        Efuse = 36,
+        /// `ETS_TWAI_INTR_SOURCE`
+        Twai = 37,
+        /// `ETS_USB_INTR_SOURCE`
+        Usb = 38,
+        /// `ETS_RTC_CORE_INTR_SOURCE`
+        RtcCore = 39,
+        /// `ETS_RMT_INTR_SOURCE`
+        Rmt = 40,
+        /// `ETS_PCNT_INTR_SOURCE`
+        Pcnt = 41,
+        /// `ETS_I2C_EXT0_INTR_SOURCE`
+        I2cExt0 = 42,
+        /// `ETS_I2C_EXT1_INTR_SOURCE`
+        I2cExt1 = 43,
+        /// `ETS_SPI2_DMA_INTR_SOURCE`
+        Spi2Dma = 44,
+        /// `ETS_SPI3_DMA_INTR_SOURCE`
+        Spi3Dma = 45,
+        /// `ETS_WDT_INTR_SOURCE`
+        Wdt = 47,
+        /// `ETS_TIMER1_INTR_SOURCE`
+        Timer1 = 48,
+        /// `ETS_TIMER2_INTR_SOURCE`
+        Timer2 = 49,
+        /// `ETS_TG0_T0_LEVEL_INTR_SOURCE`
+        Tg0T0Level = 50,
+        /// `ETS_TG0_T1_LEVEL_INTR_SOURCE`
+        Tg0T1Level = 51,
+        /// `ETS_TG0_WDT_LEVEL_INTR_SOURCE`
+        Tg0WdtLevel = 52,
+        /// `ETS_TG1_T0_LEVEL_INTR_SOURCE`
+        Tg1T0Level = 53,
+        /// `ETS_TG1_T1_LEVEL_INTR_SOURCE`
+        Tg1T1Level = 54,
+        /// `ETS_TG1_WDT_LEVEL_INTR_SOURCE`
+        Tg1WdtLevel = 55,
+        /// `ETS_CACHE_IA_INTR_SOURCE`
+        CacheIa = 56,
+        /// `ETS_SYSTIMER_TARGET0_INTR_SOURCE`
+        SystimerTarget0 = 57,
+        /// `ETS_SYSTIMER_TARGET1_INTR_SOURCE`
+        SystimerTarget1 = 58,
+        /// `ETS_SYSTIMER_TARGET2_INTR_SOURCE`
+        SystimerTarget2 = 59,
+        /// `ETS_SPI_MEM_REJECT_CACHE_INTR_SOURCE`
+        SpiMemRejectCache = 60,
+        /// `ETS_DCACHE_PRELOAD0_INTR_SOURCE`
+        DcachePreload0 = 61,
+        /// `ETS_ICACHE_PRELOAD0_INTR_SOURCE`
+        IcachePreload0 = 62,
+        /// `ETS_DCACHE_SYNC0_INTR_SOURCE`
+        DcacheSync0 = 63,
+        /// `ETS_ICACHE_SYNC0_INTR_SOURCE`
+        IcacheSync0 = 64,
+        /// `ETS_APB_ADC_INTR_SOURCE`
+        ApbAdc = 65,
+        /// `ETS_DMA_IN_CH0_INTR_SOURCE`
+        DmaInCh0 = 66,
+        /// `ETS_DMA_IN_CH1_INTR_SOURCE`
+        DmaInCh1 = 67,
+        /// `ETS_DMA_IN_CH2_INTR_SOURCE`
+        DmaInCh2 = 68,
+        /// `ETS_DMA_IN_CH3_INTR_SOURCE`
+        DmaInCh3 = 69,
+        /// `ETS_DMA_IN_CH4_INTR_SOURCE`
+        DmaInCh4 = 70,
+        /// `ETS_DMA_OUT_CH0_INTR_SOURCE`
+        DmaOutCh0 = 71,
+        /// `ETS_DMA_OUT_CH1_INTR_SOURCE`
+        DmaOutCh1 = 72,
+        /// `ETS_DMA_OUT_CH2_INTR_SOURCE`
+        DmaOutCh2 = 73,
+        /// `ETS_DMA_OUT_CH3_INTR_SOURCE`
+        DmaOutCh3 = 74,
+        /// `ETS_DMA_OUT_CH4_INTR_SOURCE`
+        DmaOutCh4 = 75,
+        /// `ETS_RSA_INTR_SOURCE`
+        Rsa = 76,
+        /// `ETS_AES_INTR_SOURCE`
+        Aes = 77,
+        /// `ETS_SHA_INTR_SOURCE`
+        Sha = 78,
+        /// `ETS_FROM_CPU_INTR0_SOURCE`
+        FromCpuIntr0 = 79,
+        /// `ETS_FROM_CPU_INTR1_SOURCE`
+        FromCpuIntr1 = 80,
+        /// `ETS_FROM_CPU_INTR2_SOURCE`
+        FromCpuIntr2 = 81,
+        /// `ETS_FROM_CPU_INTR3_SOURCE`
+        FromCpuIntr3 = 82,
+        /// `ETS_ASSIST_DEBUG_INTR_SOURCE`
+        AssistDebug = 83,
+        /// `ETS_DMA_APBPERI_PMS_INTR_SOURCE`
+        DmaApbperiPms = 84,
+        /// `ETS_CORE0_IRAM0_PMS_INTR_SOURCE`
+        Core0Iram0Pms = 85,
+        /// `ETS_CORE0_DRAM0_PMS_INTR_SOURCE`
+        Core0Dram0Pms = 86,
+        /// `ETS_CORE0_PIF_PMS_INTR_SOURCE`
+        Core0PifPms = 87,
+        /// `ETS_CORE0_PIF_PMS_SIZE_INTR_SOURCE`
+        Core0PifPmsSize = 88,
+        /// `ETS_CORE1_IRAM0_PMS_INTR_SOURCE`
+        Core1Iram0Pms = 89,
+        /// `ETS_CORE1_DRAM0_PMS_INTR_SOURCE`
+        Core1Dram0Pms = 90,
+        /// `ETS_CORE1_PIF_PMS_INTR_SOURCE`
+        Core1PifPms = 91,
+        /// `ETS_CORE1_PIF_PMS_SIZE_INTR_SOURCE`
+        Core1PifPmsSize = 92,
+        /// `ETS_BACKUP_PMS_VIOLATE_INTR_SOURCE`
+        BackupPmsViolate = 93,
+        /// `ETS_CACHE_CORE0_ACS_INTR_SOURCE`
+        CacheCore0Acs = 94,
+        /// `ETS_CACHE_CORE1_ACS_INTR_SOURCE`
+        CacheCore1Acs = 95,
+        /// `ETS_USB_SERIAL_JTAG_INTR_SOURCE`
+        UsbSerialJtag = 96,
+        /// `ETS_PERI_BACKUP_INTR_SOURCE`
+        PeriBackup = 97,
+        /// `ETS_DMA_EXTMEM_REJECT_SOURCE`
+        DmaExtmemReject = 98,
+    }
+
+    impl InterruptSource {
+        /// `ETS_SYSTIMER_TARGET0_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET0_EDGE: Self = Self::SystimerTarget0;
+        /// `ETS_SYSTIMER_TARGET1_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET1_EDGE: Self = Self::SystimerTarget1;
+        /// `ETS_SYSTIMER_TARGET2_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET2_EDGE: Self = Self::SystimerTarget2;
+        /// The sources of the Wi-Fi, BT, IEEE 802.15.4 and coex hardware
+        pub const WIRELESS: &'static [Self] = &[Self::WifiMac, Self::WifiMacNmi, Self::WifiPwr, Self::WifiBb, Self::BtMac, Self::BtBb, Self::BtBbNmi, Self::Rwbt, Self::Rwble, Self::RwbtNmi, Self::RwbleNmi];
+        /// One more than the highest source, `ETS_MAX_INTR_SOURCE`
+        pub const COUNT: u32 = 99;
+    }
+
+    impl TryFrom<u32> for InterruptSource {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(Self::WifiMac),
+                1 => Ok(Self::WifiMacNmi),
+                2 => Ok(Self::WifiPwr),
+                3 => Ok(Self::WifiBb),
+                4 => Ok(Self::BtMac),
+                5 => Ok(Self::BtBb),
+                6 => Ok(Self::BtBbNmi),
+                7 => Ok(Self::Rwbt),
+                8 => Ok(Self::Rwble),
+                9 => Ok(Self::RwbtNmi),
+                10 => Ok(Self::RwbleNmi),
+                11 => Ok(Self::I2cMaster),
+                12 => Ok(Self::Slc0),
+                13 => Ok(Self::Slc1),
+                14 => Ok(Self::Uhci0),
+                15 => Ok(Self::Uhci1),
+                16 => Ok(Self::Gpio),
+                17 => Ok(Self::GpioNmi),
+                18 => Ok(Self::GpioIntrSource2),
+                19 => Ok(Self::GpioNmiSource2),
+                20 => Ok(Self::Spi1),
+                21 => Ok(Self::Spi2),
+                22 => Ok(Self::Spi3),
+                24 => Ok(Self::LcdCam),
+                25 => Ok(Self::I2s0),
+                26 => Ok(Self::I2s1),
+                27 => Ok(Self::Uart0),
+                28 => Ok(Self::Uart1),
+                29 => Ok(Self::Uart2),
+                30 => Ok(Self::SdioHost),
+                31 => Ok(Self::Pwm0),
+                32 => Ok(Self::Pwm1),
+                35 => Ok(Self::Ledc),
+                36 => Ok(Self::Efuse),
+                37 => Ok(Self::Twai),
+                38 => Ok(Self::Usb),
+                39 => Ok(Self::RtcCore),
+                40 => Ok(Self::Rmt),
+                41 => Ok(Self::Pcnt),
+                42 => Ok(Self::I2cExt0),
+                43 => Ok(Self::I2cExt1),
+                44 => Ok(Self::Spi2Dma),
+                45 => Ok(Self::Spi3Dma),
+                47 => Ok(Self::Wdt),
+                48 => Ok(Self::Timer1),
+                49 => Ok(Self::Timer2),
+                50 => Ok(Self::Tg0T0Level),
+                51 => Ok(Self::Tg0T1Level),
+                52 => Ok(Self::Tg0WdtLevel),
+                53 => Ok(Self::Tg1T0Level),
+                54 => Ok(Self::Tg1T1Level),
+                55 => Ok(Self::Tg1WdtLevel),
+                56 => Ok(Self::CacheIa),
+                57 => Ok(Self::SystimerTarget0),
+                58 => Ok(Self::SystimerTarget1),
+                59 => Ok(Self::SystimerTarget2),
+                60 => Ok(Self::SpiMemRejectCache),
+                61 => Ok(Self::DcachePreload0),
+                62 => Ok(Self::IcachePreload0),
+                63 => Ok(Self::DcacheSync0),
+                64 => Ok(Self::IcacheSync0),
+                65 => Ok(Self::ApbAdc),
+                66 => Ok(Self::DmaInCh0),
+                67 => Ok(Self::DmaInCh1),
+                68 => Ok(Self::DmaInCh2),
+                69 => Ok(Self::DmaInCh3),
+                70 => Ok(Self::DmaInCh4),
+                71 => Ok(Self::DmaOutCh0),
+                72 => Ok(Self::DmaOutCh1),
+                73 => Ok(Self::DmaOutCh2),
+                74 => Ok(Self::DmaOutCh3),
+                75 => Ok(Self::DmaOutCh4),
+                76 => Ok(Self::Rsa),
+                77 => Ok(Self::Aes),
+                78 => Ok(Self::Sha),
+                79 => Ok(Self::FromCpuIntr0),
+                80 => Ok(Self::FromCpuIntr1),
+                81 => Ok(Self::FromCpuIntr2),
+                82 => Ok(Self::FromCpuIntr3),
+                83 => Ok(Self::AssistDebug),
+                84 => Ok(Self::DmaApbperiPms),
+                85 => Ok(Self::Core0Iram0Pms),
+                86 => Ok(Self::Core0Dram0Pms),
+                87 => Ok(Self::Core0PifPms),
+                88 => Ok(Self::Core0PifPmsSize),
+                89 => Ok(Self::Core1Iram0Pms),
+                90 => Ok(Self::Core1Dram0Pms),
+                91 => Ok(Self::Core1PifPms),
+                92 => Ok(Self::Core1PifPmsSize),
+                93 => Ok(Self::BackupPmsViolate),
+                94 => Ok(Self::CacheCore0Acs),
+                95 => Ok(Self::CacheCore1Acs),
+                96 => Ok(Self::UsbSerialJtag),
+                97 => Ok(Self::PeriBackup),
+                98 => Ok(Self::DmaExtmemReject),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl From<InterruptSource> for u32 {
+        fn from(source: InterruptSource) -> u32 {
+            source as u32
+        }
+    }
+}
+
+pub mod esp32c2 {
+    /// The `periph_interrupt_t` of `include/esp32c2/soc/interrupts.h`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    pub enum InterruptSource {
+        /// `ETS_WIFI_MAC_INTR_SOURCE`
+        WifiMac = 0,
+        /// `ETS_WIFI_MAC_NMI_SOURCE`
+        WifiMacNmi = 1,
+        /// `ETS_WIFI_PWR_INTR_SOURCE`
+        WifiPwr = 2,
+        /// `ETS_WIFI_BB_INTR_SOURCE`
+        WifiBb = 3,
+        /// `ETS_BT_MAC_INTR_SOURCE`
+        BtMac = 4,
+        /// `ETS_BT_BB_INTR_SOURCE`
+        BtBb = 5,
+        /// `ETS_BT_BB_NMI_SOURCE`
+        BtBbNmi = 6,
+        /// `ETS_LP_TIMER_SOURCE`
+        LpTimer = 7,
+        /// `ETS_COEX_SOURCE`
+        Coex = 8,
+        /// `ETS_BLE_TIMER_SOURCE`
+        BleTimer = 9,
+        /// `ETS_BLE_SEC_SOURCE`
+        BleSec = 10,
+        /// `ETS_I2C_MASTER_SOURCE`
+        I2cMaster = 11,
+        /// `ETS_APB_CTRL_INTR_SOURCE`
+        ApbCtrl = 12,
+        /// `ETS_GPIO_INTR_SOURCE`
+        Gpio = 13,
+        /// `ETS_GPIO_NMI_SOURCE`
+        GpioNmi = 14,
+        /// `ETS_SPI1_INTR_SOURCE`
+        Spi1 = 15,
+        /// `ETS_SPI2_INTR_SOURCE`
+        Spi2 = 16,
+        /// `ETS_UART0_INTR_SOURCE`
+        Uart0 = 17,
+        /// `ETS_UART1_INTR_SOURCE`
+        Uart1 = 18,
+        /// `ETS_LEDC_INTR_SOURCE`
+        Ledc = 19,
+        /// `ETS_EFUSE_INTR_SOURCE`
+        Efuse = 20,
+        /// `ETS_RTC_CORE_INTR_SOURCE`
+        RtcCore = 21,
+        /// `ETS_I2C_EXT0_INTR_SOURCE`
+        I2cExt0 = 22,
+        /// `ETS_TG0_T0_LEVEL_INTR_SOURCE`
+        Tg0T0Level = 23,
+        /// `ETS_TG0_WDT_LEVEL_INTR_SOURCE`
+        Tg0WdtLevel = 24,
+        /// `ETS_CACHE_IA_INTR_SOURCE`
+        CacheIa = 25,
+        /// `ETS_SYSTIMER_TARGET0_INTR_SOURCE`
+        SystimerTarget0 = 26,
+        /// `ETS_SYSTIMER_TARGET1_INTR_SOURCE`
+        SystimerTarget1 = 27,
+        /// `ETS_SYSTIMER_TARGET2_INTR_SOURCE`
+        SystimerTarget2 = 28,
+        /// `ETS_SPI_MEM_REJECT_CACHE_INTR_SOURCE`
+        SpiMemRejectCache = 29,
+        /// `ETS_ICACHE_PRELOAD0_INTR_SOURCE`
+        IcachePreload0 = 30,
+        /// `ETS_ICACHE_SYNC0_INTR_SOURCE`
+        IcacheSync0 = 31,
+        /// `ETS_APB_ADC_INTR_SOURCE`
+        ApbAdc = 32,
+        /// `ETS_DMA_CH0_INTR_SOURCE`
+        DmaCh0 = 33,
+        /// `ETS_SHA_INTR_SOURCE`
+        Sha = 34,
+        /// `ETS_ECC_INTR_SOURCE`
+        Ecc = 35,
+        /// `ETS_FROM_CPU_INTR0_SOURCE`
+        FromCpuIntr0 = 36,
+        /// `ETS_FROM_CPU_INTR1_SOURCE`
+        FromCpuIntr1 = 37,
+        /// `ETS_FROM_CPU_INTR2_SOURCE`
+        FromCpuIntr2 = 38,
+        /// `ETS_FROM_CPU_INTR3_SOURCE`
+        FromCpuIntr3 = 39,
+        /// `ETS_ASSIST_DEBUG_INTR_SOURCE`
+        AssistDebug = 40,
+        /// `ETS_CORE0_PIF_PMS_SIZE_INTR_SOURCE`
+        Core0PifPmsSize = 41,
+        /// `ETS_CACHE_CORE0_ACS_INTR_SOURCE`
+        CacheCore0Acs = 42,
+    }
+
+    impl InterruptSource {
+        /// `ETS_SYSTIMER_TARGET0_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET0_EDGE: Self = Self::SystimerTarget0;
+        /// `ETS_SYSTIMER_TARGET1_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET1_EDGE: Self = Self::SystimerTarget1;
+        /// `ETS_SYSTIMER_TARGET2_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET2_EDGE: Self = Self::SystimerTarget2;
+        /// The sources of the Wi-Fi, BT, IEEE 802.15.4 and coex hardware
+        pub const WIRELESS: &'static [Self] = &[Self::WifiMac, Self::WifiMacNmi, Self::WifiPwr, Self::WifiBb, Self::BtMac, Self::BtBb, Self::BtBbNmi, Self::Coex, Self::BleTimer, Self::BleSec];
+        /// One more than the highest source, `ETS_MAX_INTR_SOURCE`
+        pub const COUNT: u32 = 43;
+    }
+
+    impl TryFrom<u32> for InterruptSource {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(Self::WifiMac),
+                1 => Ok(Self::WifiMacNmi),
+                2 => Ok(Self::WifiPwr),
+                3 => Ok(Self::WifiBb),
+                4 => Ok(Self::BtMac),
+                5 => Ok(Self::BtBb),
+                6 => Ok(Self::BtBbNmi),
+                7 => Ok(Self::LpTimer),
+                8 => Ok(Self::Coex),
+                9 => Ok(Self::BleTimer),
+                10 => Ok(Self::BleSec),
+                11 => Ok(Self::I2cMaster),
+                12 => Ok(Self::ApbCtrl),
+                13 => Ok(Self::Gpio),
+                14 => Ok(Self::GpioNmi),
+                15 => Ok(Self::Spi1),
+                16 => Ok(Self::Spi2),
+                17 => Ok(Self::Uart0),
+                18 => Ok(Self::Uart1),
+                19 => Ok(Self::Ledc),
+                20 => Ok(Self::Efuse),
+                21 => Ok(Self::RtcCore),
+                22 => Ok(Self::I2cExt0),
+                23 => Ok(Self::Tg0T0Level),
+                24 => Ok(Self::Tg0WdtLevel),
+                25 => Ok(Self::CacheIa),
+                26 => Ok(Self::SystimerTarget0),
+                27 => Ok(Self::SystimerTarget1),
+                28 => Ok(Self::SystimerTarget2),
+                29 => Ok(Self::SpiMemRejectCache),
+                30 => Ok(Self::IcachePreload0),
+                31 => Ok(Self::IcacheSync0),
+                32 => Ok(Self::ApbAdc),
+                33 => Ok(Self::DmaCh0),
+                34 => Ok(Self::Sha),
+                35 => Ok(Self::Ecc),
+                36 => Ok(Self::FromCpuIntr0),
+                37 => Ok(Self::FromCpuIntr1),
+                38 => Ok(Self::FromCpuIntr2),
+                39 => Ok(Self::FromCpuIntr3),
+                40 => Ok(Self::AssistDebug),
+                41 => Ok(Self::Core0PifPmsSize),
+                42 => Ok(Self::CacheCore0Acs),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl From<InterruptSource> for u32 {
+        fn from(source: InterruptSource) -> u32 {
+            source as u32
+        }
+    }
+}
+
+pub mod esp32c3 {
+    /// The `periph_interrupt_t` of `include/esp32c3/soc/interrupts.h`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    pub enum InterruptSource {
+        /// `ETS_WIFI_MAC_INTR_SOURCE`
+        WifiMac = 0,
+        /// `ETS_WIFI_MAC_NMI_SOURCE`
+        WifiMacNmi = 1,
+        /// `ETS_WIFI_PWR_INTR_SOURCE`
+        WifiPwr = 2,
+        /// `ETS_WIFI_BB_INTR_SOURCE`
+        WifiBb = 3,
+        /// `ETS_BT_MAC_INTR_SOURCE`
+        BtMac = 4,
+        /// `ETS_BT_BB_INTR_SOURCE`
+        BtBb = 5,
+        /// `ETS_BT_BB_NMI_SOURCE`
+        BtBbNmi = 6,
+        /// `ETS_RWBT_INTR_SOURCE`
+        Rwbt = 7,
+        /// `ETS_RWBLE_INTR_SOURCE`
+        Rwble = 8,
+        /// `ETS_RWBT_NMI_SOURCE`
+        RwbtNmi = 9,
+        /// `ETS_RWBLE_NMI_SOURCE`
+        RwbleNmi = 10,
+        /// `ETS_I2C_MASTER_SOURCE`
+        I2cMaster = 11,
+        /// `ETS_SLC0_INTR_SOURCE`
+        Slc0 = 12,
+        /// `ETS_SLC1_INTR_SOURCE`
+        Slc1 = 13,
+        /// `ETS_APB_CTRL_INTR_SOURCE`
+        ApbCtrl = 14,
+        /// `ETS_UHCI0_INTR_SOURCE`
+        Uhci0 = 15,
+        /// `ETS_GPIO_INTR_SOURCE`
+        Gpio = 16,
+        /// `ETS_GPIO_NMI_SOURCE`
+        GpioNmi = 17,
+        /// `ETS_SPI1_INTR_SOURCE`
+        Spi1 = 18,
+        /// `ETS_SPI2_INTR_SOURCE`
+        Spi2 = 19,
+        /// `ETS_I2S0_INTR_SOURCE`
+        I2s0 = 20,
+        /// `ETS_UART0_INTR_SOURCE`
+        Uart0 = 21,
+        /// `ETS_UART1_INTR_SOURCE`
+        Uart1 = 22,
+        /// `ETS_LEDC_INTR_SOURCE`
+        Ledc = 23,
+        /// `ETS_EFUSE_INTR_SOURCE`
+        Efuse = 24,
+        /// `ETS_TWAI_INTR_SOURCE`
+        Twai = 25,
+        /// `ETS_USB_SERIAL_JTAG_INTR_SOURCE`
+        UsbSerialJtag = 26,
+        /// `ETS_RTC_CORE_INTR_SOURCE`
+        RtcCore = 27,
+        /// `ETS_RMT_INTR_SOURCE`
+        Rmt = 28,
+        /// `ETS_I2C_EXT0_INTR_SOURCE`
+        I2cExt0 = 29,
+        /// `ETS_TIMER1_INTR_SOURCE`
+        Timer1 = 30,
+        /// `ETS_TIMER2_INTR_SOURCE`
+        Timer2 = 31,
+        /// `ETS_TG0_T0_LEVEL_INTR_SOURCE`
+        Tg0T0Level = 32,
+        /// `ETS_TG0_WDT_LEVEL_INTR_SOURCE`
+        Tg0WdtLevel = 33,
+        /// `ETS_TG1_T0_LEVEL_INTR_SOURCE`
+        Tg1T0Level = 34,
+        /// `ETS_TG1_WDT_LEVEL_INTR_SOURCE`
+        Tg1WdtLevel = 35,
+        /// `ETS_CACHE_IA_INTR_SOURCE`
+        CacheIa = 36,
+        /// `ETS_SYSTIMER_TARGET0_INTR_SOURCE`
+        SystimerTarget0 = 37,
+        /// `ETS_SYSTIMER_TARGET1_INTR_SOURCE`
+        SystimerTarget1 = 38,
+        /// `ETS_SYSTIMER_TARGET2_INTR_SOURCE`
+        SystimerTarget2 = 39,
+        /// `ETS_SPI_MEM_REJECT_CACHE_INTR_SOURCE`
+        SpiMemRejectCache = 40,
+        /// `ETS_ICACHE_PRELOAD0_INTR_SOURCE`
+        IcachePreload0 = 41,
+        /// `ETS_ICACHE_SYNC0_INTR_SOURCE`
+        IcacheSync0 = 42,
+        /// `ETS_APB_ADC_INTR_SOURCE`
+        ApbAdc = 43,
+        /// `ETS_DMA_CH0_INTR_SOURCE`
+        DmaCh0 = 44,
+        /// `ETS_DMA_CH1_INTR_SOURCE`
+        DmaCh1 = 45,
+        /// `ETS_DMA_CH2_INTR_SOURCE`
+        DmaCh2 = 46,
+        /// `ETS_RSA_INTR_SOURCE`
+        Rsa = 47,
+        /// `ETS_AES_INTR_SOURCE`
+        Aes = 48,
+        /// `ETS_SHA_INTR_SOURCE`
+        Sha = 49,
+        /// `ETS_FROM_CPU_INTR0_SOURCE`
+        FromCpuIntr0 = 50,
+        /// `ETS_FROM_CPU_INTR1_SOURCE`
+        FromCpuIntr1 = 51,
+        /// `ETS_FROM_CPU_INTR2_SOURCE`
+        FromCpuIntr2 = 52,
+        /// `ETS_FROM_CPU_INTR3_SOURCE`
+        FromCpuIntr3 = 53,
+        /// `ETS_ASSIST_DEBUG_INTR_SOURCE`
+        AssistDebug = 54,
+        /// `ETS_DMA_APBPERI_PMS_INTR_SOURCE`
+        DmaApbperiPms = 55,
+        /// `ETS_CORE0_IRAM0_PMS_INTR_SOURCE`
+        Core0Iram0Pms = 56,
+        /// `ETS_CORE0_DRAM0_PMS_INTR_SOURCE`
+        Core0Dram0Pms = 57,
+        /// `ETS_CORE0_PIF_PMS_INTR_SOURCE`
+        Core0PifPms = 58,
+        /// `ETS_CORE0_PIF_PMS_SIZE_INTR_SOURCE`
+        Core0PifPmsSize = 59,
+        /// `ETS_BAK_PMS_VIOLATE_INTR_SOURCE`
+        BakPmsViolate = 60,
+        /// `ETS_CACHE_CORE0_ACS_INTR_SOURCE`
+        CacheCore0Acs = 61,
+    }
+
+    impl InterruptSource {
+        /// `ETS_SYSTIMER_TARGET0_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET0_EDGE: Self = Self::SystimerTarget0;
+        /// `ETS_SYSTIMER_TARGET1_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET1_EDGE: Self = Self::SystimerTarget1;
+        /// `ETS_SYSTIMER_TARGET2_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET2_EDGE: Self = Self::SystimerTarget2;
+        /// The sources of the Wi-Fi, BT, IEEE 802.15.4 and coex hardware
+        pub const WIRELESS: &'static [Self] = &[Self::WifiMac, Self::WifiMacNmi, Self::WifiPwr, Self::WifiBb, Self::BtMac, Self::BtBb, Self::BtBbNmi, Self::Rwbt, Self::Rwble, Self::RwbtNmi, Self::RwbleNmi];
+        /// One more than the highest source, `ETS_MAX_INTR_SOURCE`
+        pub const COUNT: u32 = 62;
+    }
+
+    impl TryFrom<u32> for InterruptSource {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(Self::WifiMac),
+                1 => Ok(Self::WifiMacNmi),
+                2 => Ok(Self::WifiPwr),
+                3 => Ok(Self::WifiBb),
+                4 => Ok(Self::BtMac),
+                5 => Ok(Self::BtBb),
+                6 => Ok(Self::BtBbNmi),
+                7 => Ok(Self::Rwbt),
+                8 => Ok(Self::Rwble),
+                9 => Ok(Self::RwbtNmi),
+                10 => Ok(Self::RwbleNmi),
+                11 => Ok(Self::I2cMaster),
+                12 => Ok(Self::Slc0),
+                13 => Ok(Self::Slc1),
+                14 => Ok(Self::ApbCtrl),
+                15 => Ok(Self::Uhci0),
+                16 => Ok(Self::Gpio),
+                17 => Ok(Self::GpioNmi),
+                18 => Ok(Self::Spi1),
+                19 => Ok(Self::Spi2),
+                20 => Ok(Self::I2s0),
+                21 => Ok(Self::Uart0),
+                22 => Ok(Self::Uart1),
+                23 => Ok(Self::Ledc),
+                24 => Ok(Self::Efuse),
+                25 => Ok(Self::Twai),
+                26 => Ok(Self::UsbSerialJtag),
+                27 => Ok(Self::RtcCore),
+                28 => Ok(Self::Rmt),
+                29 => Ok(Self::I2cExt0),
+                30 => Ok(Self::Timer1),
+                31 => Ok(Self::Timer2),
+                32 => Ok(Self::Tg0T0Level),
+                33 => Ok(Self::Tg0WdtLevel),
+                34 => Ok(Self::Tg1T0Level),
+                35 => Ok(Self::Tg1WdtLevel),
+                36 => Ok(Self::CacheIa),
+                37 => Ok(Self::SystimerTarget0),
+                38 => Ok(Self::SystimerTarget1),
+                39 => Ok(Self::SystimerTarget2),
+                40 => Ok(Self::SpiMemRejectCache),
+                41 => Ok(Self::IcachePreload0),
+                42 => Ok(Self::IcacheSync0),
+                43 => Ok(Self::ApbAdc),
+                44 => Ok(Self::DmaCh0),
+                45 => Ok(Self::DmaCh1),
+                46 => Ok(Self::DmaCh2),
+                47 => Ok(Self::Rsa),
+                48 => Ok(Self::Aes),
+                49 => Ok(Self::Sha),
+                50 => Ok(Self::FromCpuIntr0),
+                51 => Ok(Self::FromCpuIntr1),
+                52 => Ok(Self::FromCpuIntr2),
+                53 => Ok(Self::FromCpuIntr3),
+                54 => Ok(Self::AssistDebug),
+                55 => Ok(Self::DmaApbperiPms),
+                56 => Ok(Self::Core0Iram0Pms),
+                57 => Ok(Self::Core0Dram0Pms),
+                58 => Ok(Self::Core0PifPms),
+                59 => Ok(Self::Core0PifPmsSize),
+                60 => Ok(Self::BakPmsViolate),
+                61 => Ok(Self::CacheCore0Acs),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl From<InterruptSource> for u32 {
+        fn from(source: InterruptSource) -> u32 {
+            source as u32
+        }
+    }
+}
+
+pub mod esp32c6 {
+    /// The `periph_interrupt_t` of `include/esp32c6/soc/interrupts.h`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    pub enum InterruptSource {
+        /// `ETS_WIFI_MAC_INTR_SOURCE`
+        WifiMac = 0,
+        /// `ETS_WIFI_MAC_NMI_SOURCE`
+        WifiMacNmi = 1,
+        /// `ETS_WIFI_PWR_INTR_SOURCE`
+        WifiPwr = 2,
+        /// `ETS_WIFI_BB_INTR_SOURCE`
+        WifiBb = 3,
+        /// `ETS_BT_MAC_INTR_SOURCE`
+        BtMac = 4,
+        /// `ETS_BT_BB_INTR_SOURCE`
+        BtBb = 5,
+        /// `ETS_BT_BB_NMI_SOURCE`
+        BtBbNmi = 6,
+        /// `ETS_LP_TIMER_INTR_SOURCE`
+        LpTimer = 7,
+        /// `ETS_COEX_INTR_SOURCE`
+        Coex = 8,
+        /// `ETS_BLE_TIMER_INTR_SOURCE`
+        BleTimer = 9,
+        /// `ETS_BLE_SEC_INTR_SOURCE`
+        BleSec = 10,
+        /// `ETS_I2C_MASTER_SOURCE`
+        I2cMaster = 11,
+        /// `ETS_ZB_MAC_SOURCE`
+        ZbMac = 12,
+        /// `ETS_PMU_INTR_SOURCE`
+        Pmu = 13,
+        /// `ETS_EFUSE_INTR_SOURCE`
+        Efuse = 14,
+        /// `ETS_LP_RTC_TIMER_INTR_SOURCE`
+        LpRtcTimer = 15,
+        /// `ETS_LP_UART_INTR_SOURCE`
+        LpUart = 16,
+        /// `ETS_LP_I2C_INTR_SOURCE`
+        LpI2c = 17,
+        /// `ETS_LP_WDT_INTR_SOURCE`
+        LpWdt = 18,
+        /// `ETS_LP_PERI_TIMEOUT_INTR_SOURCE`
+        LpPeriTimeout = 19,
+        /// `ETS_LP_APM_M0_INTR_SOURCE`
+        LpApmM0 = 20,
+        /// `ETS_LP_APM_M1_INTR_SOURCE`
+        LpApmM1 = 21,
+        /// `ETS_FROM_CPU_INTR0_SOURCE`
+        FromCpuIntr0 = 22,
+        /// `ETS_FROM_CPU_INTR1_SOURCE`
+        FromCpuIntr1 = 23,
+        /// `ETS_FROM_CPU_INTR2_SOURCE`
+        FromCpuIntr2 = 24,
+        /// `ETS_FROM_CPU_INTR3_SOURCE`
+        FromCpuIntr3 = 25,
+        /// `ETS_ASSIST_DEBUG_INTR_SOURCE`
+        AssistDebug = 26,
+        /// `ETS_TRACE_INTR_SOURCE`
+        Trace = 27,
+        /// `ETS_CACHE_INTR_SOURCE`
+        Cache = 28,
+        /// `ETS_CPU_PERI_TIMEOUT_INTR_SOURCE`
+        CpuPeriTimeout = 29,
+        /// `ETS_GPIO_INTR_SOURCE`
+        Gpio = 30,
+        /// `ETS_GPIO_NMI_SOURCE`
+        GpioNmi = 31,
+        /// `ETS_PAU_INTR_SOURCE`
+        Pau = 32,
+        /// `ETS_HP_PERI_TIMEOUT_INTR_SOURCE`
+        HpPeriTimeout = 33,
+        /// `ETS_MODEM_PERI_TIMEOUT_INTR_SOURCE`
+        ModemPeriTimeout = 34,
+        /// `ETS_HP_APM_M0_INTR_SOURCE`
+        HpApmM0 = 35,
+        /// `ETS_HP_APM_M1_INTR_SOURCE`
+        HpApmM1 = 36,
+        /// `ETS_HP_APM_M2_INTR_SOURCE`
+        HpApmM2 = 37,
+        /// `ETS_HP_APM_M3_INTR_SOURCE`
+        HpApmM3 = 38,
+        /// `ETS_LP_APM0_INTR_SOURCE`
+        LpApm0 = 39,
+        /// `ETS_MSPI_INTR_SOURCE`
+        Mspi = 40,
+        /// `ETS_I2S0_INTR_SOURCE`
+        I2s0 = 41,
+        /// `ETS_UHCI0_INTR_SOURCE`
+        Uhci0 = 42,
+        /// `ETS_UART0_INTR_SOURCE`
+        Uart0 = 43,
+        /// `ETS_UART1_INTR_SOURCE`
+        Uart1 = 44,
+        /// `ETS_LEDC_INTR_SOURCE`
+        Ledc = 45,
+        /// `ETS_TWAI0_INTR_SOURCE`
+        Twai0 = 46,
+        /// `ETS_TWAI1_INTR_SOURCE`
+        Twai1 = 47,
+        /// `ETS_USB_SERIAL_JTAG_INTR_SOURCE`
+        UsbSerialJtag = 48,
+        /// `ETS_RMT_INTR_SOURCE`
+        Rmt = 49,
+        /// `ETS_I2C_EXT0_INTR_SOURCE`
+        I2cExt0 = 50,
+        /// `ETS_TG0_T0_LEVEL_INTR_SOURCE`
+        Tg0T0Level = 51,
+        /// `ETS_TG0_T1_LEVEL_INTR_SOURCE`
+        Tg0T1Level = 52,
+        /// `ETS_TG0_WDT_LEVEL_INTR_SOURCE`
+        Tg0WdtLevel = 53,
+        /// `ETS_TG1_T0_LEVEL_INTR_SOURCE`
+        Tg1T0Level = 54,
+        /// `ETS_TG1_T1_LEVEL_INTR_SOURCE`
+        Tg1T1Level = 55,
+        /// `ETS_TG1_WDT_LEVEL_INTR_SOURCE`
+        Tg1WdtLevel = 56,
+        /// `ETS_SYSTIMER_TARGET0_INTR_SOURCE`
+        SystimerTarget0 = 57,
+        /// `ETS_SYSTIMER_TARGET1_INTR_SOURCE`
+        SystimerTarget1 = 58,
+        /// `ETS_SYSTIMER_TARGET2_INTR_SOURCE`
+        SystimerTarget2 = 59,
+        /// `ETS_APB_ADC_INTR_SOURCE`
+        ApbAdc = 60,
+        /// `ETS_MCPWM0_INTR_SOURCE`
+        Mcpwm0 = 61,
+        /// `ETS_PCNT_INTR_SOURCE`
+        Pcnt = 62,
+        /// `ETS_PARL_IO_INTR_SOURCE`
+        ParlIo = 63,
+        /// `ETS_SLC0_INTR_SOURCE`
+        Slc0 = 64,
+        /// `ETS_SLC_INTR_SOURCE`
+        Slc = 65,
+        /// `ETS_DMA_IN_CH0_INTR_SOURCE`
+        DmaInCh0 = 66,
+        /// `ETS_DMA_IN_CH1_INTR_SOURCE`
+        DmaInCh1 = 67,
+        /// `ETS_DMA_IN_CH2_INTR_SOURCE`
+        DmaInCh2 = 68,
+        /// `ETS_DMA_OUT_CH0_INTR_SOURCE`
+        DmaOutCh0 = 69,
+        /// `ETS_DMA_OUT_CH1_INTR_SOURCE`
+        DmaOutCh1 = 70,
+        /// `ETS_DMA_OUT_CH2_INTR_SOURCE`
+        DmaOutCh2 = 71,
+        /// `ETS_GSPI2_INTR_SOURCE`
+        Gspi2 = 72,
+        /// `ETS_AES_INTR_SOURCE`
+        Aes = 73,
+        /// `ETS_SHA_INTR_SOURCE`
+        Sha = 74,
+        /// `ETS_RSA_INTR_SOURCE`
+        Rsa = 75,
+        /// `ETS_ECC_INTR_SOURCE`
+        Ecc = 76,
+    }
+
+    impl InterruptSource {
+        /// `ETS_SYSTIMER_TARGET0_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET0_EDGE: Self = Self::SystimerTarget0;
+        /// `ETS_SYSTIMER_TARGET1_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET1_EDGE: Self = Self::SystimerTarget1;
+        /// `ETS_SYSTIMER_TARGET2_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET2_EDGE: Self = Self::SystimerTarget2;
+        /// `ETS_TEMPERATURE_SENSOR_INTR_SOURCE`
+        pub const TEMPERATURE_SENSOR: Self = Self::ApbAdc;
+        /// The sources of the Wi-Fi, BT, IEEE 802.15.4 and coex hardware
+        pub const WIRELESS: &'static [Self] = &[Self::WifiMac, Self::WifiMacNmi, Self::WifiPwr, Self::WifiBb, Self::BtMac, Self::BtBb, Self::BtBbNmi, Self::Coex, Self::BleTimer, Self::BleSec, Self::ZbMac];
+        /// One more than the highest source, `ETS_MAX_INTR_SOURCE`
+        pub const COUNT: u32 = 77;
+    }
+
+    impl TryFrom<u32> for InterruptSource {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(Self::WifiMac),
+                1 => Ok(Self::WifiMacNmi),
+                2 => Ok(Self::WifiPwr),
+                3 => Ok(Self::WifiBb),
+                4 => Ok(Self::BtMac),
+                5 => Ok(Self::BtBb),
+                6 => Ok(Self::BtBbNmi),
+                7 => Ok(Self::LpTimer),
+                8 => Ok(Self::Coex),
+                9 => Ok(Self::BleTimer),
+                10 => Ok(Self::BleSec),
+                11 => Ok(Self::I2cMaster),
+                12 => Ok(Self::ZbMac),
+                13 => Ok(Self::Pmu),
+                14 => Ok(Self::Efuse),
+                15 => Ok(Self::LpRtcTimer),
+                16 => Ok(Self::LpUart),
+                17 => Ok(Self::LpI2c),
+                18 => Ok(Self::LpWdt),
+                19 => Ok(Self::LpPeriTimeout),
+                20 => Ok(Self::LpApmM0),
+                21 => Ok(Self::LpApmM1),
+                22 => Ok(Self::FromCpuIntr0),
+                23 => Ok(Self::FromCpuIntr1),
+                24 => Ok(Self::FromCpuIntr2),
+                25 => Ok(Self::FromCpuIntr3),
+                26 => Ok(Self::AssistDebug),
+                27 => Ok(Self::Trace),
+                28 => Ok(Self::Cache),
+                29 => Ok(Self::CpuPeriTimeout),
+                30 => Ok(Self::Gpio),
+                31 => Ok(Self::GpioNmi),
+                32 => Ok(Self::Pau),
+                33 => Ok(Self::HpPeriTimeout),
+                34 => Ok(Self::ModemPeriTimeout),
+                35 => Ok(Self::HpApmM0),
+                36 => Ok(Self::HpApmM1),
+                37 => Ok(Self::HpApmM2),
+                38 => Ok(Self::HpApmM3),
+                39 => Ok(Self::LpApm0),
+                40 => Ok(Self::Mspi),
+                41 => Ok(Self::I2s0),
+                42 => Ok(Self::Uhci0),
+                43 => Ok(Self::Uart0),
+                44 => Ok(Self::Uart1),
+                45 => Ok(Self::Ledc),
+                46 => Ok(Self::Twai0),
+                47 => Ok(Self::Twai1),
+                48 => Ok(Self::UsbSerialJtag),
+                49 => Ok(Self::Rmt),
+                50 => Ok(Self::I2cExt0),
+                51 => Ok(Self::Tg0T0Level),
+                52 => Ok(Self::Tg0T1Level),
+                53 => Ok(Self::Tg0WdtLevel),
+                54 => Ok(Self::Tg1T0Level),
+                55 => Ok(Self::Tg1T1Level),
+                56 => Ok(Self::Tg1WdtLevel),
+                57 => Ok(Self::SystimerTarget0),
+                58 => Ok(Self::SystimerTarget1),
+                59 => Ok(Self::SystimerTarget2),
+                60 => Ok(Self::ApbAdc),
+                61 => Ok(Self::Mcpwm0),
+                62 => Ok(Self::Pcnt),
+                63 => Ok(Self::ParlIo),
+                64 => Ok(Self::Slc0),
+                65 => Ok(Self::Slc),
+                66 => Ok(Self::DmaInCh0),
+                67 => Ok(Self::DmaInCh1),
+                68 => Ok(Self::DmaInCh2),
+                69 => Ok(Self::DmaOutCh0),
+                70 => Ok(Self::DmaOutCh1),
+                71 => Ok(Self::DmaOutCh2),
+                72 => Ok(Self::Gspi2),
+                73 => Ok(Self::Aes),
+                74 => Ok(Self::Sha),
+                75 => Ok(Self::Rsa),
+                76 => Ok(Self::Ecc),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl From<InterruptSource> for u32 {
+        fn from(source: InterruptSource) -> u32 {
+            source as u32
+        }
+    }
+}
+
+pub mod esp32h2 {
+    /// The `periph_interrupt_t` of `include/esp32h2/soc/interrupts.h`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    pub enum InterruptSource {
+        /// `ETS_PMU_INTR_SOURCE`
+        Pmu = 0,
+        /// `ETS_EFUSE_INTR_SOURCE`
+        Efuse = 1,
+        /// `ETS_LP_RTC_TIMER_INTR_SOURCE`
+        LpRtcTimer = 2,
+        /// `ETS_LP_BLE_TIMER_INTR_SOURCE`
+        LpBleTimer = 3,
+        /// `ETS_LP_WDT_INTR_SOURCE`
+        LpWdt = 4,
+        /// `ETS_LP_PERI_TIMEOUT_INTR_SOURCE`
+        LpPeriTimeout = 5,
+        /// `ETS_LP_APM_M0_INTR_SOURCE`
+        LpApmM0 = 6,
+        /// `ETS_FROM_CPU_INTR0_SOURCE`
+        FromCpuIntr0 = 7,
+        /// `ETS_FROM_CPU_INTR1_SOURCE`
+        FromCpuIntr1 = 8,
+        /// `ETS_FROM_CPU_INTR2_SOURCE`
+        FromCpuIntr2 = 9,
+        /// `ETS_FROM_CPU_INTR3_SOURCE`
+        FromCpuIntr3 = 10,
+        /// `ETS_ASSIST_DEBUG_INTR_SOURCE`
+        AssistDebug = 11,
+        /// `ETS_TRACE_INTR_SOURCE`
+        Trace = 12,
+        /// `ETS_CACHE_INTR_SOURCE`
+        Cache = 13,
+        /// `ETS_CPU_PERI_TIMEOUT_INTR_SOURCE`
+        CpuPeriTimeout = 14,
+        /// `ETS_BT_MAC_INTR_SOURCE`
+        BtMac = 15,
+        /// `ETS_BT_BB_INTR_SOURCE`
+        BtBb = 16,
+        /// `ETS_BT_BB_NMI_INTR_SOURCE`
+        BtBbNmi = 17,
+        /// `ETS_COEX_INTR_SOURCE`
+        Coex = 18,
+        /// `ETS_BLE_TIMER_INTR_SOURCE`
+        BleTimer = 19,
+        /// `ETS_BLE_SEC_INTR_SOURCE`
+        BleSec = 20,
+        /// `ETS_ZB_MAC_INTR_SOURCE`
+        ZbMac = 21,
+        /// `ETS_GPIO_INTR_SOURCE`
+        Gpio = 22,
+        /// `ETS_GPIO_NMI_SOURCE`
+        GpioNmi = 23,
+        /// `ETS_PAU_INTR_SOURCE`
+        Pau = 24,
+        /// `ETS_HP_PERI_TIMEOUT_INTR_SOURCE`
+        HpPeriTimeout = 25,
+        /// `ETS_HP_APM_M0_INTR_SOURCE`
+        HpApmM0 = 26,
+        /// `ETS_HP_APM_M1_INTR_SOURCE`
+        HpApmM1 = 27,
+        /// `ETS_HP_APM_M2_INTR_SOURCE`
+        HpApmM2 = 28,
+        /// `ETS_HP_APM_M3_INTR_SOURCE`
+        HpApmM3 = 29,
+        /// `ETS_MSPI_INTR_SOURCE`
+        Mspi = 30,
+        /// `ETS_I2S0_INTR_SOURCE`
+        I2s0 = 31,
+        /// `ETS_UHCI0_INTR_SOURCE`
+        Uhci0 = 32,
+        /// `ETS_UART0_INTR_SOURCE`
+        Uart0 = 33,
+        /// `ETS_UART1_INTR_SOURCE`
+        Uart1 = 34,
+        /// `ETS_LEDC_INTR_SOURCE`
+        Ledc = 35,
+        /// `ETS_TWAI0_INTR_SOURCE`
+        Twai0 = 36,
+        /// `ETS_USB_SERIAL_JTAG_INTR_SOURCE`
+        UsbSerialJtag = 37,
+        /// `ETS_RMT_INTR_SOURCE`
+        Rmt = 38,
+        /// `ETS_I2C_EXT0_INTR_SOURCE`
+        I2cExt0 = 39,
+        /// `ETS_I2C_EXT1_INTR_SOURCE`
+        I2cExt1 = 40,
+        /// `ETS_TG0_T0_LEVEL_INTR_SOURCE`
+        Tg0T0Level = 41,
+        /// `ETS_TG0_WDT_LEVEL_INTR_SOURCE`
+        Tg0WdtLevel = 42,
+        /// `ETS_TG1_T0_LEVEL_INTR_SOURCE`
+        Tg1T0Level = 43,
+        /// `ETS_TG1_WDT_LEVEL_INTR_SOURCE`
+        Tg1WdtLevel = 44,
+        /// `ETS_SYSTIMER_TARGET0_INTR_SOURCE`
+        SystimerTarget0 = 45,
+        /// `ETS_SYSTIMER_TARGET1_INTR_SOURCE`
+        SystimerTarget1 = 46,
+        /// `ETS_SYSTIMER_TARGET2_INTR_SOURCE`
+        SystimerTarget2 = 47,
+        /// `ETS_APB_ADC_INTR_SOURCE`
+        ApbAdc = 48,
+        /// `ETS_MCPWM0_INTR_SOURCE`
+        Mcpwm0 = 49,
+        /// `ETS_PCNT_INTR_SOURCE`
+        Pcnt = 50,
+        /// `ETS_PARL_IO_TX_INTR_SOURCE`
+        ParlIoTx = 51,
+        /// `ETS_PARL_IO_RX_INTR_SOURCE`
+        ParlIoRx = 52,
+        /// `ETS_DMA_IN_CH0_INTR_SOURCE`
+        DmaInCh0 = 53,
+        /// `ETS_DMA_IN_CH1_INTR_SOURCE`
+        DmaInCh1 = 54,
+        /// `ETS_DMA_IN_CH2_INTR_SOURCE`
+        DmaInCh2 = 55,
+        /// `ETS_DMA_OUT_CH0_INTR_SOURCE`
+        DmaOutCh0 = 56,
+        /// `ETS_DMA_OUT_CH1_INTR_SOURCE`
+        DmaOutCh1 = 57,
+        /// `ETS_DMA_OUT_CH2_INTR_SOURCE`
+        DmaOutCh2 = 58,
+        /// `ETS_GSPI2_INTR_SOURCE`
+        Gspi2 = 59,
+        /// `ETS_AES_INTR_SOURCE`
+        Aes = 60,
+        /// `ETS_SHA_INTR_SOURCE`
+        Sha = 61,
+        /// `ETS_RSA_INTR_SOURCE`
+        Rsa = 62,
+        /// `ETS_ECC_INTR_SOURCE`
+        Ecc = 63,
+        /// `ETS_ECDSA_INTR_SOURCE`
+        Ecdsa = 64,
+    }
+
+    impl InterruptSource {
+        /// `ETS_SYSTIMER_TARGET0_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET0_EDGE: Self = Self::SystimerTarget0;
+        /// `ETS_SYSTIMER_TARGET1_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET1_EDGE: Self = Self::SystimerTarget1;
+        /// `ETS_SYSTIMER_TARGET2_EDGE_INTR_SOURCE`
+        pub const SYSTIMER_TARGET2_EDGE: Self = Self::SystimerTarget2;
+        /// `ETS_TEMPERATURE_SENSOR_INTR_SOURCE`
+        pub const TEMPERATURE_SENSOR: Self = Self::ApbAdc;
+        /// The sources of the Wi-Fi, BT, IEEE 802.15.4 and coex hardware
+        pub const WIRELESS: &'static [Self] = &[Self::LpBleTimer, Self::BtMac, Self::BtBb, Self::BtBbNmi, Self::Coex, Self::BleTimer, Self::BleSec, Self::ZbMac];
+        /// One more than the highest source, `ETS_MAX_INTR_SOURCE`
+        pub const COUNT: u32 = 65;
+    }
+
+    impl TryFrom<u32> for InterruptSource {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(Self::Pmu),
+                1 => Ok(Self::Efuse),
+                2 => Ok(Self::LpRtcTimer),
+                3 => Ok(Self::LpBleTimer),
+                4 => Ok(Self::LpWdt),
+                5 => Ok(Self::LpPeriTimeout),
+                6 => Ok(Self::LpApmM0),
+                7 => Ok(Self::FromCpuIntr0),
+                8 => Ok(Self::FromCpuIntr1),
+                9 => Ok(Self::FromCpuIntr2),
+                10 => Ok(Self::FromCpuIntr3),
+                11 => Ok(Self::AssistDebug),
+                12 => Ok(Self::Trace),
+                13 => Ok(Self::Cache),
+                14 => Ok(Self::CpuPeriTimeout),
+                15 => Ok(Self::BtMac),
+                16 => Ok(Self::BtBb),
+                17 => Ok(Self::BtBbNmi),
+                18 => Ok(Self::Coex),
+                19 => Ok(Self::BleTimer),
+                20 => Ok(Self::BleSec),
+                21 => Ok(Self::ZbMac),
+                22 => Ok(Self::Gpio),
+                23 => Ok(Self::GpioNmi),
+                24 => Ok(Self::Pau),
+                25 => Ok(Self::HpPeriTimeout),
+                26 => Ok(Self::HpApmM0),
+                27 => Ok(Self::HpApmM1),
+                28 => Ok(Self::HpApmM2),
+                29 => Ok(Self::HpApmM3),
+                30 => Ok(Self::Mspi),
+                31 => Ok(Self::I2s0),
+                32 => Ok(Self::Uhci0),
+                33 => Ok(Self::Uart0),
+                34 => Ok(Self::Uart1),
+                35 => Ok(Self::Ledc),
+                36 => Ok(Self::Twai0),
+                37 => Ok(Self::UsbSerialJtag),
+                38 => Ok(Self::Rmt),
+                39 => Ok(Self::I2cExt0),
+                40 => Ok(Self::I2cExt1),
+                41 => Ok(Self::Tg0T0Level),
+                42 => Ok(Self::Tg0WdtLevel),
+                43 => Ok(Self::Tg1T0Level),
+                44 => Ok(Self::Tg1WdtLevel),
+                45 => Ok(Self::SystimerTarget0),
+                46 => Ok(Self::SystimerTarget1),
+                47 => Ok(Self::SystimerTarget2),
+                48 => Ok(Self::ApbAdc),
+                49 => Ok(Self::Mcpwm0),
+                50 => Ok(Self::Pcnt),
+                51 => Ok(Self::ParlIoTx),
+                52 => Ok(Self::ParlIoRx),
+                53 => Ok(Self::DmaInCh0),
+                54 => Ok(Self::DmaInCh1),
+                55 => Ok(Self::DmaInCh2),
+                56 => Ok(Self::DmaOutCh0),
+                57 => Ok(Self::DmaOutCh1),
+                58 => Ok(Self::DmaOutCh2),
+                59 => Ok(Self::Gspi2),
+                60 => Ok(Self::Aes),
+                61 => Ok(Self::Sha),
+                62 => Ok(Self::Rsa),
+                63 => Ok(Self::Ecc),
+                64 => Ok(Self::Ecdsa),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl From<InterruptSource> for u32 {
+        fn from(source: InterruptSource) -> u32 {
+            source as u32
+        }
+    }
+}
+
+pub mod esp32c5 {
+    /// The `periph_interrupt_t` of `include/esp32c5/soc/interrupts.h`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    pub enum InterruptSource {
+        /// `ETS_WIFI_MAC_INTR_SOURCE`
+        WifiMac = 0,
+        /// `ETS_WIFI_MAC_NMI_SOURCE`
+        WifiMacNmi = 1,
+        /// `ETS_WIFI_PWR_INTR_SOURCE`
+        WifiPwr = 2,
+        /// `ETS_WIFI_BB_INTR_SOURCE`
+        WifiBb = 3,
+        /// `ETS_BT_MAC_INTR_SOURCE`
+        BtMac = 4,
+        /// `ETS_BT_BB_INTR_SOURCE`
+        BtBb = 5,
+        /// `ETS_BT_BB_NMI_SOURCE`
+        BtBbNmi = 6,
+        /// `ETS_LP_TIMER_INTR_SOURCE`
+        LpTimer = 7,
+        /// `ETS_COEX_INTR_SOURCE`
+        Coex = 8,
+        /// `ETS_BLE_TIMER_INTR_SOURCE`
+        BleTimer = 9,
+        /// `ETS_BLE_SEC_INTR_SOURCE`
+        BleSec = 10,
+        /// `ETS_I2C_MASTER_SOURCE`
+        I2cMaster = 11,
+        /// `ETS_ZB_MAC_INTR_SOURCE`
+        ZbMac = 12,
+        /// `ETS_PMU_INTR_SOURCE`
+        Pmu = 13,
+        /// `ETS_EFUSE_INTR_SOURCE`
+        Efuse = 14,
+        /// `ETS_LP_RTC_TIMER_INTR_SOURCE`
+        LpRtcTimer = 15,
+        /// `ETS_LP_UART_INTR_SOURCE`
+        LpUart = 16,
+        /// `ETS_LP_I2C_INTR_SOURCE`
+        LpI2c = 17,
+        /// `ETS_LP_WDT_INTR_SOURCE`
+        LpWdt = 18,
+        /// `ETS_LP_PERI_TIMEOUT_INTR_SOURCE`
+        LpPeriTimeout = 19,
+        /// `ETS_LP_APM_M0_INTR_SOURCE`
+        LpApmM0 = 20,
+        /// `ETS_LP_APM_M1_INTR_SOURCE`
+        LpApmM1 = 21,
+        /// `ETS_HUK_INTR_SOURCE`
+        Huk = 22,
+        /// `ETS_FROM_CPU_INTR0_SOURCE`
+        FromCpuIntr0 = 23,
+        /// `ETS_FROM_CPU_INTR1_SOURCE`
+        FromCpuIntr1 = 24,
+        /// `ETS_FROM_CPU_INTR2_SOURCE`
+        FromCpuIntr2 = 25,
+        /// `ETS_FROM_CPU_INTR3_SOURCE`
+        FromCpuIntr3 = 26,
+        /// `ETS_ASSIST_DEBUG_INTR_SOURCE`
+        AssistDebug = 27,
+        /// `ETS_TRACE_INTR_SOURCE`
+        Trace = 28,
+        /// `ETS_CACHE_INTR_SOURCE`
+        Cache = 29,
+        /// `ETS_CPU_PERI_TIMEOUT_INTR_SOURCE`
+        CpuPeriTimeout = 30,
+        /// `ETS_GPIO_INTR_SOURCE`
+        Gpio = 31,
+        /// `ETS_GPIO_EXT_SOURCE`
+        GpioExt = 32,
+        /// `ETS_PAU_INTR_SOURCE`
+        Pau = 33,
+        /// `ETS_HP_PERI_TIMEOUT_INTR_SOURCE`
+        HpPeriTimeout = 34,
+        /// `ETS_MODEM_PERI_TIMEOUT_INTR_SOURCE`
+        ModemPeriTimeout = 35,
+        /// `ETS_HP_APM_M0_INTR_SOURCE`
+        HpApmM0 = 36,
+        /// `ETS_HP_APM_M1_INTR_SOURCE`
+        HpApmM1 = 37,
+        /// `ETS_HP_APM_M2_INTR_SOURCE`
+        HpApmM2 = 38,
+        /// `ETS_HP_APM_M3_INTR_SOURCE`
+        HpApmM3 = 39,
+        /// `ETS_HP_APM_M4_INTR_SOURCE`
+        HpApmM4 = 40,
+        /// `ETS_LP_APM0_INTR_SOURCE`
+        LpApm0 = 41,
+        /// `ETS_CPU_APM_M0_INTR_SOURCE`
+        CpuApmM0 = 42,
+        /// `ETS_CPU_APM_M1_INTR_SOURCE`
+        CpuApmM1 = 43,
+        /// `ETS_MSPI_INTR_SOURCE`
+        Mspi = 44,
+        /// `ETS_I2S0_INTR_SOURCE`
+        I2s0 = 45,
+        /// `ETS_UHCI0_INTR_SOURCE`
+        Uhci0 = 46,
+        /// `ETS_UART0_INTR_SOURCE`
+        Uart0 = 47,
+        /// `ETS_UART1_INTR_SOURCE`
+        Uart1 = 48,
+        /// `ETS_LEDC_INTR_SOURCE`
+        Ledc = 49,
+        /// `ETS_TWAI0_INTR_SOURCE`
+        Twai0 = 50,
+        /// `ETS_TWAI0_TIMER_INTR_SOURCE`
+        Twai0Timer = 51,
+        /// `ETS_TWAI1_INTR_SOURCE`
+        Twai1 = 52,
+        /// `ETS_TWAI1_TIMER_INTR_SOURCE`
+        Twai1Timer = 53,
+        /// `ETS_USB_SERIAL_JTAG_INTR_SOURCE`
+        UsbSerialJtag = 54,
+        /// `ETS_RMT_INTR_SOURCE`
+        Rmt = 55,
+        /// `ETS_I2C_EXT0_INTR_SOURCE`
+        I2cExt0 = 56,
+        /// `ETS_TG0_T0_LEVEL_INTR_SOURCE`
+        Tg0T0Level = 57,
+        /// `ETS_TG0_WDT_LEVEL_INTR_SOURCE`
+        Tg0WdtLevel = 58,
+        /// `ETS_TG1_T0_LEVEL_INTR_SOURCE`
+        Tg1T0Level = 59,
+        /// `ETS_TG1_WDT_LEVEL_INTR_SOURCE`
+        Tg1WdtLevel = 60,
+        /// `ETS_SYSTIMER_TARGET0_INTR_SOURCE`
+        SystimerTarget0 = 61,
+        /// `ETS_SYSTIMER_TARGET1_INTR_SOURCE`
+        SystimerTarget1 = 62,
+        /// `ETS_SYSTIMER_TARGET2_INTR_SOURCE`
+        SystimerTarget2 = 63,
+        /// `ETS_APB_ADC_INTR_SOURCE`
+        ApbAdc = 64,
+        /// `ETS_MCPWM0_INTR_SOURCE`
+        Mcpwm0 = 65,
+        /// `ETS_PCNT_INTR_SOURCE`
+        Pcnt = 66,
+        /// `ETS_PARL_IO_TX_INTR_SOURCE`
+        ParlIoTx = 67,
+        /// `ETS_PARL_IO_RX_INTR_SOURCE`
+        ParlIoRx = 68,
+        /// `ETS_SLC0_INTR_SOURCE`
+        Slc0 = 69,
+        /// `ETS_SLC1_INTR_SOURCE`
+        Slc1 = 70,
+        /// `ETS_DMA_IN_CH0_INTR_SOURCE`
+        DmaInCh0 = 71,
+        /// `ETS_DMA_IN_CH1_INTR_SOURCE`
+        DmaInCh1 = 72,
+        /// `ETS_DMA_IN_CH2_INTR_SOURCE`
+        DmaInCh2 = 73,
+        /// `ETS_DMA_OUT_CH0_INTR_SOURCE`
+        DmaOutCh0 = 74,
+        /// `ETS_DMA_OUT_CH1_INTR_SOURCE`
+        DmaOutCh1 = 75,
+        /// `ETS_DMA_OUT_CH2_INTR_SOURCE`
+        DmaOutCh2 = 76,
+        /// `ETS_GPSPI2_INTR_SOURCE`
+        Gpspi2 = 77,
+        /// `ETS_AES_INTR_SOURCE`
+        Aes = 78,
+        /// `ETS_SHA_INTR_SOURCE`
+        Sha = 79,
+        /// `ETS_RSA_INTR_SOURCE`
+        Rsa = 80,
+        /// `ETS_ECC_INTR_SOURCE`
+        Ecc = 81,
+        /// `ETS_ECDSA_INTR_SOURCE`
+        Ecdsa = 82,
+        /// `ETS_KM_INTR_SOURCE`
+        Km = 83,
+    }
+
+    impl InterruptSource {
+        /// `ETS_TEMPERATURE_SENSOR_INTR_SOURCE`
+        pub const TEMPERATURE_SENSOR: Self = Self::ApbAdc;
+        /// The sources of the Wi-Fi, BT, IEEE 802.15.4 and coex hardware
+        pub const WIRELESS: &'static [Self] = &[Self::WifiMac, Self::WifiMacNmi, Self::WifiPwr, Self::WifiBb, Self::BtMac, Self::BtBb, Self::BtBbNmi, Self::Coex, Self::BleTimer, Self::BleSec, Self::ZbMac];
+        /// One more than the highest source, `ETS_MAX_INTR_SOURCE`
+        pub const COUNT: u32 = 84;
+    }
+
+    impl TryFrom<u32> for InterruptSource {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(Self::WifiMac),
+                1 => Ok(Self::WifiMacNmi),
+                2 => Ok(Self::WifiPwr),
+                3 => Ok(Self::WifiBb),
+                4 => Ok(Self::BtMac),
+                5 => Ok(Self::BtBb),
+                6 => Ok(Self::BtBbNmi),
+                7 => Ok(Self::LpTimer),
+                8 => Ok(Self::Coex),
+                9 => Ok(Self::BleTimer),
+                10 => Ok(Self::BleSec),
+                11 => Ok(Self::I2cMaster),
+                12 => Ok(Self::ZbMac),
+                13 => Ok(Self::Pmu),
+                14 => Ok(Self::Efuse),
+                15 => Ok(Self::LpRtcTimer),
+                16 => Ok(Self::LpUart),
+                17 => Ok(Self::LpI2c),
+                18 => Ok(Self::LpWdt),
+                19 => Ok(Self::LpPeriTimeout),
+                20 => Ok(Self::LpApmM0),
+                21 => Ok(Self::LpApmM1),
+                22 => Ok(Self::Huk),
+                23 => Ok(Self::FromCpuIntr0),
+                24 => Ok(Self::FromCpuIntr1),
+                25 => Ok(Self::FromCpuIntr2),
+                26 => Ok(Self::FromCpuIntr3),
+                27 => Ok(Self::AssistDebug),
+                28 => Ok(Self::Trace),
+                29 => Ok(Self::Cache),
+                30 => Ok(Self::CpuPeriTimeout),
+                31 => Ok(Self::Gpio),
+                32 => Ok(Self::GpioExt),
+                33 => Ok(Self::Pau),
+                34 => Ok(Self::HpPeriTimeout),
+                35 => Ok(Self::ModemPeriTimeout),
+                36 => Ok(Self::HpApmM0),
+                37 => Ok(Self::HpApmM1),
+                38 => Ok(Self::HpApmM2),
+                39 => Ok(Self::HpApmM3),
+                40 => Ok(Self::HpApmM4),
+                41 => Ok(Self::LpApm0),
+                42 => Ok(Self::CpuApmM0),
+                43 => Ok(Self::CpuApmM1),
+                44 => Ok(Self::Mspi),
+                45 => Ok(Self::I2s0),
+                46 => Ok(Self::Uhci0),
+                47 => Ok(Self::Uart0),
+                48 => Ok(Self::Uart1),
+                49 => Ok(Self::Ledc),
+                50 => Ok(Self::Twai0),
+                51 => Ok(Self::Twai0Timer),
+                52 => Ok(Self::Twai1),
+                53 => Ok(Self::Twai1Timer),
+                54 => Ok(Self::UsbSerialJtag),
+                55 => Ok(Self::Rmt),
+                56 => Ok(Self::I2cExt0),
+                57 => Ok(Self::Tg0T0Level),
+                58 => Ok(Self::Tg0WdtLevel),
+                59 => Ok(Self::Tg1T0Level),
+                60 => Ok(Self::Tg1WdtLevel),
+                61 => Ok(Self::SystimerTarget0),
+                62 => Ok(Self::SystimerTarget1),
+                63 => Ok(Self::SystimerTarget2),
+                64 => Ok(Self::ApbAdc),
+                65 => Ok(Self::Mcpwm0),
+                66 => Ok(Self::Pcnt),
+                67 => Ok(Self::ParlIoTx),
+                68 => Ok(Self::ParlIoRx),
+                69 => Ok(Self::Slc0),
+                70 => Ok(Self::Slc1),
+                71 => Ok(Self::DmaInCh0),
+                72 => Ok(Self::DmaInCh1),
+                73 => Ok(Self::DmaInCh2),
+                74 => Ok(Self::DmaOutCh0),
+                75 => Ok(Self::DmaOutCh1),
+                76 => Ok(Self::DmaOutCh2),
+                77 => Ok(Self::Gpspi2),
+                78 => Ok(Self::Aes),
+                79 => Ok(Self::Sha),
+                80 => Ok(Self::Rsa),
+                81 => Ok(Self::Ecc),
+                82 => Ok(Self::Ecdsa),
+                83 => Ok(Self::Km),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl From<InterruptSource> for u32 {
+        fn from(source: InterruptSource) -> u32 {
+            source as u32
+        }
+    }
+}
+
+pub mod esp32c61 {
+    /// The `periph_interrupt_t` of `include/esp32c61/soc/interrupts.h`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[repr(u32)]
+    pub enum InterruptSource {
+        /// `ETS_WIFI_MAC_INTR_SOURCE`
+        WifiMac = 0,
+        /// `ETS_WIFI_MAC_NMI_SOURCE`
+        WifiMacNmi = 1,
+        /// `ETS_WIFI_PWR_INTR_SOURCE`
+        WifiPwr = 2,
+        /// `ETS_WIFI_BB_INTR_SOURCE`
+        WifiBb = 3,
+        /// `ETS_BT_MAC_INTR_SOURCE`
+        BtMac = 4,
+        /// `ETS_BT_BB_INTR_SOURCE`
+        BtBb = 5,
+        /// `ETS_BT_BB_NMI_SOURCE`
+        BtBbNmi = 6,
+        /// `ETS_LP_TIMER_INTR_SOURCE`
+        LpTimer = 7,
+        /// `ETS_COEX_INTR_SOURCE`
+        Coex = 8,
+        /// `ETS_BLE_TIMER_INTR_SOURCE`
+        BleTimer = 9,
+        /// `ETS_BLE_SEC_INTR_SOURCE`
+        BleSec = 10,
+        /// `ETS_I2C_MST_INTR_SOURCE`
+        I2cMst = 11,
+        /// `ETS_ZB_MAC_INTR_SOURCE`
+        ZbMac = 12,
+        /// `ETS_PMU_INTR_SOURCE`
+        Pmu = 13,
+        /// `ETS_EFUSE_INTR_SOURCE`
+        Efuse = 14,
+        /// `ETS_LP_RTC_TIMER_INTR_SOURCE`
+        LpRtcTimer = 15,
+        /// `ETS_LP_WDT_INTR_SOURCE`
+        LpWdt = 16,
+        /// `ETS_LP_PERI_TIMEOUT_INTR_SOURCE`
+        LpPeriTimeout = 17,
+        /// `ETS_LP_APM_M0_INTR_SOURCE`
+        LpApmM0 = 18,
+        /// `ETS_CPU_INTR_FROM_CPU_0_SOURCE`
+        CpuIntrFromCpu0 = 19,
+        /// `ETS_CPU_INTR_FROM_CPU_1_SOURCE`
+        CpuIntrFromCpu1 = 20,
+        /// `ETS_CPU_INTR_FROM_CPU_2_SOURCE`
+        CpuIntrFromCpu2 = 21,
+        /// `ETS_CPU_INTR_FROM_CPU_3_SOURCE`
+        CpuIntrFromCpu3 = 22,
+        /// `ETS_ASSIST_DEBUG_INTR_SOURCE`
+        AssistDebug = 23,
+        /// `ETS_TRACE_INTR_SOURCE`
+        Trace = 24,
+        /// `ETS_CACHE_INTR_SOURCE`
+        Cache = 25,
+        /// `ETS_CPU_PERI_TIMEOUT_INTR_SOURCE`
+        CpuPeriTimeout = 26,
+        /// `ETS_GPIO_INTERRUPT_PRO_SOURCE`
+        GpioInterruptPro = 27,
+        /// `ETS_GPIO_INTERRUPT_EXT_SOURCE`
+        GpioInterruptExt = 28,
+        /// `ETS_PAU_INTR_SOURCE`
+        Pau = 29,
+        /// `ETS_HP_PERI_TIMEOUT_INTR_SOURCE`
+        HpPeriTimeout = 30,
+        /// `ETS_MODEM_PERI_TIMEOUT_INTR_SOURCE`
+        ModemPeriTimeout = 31,
+        /// `ETS_HP_APM_M0_INTR_SOURCE`
+        HpApmM0 = 32,
+        /// `ETS_HP_APM_M1_INTR_SOURCE`
+        HpApmM1 = 33,
+        /// `ETS_HP_APM_M2_INTR_SOURCE`
+        HpApmM2 = 34,
+        /// `ETS_HP_APM_M3_INTR_SOURCE`
+        HpApmM3 = 35,
+        /// `ETS_CPU_APM_M0_INTR_SOURCE`
+        CpuApmM0 = 36,
+        /// `ETS_CPU_APM_M1_INTR_SOURCE`
+        CpuApmM1 = 37,
+        /// `ETS_MSPI_INTR_SOURCE`
+        Mspi = 38,
+        /// `ETS_I2S0_INTR_SOURCE`
+        I2s0 = 39,
+        /// `ETS_UART0_INTR_SOURCE`
+        Uart0 = 40,
+        /// `ETS_UART1_INTR_SOURCE`
+        Uart1 = 41,
+        /// `ETS_UART2_INTR_SOURCE`
+        Uart2 = 42,
+        /// `ETS_LEDC_INTR_SOURCE`
+        Ledc = 43,
+        /// `ETS_USB_SERIAL_JTAG_INTR_SOURCE`
+        UsbSerialJtag = 44,
+        /// `ETS_I2C_EXT0_INTR_SOURCE`
+        I2cExt0 = 45,
+        /// `ETS_TG0_T0_INTR_SOURCE`
+        Tg0T0 = 46,
+        /// `ETS_TG0_T1_INTR_SOURCE`
+        Tg0T1 = 47,
+        /// `ETS_TG0_WDT_INTR_SOURCE`
+        Tg0Wdt = 48,
+        /// `ETS_TG1_T0_INTR_SOURCE`
+        Tg1T0 = 49,
+        /// `ETS_TG1_T1_INTR_SOURCE`
+        Tg1T1 = 50,
+        /// `ETS_TG1_WDT_INTR_SOURCE`
+        Tg1Wdt = 51,
+        /// `ETS_SYSTIMER_TARGET0_INTR_SOURCE`
+        SystimerTarget0 = 52,
+        /// `ETS_SYSTIMER_TARGET1_INTR_SOURCE`
+        SystimerTarget1 = 53,
+        /// `ETS_SYSTIMER_TARGET2_INTR_SOURCE`
+        SystimerTarget2 = 54,
+        /// `ETS_APB_ADC_INTR_SOURCE`
+        ApbAdc = 55,
+        /// `ETS_SLC0_INTR_SOURCE`
+        Slc0 = 56,
+        /// `ETS_SLC1_INTR_SOURCE`
+        Slc1 = 57,
+        /// `ETS_DMA_IN_CH0_INTR_SOURCE`
+        DmaInCh0 = 58,
+        /// `ETS_DMA_IN_CH1_INTR_SOURCE`
+        DmaInCh1 = 59,
+        /// `ETS_DMA_OUT_CH0_INTR_SOURCE`
+        DmaOutCh0 = 60,
+        /// `ETS_DMA_OUT_CH1_INTR_SOURCE`
+        DmaOutCh1 = 61,
+        /// `ETS_GPSPI2_INTR_SOURCE`
+        Gpspi2 = 62,
+        /// `ETS_SHA_INTR_SOURCE`
+        Sha = 63,
+        /// `ETS_ECC_INTR_SOURCE`
+        Ecc = 64,
+        /// `ETS_ECDSA_INTR_SOURCE`
+        Ecdsa = 65,
+    }
+
+    impl InterruptSource {
+        /// `ETS_TEMPERATURE_SENSOR_INTR_SOURCE`
+        pub const TEMPERATURE_SENSOR: Self = Self::ApbAdc;
+        /// The sources of the Wi-Fi, BT, IEEE 802.15.4 and coex hardware
+        pub const WIRELESS: &'static [Self] = &[Self::WifiMac, Self::WifiMacNmi, Self::WifiPwr, Self::WifiBb, Self::BtMac, Self::BtBb, Self::BtBbNmi, Self::Coex, Self::BleTimer, Self::BleSec, Self::ZbMac];
+        /// One more than the highest source, `ETS_MAX_INTR_SOURCE`
+        pub const COUNT: u32 = 66;
+    }
+
+    impl TryFrom<u32> for InterruptSource {
+        type Error = u32;
+
+        fn try_from(value: u32) -> Result<Self, Self::Error> {
+            match value {
+                0 => Ok(Self::WifiMac),
+                1 => Ok(Self::WifiMacNmi),
+                2 => Ok(Self::WifiPwr),
+                3 => Ok(Self::WifiBb),
+                4 => Ok(Self::BtMac),
+                5 => Ok(Self::BtBb),
+                6 => Ok(Self::BtBbNmi),
+                7 => Ok(Self::LpTimer),
+                8 => Ok(Self::Coex),
+                9 => Ok(Self::BleTimer),
+                10 => Ok(Self::BleSec),
+                11 => Ok(Self::I2cMst),
+                12 => Ok(Self::ZbMac),
+                13 => Ok(Self::Pmu),
+                14 => Ok(Self::Efuse),
+                15 => Ok(Self::LpRtcTimer),
+                16 => Ok(Self::LpWdt),
+                17 => Ok(Self::LpPeriTimeout),
+                18 => Ok(Self::LpApmM0),
+                19 => Ok(Self::CpuIntrFromCpu0),
+                20 => Ok(Self::CpuIntrFromCpu1),
+                21 => Ok(Self::CpuIntrFromCpu2),
+                22 => Ok(Self::CpuIntrFromCpu3),
+                23 => Ok(Self::AssistDebug),
+                24 => Ok(Self::Trace),
+                25 => Ok(Self::Cache),
+                26 => Ok(Self::CpuPeriTimeout),
+                27 => Ok(Self::GpioInterruptPro),
+                28 => Ok(Self::GpioInterruptExt),
+                29 => Ok(Self::Pau),
+                30 => Ok(Self::HpPeriTimeout),
+                31 => Ok(Self::ModemPeriTimeout),
+                32 => Ok(Self::HpApmM0),
+                33 => Ok(Self::HpApmM1),
+                34 => Ok(Self::HpApmM2),
+                35 => Ok(Self::HpApmM3),
+                36 => Ok(Self::CpuApmM0),
+                37 => Ok(Self::CpuApmM1),
+                38 => Ok(Self::Mspi),
+                39 => Ok(Self::I2s0),
+                40 => Ok(Self::Uart0),
+                41 => Ok(Self::Uart1),
+                42 => Ok(Self::Uart2),
+                43 => Ok(Self::Ledc),
+                44 => Ok(Self::UsbSerialJtag),
+                45 => Ok(Self::I2cExt0),
+                46 => Ok(Self::Tg0T0),
+                47 => Ok(Self::Tg0T1),
+                48 => Ok(Self::Tg0Wdt),
+                49 => Ok(Self::Tg1T0),
+                50 => Ok(Self::Tg1T1),
+                51 => Ok(Self::Tg1Wdt),
+                52 => Ok(Self::SystimerTarget0),
+                53 => Ok(Self::SystimerTarget1),
+                54 => Ok(Self::SystimerTarget2),
+                55 => Ok(Self::ApbAdc),
+                56 => Ok(Self::Slc0),
+                57 => Ok(Self::Slc1),
+                58 => Ok(Self::DmaInCh0),
+                59 => Ok(Self::DmaInCh1),
+                60 => Ok(Self::DmaOutCh0),
+                61 => Ok(Self::DmaOutCh1),
+                62 => Ok(Self::Gpspi2),
+                63 => Ok(Self::Sha),
+                64 => Ok(Self::Ecc),
+                65 => Ok(Self::Ecdsa),
+                _ => Err(value),
+            }
+        }
+    }
+
+    impl From<InterruptSource> for u32 {
+        fn from(source: InterruptSource) -> u32 {
+            source as u32
+        }
+    }
+}
+
+/// The Wi-Fi, BT, IEEE 802.15.4 and coex sources by name, with their
+/// number on the chips having them
+pub const WIRELESS: &[(&str, &[(&str, u32)])] = &[
+    ("BLE_SEC", &[("esp32c2", 10), ("esp32c6", 10), ("esp32h2", 20), ("esp32c5", 10), ("esp32c61", 10)]),
+    ("BLE_TIMER", &[("esp32c2", 9), ("esp32c6", 9), ("esp32h2", 19), ("esp32c5", 9), ("esp32c61", 9)]),
+    ("BT_BB", &[("esp32", 4), ("esp32s2", 5), ("esp32s3", 5), ("esp32c2", 5), ("esp32c3", 5), ("esp32c6", 5), ("esp32h2", 16), ("esp32c5", 5), ("esp32c61", 5)]),
+    ("BT_BB_NMI", &[("esp32", 5), ("esp32s2", 6), ("esp32s3", 6), ("esp32c2", 6), ("esp32c3", 6), ("esp32c6", 6), ("esp32h2", 17), ("esp32c5", 6), ("esp32c61", 6)]),
+    ("BT_MAC", &[("esp32", 3), ("esp32s2", 4), ("esp32s3", 4), ("esp32c2", 4), ("esp32c3", 4), ("esp32c6", 4), ("esp32h2", 15), ("esp32c5", 4), ("esp32c61", 4)]),
+    ("COEX", &[("esp32c2", 8), ("esp32c6", 8), ("esp32h2", 18), ("esp32c5", 8), ("esp32c61", 8)]),
+    ("LP_BLE_TIMER", &[("esp32h2", 3)]),
+    ("RWBLE", &[("esp32", 7), ("esp32s2", 8), ("esp32s3", 8), ("esp32c3", 8)]),
+    ("RWBLE_NMI", &[("esp32", 9), ("esp32s2", 10), ("esp32s3", 10), ("esp32c3", 10)]),
+    ("RWBT", &[("esp32", 6), ("esp32s2", 7), ("esp32s3", 7), ("esp32c3", 7)]),
+    ("RWBT_NMI", &[("esp32", 8), ("esp32s2", 9), ("esp32s3", 9), ("esp32c3", 9)]),
+    ("WIFI_BB", &[("esp32", 2), ("esp32s2", 3), ("esp32s3", 3), ("esp32c2", 3), ("esp32c3", 3), ("esp32c6", 3), ("esp32c5", 3), ("esp32c61", 3)]),
+    ("WIFI_MAC", &[("esp32", 0), ("esp32s2", 0), ("esp32s3", 0), ("esp32c2", 0), ("esp32c3", 0), ("esp32c6", 0), ("esp32c5", 0), ("esp32c61", 0)]),
+    ("WIFI_MAC_NMI", &[("esp32", 1), ("esp32s2", 1), ("esp32s3", 1), ("esp32c2", 1), ("esp32c3", 1), ("esp32c6", 1), ("esp32c5", 1), ("esp32c61", 1)]),
+    ("WIFI_PWR", &[("esp32s2", 2), ("esp32s3", 2), ("esp32c2", 2), ("esp32c3", 2), ("esp32c6", 2), ("esp32c5", 2), ("esp32c61", 2)]),
+    ("ZB_MAC", &[("esp32c6", 12), ("esp32h2", 21), ("esp32c5", 12), ("esp32c61", 12)]),
+];
@@ -0,0 +1,106 @@
+# ESP-IDF v5.5.1 wireless libraries for the esp32c6
+# Generated by `cargo xtask package-files`
+
+if(TARGET esp_wireless::esp_wireless)
+  return()
+endif()
+
+set(esp-wireless-esp32c6_VERSION 5.5.1)
+get_filename_component(_esp_wireless_root "${CMAKE_CURRENT_LIST_DIR}/../.." ABSOLUTE)
+
+add_library(esp_wireless::headers INTERFACE IMPORTED)
+set_target_properties(esp_wireless::headers PROPERTIES
+  INTERFACE_INCLUDE_DIRECTORIES "${_esp_wireless_root}/include;${_esp_wireless_root}/include/esp32c6;${_esp_wireless_root}/patch"
+)
+
+add_library(esp_wireless::ble_app STATIC IMPORTED)
+add_library(esp_wireless::coexist STATIC IMPORTED)
+add_library(esp_wireless::wapi STATIC IMPORTED)
+add_library(esp_wireless::smartconfig STATIC IMPORTED)
+add_library(esp_wireless::core STATIC IMPORTED)
+add_library(esp_wireless::pp STATIC IMPORTED)
+add_library(esp_wireless::espnow STATIC IMPORTED)
+add_library(esp_wireless::mesh STATIC IMPORTED)
+add_library(esp_wireless::net80211 STATIC IMPORTED)
+add_library(esp_wireless::btbb STATIC IMPORTED)
+add_library(esp_wireless::phy STATIC IMPORTED)
+add_library(esp_wireless::printf STATIC IMPORTED)
+add_library(esp_wireless::regulatory STATIC IMPORTED)
+
+set_target_properties(esp_wireless::ble_app PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libble_app.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers;esp_wireless::btbb;esp_wireless::printf"
+)
+
+set_target_properties(esp_wireless::coexist PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libcoexist.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers"
+)
+
+set_target_properties(esp_wireless::wapi PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libwapi.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers;esp_wireless::pp;esp_wireless::net80211"
+)
+
+set_target_properties(esp_wireless::smartconfig PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libsmartconfig.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers;esp_wireless::net80211"
+)
+
+set_target_properties(esp_wireless::core PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libcore.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers;esp_wireless::net80211"
+  IMPORTED_LINK_INTERFACE_MULTIPLICITY 5
+)
+
+set_target_properties(esp_wireless::pp PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libpp.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers;esp_wireless::net80211;esp_wireless::phy;esp_wireless::printf"
+  IMPORTED_LINK_INTERFACE_MULTIPLICITY 5
+)
+
+set_target_properties(esp_wireless::espnow PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libespnow.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers;esp_wireless::pp;esp_wireless::net80211"
+  IMPORTED_LINK_INTERFACE_MULTIPLICITY 5
+)
+
+set_target_properties(esp_wireless::mesh PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libmesh.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers;esp_wireless::core;esp_wireless::pp;esp_wireless::net80211;esp_wireless::printf"
+  IMPORTED_LINK_INTERFACE_MULTIPLICITY 5
+)
+
+set_target_properties(esp_wireless::net80211 PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libnet80211.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers;esp_wireless::core;esp_wireless::pp;esp_wireless::espnow;esp_wireless::mesh;esp_wireless::phy;esp_wireless::printf;esp_wireless::regulatory"
+  IMPORTED_LINK_INTERFACE_MULTIPLICITY 5
+)
+
+set_target_properties(esp_wireless::btbb PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libbtbb.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers;esp_wireless::phy"
+)
+
+set_target_properties(esp_wireless::phy PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libphy.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers;esp_wireless::printf"
+)
+
+set_target_properties(esp_wireless::printf PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libprintf.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers"
+)
+
+set_target_properties(esp_wireless::regulatory PROPERTIES
+  IMPORTED_LOCATION "${CMAKE_CURRENT_LIST_DIR}/libregulatory.a"
+  INTERFACE_LINK_LIBRARIES "esp_wireless::headers"
+)
+
+# All libraries of the chip
+add_library(esp_wireless::esp_wireless INTERFACE IMPORTED)
+set_target_properties(esp_wireless::esp_wireless PROPERTIES
+  INTERFACE_LINK_LIBRARIES "esp_wireless::ble_app;esp_wireless::coexist;esp_wireless::wapi;esp_wireless::smartconfig;esp_wireless::core;esp_wireless::pp;esp_wireless::espnow;esp_wireless::mesh;esp_wireless::net80211;esp_wireless::btbb;esp_wireless::phy;esp_wireless::printf;esp_wireless::regulatory"
+)
+
+unset(_esp_wireless_root)
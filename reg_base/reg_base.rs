@@ -0,0 +1,535 @@
+//! Peripheral register base addresses of ESP-IDF v5.5.1, generated by
+//! `cargo xtask reg-base`
+
+pub const IDF_VERSION: &str = "ESP-IDF v5.5.1";
+
+pub mod esp32 {
+    pub const DR_REG_AES_BASE: u32 = 0x3ff01000;
+    pub const DR_REG_APB_CTRL_BASE: u32 = 0x3ff66000;
+    pub const DR_REG_BB_BASE: u32 = 0x3ff5d000;
+    pub const DR_REG_BT_BASE: u32 = 0x3ff51000;
+    pub const DR_REG_CAN_BASE: u32 = 0x3ff6b000;
+    pub const DR_REG_DPORT_BASE: u32 = 0x3ff00000;
+    pub const DR_REG_EFUSE_BASE: u32 = 0x3ff5a000;
+    pub const DR_REG_EMAC_BASE: u32 = 0x3ff69000;
+    pub const DR_REG_FE2_BASE: u32 = 0x3ff45000;
+    pub const DR_REG_FE_BASE: u32 = 0x3ff46000;
+    pub const DR_REG_FRC_TIMER_BASE: u32 = 0x3ff47000;
+    pub const DR_REG_GPIO_BASE: u32 = 0x3ff44000;
+    pub const DR_REG_GPIO_SD_BASE: u32 = 0x3ff44f00;
+    pub const DR_REG_HINF_BASE: u32 = 0x3ff4b000;
+    pub const DR_REG_I2C1_EXT_BASE: u32 = 0x3ff67000;
+    pub const DR_REG_I2C_EXT_BASE: u32 = 0x3ff53000;
+    pub const DR_REG_I2S1_BASE: u32 = 0x3ff6d000;
+    pub const DR_REG_I2S_BASE: u32 = 0x3ff4f000;
+    pub const DR_REG_IO_MUX_BASE: u32 = 0x3ff49000;
+    pub const DR_REG_LEDC_BASE: u32 = 0x3ff59000;
+    pub const DR_REG_NRX_BASE: u32 = 0x3ff5cc00;
+    pub const DR_REG_PCNT_BASE: u32 = 0x3ff57000;
+    pub const DR_REG_PWM0_BASE: u32 = 0x3ff5e000;
+    pub const DR_REG_PWM1_BASE: u32 = 0x3ff6c000;
+    pub const DR_REG_RMT_BASE: u32 = 0x3ff56000;
+    pub const DR_REG_RSA_BASE: u32 = 0x3ff02000;
+    pub const DR_REG_RTCCNTL_BASE: u32 = 0x3ff48000;
+    pub const DR_REG_RTCIO_BASE: u32 = 0x3ff48400;
+    pub const DR_REG_RTCMEM0_BASE: u32 = 0x3ff61000;
+    pub const DR_REG_RTCMEM1_BASE: u32 = 0x3ff62000;
+    pub const DR_REG_RTCMEM2_BASE: u32 = 0x3ff63000;
+    pub const DR_REG_RTC_I2C_BASE: u32 = 0x3ff48c00;
+    pub const DR_REG_SDMMC_BASE: u32 = 0x3ff68000;
+    pub const DR_REG_SENS_BASE: u32 = 0x3ff48800;
+    pub const DR_REG_SHA_BASE: u32 = 0x3ff03000;
+    pub const DR_REG_SLCHOST_BASE: u32 = 0x3ff55000;
+    pub const DR_REG_SLC_BASE: u32 = 0x3ff58000;
+    pub const DR_REG_SPI0_BASE: u32 = 0x3ff43000;
+    pub const DR_REG_SPI1_BASE: u32 = 0x3ff42000;
+    pub const DR_REG_SPI2_BASE: u32 = 0x3ff64000;
+    pub const DR_REG_SPI3_BASE: u32 = 0x3ff65000;
+    pub const DR_REG_SPI_ENCRYPT_BASE: u32 = 0x3ff5b000;
+    pub const DR_REG_SYSCON_BASE: u32 = 0x3ff66000;
+    pub const DR_REG_TIMERGROUP0_BASE: u32 = 0x3ff5f000;
+    pub const DR_REG_TIMERGROUP1_BASE: u32 = 0x3ff60000;
+    pub const DR_REG_UART1_BASE: u32 = 0x3ff50000;
+    pub const DR_REG_UART2_BASE: u32 = 0x3ff6e000;
+    pub const DR_REG_UART_BASE: u32 = 0x3ff40000;
+    pub const DR_REG_UHCI0_BASE: u32 = 0x3ff54000;
+    pub const DR_REG_UHCI1_BASE: u32 = 0x3ff4c000;
+}
+
+pub mod esp32s2 {
+    pub const DR_REG_AES_BASE: u32 = 0x6003a000;
+    pub const DR_REG_APB_CTRL_BASE: u32 = 0x3f426000;
+    pub const DR_REG_APB_SARADC_BASE: u32 = 0x3f440000;
+    pub const DR_REG_ASSIST_DEBUG_BASE: u32 = 0x3f4ce000;
+    pub const DR_REG_BB_BASE: u32 = 0x3f41d000;
+    pub const DR_REG_CP_BASE: u32 = 0x3f4c3000;
+    pub const DR_REG_CRYPTO_DMA_BASE: u32 = 0x6003f000;
+    pub const DR_REG_DEDICATED_GPIO_BASE: u32 = 0x3f4cf000;
+    pub const DR_REG_DIGITAL_SIGNATURE_BASE: u32 = 0x6003d000;
+    pub const DR_REG_DMA_COPY_BASE: u32 = 0x3f4c3000;
+    pub const DR_REG_EFUSE_BASE: u32 = 0x3f41a000;
+    pub const DR_REG_EXTMEM_BASE: u32 = 0x61800000;
+    pub const DR_REG_FE2_BASE: u32 = 0x3f405000;
+    pub const DR_REG_FE_BASE: u32 = 0x3f406000;
+    pub const DR_REG_FRC_TIMER_BASE: u32 = 0x3f407000;
+    pub const DR_REG_GPIO_BASE: u32 = 0x3f404000;
+    pub const DR_REG_GPIO_SD_BASE: u32 = 0x3f404f00;
+    pub const DR_REG_HINF_BASE: u32 = 0x3f40b000;
+    pub const DR_REG_HMAC_BASE: u32 = 0x6003e000;
+    pub const DR_REG_I2C1_EXT_BASE: u32 = 0x3f427000;
+    pub const DR_REG_I2C_EXT_BASE: u32 = 0x3f413000;
+    pub const DR_REG_I2S_BASE: u32 = 0x3f40f000;
+    pub const DR_REG_INTERRUPT_BASE: u32 = 0x3f4c2000;
+    pub const DR_REG_INTRUSION_BASE: u32 = 0x3f4d0000;
+    pub const DR_REG_IO_MUX_BASE: u32 = 0x3f409000;
+    pub const DR_REG_LEDC_BASE: u32 = 0x3f419000;
+    pub const DR_REG_NRX_BASE: u32 = 0x3f41cc00;
+    pub const DR_REG_PCNT_BASE: u32 = 0x3f417000;
+    pub const DR_REG_RMT_BASE: u32 = 0x3f416000;
+    pub const DR_REG_RSA_BASE: u32 = 0x6003c000;
+    pub const DR_REG_RTCCNTL_BASE: u32 = 0x3f408000;
+    pub const DR_REG_RTCIO_BASE: u32 = 0x3f408400;
+    pub const DR_REG_RTC_I2C_BASE: u32 = 0x3f408c00;
+    pub const DR_REG_RTC_SLOWMEM_BASE: u32 = 0x3f421000;
+    pub const DR_REG_SENSITIVE_BASE: u32 = 0x3f4c1000;
+    pub const DR_REG_SENS_BASE: u32 = 0x3f408800;
+    pub const DR_REG_SHA_BASE: u32 = 0x6003b000;
+    pub const DR_REG_SLCHOST_BASE: u32 = 0x3f415000;
+    pub const DR_REG_SLC_BASE: u32 = 0x3f418000;
+    pub const DR_REG_SPI0_BASE: u32 = 0x3f403000;
+    pub const DR_REG_SPI1_BASE: u32 = 0x3f402000;
+    pub const DR_REG_SPI2_BASE: u32 = 0x3f424000;
+    pub const DR_REG_SPI3_BASE: u32 = 0x3f425000;
+    pub const DR_REG_SYSCON_BASE: u32 = 0x3f426000;
+    pub const DR_REG_SYSTEM_BASE: u32 = 0x3f4c0000;
+    pub const DR_REG_SYSTIMER_BASE: u32 = 0x3f423000;
+    pub const DR_REG_TIMERGROUP0_BASE: u32 = 0x3f41f000;
+    pub const DR_REG_TIMERGROUP1_BASE: u32 = 0x3f420000;
+    pub const DR_REG_UART1_BASE: u32 = 0x3f410000;
+    pub const DR_REG_UART_BASE: u32 = 0x3f400000;
+    pub const DR_REG_UHCI0_BASE: u32 = 0x3f414000;
+    pub const DR_REG_USB_BASE: u32 = 0x60080000;
+    pub const DR_REG_USB_WRAP_BASE: u32 = 0x3f439000;
+}
+
+pub mod esp32s3 {
+    pub const DR_REG_AES_BASE: u32 = 0x6003a000;
+    pub const DR_REG_APB_CTRL_BASE: u32 = 0x60026000;
+    pub const DR_REG_APB_SARADC_BASE: u32 = 0x60040000;
+    pub const DR_REG_ASSIST_DEBUG_BASE: u32 = 0x600ce000;
+    pub const DR_REG_BB_BASE: u32 = 0x6001d000;
+    pub const DR_REG_BT_BASE: u32 = 0x60011000;
+    pub const DR_REG_DIGITAL_SIGNATURE_BASE: u32 = 0x6003d000;
+    pub const DR_REG_EFUSE_BASE: u32 = 0x60007000;
+    pub const DR_REG_EXTMEM_BASE: u32 = 0x600c4000;
+    pub const DR_REG_FE2_BASE: u32 = 0x60005000;
+    pub const DR_REG_FE_BASE: u32 = 0x60006000;
+    pub const DR_REG_GDMA_BASE: u32 = 0x6003f000;
+    pub const DR_REG_GPIO_BASE: u32 = 0x60004000;
+    pub const DR_REG_GPIO_SD_BASE: u32 = 0x60004f00;
+    pub const DR_REG_HINF_BASE: u32 = 0x6000b000;
+    pub const DR_REG_HMAC_BASE: u32 = 0x6003e000;
+    pub const DR_REG_I2C1_EXT_BASE: u32 = 0x60027000;
+    pub const DR_REG_I2C_EXT_BASE: u32 = 0x60013000;
+    pub const DR_REG_I2S1_BASE: u32 = 0x6002d000;
+    pub const DR_REG_I2S_BASE: u32 = 0x6000f000;
+    pub const DR_REG_INTERRUPT_BASE: u32 = 0x600c2000;
+    pub const DR_REG_IO_MUX_BASE: u32 = 0x60009000;
+    pub const DR_REG_LCD_CAM_BASE: u32 = 0x60041000;
+    pub const DR_REG_LEDC_BASE: u32 = 0x60019000;
+    pub const DR_REG_NRX_BASE: u32 = 0x6001cc00;
+    pub const DR_REG_PCNT_BASE: u32 = 0x60017000;
+    pub const DR_REG_PERI_BACKUP_BASE: u32 = 0x6002a000;
+    pub const DR_REG_PWM0_BASE: u32 = 0x6001e000;
+    pub const DR_REG_PWM1_BASE: u32 = 0x6002c000;
+    pub const DR_REG_RMT_BASE: u32 = 0x60016000;
+    pub const DR_REG_RSA_BASE: u32 = 0x6003c000;
+    pub const DR_REG_RTCCNTL_BASE: u32 = 0x60008000;
+    pub const DR_REG_RTCIO_BASE: u32 = 0x60008400;
+    pub const DR_REG_RTC_I2C_BASE: u32 = 0x60008c00;
+    pub const DR_REG_RTC_SLOWMEM_BASE: u32 = 0x60021000;
+    pub const DR_REG_SDMMC_BASE: u32 = 0x60028000;
+    pub const DR_REG_SENSITIVE_BASE: u32 = 0x600c1000;
+    pub const DR_REG_SENS_BASE: u32 = 0x60008800;
+    pub const DR_REG_SHA_BASE: u32 = 0x6003b000;
+    pub const DR_REG_SLCHOST_BASE: u32 = 0x60015000;
+    pub const DR_REG_SLC_BASE: u32 = 0x60018000;
+    pub const DR_REG_SPI0_BASE: u32 = 0x60003000;
+    pub const DR_REG_SPI1_BASE: u32 = 0x60002000;
+    pub const DR_REG_SPI2_BASE: u32 = 0x60024000;
+    pub const DR_REG_SPI3_BASE: u32 = 0x60025000;
+    pub const DR_REG_SYSCON_BASE: u32 = 0x60026000;
+    pub const DR_REG_SYSTEM_BASE: u32 = 0x600c0000;
+    pub const DR_REG_SYSTIMER_BASE: u32 = 0x60023000;
+    pub const DR_REG_TIMERGROUP0_BASE: u32 = 0x6001f000;
+    pub const DR_REG_TIMERGROUP1_BASE: u32 = 0x60020000;
+    pub const DR_REG_TWAI_BASE: u32 = 0x6002b000;
+    pub const DR_REG_UART1_BASE: u32 = 0x60010000;
+    pub const DR_REG_UART2_BASE: u32 = 0x6002e000;
+    pub const DR_REG_UART_BASE: u32 = 0x60000000;
+    pub const DR_REG_UHCI0_BASE: u32 = 0x60014000;
+    pub const DR_REG_UHCI1_BASE: u32 = 0x6000c000;
+    pub const DR_REG_USB_SERIAL_JTAG_BASE: u32 = 0x60038000;
+    pub const DR_REG_USB_WRAP_BASE: u32 = 0x60039000;
+    pub const DR_REG_WCL_BASE: u32 = 0x600d0000;
+}
+
+pub mod esp32c2 {
+    pub const DR_REG_AES_XTS_BASE: u32 = 0x600cc000;
+    pub const DR_REG_APB_SARADC_BASE: u32 = 0x60040000;
+    pub const DR_REG_ASSIST_DEBUG_BASE: u32 = 0x600ce000;
+    pub const DR_REG_BB_BASE: u32 = 0x6001d000;
+    pub const DR_REG_BLE_SEC_BASE: u32 = 0x6004c000;
+    pub const DR_REG_BLE_TIMER_BASE: u32 = 0x6004b800;
+    pub const DR_REG_COEX_BIT_BASE: u32 = 0x6004c400;
+    pub const DR_REG_DEDICATED_GPIO_BASE: u32 = 0x600cf000;
+    pub const DR_REG_ECC_MULT_BASE: u32 = 0x6003e000;
+    pub const DR_REG_EFUSE_BASE: u32 = 0x60008800;
+    pub const DR_REG_ETM_BIT_BASE: u32 = 0x6004b000;
+    pub const DR_REG_EXTMEM_BASE: u32 = 0x600c4000;
+    pub const DR_REG_FE2_BASE: u32 = 0x60005000;
+    pub const DR_REG_FE_BASE: u32 = 0x60006000;
+    pub const DR_REG_GDMA_BASE: u32 = 0x6003f000;
+    pub const DR_REG_GPIO_BASE: u32 = 0x60004000;
+    pub const DR_REG_I2C_EXT_BASE: u32 = 0x60013000;
+    pub const DR_REG_I2C_MST_BASE: u32 = 0x6004e800;
+    pub const DR_REG_INTERRUPT_BASE: u32 = 0x600c2000;
+    pub const DR_REG_IO_MUX_BASE: u32 = 0x60009000;
+    pub const DR_REG_LEDC_BASE: u32 = 0x60019000;
+    pub const DR_REG_MODEM_CLKRST_BASE: u32 = 0x6004d800;
+    pub const DR_REG_NRX_BASE: u32 = 0x6001cc00;
+    pub const DR_REG_RTCCNTL_BASE: u32 = 0x60008000;
+    pub const DR_REG_RTC_I2C_BASE: u32 = 0x6000e000;
+    pub const DR_REG_SENSITIVE_BASE: u32 = 0x600c1000;
+    pub const DR_REG_SHA_BASE: u32 = 0x6003b000;
+    pub const DR_REG_SPI0_BASE: u32 = 0x60003000;
+    pub const DR_REG_SPI1_BASE: u32 = 0x60002000;
+    pub const DR_REG_SPI2_BASE: u32 = 0x60024000;
+    pub const DR_REG_SYSCON_BASE: u32 = 0x60026000;
+    pub const DR_REG_SYSTEM_BASE: u32 = 0x600c0000;
+    pub const DR_REG_SYSTIMER_BASE: u32 = 0x60023000;
+    pub const DR_REG_TIMERGROUP0_BASE: u32 = 0x6001f000;
+    pub const DR_REG_UART1_BASE: u32 = 0x60010000;
+    pub const DR_REG_UART_BASE: u32 = 0x60000000;
+    pub const DR_REG_WDEVLE_BASE: u32 = 0x60045000;
+    pub const DR_REG_WORLD_CNTL_BASE: u32 = 0x600d0000;
+    pub const DR_REG_XTS_AES_BASE: u32 = 0x600cc000;
+}
+
+pub mod esp32c3 {
+    pub const DR_REG_AES_BASE: u32 = 0x6003a000;
+    pub const DR_REG_AES_XTS_BASE: u32 = 0x600cc000;
+    pub const DR_REG_APB_CTRL_BASE: u32 = 0x60026000;
+    pub const DR_REG_APB_SARADC_BASE: u32 = 0x60040000;
+    pub const DR_REG_ASSIST_DEBUG_BASE: u32 = 0x600ce000;
+    pub const DR_REG_BB_BASE: u32 = 0x6001d000;
+    pub const DR_REG_DEDICATED_GPIO_BASE: u32 = 0x600cf000;
+    pub const DR_REG_DIGITAL_SIGNATURE_BASE: u32 = 0x6003d000;
+    pub const DR_REG_EFUSE_BASE: u32 = 0x60008800;
+    pub const DR_REG_EXTMEM_BASE: u32 = 0x600c4000;
+    pub const DR_REG_FE2_BASE: u32 = 0x60005000;
+    pub const DR_REG_FE_BASE: u32 = 0x60006000;
+    pub const DR_REG_GDMA_BASE: u32 = 0x6003f000;
+    pub const DR_REG_GPIO_BASE: u32 = 0x60004000;
+    pub const DR_REG_HMAC_BASE: u32 = 0x6003e000;
+    pub const DR_REG_I2C_EXT_BASE: u32 = 0x60013000;
+    pub const DR_REG_I2S_BASE: u32 = 0x6002d000;
+    pub const DR_REG_INTERRUPT_BASE: u32 = 0x600c2000;
+    pub const DR_REG_IO_MUX_BASE: u32 = 0x60009000;
+    pub const DR_REG_LEDC_BASE: u32 = 0x60019000;
+    pub const DR_REG_NRX_BASE: u32 = 0x6001cc00;
+    pub const DR_REG_RMT_BASE: u32 = 0x60016000;
+    pub const DR_REG_RSA_BASE: u32 = 0x6003c000;
+    pub const DR_REG_RTCCNTL_BASE: u32 = 0x60008000;
+    pub const DR_REG_RTC_I2C_BASE: u32 = 0x6000e000;
+    pub const DR_REG_SENSITIVE_BASE: u32 = 0x600c1000;
+    pub const DR_REG_SHA_BASE: u32 = 0x6003b000;
+    pub const DR_REG_SPI0_BASE: u32 = 0x60003000;
+    pub const DR_REG_SPI1_BASE: u32 = 0x60002000;
+    pub const DR_REG_SPI2_BASE: u32 = 0x60024000;
+    pub const DR_REG_SYSCON_BASE: u32 = 0x60026000;
+    pub const DR_REG_SYSTEM_BASE: u32 = 0x600c0000;
+    pub const DR_REG_SYSTIMER_BASE: u32 = 0x60023000;
+    pub const DR_REG_TIMERGROUP0_BASE: u32 = 0x6001f000;
+    pub const DR_REG_TIMERGROUP1_BASE: u32 = 0x60020000;
+    pub const DR_REG_TWAI_BASE: u32 = 0x6002b000;
+    pub const DR_REG_UART1_BASE: u32 = 0x60010000;
+    pub const DR_REG_UART_BASE: u32 = 0x60000000;
+    pub const DR_REG_UHCI0_BASE: u32 = 0x60014000;
+    pub const DR_REG_USB_SERIAL_JTAG_BASE: u32 = 0x60043000;
+    pub const DR_REG_WORLD_CNTL_BASE: u32 = 0x600d0000;
+    pub const DR_REG_XTS_AES_BASE: u32 = 0x600cc000;
+}
+
+pub mod esp32c6 {
+    pub const DR_REG_AES_BASE: u32 = 0x60088000;
+    pub const DR_REG_APB_SARADC_BASE: u32 = 0x6000e000;
+    pub const DR_REG_ASSIST_DEBUG_BASE: u32 = 0x600c2000;
+    pub const DR_REG_ATOMIC_BASE: u32 = 0x60011000;
+    pub const DR_REG_CLINT_M_BASE: u32 = 0x20001800;
+    pub const DR_REG_CLINT_U_BASE: u32 = 0x20001c00;
+    pub const DR_REG_CPU_BUS_MONITOR_BASE: u32 = 0x600c2000;
+    pub const DR_REG_DS_BASE: u32 = 0x6008c000;
+    pub const DR_REG_ECC_MULT_BASE: u32 = 0x6008b000;
+    pub const DR_REG_EFUSE_BASE: u32 = 0x600b0800;
+    pub const DR_REG_EXTMEM_BASE: u32 = 0x600c8000;
+    pub const DR_REG_GDMA_BASE: u32 = 0x60080000;
+    pub const DR_REG_GPIO_BASE: u32 = 0x60091000;
+    pub const DR_REG_GPIO_EXT_BASE: u32 = 0x60091f00;
+    pub const DR_REG_HINF_BASE: u32 = 0x60016000;
+    pub const DR_REG_HMAC_BASE: u32 = 0x6008d000;
+    pub const DR_REG_HP_APM_BASE: u32 = 0x60099000;
+    pub const DR_REG_HP_SYSTEM_BASE: u32 = 0x60095000;
+    pub const DR_REG_I2C_ANA_MST_BASE: u32 = 0x600af800;
+    pub const DR_REG_I2C_EXT_BASE: u32 = 0x60004000;
+    pub const DR_REG_I2S_BASE: u32 = 0x6000c000;
+    pub const DR_REG_INTMTX_BASE: u32 = 0x60010000;
+    pub const DR_REG_INTPRI_BASE: u32 = 0x600c5000;
+    pub const DR_REG_IO_MUX_BASE: u32 = 0x60090000;
+    pub const DR_REG_LEDC_BASE: u32 = 0x60007000;
+    pub const DR_REG_LPPERI_BASE: u32 = 0x600b2800;
+    pub const DR_REG_LP_ANALOG_PERI_BASE: u32 = 0x600b2c00;
+    pub const DR_REG_LP_AON_BASE: u32 = 0x600b1000;
+    pub const DR_REG_LP_APM0_BASE: u32 = 0x60099800;
+    pub const DR_REG_LP_APM_BASE: u32 = 0x600b3800;
+    pub const DR_REG_LP_CLKRST_BASE: u32 = 0x600b0400;
+    pub const DR_REG_LP_I2C_ANA_MST_BASE: u32 = 0x600b2400;
+    pub const DR_REG_LP_I2C_BASE: u32 = 0x600b1800;
+    pub const DR_REG_LP_IO_BASE: u32 = 0x600b2000;
+    pub const DR_REG_LP_TEE_BASE: u32 = 0x600b3400;
+    pub const DR_REG_LP_TIMER_BASE: u32 = 0x600b0c00;
+    pub const DR_REG_LP_UART_BASE: u32 = 0x600b1400;
+    pub const DR_REG_LP_WDT_BASE: u32 = 0x600b1c00;
+    pub const DR_REG_MCPWM_BASE: u32 = 0x60014000;
+    pub const DR_REG_MEM_MONITOR_BASE: u32 = 0x60092000;
+    pub const DR_REG_MISC_BASE: u32 = 0x6009f000;
+    pub const DR_REG_OPT_DEBUG_BASE: u32 = 0x600b3c00;
+    pub const DR_REG_PARL_IO_BASE: u32 = 0x60015000;
+    pub const DR_REG_PAU_BASE: u32 = 0x60093000;
+    pub const DR_REG_PCNT_BASE: u32 = 0x60012000;
+    pub const DR_REG_PCR_BASE: u32 = 0x60096000;
+    pub const DR_REG_PLIC_MX_BASE: u32 = 0x20001000;
+    pub const DR_REG_PLIC_UX_BASE: u32 = 0x20001400;
+    pub const DR_REG_PMU_BASE: u32 = 0x600b0000;
+    pub const DR_REG_PVT_MONITOR_BASE: u32 = 0x60019000;
+    pub const DR_REG_RMT_BASE: u32 = 0x60006000;
+    pub const DR_REG_RSA_BASE: u32 = 0x6008a000;
+    pub const DR_REG_SHA_BASE: u32 = 0x60089000;
+    pub const DR_REG_SLCHOST_BASE: u32 = 0x60018000;
+    pub const DR_REG_SLC_BASE: u32 = 0x60017000;
+    pub const DR_REG_SOC_ETM_BASE: u32 = 0x60013000;
+    pub const DR_REG_SPI0_BASE: u32 = 0x60002000;
+    pub const DR_REG_SPI1_BASE: u32 = 0x60003000;
+    pub const DR_REG_SPI2_BASE: u32 = 0x60081000;
+    pub const DR_REG_SYSTIMER_BASE: u32 = 0x6000a000;
+    pub const DR_REG_TEE_BASE: u32 = 0x60098000;
+    pub const DR_REG_TIMERGROUP0_BASE: u32 = 0x60008000;
+    pub const DR_REG_TIMERGROUP1_BASE: u32 = 0x60009000;
+    pub const DR_REG_TRACE_BASE: u32 = 0x600c0000;
+    pub const DR_REG_TWAI0_BASE: u32 = 0x6000b000;
+    pub const DR_REG_TWAI1_BASE: u32 = 0x6000d000;
+    pub const DR_REG_UART1_BASE: u32 = 0x60001000;
+    pub const DR_REG_UART_BASE: u32 = 0x60000000;
+    pub const DR_REG_UHCI0_BASE: u32 = 0x60005000;
+    pub const DR_REG_USB_SERIAL_JTAG_BASE: u32 = 0x6000f000;
+}
+
+pub mod esp32h2 {
+    pub const DR_REG_AES_BASE: u32 = 0x60088000;
+    pub const DR_REG_APB_SARADC_BASE: u32 = 0x6000e000;
+    pub const DR_REG_ASSIST_DEBUG_BASE: u32 = 0x600c2000;
+    pub const DR_REG_CACHE_BASE: u32 = 0x600c8000;
+    pub const DR_REG_DS_BASE: u32 = 0x6008c000;
+    pub const DR_REG_ECC_MULT_BASE: u32 = 0x6008b000;
+    pub const DR_REG_ECDSA_BASE: u32 = 0x6008e000;
+    pub const DR_REG_EFUSE_BASE: u32 = 0x600b0800;
+    pub const DR_REG_GDMA_BASE: u32 = 0x60080000;
+    pub const DR_REG_GPIO_BASE: u32 = 0x60091000;
+    pub const DR_REG_GPIO_EXT_BASE: u32 = 0x60091f00;
+    pub const DR_REG_HMAC_BASE: u32 = 0x6008d000;
+    pub const DR_REG_HP_APM_BASE: u32 = 0x60099000;
+    pub const DR_REG_HP_SYSTEM_BASE: u32 = 0x60095000;
+    pub const DR_REG_I2C_ANA_MST_BASE: u32 = 0x600ad800;
+    pub const DR_REG_I2C_EXT0_BASE: u32 = 0x60004000;
+    pub const DR_REG_I2C_EXT1_BASE: u32 = 0x60005000;
+    pub const DR_REG_I2S_BASE: u32 = 0x6000d000;
+    pub const DR_REG_INTMTX_BASE: u32 = 0x60010000;
+    pub const DR_REG_INTPRI_BASE: u32 = 0x600c5000;
+    pub const DR_REG_IO_MUX_BASE: u32 = 0x60090000;
+    pub const DR_REG_LEDC_BASE: u32 = 0x60008000;
+    pub const DR_REG_LPPERI_BASE: u32 = 0x600b2800;
+    pub const DR_REG_LP_ANALOG_PERI_BASE: u32 = 0x600b2c00;
+    pub const DR_REG_LP_AON_BASE: u32 = 0x600b1000;
+    pub const DR_REG_LP_APM0_BASE: u32 = 0x60099800;
+    pub const DR_REG_LP_APM_BASE: u32 = 0x600b3800;
+    pub const DR_REG_LP_CLKRST_BASE: u32 = 0x600b0400;
+    pub const DR_REG_LP_IO_BASE: u32 = 0x600b2000;
+    pub const DR_REG_LP_TIMER_BASE: u32 = 0x600b0c00;
+    pub const DR_REG_LP_WDT_BASE: u32 = 0x600b1c00;
+    pub const DR_REG_MCPWM_BASE: u32 = 0x60014000;
+    pub const DR_REG_MEM_ACS_MONITOR_BASE: u32 = 0x60092000;
+    pub const DR_REG_MEM_MONITOR_BASE: u32 = 0x60092000;
+    pub const DR_REG_MISC_BASE: u32 = 0x6009f000;
+    pub const DR_REG_OTP_DEBUG_BASE: u32 = 0x600b3c00;
+    pub const DR_REG_PARL_IO_BASE: u32 = 0x60015000;
+    pub const DR_REG_PAU_BASE: u32 = 0x60093000;
+    pub const DR_REG_PCNT_BASE: u32 = 0x60012000;
+    pub const DR_REG_PCR_BASE: u32 = 0x60096000;
+    pub const DR_REG_PMU_BASE: u32 = 0x600b0000;
+    pub const DR_REG_PVT_MONITOR_BASE: u32 = 0x60019000;
+    pub const DR_REG_REGDMA_BASE: u32 = 0x60093000;
+    pub const DR_REG_RMT_BASE: u32 = 0x60007000;
+    pub const DR_REG_RSA_BASE: u32 = 0x6008a000;
+    pub const DR_REG_SHA_BASE: u32 = 0x60089000;
+    pub const DR_REG_SOC_ETM_BASE: u32 = 0x60013000;
+    pub const DR_REG_SPI0_BASE: u32 = 0x60002000;
+    pub const DR_REG_SPI1_BASE: u32 = 0x60003000;
+    pub const DR_REG_SPI2_BASE: u32 = 0x60081000;
+    pub const DR_REG_SYSTIMER_BASE: u32 = 0x6000b000;
+    pub const DR_REG_TEE_BASE: u32 = 0x60098000;
+    pub const DR_REG_TIMERGROUP0_BASE: u32 = 0x60009000;
+    pub const DR_REG_TIMERGROUP1_BASE: u32 = 0x6000a000;
+    pub const DR_REG_TRACE_BASE: u32 = 0x600c0000;
+    pub const DR_REG_TWAI_BASE: u32 = 0x6000c000;
+    pub const DR_REG_UART1_BASE: u32 = 0x60001000;
+    pub const DR_REG_UART_BASE: u32 = 0x60000000;
+    pub const DR_REG_UHCI0_BASE: u32 = 0x60006000;
+    pub const DR_REG_USB_SERIAL_JTAG_BASE: u32 = 0x6000f000;
+}
+
+pub mod esp32c5 {
+    pub const DR_REG_AES_BASE: u32 = 0x60088000;
+    pub const DR_REG_AHB_DMA_BASE: u32 = 0x60080000;
+    pub const DR_REG_APB_SARADC_BASE: u32 = 0x6000e000;
+    pub const DR_REG_BITSCRAMBLER_BASE: u32 = 0x60082000;
+    pub const DR_REG_BUS_MONITOR_BASE: u32 = 0x600c2000;
+    pub const DR_REG_CACHE_BASE: u32 = 0x600c8000;
+    pub const DR_REG_CLINT_M_BASE: u32 = 0x20000000;
+    pub const DR_REG_CPU_APM_BASE: u32 = 0x6009a000;
+    pub const DR_REG_DS_BASE: u32 = 0x6008c000;
+    pub const DR_REG_ECC_MULT_BASE: u32 = 0x6008b000;
+    pub const DR_REG_ECDSA_BASE: u32 = 0x6008e000;
+    pub const DR_REG_EFUSE_BASE: u32 = 0x600b4800;
+    pub const DR_REG_GPIO_BASE: u32 = 0x60091000;
+    pub const DR_REG_GPIO_EXT_BASE: u32 = 0x60091e00;
+    pub const DR_REG_HINF_BASE: u32 = 0x60016000;
+    pub const DR_REG_HMAC_BASE: u32 = 0x6008d000;
+    pub const DR_REG_HP_APM_BASE: u32 = 0x60099000;
+    pub const DR_REG_HP_SYSTEM_BASE: u32 = 0x60095000;
+    pub const DR_REG_HUK_BASE: u32 = 0x600b3000;
+    pub const DR_REG_I2C_ANA_MST_BASE: u32 = 0x600af800;
+    pub const DR_REG_I2C_BASE: u32 = 0x60004000;
+    pub const DR_REG_I2S_BASE: u32 = 0x6000c000;
+    pub const DR_REG_INTERRUPT_CORE0_BASE: u32 = 0x60010000;
+    pub const DR_REG_INTMTX_BASE: u32 = 0x60010000;
+    pub const DR_REG_INTPRI_BASE: u32 = 0x600c5000;
+    pub const DR_REG_IO_MUX_BASE: u32 = 0x60090000;
+    pub const DR_REG_KEYMNG_BASE: u32 = 0x60087000;
+    pub const DR_REG_LEDC_BASE: u32 = 0x60007000;
+    pub const DR_REG_LPPERI_BASE: u32 = 0x600b2800;
+    pub const DR_REG_LP_ANA_BASE: u32 = 0x600b2c00;
+    pub const DR_REG_LP_AON_BASE: u32 = 0x600b1000;
+    pub const DR_REG_LP_APM0_BASE: u32 = 0x60099800;
+    pub const DR_REG_LP_APM_BASE: u32 = 0x600b3800;
+    pub const DR_REG_LP_CLKRST_BASE: u32 = 0x600b0400;
+    pub const DR_REG_LP_GPIO_BASE: u32 = 0x600b4400;
+    pub const DR_REG_LP_I2C_ANA_MST_BASE: u32 = 0x600b2400;
+    pub const DR_REG_LP_I2C_BASE: u32 = 0x600b1800;
+    pub const DR_REG_LP_IO_MUX_BASE: u32 = 0x600b4000;
+    pub const DR_REG_LP_TEE_BASE: u32 = 0x600b3400;
+    pub const DR_REG_LP_TIMER_BASE: u32 = 0x600b0c00;
+    pub const DR_REG_LP_UART_BASE: u32 = 0x600b1400;
+    pub const DR_REG_LP_WDT_BASE: u32 = 0x600b1c00;
+    pub const DR_REG_MCPWM_BASE: u32 = 0x60014000;
+    pub const DR_REG_MEM_MONITOR_BASE: u32 = 0x60092000;
+    pub const DR_REG_MISC_BASE: u32 = 0x6009f000;
+    pub const DR_REG_MODEM0_BASE: u32 = 0x600a0000;
+    pub const DR_REG_MODEM1_BASE: u32 = 0x600ac000;
+    pub const DR_REG_MODEM_PWR0_BASE: u32 = 0x600ad000;
+    pub const DR_REG_MODEM_PWR1_BASE: u32 = 0x600af000;
+    pub const DR_REG_OTP_DEBUG_BASE: u32 = 0x600b4d00;
+    pub const DR_REG_PARL_IO_BASE: u32 = 0x60015000;
+    pub const DR_REG_PAU_BASE: u32 = 0x60093000;
+    pub const DR_REG_PCNT_BASE: u32 = 0x60012000;
+    pub const DR_REG_PCR_BASE: u32 = 0x60096000;
+    pub const DR_REG_PMU_BASE: u32 = 0x600b0000;
+    pub const DR_REG_PSRAM_MEM_MONITOR_BASE: u32 = 0x6001a000;
+    pub const DR_REG_PVT_MONITOR_BASE: u32 = 0x60019000;
+    pub const DR_REG_RMT_BASE: u32 = 0x60006000;
+    pub const DR_REG_RSA_BASE: u32 = 0x6008a000;
+    pub const DR_REG_SHA_BASE: u32 = 0x60089000;
+    pub const DR_REG_SLCHOST_BASE: u32 = 0x60018000;
+    pub const DR_REG_SLC_BASE: u32 = 0x60017000;
+    pub const DR_REG_SOC_ETM_BASE: u32 = 0x60013000;
+    pub const DR_REG_SPI2_BASE: u32 = 0x60081000;
+    pub const DR_REG_SPIMEM0_BASE: u32 = 0x60002000;
+    pub const DR_REG_SPIMEM1_BASE: u32 = 0x60003000;
+    pub const DR_REG_SYSTIMER_BASE: u32 = 0x6000a000;
+    pub const DR_REG_TEE_BASE: u32 = 0x60098000;
+    pub const DR_REG_TIMERG0_BASE: u32 = 0x60008000;
+    pub const DR_REG_TIMERG1_BASE: u32 = 0x60009000;
+    pub const DR_REG_TRACE_BASE: u32 = 0x600c0000;
+    pub const DR_REG_TWAI0_BASE: u32 = 0x6000b000;
+    pub const DR_REG_TWAI1_BASE: u32 = 0x6000d000;
+    pub const DR_REG_UART0_BASE: u32 = 0x60000000;
+    pub const DR_REG_UART1_BASE: u32 = 0x60001000;
+    pub const DR_REG_UHCI_BASE: u32 = 0x60005000;
+    pub const DR_REG_USB_SERIAL_JTAG_BASE: u32 = 0x6000f000;
+}
+
+pub mod esp32c61 {
+    pub const DR_REG_AHB_DMA_BASE: u32 = 0x60080000;
+    pub const DR_REG_BUS_MONITOR_BASE: u32 = 0x600c2000;
+    pub const DR_REG_CACHE_BASE: u32 = 0x600c8000;
+    pub const DR_REG_CLINT_M_BASE: u32 = 0x20000000;
+    pub const DR_REG_CPU_APM_REG_BASE: u32 = 0x6009a000;
+    pub const DR_REG_ECC_MULT_BASE: u32 = 0x6008b000;
+    pub const DR_REG_ECDSA_BASE: u32 = 0x6008e000;
+    pub const DR_REG_EFUSE0_BASE: u32 = 0x600b4800;
+    pub const DR_REG_GPIO_BASE: u32 = 0x60091000;
+    pub const DR_REG_GPIO_EXT_BASE: u32 = 0x60091e00;
+    pub const DR_REG_HINF_BASE: u32 = 0x60016000;
+    pub const DR_REG_HOST_BASE: u32 = 0x60018000;
+    pub const DR_REG_HP_APM_BASE: u32 = 0x60099000;
+    pub const DR_REG_HP_SYSTEM_BASE: u32 = 0x60095000;
+    pub const DR_REG_I2C_ANA_MST_BASE: u32 = 0x600af800;
+    pub const DR_REG_I2C_BASE: u32 = 0x60004000;
+    pub const DR_REG_I2S_BASE: u32 = 0x6000c000;
+    pub const DR_REG_INTERRUPT_CORE0_BASE: u32 = 0x60010000;
+    pub const DR_REG_INTMTX_BASE: u32 = 0x60010000;
+    pub const DR_REG_INTPRI_BASE: u32 = 0x600c5000;
+    pub const DR_REG_IO_MUX_BASE: u32 = 0x60090000;
+    pub const DR_REG_LEDC_BASE: u32 = 0x60007000;
+    pub const DR_REG_LPPERI_BASE: u32 = 0x600b2800;
+    pub const DR_REG_LP_ANA_BASE: u32 = 0x600b2c00;
+    pub const DR_REG_LP_AON_BASE: u32 = 0x600b1000;
+    pub const DR_REG_LP_APM_BASE: u32 = 0x600b3800;
+    pub const DR_REG_LP_CLKRST_BASE: u32 = 0x600b0400;
+    pub const DR_REG_LP_GPIO_BASE: u32 = 0x600b4400;
+    pub const DR_REG_LP_IO_MUX_BASE: u32 = 0x600b4000;
+    pub const DR_REG_LP_TEE_BASE: u32 = 0x600b3400;
+    pub const DR_REG_LP_TIMER_BASE: u32 = 0x600b0c00;
+    pub const DR_REG_LP_WDT_BASE: u32 = 0x600b1c00;
+    pub const DR_REG_MEM_MONITOR_BASE: u32 = 0x60092000;
+    pub const DR_REG_MSPI0_BASE: u32 = 0x60002000;
+    pub const DR_REG_MSPI1_BASE: u32 = 0x60003000;
+    pub const DR_REG_OTP_DEBUG_BASE: u32 = 0x600b4c00;
+    pub const DR_REG_PAU_BASE: u32 = 0x60093000;
+    pub const DR_REG_PCR_BASE: u32 = 0x60096000;
+    pub const DR_REG_PMU_BASE: u32 = 0x600b0000;
+    pub const DR_REG_PVT_BASE: u32 = 0x60019000;
+    pub const DR_REG_SARADC_BASE: u32 = 0x6000e000;
+    pub const DR_REG_SHA_BASE: u32 = 0x60089000;
+    pub const DR_REG_SLC_BASE: u32 = 0x60017000;
+    pub const DR_REG_SOC_ETM_BASE: u32 = 0x60013000;
+    pub const DR_REG_SPI2_BASE: u32 = 0x60081000;
+    pub const DR_REG_SYSTIMER_BASE: u32 = 0x6000a000;
+    pub const DR_REG_TEE_BASE: u32 = 0x60098000;
+    pub const DR_REG_TIMG0_BASE: u32 = 0x60008000;
+    pub const DR_REG_TIMG1_BASE: u32 = 0x60009000;
+    pub const DR_REG_TRACE_BASE: u32 = 0x600c0000;
+    pub const DR_REG_UART0_BASE: u32 = 0x60000000;
+    pub const DR_REG_UART1_BASE: u32 = 0x60001000;
+    pub const DR_REG_UART2_BASE: u32 = 0x60006000;
+    pub const DR_REG_USB_SERIAL_JTAG_BASE: u32 = 0x6000f000;
+}
@@ -0,0 +1,57 @@
+// Generated by `cargo xtask gen-sys-crates`, do not edit
+
+use std::{env, path::Path};
+
+/// The vendored libraries in link order, with the feature enabling them.
+/// Libraries depending on each other share a group.
+const LIBRARIES: &[&[(&str, Option<&str>)]] = &[
+    &[("ble_app", Some("ble"))],
+    &[("coexist", None)],
+    &[("wapi", Some("wapi"))],
+    &[("smartconfig", Some("smartconfig"))],
+    &[
+        ("core", Some("wifi")),
+        ("pp", Some("wifi")),
+        ("espnow", Some("espnow")),
+        ("mesh", Some("mesh")),
+        ("net80211", Some("wifi")),
+    ],
+    &[("btbb", None)],
+    &[("phy", None)],
+    &[("printf", None)],
+    &[("regulatory", Some("wifi"))],
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // host builds of the workspace only check the crate
+    if env::var("CARGO_CFG_TARGET_ARCH").unwrap() != "riscv32" {
+        return;
+    }
+
+    let libs = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("../../libs/esp32c5");
+    println!("cargo:rustc-link-search=native={}", libs.display());
+    for group in LIBRARIES {
+        let enabled: Vec<&str> = group
+            .iter()
+            .filter(|(_, feature)| {
+                feature.is_none_or(|feature| {
+                    env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase())).is_some()
+                })
+            })
+            .map(|(name, _)| *name)
+            .collect();
+        for name in &enabled {
+            println!("cargo:rerun-if-changed={}/lib{name}.a", libs.display());
+        }
+        // `rustc-link-arg` doesn't reach the crates linking this one, so
+        // instead of `--start-group` a group is repeated once per library
+        let passes = if enabled.len() > 1 { enabled.len() } else { 1 };
+        for _ in 0..passes {
+            for name in &enabled {
+                println!("cargo:rustc-link-lib=static={name}");
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+//! Links the ESP-IDF v5.5.1 wireless libraries of the esp32c6 vendored in
+//! `libs/esp32c6/`. Optional libraries are enabled by the cargo features.
+//!
+//! Generated by `cargo xtask gen-sys-crates`, do not edit.
+
+#![no_std]
+
+/// The ESP-IDF version the libraries come from
+pub const IDF_VERSION: &str = "ESP-IDF v5.5.1";
@@ -0,0 +1,432 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs,
+    path::Path,
+    process::Command,
+};
+
+use crate::{
+    cexpr::{self, Macros},
+    cheader::{self, AggregateKind, DeclarationKind},
+    CHIPS,
+};
+
+/// Headers evaluated before all others, their macros are not reported as
+/// part of every header
+const BASE_HEADERS: &[&str] = &["sdkconfig.h", "soc/soc_caps.h"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Kind {
+    Function,
+    Struct,
+    Union,
+    Enum,
+    Macro,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Kind::Function => "function",
+            Kind::Struct => "struct",
+            Kind::Union => "union",
+            Kind::Enum => "enum",
+            Kind::Macro => "macro",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A function, aggregate or macro of a chip's headers
+#[derive(Debug, PartialEq, Eq)]
+struct Item {
+    header: String,
+    /// The prototype or macro body, the members of aggregates
+    members: Vec<String>,
+}
+
+type Api = BTreeMap<(Kind, String), Item>;
+
+/// Report the functions, structs, unions, enums and macros of `include/`
+/// added, removed or changed between two git refs, `new` defaults to the
+/// working tree
+pub fn diff(old: &str, new: Option<&str>) {
+    let old_tree = read_tree(Some(old));
+    let new_tree = read_tree(new);
+    println!("API changes {old} -> {}", new.unwrap_or("working tree"));
+
+    let chips: Vec<&str> = CHIPS
+        .iter()
+        .copied()
+        .filter(|chip| has_chip(&old_tree, chip) || has_chip(&new_tree, chip))
+        .collect();
+
+    // chips sharing headers usually share the changes
+    let mut changes: Vec<(Vec<&str>, Vec<String>)> = Vec::new();
+    let mut layout: Vec<(Vec<&str>, Vec<String>)> = Vec::new();
+    for chip in chips {
+        let (lines, layout_lines) = match (has_chip(&old_tree, chip), has_chip(&new_tree, chip)) {
+            (true, true) => {
+                let (old, new) = (api(&old_tree, chip), api(&new_tree, chip));
+                (item_changes(&old.0, &new.0), layout_changes(&old, &new))
+            }
+            (false, _) => (vec!["new chip".to_string()], Vec::new()),
+            (_, false) => (vec!["removed chip".to_string()], Vec::new()),
+        };
+        for (groups, lines) in [(&mut changes, lines), (&mut layout, layout_lines)] {
+            if lines.is_empty() {
+                continue;
+            }
+            match groups.iter_mut().find(|(_, other)| *other == lines) {
+                Some((chips, _)) => chips.push(chip),
+                None => groups.push((vec![chip], lines)),
+            }
+        }
+    }
+
+    if changes.is_empty() {
+        println!("No changes");
+    }
+    print_groups(&changes);
+    if !layout.is_empty() {
+        println!("\nLayout changes:");
+        print_groups(&layout);
+    }
+}
+
+fn print_groups(groups: &[(Vec<&str>, Vec<String>)]) {
+    for (chips, lines) in groups {
+        println!("{}:", chips.join(", "));
+        for line in lines {
+            println!("  {line}");
+        }
+    }
+}
+
+/// Header path relative to `include/` → content, of a git ref or the
+/// working tree
+fn read_tree(rev: Option<&str>) -> BTreeMap<String, String> {
+    let mut tree = BTreeMap::new();
+    let Some(rev) = rev else {
+        collect(Path::new("./include"), "", &mut tree);
+        return tree;
+    };
+
+    let files = git(&["ls-tree", "-r", "--name-only", rev, "--", "include"]);
+    for path in files.lines().filter(|path| path.ends_with(".h")) {
+        let header = path.strip_prefix("include/").unwrap();
+        tree.insert(header.to_string(), git(&["show", &format!("{rev}:{path}")]));
+    }
+    tree
+}
+
+fn collect(dir: &Path, prefix: &str, tree: &mut BTreeMap<String, String>) {
+    for entry in fs::read_dir(dir).unwrap_or_else(|_| panic!("Unable to read {dir:?}")) {
+        let entry = entry.unwrap();
+        let name = format!("{prefix}{}", entry.file_name().to_string_lossy());
+        let path = entry.path();
+        if path.is_dir() {
+            collect(&path, &format!("{name}/"), tree);
+        } else if name.ends_with(".h") {
+            let source =
+                fs::read_to_string(&path).unwrap_or_else(|_| panic!("Unable to read {path:?}"));
+            tree.insert(name, source);
+        }
+    }
+}
+
+fn git(args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .expect("Unable to run git");
+    if !output.status.success() {
+        eprintln!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        std::process::exit(1);
+    }
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+fn has_chip(tree: &BTreeMap<String, String>, chip: &str) -> bool {
+    let prefix = format!("{chip}/");
+    tree.keys().any(|header| header.starts_with(&prefix))
+}
+
+/// Headers visible to a chip as include path → content: `include/` without
+/// the other chips' directories, and `include/<chip>/`
+fn chip_headers<'a>(tree: &'a BTreeMap<String, String>, chip: &str) -> Vec<(&'a str, &'a str)> {
+    let mut headers: Vec<(&str, &str)> = tree
+        .iter()
+        .filter_map(|(path, source)| {
+            let header = match path.split_once('/') {
+                Some((dir, header)) if dir == chip => header,
+                Some((dir, _)) if CHIPS.contains(&dir) => return None,
+                _ => path,
+            };
+            Some((header, source.as_str()))
+        })
+        .collect();
+    headers.sort();
+    headers
+}
+
+/// The API of a chip and the macros of all its headers
+fn api(tree: &BTreeMap<String, String>, chip: &str) -> (Api, Macros) {
+    let headers = chip_headers(tree, chip);
+
+    let mut base = Macros::new();
+    for name in BASE_HEADERS {
+        if let Some((_, source)) = headers.iter().find(|(header, _)| header == name) {
+            base.preprocess(source);
+        }
+    }
+    let base_definitions: BTreeMap<&str, String> = base.definitions().collect();
+
+    let mut api = Api::new();
+    let mut all = base.clone();
+    for (header, source) in headers {
+        let is_base = BASE_HEADERS.contains(&header);
+        let mut macros = if is_base { Macros::new() } else { base.clone() };
+        let source = macros.preprocess(source);
+        all.extend(&macros);
+
+        let mut insert = |kind, name: &str, members| {
+            api.entry((kind, name.to_string())).or_insert(Item {
+                header: header.to_string(),
+                members,
+            });
+        };
+        for declaration in cheader::declarations(&source, header) {
+            if declaration.kind == DeclarationKind::Function {
+                insert(Kind::Function, &declaration.name, vec![declaration.text]);
+            }
+        }
+        for aggregate in cheader::aggregates(&source) {
+            let kind = match aggregate.kind {
+                AggregateKind::Struct => Kind::Struct,
+                AggregateKind::Union => Kind::Union,
+                AggregateKind::Enum => Kind::Enum,
+            };
+            insert(kind, &aggregate.name, aggregate.members);
+        }
+        for (name, body) in macros.definitions() {
+            // include guards and flags
+            if body.is_empty() || (!is_base && base_definitions.get(name) == Some(&body)) {
+                continue;
+            }
+            insert(Kind::Macro, name, vec![body]);
+        }
+    }
+    (api, all)
+}
+
+fn item_changes(old: &Api, new: &Api) -> Vec<String> {
+    let mut lines = Vec::new();
+    for ((kind, name), item) in new {
+        match old.get(&(*kind, name.clone())) {
+            None => lines.push(format!("+ {kind} {name} ({})", item.header)),
+            Some(old_item) if old_item.members != item.members => {
+                lines.push(format!("~ {kind} {name} ({})", item.header));
+                lines.extend(member_changes(&old_item.members, &item.members));
+            }
+            Some(_) => (),
+        }
+    }
+    for ((kind, name), item) in old {
+        if !new.contains_key(&(*kind, name.clone())) {
+            lines.push(format!("- {kind} {name} ({})", item.header));
+        }
+    }
+    lines
+}
+
+fn member_changes(old: &[String], new: &[String]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for member in old.iter().filter(|member| !new.contains(member)) {
+        lines.push(format!("    - {member}"));
+    }
+    for member in new.iter().filter(|member| !old.contains(member)) {
+        lines.push(format!("    + {member}"));
+    }
+    lines
+}
+
+/// Structs and unions in both trees whose members changed in type, order or
+/// number, with the macros expanded, the ones embedding them and the enums
+/// whose values changed
+fn layout_changes(old: &(Api, Macros), new: &(Api, Macros)) -> Vec<String> {
+    let aggregates = |api: &Api| -> BTreeMap<String, Vec<String>> {
+        api.iter()
+            .filter(|((kind, _), _)| matches!(kind, Kind::Struct | Kind::Union))
+            .map(|((_, name), item)| (name.clone(), item.members.clone()))
+            .collect()
+    };
+    let (old_values, new_values) = (enum_values(old), enum_values(new));
+    let ((old, old_macros), (new, new_macros)) =
+        ((aggregates(&old.0), &old.1), (aggregates(&new.0), &new.1));
+
+    let mut changed: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for (name, members) in &new {
+        let Some(old_members) = old.get(name) else {
+            continue;
+        };
+        let old_layout: Vec<String> = old_members
+            .iter()
+            .map(|m| signature(m, old_macros))
+            .collect();
+        let layout: Vec<String> = members.iter().map(|m| signature(m, new_macros)).collect();
+        if old_layout != layout {
+            let mut lines = member_changes(old_members, members);
+            // only the macros they use changed
+            if lines.is_empty() {
+                for (member, (old, new)) in members.iter().zip(old_layout.iter().zip(&layout)) {
+                    if old != new {
+                        lines.push(format!("    ~ {member}: {old} -> {new}"));
+                    }
+                }
+            }
+            changed.insert(name, lines);
+        }
+    }
+
+    // members of a changed type change the layout of their aggregate too
+    loop {
+        let embedding: BTreeSet<(&str, &str)> = new
+            .iter()
+            .filter(|(name, _)| old.contains_key(*name) && !changed.contains_key(name.as_str()))
+            .flat_map(|(name, members)| {
+                let changed = &changed;
+                members.iter().filter_map(move |member| {
+                    let inner = changed.keys().find(|inner| {
+                        !member.contains('*') && cexpr::tokenize(member).iter().any(|t| t == *inner)
+                    })?;
+                    Some((name.as_str(), *inner))
+                })
+            })
+            .collect();
+        if embedding.is_empty() {
+            break;
+        }
+        for (name, inner) in embedding {
+            changed
+                .entry(name)
+                .or_default()
+                .push(format!("    layout of {inner} changed"));
+        }
+    }
+
+    // enumerators passed by value change their meaning, not the layout
+    for (name, values) in &new_values {
+        let Some(old_values) = old_values.get(name) else {
+            continue;
+        };
+        for (enumerator, value) in values {
+            match old_values.get(enumerator) {
+                Some(old_value) if old_value != value => {
+                    changed.entry(name).or_default().push(format!(
+                        "    {enumerator} = {} -> {}",
+                        show(*old_value),
+                        show(*value)
+                    ))
+                }
+                _ => (),
+            }
+        }
+    }
+
+    changed
+        .into_iter()
+        .flat_map(|(name, lines)| std::iter::once(format!("~ {name}")).chain(lines))
+        .collect()
+}
+
+fn show(value: Option<i64>) -> String {
+    value.map_or("?".to_string(), |value| value.to_string())
+}
+
+/// The values of the enumerators of every enum, `None` where they don't
+/// evaluate
+fn enum_values((api, macros): &(Api, Macros)) -> BTreeMap<String, BTreeMap<String, Option<i64>>> {
+    let enums: Vec<(&String, &Item)> = api
+        .iter()
+        .filter(|((kind, _), _)| *kind == Kind::Enum)
+        .map(|((_, name), item)| (name, item))
+        .collect();
+
+    // enumerators may be defined by others of a later enum, evaluate until
+    // no more resolve
+    let mut macros = macros.clone();
+    let mut values = BTreeMap::new();
+    loop {
+        let mut resolved = 0;
+        for (name, item) in &enums {
+            let mut enumerators = BTreeMap::new();
+            let mut next = Some(0);
+            for member in &item.members {
+                let (enumerator, value) = match member.split_once('=') {
+                    Some((enumerator, expression)) => {
+                        (enumerator.trim(), macros.eval(expression.trim()).ok())
+                    }
+                    None => (member.trim(), next),
+                };
+                if let Some(value) = value {
+                    if !macros.is_defined(enumerator) {
+                        macros.define(enumerator, &value.to_string());
+                        resolved += 1;
+                    }
+                }
+                next = value.map(|value| value + 1);
+                enumerators.insert(enumerator.to_string(), value);
+            }
+            values.insert(name.to_string(), enumerators);
+        }
+        if resolved == 0 {
+            return values;
+        }
+    }
+}
+
+/// A member declaration with its name replaced by `_`, the macros expanded
+/// and array sizes and bit widths evaluated, equal for members taking the
+/// same space
+fn signature(member: &str, macros: &Macros) -> String {
+    let name = cheader::member_name(member).unwrap_or_default();
+    let tokens: Vec<String> = cexpr::tokenize(member)
+        .into_iter()
+        .map(|token| {
+            if token == name {
+                "_".to_string()
+            } else {
+                token
+            }
+        })
+        .collect();
+    let tokens = macros.expand_tokens(&tokens);
+
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let end = match tokens[i].as_str() {
+            "[" => tokens[i..].iter().position(|t| t == "]").map(|end| i + end),
+            ":" => Some(tokens.len()),
+            _ => None,
+        };
+        out.push(tokens[i].clone());
+        let Some(end) = end else {
+            i += 1;
+            continue;
+        };
+        let size = &tokens[i + 1..end];
+        match macros.eval(&size.join(" ")) {
+            Ok(value) => out.push(value.to_string()),
+            Err(_) => out.extend(size.iter().cloned()),
+        }
+        out.extend(tokens.get(end).cloned());
+        i = end + 1;
+    }
+    out.join(" ")
+}
@@ -0,0 +1,465 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
+
+use object::{
+    build::elf::Builder, elf, read::archive::ArchiveFile, Object, ObjectSection, ObjectSymbol,
+    RelocationFlags, RelocationTarget, SectionKind, SymbolSection,
+};
+
+/// Symbol tables of a static library
+#[derive(Debug)]
+pub struct Archive {
+    /// File name, e.g. `libpp.a`
+    pub name: String,
+    pub members: Vec<Member>,
+}
+
+/// An object file inside of an archive
+#[derive(Debug)]
+pub struct Member {
+    pub name: String,
+    pub defined: Vec<Symbol>,
+    pub undefined: Vec<String>,
+    /// The undefined symbols which are only referenced weakly
+    pub weak_undefined: BTreeSet<String>,
+    /// Undefined symbols referenced by call relocations, only known for
+    /// RISC-V where calls have their own relocation types
+    pub called: BTreeSet<String>,
+    pub size: Size,
+}
+
+/// Bytes of the allocated sections of a member
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Size {
+    pub text: u64,
+    /// Initialized data, including read-only data
+    pub data: u64,
+    pub bss: u64,
+}
+
+impl std::ops::AddAssign for Size {
+    fn add_assign(&mut self, other: Size) {
+        self.text += other.text;
+        self.data += other.data;
+        self.bss += other.bss;
+    }
+}
+
+/// A global symbol defined by a member
+#[derive(Debug)]
+pub struct Symbol {
+    pub name: String,
+    pub binding: Binding,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Binding {
+    Strong,
+    Weak,
+    Common,
+}
+
+impl Archive {
+    pub fn read(path: &Path) -> Archive {
+        let members = read_members(path)
+            .into_iter()
+            .filter_map(|(name, data)| {
+                let member = Member::parse(&name, &data);
+                if member.is_none() {
+                    // some blobs contain empty placeholder members
+                    log::debug!("Skipping {name} in {path:?}");
+                }
+                member
+            })
+            .collect();
+
+        Archive {
+            name: path.file_name().unwrap().to_string_lossy().to_string(),
+            members,
+        }
+    }
+
+    /// All archives vendored for the given chip, sorted by name
+    pub fn read_chip(chip: &str) -> Vec<Archive> {
+        let mut paths: Vec<_> = fs::read_dir(format!("./libs/{chip}"))
+            .unwrap_or_else(|_| panic!("No libraries vendored for {chip}"))
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "a"))
+            .collect();
+        paths.sort();
+
+        paths.iter().map(|path| Archive::read(path)).collect()
+    }
+}
+
+impl Member {
+    /// Global symbols of an object file, `None` if it is not one
+    fn parse(name: &str, data: &[u8]) -> Option<Member> {
+        let object = object::File::parse(data).ok()?;
+
+        let mut defined = Vec::new();
+        let mut undefined = Vec::new();
+        let mut weak_undefined = BTreeSet::new();
+        let mut size = Size::default();
+        for symbol in object.symbols() {
+            if !symbol.is_global() {
+                continue;
+            }
+            let Ok(sym_name) = symbol.name() else {
+                continue;
+            };
+            if sym_name.is_empty() {
+                continue;
+            }
+
+            match symbol.section() {
+                SymbolSection::Undefined => {
+                    if symbol.is_weak() {
+                        weak_undefined.insert(sym_name.to_string());
+                    }
+                    undefined.push(sym_name.to_string());
+                }
+                SymbolSection::Common => {
+                    // allocated in .bss by the linker
+                    size.bss += symbol.size();
+                    defined.push(Symbol {
+                        name: sym_name.to_string(),
+                        binding: Binding::Common,
+                    })
+                }
+                _ => defined.push(Symbol {
+                    name: sym_name.to_string(),
+                    binding: if symbol.is_weak() {
+                        Binding::Weak
+                    } else {
+                        Binding::Strong
+                    },
+                }),
+            }
+        }
+
+        let mut called = BTreeSet::new();
+        for section in object.sections() {
+            for (_, relocation) in section.relocations() {
+                let RelocationFlags::Elf { r_type } = relocation.flags() else {
+                    continue;
+                };
+                if !matches!(
+                    r_type,
+                    elf::R_RISCV_CALL | elf::R_RISCV_CALL_PLT | elf::R_RISCV_JAL
+                ) || object.architecture() != object::Architecture::Riscv32
+                {
+                    continue;
+                }
+                if let RelocationTarget::Symbol(index) = relocation.target() {
+                    if let Ok(symbol) = object.symbol_by_index(index) {
+                        if symbol.is_undefined() {
+                            called.insert(symbol.name().unwrap_or_default().to_string());
+                        }
+                    }
+                }
+            }
+        }
+
+        for section in object.sections() {
+            match section.kind() {
+                SectionKind::Text => size.text += section.size(),
+                SectionKind::Data
+                | SectionKind::ReadOnlyData
+                | SectionKind::ReadOnlyDataWithRel
+                | SectionKind::ReadOnlyString => size.data += section.size(),
+                SectionKind::UninitializedData => size.bss += section.size(),
+                _ => (),
+            }
+        }
+
+        Some(Member {
+            name: name.to_string(),
+            defined,
+            undefined,
+            weak_undefined,
+            called,
+            size,
+        })
+    }
+}
+
+/// Raw members of an archive in their original order
+pub fn read_members(path: &Path) -> Vec<(String, Vec<u8>)> {
+    let data = fs::read(path).unwrap_or_else(|_| panic!("Unable to read {path:?}"));
+    let archive =
+        ArchiveFile::parse(&*data).unwrap_or_else(|_| panic!("{path:?} is not an archive"));
+
+    archive
+        .members()
+        .map(|member| {
+            let member = member.expect("Corrupt archive member");
+            let name = String::from_utf8_lossy(member.name()).to_string();
+            let data = member.data(&*data).expect("Corrupt archive member");
+            (name, data.to_vec())
+        })
+        .collect()
+}
+
+/// Write a GNU archive with a symbol index, like `ar -rcD` does
+pub fn write_members(path: &Path, members: &[(String, Vec<u8>)]) {
+    const HEADER_LEN: usize = 60;
+
+    fn header(out: &mut Vec<u8>, name: &str, size: usize) {
+        let start = out.len();
+        out.extend_from_slice(
+            format!("{name:<16}{:<12}{:<6}{:<6}{:<8}{size:<10}`\n", 0, 0, 0, 644).as_bytes(),
+        );
+        assert_eq!(out.len() - start, HEADER_LEN);
+    }
+
+    fn padded(len: usize) -> usize {
+        len + len % 2
+    }
+
+    // long member names go into the `//` table
+    let mut long_names = Vec::new();
+    let header_names: Vec<String> = members
+        .iter()
+        .map(|(name, _)| {
+            if name.len() < 16 {
+                format!("{name}/")
+            } else {
+                let offset = long_names.len();
+                long_names.extend_from_slice(name.as_bytes());
+                long_names.extend_from_slice(b"/\n");
+                format!("/{offset}")
+            }
+        })
+        .collect();
+
+    let symbols: Vec<Vec<String>> = members
+        .iter()
+        .map(|(name, data)| {
+            Member::parse(name, data)
+                .map(|member| member.defined.into_iter().map(|sym| sym.name).collect())
+                .unwrap_or_default()
+        })
+        .collect();
+    let symbol_count: usize = symbols.iter().map(Vec::len).sum();
+    let index_len =
+        4 + 4 * symbol_count + symbols.iter().flatten().map(|s| s.len() + 1).sum::<usize>();
+
+    let mut offset = 8 + HEADER_LEN + padded(index_len);
+    if !long_names.is_empty() {
+        offset += HEADER_LEN + padded(long_names.len());
+    }
+    let mut member_offsets = Vec::new();
+    for (_, data) in members {
+        member_offsets.push(offset as u32);
+        offset += HEADER_LEN + padded(data.len());
+    }
+
+    let mut out = b"!<arch>\n".to_vec();
+
+    header(&mut out, "/", index_len);
+    out.extend_from_slice(&(symbol_count as u32).to_be_bytes());
+    for (member_offset, names) in member_offsets.iter().zip(&symbols) {
+        for _ in names {
+            out.extend_from_slice(&member_offset.to_be_bytes());
+        }
+    }
+    for name in symbols.iter().flatten() {
+        out.extend_from_slice(name.as_bytes());
+        out.push(0);
+    }
+    if index_len % 2 == 1 {
+        out.push(b'\n');
+    }
+
+    if !long_names.is_empty() {
+        header(&mut out, "//", long_names.len());
+        out.extend_from_slice(&long_names);
+        if long_names.len() % 2 == 1 {
+            out.push(b'\n');
+        }
+    }
+
+    for ((_, data), name) in members.iter().zip(&header_names) {
+        header(&mut out, name, data.len());
+        out.extend_from_slice(data);
+        if data.len() % 2 == 1 {
+            out.push(b'\n');
+        }
+    }
+
+    fs::write(path, out).unwrap_or_else(|_| panic!("Unable to write {path:?}"));
+}
+
+/// Rename global symbols (defined and undefined) of an ELF object file and
+/// turn the definitions in `localize` into local symbols
+///
+/// Returns `None` if nothing had to be changed.
+pub fn rename_symbols(
+    data: &[u8],
+    renames: &BTreeMap<String, String>,
+    localize: &BTreeSet<String>,
+) -> Option<Vec<u8>> {
+    let mut builder = Builder::read(data).ok()?;
+
+    let mut changed = false;
+    for symbol in builder.symbols.iter_mut() {
+        if symbol.st_bind() == elf::STB_LOCAL {
+            continue;
+        }
+        let name = String::from_utf8_lossy(&symbol.name).to_string();
+
+        if let Some(new_name) = renames.get(&name) {
+            symbol.name = new_name.clone().into_bytes().into();
+            changed = true;
+        } else if localize.contains(&name) && symbol.section.is_some() {
+            let st_type = symbol.st_type();
+            symbol.set_st_info(elf::STB_LOCAL, st_type);
+            changed = true;
+        }
+    }
+
+    if !changed {
+        return None;
+    }
+
+    let mut out = Vec::new();
+    builder
+        .write(&mut out)
+        .expect("Unable to write object file");
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use object::build::elf::SectionData;
+
+    use super::*;
+
+    /// A RISC-V object file defining `defined` in `.text` and referencing
+    /// `undefined`
+    fn object_file(defined: &[&str], undefined: &[&str]) -> Vec<u8> {
+        let mut builder = Builder::new(object::Endianness::Little, false);
+        builder.header.e_type = elf::ET_REL;
+        builder.header.e_machine = elf::EM_RISCV;
+
+        let section = builder.sections.add();
+        section.name = b".shstrtab"[..].into();
+        section.sh_type = elf::SHT_STRTAB;
+        section.data = SectionData::SectionString;
+
+        let section = builder.sections.add();
+        section.name = b".text"[..].into();
+        section.sh_type = elf::SHT_PROGBITS;
+        section.sh_flags = (elf::SHF_ALLOC | elf::SHF_EXECINSTR).into();
+        section.sh_addralign = 4;
+        section.data = SectionData::Data(vec![0; 6].into());
+        let text = section.id();
+
+        let section = builder.sections.add();
+        section.name = b".strtab"[..].into();
+        section.sh_type = elf::SHT_STRTAB;
+        section.data = SectionData::String;
+        let strtab = section.id();
+
+        let section = builder.sections.add();
+        section.name = b".symtab"[..].into();
+        section.sh_type = elf::SHT_SYMTAB;
+        section.sh_link_section = Some(strtab);
+        section.data = SectionData::Symbol;
+
+        for (names, section, st_type) in [
+            (defined, Some(text), elf::STT_FUNC),
+            (undefined, None, elf::STT_NOTYPE),
+        ] {
+            for name in names {
+                let symbol = builder.symbols.add();
+                symbol.name = name.as_bytes().to_vec().into();
+                symbol.section = section;
+                symbol.set_st_info(elf::STB_GLOBAL, st_type);
+            }
+        }
+
+        let mut out = Vec::new();
+        builder.write(&mut out).unwrap();
+        out
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("xtask-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn written_archives_read_back() {
+        let members = vec![
+            (
+                "wifi.o".to_string(),
+                object_file(&["esp_wifi_init"], &["malloc"]),
+            ),
+            (
+                "a_long_member_name.o".to_string(),
+                object_file(&["pp_post", "lmac_init"], &[]),
+            ),
+            ("odd.txt".to_string(), b"odd".to_vec()),
+        ];
+        let path = temp_path("written.a");
+        write_members(&path, &members);
+
+        assert_eq!(read_members(&path), members);
+
+        let data = fs::read(&path).unwrap();
+        let archive = ArchiveFile::parse(&*data).unwrap();
+        let index: Vec<(String, String)> = archive
+            .symbols()
+            .unwrap()
+            .unwrap()
+            .map(|symbol| {
+                let symbol = symbol.unwrap();
+                let member = archive.member(symbol.offset()).unwrap();
+                (
+                    String::from_utf8_lossy(symbol.name()).to_string(),
+                    String::from_utf8_lossy(member.name()).to_string(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            index,
+            [
+                ("esp_wifi_init".to_string(), "wifi.o".to_string()),
+                ("pp_post".to_string(), "a_long_member_name.o".to_string()),
+                ("lmac_init".to_string(), "a_long_member_name.o".to_string()),
+            ]
+        );
+
+        let archive = Archive::read(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(archive.name, path.file_name().unwrap().to_string_lossy());
+        assert_eq!(archive.members.len(), 2);
+        assert_eq!(archive.members[0].undefined, ["malloc"]);
+        assert_eq!(archive.members[0].size.text, 6);
+    }
+
+    #[test]
+    fn renames_and_localizes_symbols() {
+        let data = object_file(&["printf", "helper"], &["vsnprintf"]);
+        let renames = BTreeMap::from([
+            ("printf".to_string(), "esp_printf".to_string()),
+            ("vsnprintf".to_string(), "esp_vsnprintf".to_string()),
+        ]);
+        let localize = BTreeSet::from(["helper".to_string()]);
+
+        let renamed = rename_symbols(&data, &renames, &localize).unwrap();
+        let member = Member::parse("printf.o", &renamed).unwrap();
+        let defined: Vec<&str> = member.defined.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(defined, ["esp_printf"]);
+        assert_eq!(member.undefined, ["esp_vsnprintf"]);
+
+        assert!(rename_symbols(&data, &BTreeMap::new(), &BTreeSet::new()).is_none());
+        // undefined symbols can't be localized
+        let localize = BTreeSet::from(["vsnprintf".to_string()]);
+        assert!(rename_symbols(&data, &BTreeMap::new(), &localize).is_none());
+    }
+}
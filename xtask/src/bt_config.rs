@@ -0,0 +1,270 @@
+use std::{collections::BTreeSet, fmt::Write, fs, path::Path};
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+    cdata,
+    cexpr::{self, Macros},
+    sdkconfig, CHIPS,
+};
+
+const DIR: &str = "./bt_config";
+const JSON: &str = "./bt_config/bt_config.json";
+const RUST: &str = "./bt_config/bt_config.rs";
+
+const MACRO: &str = "BT_CONTROLLER_INIT_CONFIG_DEFAULT";
+
+/// Headers defining the macros the default configuration uses, in the
+/// order `esp_bt.h` sees them
+const HEADERS: &[&str] = &[
+    "../esp_bit_defs.h",
+    "../esp_task.h",
+    "esp_bt_cfg.h",
+    "esp_bt.h",
+];
+
+/// Initializers only known at runtime, they have no value. Any other field
+/// which doesn't evaluate fails the export.
+const RUNTIME: &[(&str, &str)] = &[
+    (
+        "ESP_TASK_BT_CONTROLLER_PRIO",
+        "relative to configMAX_PRIORITIES of the FreeRTOS port",
+    ),
+    ("NULL", "a pointer"),
+    (
+        "esp_ble_get_chip_rev_version()",
+        "reads the chip revision from eFuse",
+    ),
+];
+
+/// A field of `BT_CONTROLLER_INIT_CONFIG_DEFAULT()`
+struct Field {
+    name: String,
+    /// The initializer as written, e.g. `DEFAULT_BT_LE_ACL_BUF_SIZE`
+    expression: String,
+    /// `None` for the initializers in [RUNTIME]
+    value: Option<i64>,
+    /// The macros the expression expands through, outermost first
+    macros: Vec<String>,
+}
+
+/// Evaluate `BT_CONTROLLER_INIT_CONFIG_DEFAULT()` of every chip with a BT
+/// controller and write `bt_config/bt_config.json` and
+/// `bt_config/bt_config.rs`
+pub fn export() {
+    let version = fs::read_to_string("version").expect("Unable to read version");
+    let version = version.trim();
+
+    let chips: Vec<(&str, Vec<Field>)> = CHIPS
+        .iter()
+        .filter_map(|chip| Some((*chip, evaluate(chip)?)))
+        .collect();
+    if chips.is_empty() {
+        eprintln!("No include/<chip>/esp_bt.h, run the update first");
+        std::process::exit(1);
+    }
+
+    let json = json!({
+        "idf_version": version,
+        "chips": chips
+            .iter()
+            .map(|(chip, fields)| {
+                let fields: Vec<Value> = fields
+                    .iter()
+                    .map(|field| {
+                        json!({
+                            "name": field.name,
+                            "expression": field.expression,
+                            "value": field.value,
+                            "macros": field.macros,
+                        })
+                    })
+                    .collect();
+                (chip.to_string(), Value::Array(fields))
+            })
+            .collect::<Map<_, _>>(),
+    });
+
+    fs::create_dir_all(DIR).unwrap();
+    write(JSON, &(serde_json::to_string_pretty(&json).unwrap() + "\n"));
+    write(RUST, &rust(version, &chips));
+    log::info!("Wrote {JSON} and {RUST}");
+}
+
+/// The fields of a chip's default controller configuration, `None` without
+/// BT or if its vendored `sdkconfig.h` doesn't enable it
+fn evaluate(chip: &str) -> Option<Vec<Field>> {
+    let dir = format!("./include/{chip}");
+    if !Path::new(&format!("{dir}/esp_bt.h")).exists() {
+        return None;
+    }
+
+    let mut macros = sdkconfig::macros(chip);
+    // without it the controller options are missing from `sdkconfig.h`
+    if !macros.is_defined("CONFIG_BT_ENABLED") {
+        log::warn!("{chip}: CONFIG_BT_ENABLED is not set in sdkconfig.h, skipping");
+        return None;
+    }
+    // `esp_bt_cfg.h` gets them from `sys/param.h`
+    macros.preprocess(
+        "#define MIN(a, b) (((a) < (b)) ? (a) : (b))\n#define MAX(a, b) (((a) > (b)) ? (a) : (b))\n",
+    );
+    for header in HEADERS {
+        let path = format!("{dir}/{header}");
+        match fs::read_to_string(&path) {
+            Ok(source) => {
+                let source = macros.preprocess(&source);
+                // enum constants like `ESP_BT_MODE_BTDM`
+                let data = cdata::parse(&source, &macros)
+                    .unwrap_or_else(|error| panic!("Unable to parse {path}: {error}"));
+                for (name, value) in data.enums.iter().flat_map(|e| &e.enumerators) {
+                    macros.define(name, &value.to_string());
+                }
+            }
+            Err(_) if *header == "esp_bt_cfg.h" => {
+                let bt = fs::read_to_string(format!("{dir}/esp_bt.h")).unwrap();
+                if bt.contains("esp_bt_cfg.h") {
+                    eprintln!("{chip}: esp_bt_cfg.h is not vendored, run the update");
+                    std::process::exit(1);
+                }
+            }
+            Err(_) => panic!("Unable to read {path}"),
+        }
+    }
+
+    let Some((_, definition)) = macros.definitions().find(|(name, _)| *name == MACRO) else {
+        log::warn!("{chip}: esp_bt.h doesn't define {MACRO}()");
+        return None;
+    };
+    let initializer = definition
+        .strip_prefix("()")
+        .map(str::trim)
+        .and_then(|body| body.strip_prefix('{'))
+        .and_then(|body| body.strip_suffix('}'))
+        .unwrap_or_default();
+
+    let mut fields = Vec::new();
+    for member in split_top_level(initializer) {
+        let Some((name, expression)) = member
+            .strip_prefix('.')
+            .and_then(|member| member.split_once('='))
+        else {
+            continue;
+        };
+        let (name, expression) = (name.trim(), expression.trim());
+        let value = match macros.eval(expression) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                if let Some((_, reason)) = RUNTIME.iter().find(|(e, _)| *e == expression) {
+                    log::debug!("{chip}: .{name} = {expression} {reason}");
+                    None
+                } else {
+                    eprintln!("{chip}: unable to evaluate .{name} = {expression}: {error}");
+                    std::process::exit(1);
+                }
+            }
+        };
+        fields.push(Field {
+            name: name.to_string(),
+            expression: expression.to_string(),
+            value,
+            macros: expansion(&macros, expression),
+        });
+    }
+
+    // the variant for disabled BT only fails the build
+    if fields.is_empty() {
+        log::warn!("{chip}: {MACRO}() has no fields, is CONFIG_BT_ENABLED set?");
+        return None;
+    }
+    Some(fields)
+}
+
+/// Split at the commas outside of parentheses, brackets and braces
+fn split_top_level(source: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in source.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(source[start..i].trim());
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    parts.push(source[start..].trim());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+/// The object-like macros `expression` names, followed into their bodies
+fn expansion(macros: &Macros, expression: &str) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    let mut chain = Vec::new();
+    let mut pending: Vec<String> = cexpr::tokenize(expression);
+    pending.reverse();
+    while let Some(token) = pending.pop() {
+        let Some(body) = macros.get(&token) else {
+            continue;
+        };
+        if !seen.insert(token.clone()) {
+            continue;
+        }
+        chain.push(token);
+        let mut tokens = cexpr::tokenize(body);
+        tokens.reverse();
+        pending.extend(tokens);
+    }
+    chain
+}
+
+fn rust(version: &str, chips: &[(&str, Vec<Field>)]) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! `{MACRO}()` of {version}, generated by `cargo xtask bt-config`\n"
+    )
+    .unwrap();
+    writeln!(out, "pub const IDF_VERSION: &str = \"{version}\";\n").unwrap();
+    writeln!(
+        out,
+        "/// A field of the default controller configuration\n#[derive(Debug, Clone, Copy)]\npub struct Field {{\n    pub name: &'static str,\n    /// The initializer in `esp_bt.h`\n    pub expression: &'static str,\n    /// `None` if only known at runtime, like the task priority or the chip revision\n    pub value: Option<i64>,\n    /// The macros the initializer expands through, outermost first\n    pub macros: &'static [&'static str],\n}}"
+    )
+    .unwrap();
+
+    for (chip, fields) in chips {
+        writeln!(out, "\npub mod {chip} {{").unwrap();
+        writeln!(out, "    use super::Field;\n").unwrap();
+        writeln!(
+            out,
+            "    /// The fields of `esp_bt_controller_config_t` in `include/{chip}/esp_bt.h` order"
+        )
+        .unwrap();
+        writeln!(out, "    pub const {MACRO}: &[Field] = &[").unwrap();
+        for field in fields {
+            let value = match field.value {
+                Some(value) => format!("Some({value})"),
+                None => "None".to_string(),
+            };
+            let macros: Vec<String> = field.macros.iter().map(|m| format!("\"{m}\"")).collect();
+            writeln!(
+                out,
+                "        Field {{\n            name: \"{}\",\n            expression: {:?},\n            value: {value},\n            macros: &[{}],\n        }},",
+                field.name,
+                field.expression,
+                macros.join(", ")
+            )
+            .unwrap();
+        }
+        writeln!(out, "    ];\n}}").unwrap();
+    }
+    out
+}
+
+fn write(path: &str, content: &str) {
+    fs::write(path, content).unwrap_or_else(|_| panic!("Unable to write {path}"));
+}
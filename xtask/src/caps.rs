@@ -0,0 +1,412 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    fs,
+    path::Path,
+};
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+    archive::Archive,
+    manifest,
+    sdkconfig::{self, Sdkconfig},
+    CHIPS,
+};
+
+const DIR: &str = "./caps";
+const JSON: &str = "./caps/caps.json";
+const MARKDOWN: &str = "./caps/caps.md";
+const RUST: &str = "./caps/caps.rs";
+
+const BIT_DEFS: &str = "./include/esp_bit_defs.h";
+
+/// An evaluated `SOC_*` macro of `soc/soc_caps.h`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cap {
+    /// `SOC_*_SUPPORTED` and `SOC_*_SUPPORT_*` flags
+    Flag(bool),
+    Int {
+        value: i64,
+        /// Addresses and masks, written in hexadecimal
+        hex: bool,
+    },
+    Str(String),
+}
+
+impl Cap {
+    fn json(&self) -> Value {
+        match self {
+            Cap::Flag(flag) => json!(flag),
+            Cap::Int { value, .. } => json!(value),
+            Cap::Str(string) => json!(string),
+        }
+    }
+
+    fn markdown(&self) -> String {
+        match self {
+            Cap::Flag(true) => "✓".to_string(),
+            Cap::Flag(false) => String::new(),
+            Cap::Int { value, hex: true } => format!("{value:#x}"),
+            Cap::Int { value, hex: false } => value.to_string(),
+            Cap::Str(string) => format!("`{string:?}`"),
+        }
+    }
+}
+
+/// The object-like `SOC_*` macros of a chip's `include/<chip>/soc/soc_caps.h`
+/// evaluated against its sdkconfig, `None` if the chip has none
+pub fn evaluate(chip: &str) -> Option<BTreeMap<String, Cap>> {
+    let path = format!("./include/{chip}/soc/soc_caps.h");
+    let source = fs::read_to_string(&path).ok()?;
+
+    let mut macros = sdkconfig::macros(chip);
+    // the GPIO masks use `BITn`
+    let bit_defs =
+        fs::read_to_string(BIT_DEFS).unwrap_or_else(|_| panic!("Unable to read {BIT_DEFS}"));
+    macros.preprocess(&bit_defs);
+    // selects the caps of the minimum supported revision, like a build does
+    macros.define("SOC_CAPS_ECO_VER", "CONFIG_ESP_REV_MIN_FULL");
+    macros.preprocess(&source);
+
+    let mut caps = BTreeMap::new();
+    let names: Vec<String> = macros
+        .definitions()
+        .map(|(name, _)| name.to_string())
+        .filter(|name| name.starts_with("SOC_"))
+        .collect();
+    for name in names {
+        // function-like macros have no value
+        let Some(body) = macros.get(&name) else {
+            continue;
+        };
+        let body = body.trim();
+        if let Some(string) = body.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+            caps.insert(name, Cap::Str(string.to_string()));
+            continue;
+        }
+        let value = match macros.eval(&name) {
+            Ok(value) => value,
+            Err(error) => {
+                log::debug!("{chip}: unable to evaluate {name} `{body}`: {error}");
+                continue;
+            }
+        };
+        let cap = if name.contains("_SUPPORT") && (value == 0 || value == 1) {
+            Cap::Flag(value == 1)
+        } else {
+            let hex = body.contains("0x") || name.ends_with("_MASK");
+            Cap::Int { value, hex }
+        };
+        caps.insert(name, cap);
+    }
+    Some(caps)
+}
+
+/// Whether a chip sets the `SOC_*_SUPPORTED`/`SOC_*_SUPPORT_*` flag `name`
+pub fn supported(chip: &str, name: &str) -> bool {
+    evaluate(chip).is_some_and(|caps| caps.get(name) == Some(&Cap::Flag(true)))
+}
+
+/// Evaluate the capabilities of every chip and write `caps/caps.json`,
+/// `caps/caps.md` and `caps/caps.rs`
+pub fn export() {
+    let version = fs::read_to_string("version").expect("Unable to read version");
+    let version = version.trim();
+
+    let chips: Vec<(&str, BTreeMap<String, Cap>)> = CHIPS
+        .iter()
+        .filter_map(|chip| Some((*chip, evaluate(chip)?)))
+        .collect();
+    if chips.is_empty() {
+        eprintln!("No include/<chip>/soc/soc_caps.h, run the update first");
+        std::process::exit(1);
+    }
+
+    // name → chip → value
+    let mut matrix: BTreeMap<&str, BTreeMap<&str, &Cap>> = BTreeMap::new();
+    for (chip, caps) in &chips {
+        for (name, cap) in caps {
+            matrix.entry(name).or_default().insert(chip, cap);
+        }
+    }
+
+    let json = json!({
+        "idf_version": version,
+        "chips": chips
+            .iter()
+            .map(|(chip, caps)| {
+                let caps: Map<String, Value> =
+                    caps.iter().map(|(name, cap)| (name.clone(), cap.json())).collect();
+                (chip.to_string(), Value::Object(caps))
+            })
+            .collect::<Map<_, _>>(),
+    });
+
+    fs::create_dir_all(DIR).unwrap();
+    write(JSON, &(serde_json::to_string_pretty(&json).unwrap() + "\n"));
+    write(MARKDOWN, &markdown(version, &chips, &matrix));
+    write(RUST, &rust(version, &chips, &matrix));
+    log::info!("Wrote {JSON}, {MARKDOWN} and {RUST}");
+}
+
+fn markdown(
+    version: &str,
+    chips: &[(&str, BTreeMap<String, Cap>)],
+    matrix: &BTreeMap<&str, BTreeMap<&str, &Cap>>,
+) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "# SoC capabilities\n\nThe `SOC_*` macros of `include/<chip>/soc/soc_caps.h` in {version}, generated by `cargo xtask caps`. ✓ marks supported features, empty cells are unsupported or undefined.\n"
+    )
+    .unwrap();
+
+    let names: Vec<&str> = chips.iter().map(|(chip, _)| *chip).collect();
+    writeln!(out, "| Capability | {} |", names.join(" | ")).unwrap();
+    writeln!(out, "|---|{}", "---|".repeat(names.len())).unwrap();
+    for (name, values) in matrix {
+        let cells: Vec<String> = names
+            .iter()
+            .map(|chip| {
+                values
+                    .get(chip)
+                    .map(|cap| cap.markdown())
+                    .unwrap_or_default()
+            })
+            .collect();
+        writeln!(out, "| `{name}` | {} |", cells.join(" | ")).unwrap();
+    }
+    out
+}
+
+fn rust(
+    version: &str,
+    chips: &[(&str, BTreeMap<String, Cap>)],
+    matrix: &BTreeMap<&str, BTreeMap<&str, &Cap>>,
+) -> String {
+    let flags: Vec<&str> = matrix
+        .iter()
+        .filter(|(_, values)| values.values().all(|cap| matches!(cap, Cap::Flag(_))))
+        .map(|(name, _)| *name)
+        .collect();
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! SoC capabilities of {version}, generated by `cargo xtask caps`\n//!\n//! Every chip module defines all flags, `false` where the chip's\n//! `soc_caps.h` does not. Values are only defined for the chips having them.\n"
+    )
+    .unwrap();
+    writeln!(out, "pub const IDF_VERSION: &str = \"{version}\";").unwrap();
+
+    for (chip, caps) in chips {
+        writeln!(out, "\npub mod {chip} {{").unwrap();
+        for name in &flags {
+            let flag = caps.get(*name) == Some(&Cap::Flag(true));
+            writeln!(out, "    pub const {name}: bool = {flag};").unwrap();
+        }
+        for (name, cap) in caps {
+            if flags.contains(&name.as_str()) {
+                continue;
+            }
+            let ty = int_type(&matrix[name.as_str()]);
+            let line = match cap {
+                // a flag on this chip, a value on others
+                Cap::Flag(flag) => format!("pub const {name}: {ty} = {};", *flag as i64),
+                Cap::Int { value, hex } if *hex && *value >= 0 => {
+                    format!("pub const {name}: {ty} = {value:#x};")
+                }
+                Cap::Int { value, .. } => format!("pub const {name}: {ty} = {value};"),
+                Cap::Str(string) => format!("pub const {name}: &str = {string:?};"),
+            };
+            writeln!(out, "    {line}").unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+    out
+}
+
+/// Check that the archives in `libs/<chip>/` match the chips' capabilities
+/// and sdkconfig, exit with an error on mismatches
+pub fn check(chips: &[String]) {
+    for name in unshipped() {
+        println!("lib{name}.a is listed in the manifest but not shipped for any chip, not checked");
+    }
+
+    let mut count = 0;
+    for chip in chips {
+        log::info!("Checking the archives of {chip} against its capabilities");
+        for mismatch in mismatches(chip) {
+            println!("{chip}: {mismatch}");
+            count += 1;
+        }
+    }
+
+    if count > 0 {
+        eprintln!("{count} mismatches between the capabilities and the vendored archives");
+        std::process::exit(1);
+    }
+}
+
+fn mismatches(chip: &str) -> Vec<String> {
+    let Some(caps) = evaluate(chip) else {
+        return vec!["no soc/soc_caps.h".to_string()];
+    };
+    let config = Sdkconfig::read(chip);
+    let supported = |name: &str| caps.get(name) == Some(&Cap::Flag(true));
+    let enabled = |name: &str| config.get(name) == Some("1");
+    let shipped = |name: &str| Path::new(&format!("./libs/{chip}/lib{name}.a")).exists();
+
+    // every library vendored for any chip, by feature
+    let unshipped = unshipped();
+    let mut features: BTreeMap<&str, Option<&str>> = BTreeMap::new();
+    for other in CHIPS {
+        for library in manifest::libraries(other) {
+            if !unshipped.contains(&library.name) {
+                features.insert(library.name, library.feature);
+            }
+        }
+    }
+
+    let wifi = supported("SOC_WIFI_SUPPORTED");
+    let bt = supported("SOC_BT_SUPPORTED");
+    let mut mismatches = Vec::new();
+    let mut expect = |name: &str, expected: bool, cap: &str| match (expected, shipped(name)) {
+        (true, false) => mismatches.push(format!("lib{name}.a is missing, {cap} is set")),
+        (false, true) => mismatches.push(format!("lib{name}.a is shipped, {cap} is not set")),
+        _ => (),
+    };
+    for (&name, feature) in &features {
+        match (*feature, name) {
+            (Some("mesh"), _) => expect(
+                name,
+                wifi && supported("SOC_WIFI_MESH_SUPPORT"),
+                "SOC_WIFI_MESH_SUPPORT",
+            ),
+            (Some("wapi"), _) => expect(
+                name,
+                wifi && supported("SOC_WIFI_WAPI_SUPPORT"),
+                "SOC_WIFI_WAPI_SUPPORT",
+            ),
+            (Some("wifi" | "espnow" | "smartconfig"), _) => {
+                expect(name, wifi, "SOC_WIFI_SUPPORTED")
+            }
+            (None, "phy") => expect(name, supported("SOC_PHY_SUPPORTED"), "SOC_PHY_SUPPORTED"),
+            (Some("ieee802154"), _) => expect(
+                name,
+                supported("SOC_IEEE802154_SUPPORTED"),
+                "SOC_IEEE802154_SUPPORTED",
+            ),
+            _ => (),
+        }
+    }
+
+    // one of the controllers, depending on the chip family
+    let controllers: Vec<&str> = features
+        .iter()
+        .filter(|(_, feature)| **feature == Some("ble"))
+        .map(|(name, _)| *name)
+        .filter(|name| shipped(name))
+        .collect();
+    match (bt, controllers.is_empty()) {
+        (true, true) => {
+            mismatches.push("no BT controller archive, SOC_BT_SUPPORTED is set".to_string())
+        }
+        (false, false) => mismatches.push(format!(
+            "lib{}.a is shipped, SOC_BT_SUPPORTED is not set",
+            controllers.join(".a, lib")
+        )),
+        _ => (),
+    }
+
+    // the baseband is part of the PHY libraries on older chips
+    if shipped("btbb") && !bt {
+        mismatches.push("libbtbb.a is shipped, SOC_BT_SUPPORTED is not set".to_string());
+    }
+    if bt && !shipped("btbb") {
+        let baseband = Archive::read_chip(chip).iter().any(|archive| {
+            archive.members.iter().any(|member| {
+                member
+                    .defined
+                    .iter()
+                    .any(|symbol| symbol.name.starts_with("bt_bb_"))
+            })
+        });
+        if !baseband {
+            mismatches.push(
+                "libbtbb.a is missing and no other archive defines the BT baseband, SOC_BT_SUPPORTED is set"
+                    .to_string(),
+            );
+        }
+    }
+
+    // the headers were generated with these enabled, the BT controller is
+    // checked above
+    let requirements: &[(&str, Option<&str>, &[&str])] = &[
+        (
+            "ESP_WIFI_ENABLED",
+            Some("SOC_WIFI_SUPPORTED"),
+            &["core", "pp", "net80211"],
+        ),
+        ("BT_ENABLED", Some("SOC_BT_SUPPORTED"), &[]),
+        ("BT_CONTROLLER_ENABLED", Some("SOC_BT_SUPPORTED"), &[]),
+        ("ESP_PHY_ENABLED", Some("SOC_PHY_SUPPORTED"), &["phy"]),
+        ("ESP_COEX_ENABLED", None, &["coexist"]),
+        ("IEEE802154_ENABLED", Some("SOC_IEEE802154_SUPPORTED"), &[]),
+    ];
+    let mut missing = BTreeSet::new();
+    for (option, cap, libraries) in requirements {
+        let libraries = libraries
+            .iter()
+            .filter(|library| !unshipped.contains(*library));
+        if !enabled(option) {
+            continue;
+        }
+        if let Some(cap) = cap.filter(|cap| !supported(cap)) {
+            mismatches.push(format!("CONFIG_{option} is set, {cap} is not"));
+        }
+        for library in libraries.filter(|library| !shipped(library)) {
+            if missing.insert(*library) {
+                mismatches.push(format!("lib{library}.a is missing, CONFIG_{option} is set"));
+            }
+        }
+    }
+
+    mismatches
+}
+
+/// The libraries of the manifest no chip ships, like those a port builds
+/// from source. They can't be checked against the capabilities.
+fn unshipped() -> BTreeSet<&'static str> {
+    let libraries = CHIPS.iter().flat_map(|chip| manifest::libraries(chip));
+    libraries
+        .map(|library| library.name)
+        .filter(|name| {
+            !CHIPS
+                .iter()
+                .any(|chip| Path::new(&format!("./libs/{chip}/lib{name}.a")).exists())
+        })
+        .collect()
+}
+
+/// The smallest of `u32`, `u64` and `i64` holding the values of all chips
+fn int_type(values: &BTreeMap<&str, &Cap>) -> &'static str {
+    let values: Vec<i64> = values
+        .values()
+        .filter_map(|cap| match cap {
+            Cap::Int { value, .. } => Some(*value),
+            _ => None,
+        })
+        .collect();
+    if values.iter().all(|value| u32::try_from(*value).is_ok()) {
+        "u32"
+    } else if values.iter().all(|value| *value >= 0) {
+        "u64"
+    } else {
+        "i64"
+    }
+}
+
+fn write(path: &str, content: &str) {
+    fs::write(path, content).unwrap_or_else(|_| panic!("Unable to write {path}"));
+}
@@ -0,0 +1,468 @@
+use serde_json::{Map, Value};
+
+use crate::cexpr::{tokenize, Macros};
+
+/// A `typedef struct { ... } name;`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Struct {
+    pub name: String,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    /// Element type, e.g. `uint8_t` or another struct
+    pub ty: String,
+    /// Length of array fields
+    pub len: Option<usize>,
+    /// Width of bitfields
+    pub bits: Option<u32>,
+}
+
+/// An initialized array definition like `const T name[N] = { ... };`
+#[derive(Debug, Clone)]
+pub struct Array {
+    pub ty: String,
+    pub name: String,
+    /// Declared length, `None` for `name[]`
+    pub len: Option<usize>,
+    pub values: Vec<Value>,
+}
+
+/// An enum definition with the values of its enumerators
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enum {
+    /// Typedef name or tag, empty for anonymous enums
+    pub name: String,
+    pub enumerators: Vec<(String, i64)>,
+}
+
+/// Struct typedefs, enums and initialized arrays of a C source
+#[derive(Debug, Default)]
+pub struct Data {
+    pub structs: Vec<Struct>,
+    pub enums: Vec<Enum>,
+    pub arrays: Vec<Array>,
+}
+
+/// Raw initializer before the types are applied
+#[derive(Debug)]
+enum Init {
+    Expression(Vec<String>),
+    Str(String),
+    List(Vec<(Option<String>, Init)>),
+}
+
+impl Data {
+    /// Initialized arrays of structs, with the struct
+    pub fn struct_arrays(&self) -> impl Iterator<Item = (&Array, &Struct)> {
+        self.arrays
+            .iter()
+            .filter_map(|array| Some((array, self.structure(&array.ty)?)))
+    }
+
+    pub fn structure(&self, name: &str) -> Option<&Struct> {
+        self.structs.iter().find(|s| s.name == name)
+    }
+}
+
+/// Parse the struct typedefs and initialized arrays of a preprocessed C
+/// source, evaluating expressions with `macros` and the source's enum
+/// constants
+pub fn parse(source: &str, macros: &Macros) -> Result<Data, String> {
+    let tokens = tokenize(source);
+    let mut macros = macros.clone();
+    let macros = &mut macros;
+    let mut data = Data::default();
+
+    for statement in statements(&tokens) {
+        let statement = strip_attributes(statement);
+        if statement.iter().take(2).any(|t| t == "enum") {
+            if let Some(definition) = define_enumerators(&statement, macros) {
+                data.enums.push(definition);
+            }
+            continue;
+        }
+        if statement.first().map(String::as_str) == Some("typedef") {
+            if let Some(structure) = parse_struct(&statement, macros) {
+                data.structs.push(structure);
+            }
+            continue;
+        }
+
+        let Some(assign) = statement.iter().position(|t| t == "=") else {
+            continue;
+        };
+        let declaration = &statement[..assign];
+        let Some(open) = declaration.iter().position(|t| t == "[") else {
+            continue;
+        };
+        if open < 2 || statement.get(assign + 1).map(String::as_str) != Some("{") {
+            continue;
+        }
+
+        let name = declaration[open - 1].clone();
+        let ty = declaration[..open - 1]
+            .iter()
+            .filter(|t| !["static", "const", "volatile", "extern"].contains(&t.as_str()))
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let close = declaration
+            .iter()
+            .rposition(|t| t == "]")
+            .unwrap_or(open + 1);
+        let len = if close > open + 1 {
+            match eval(&declaration[open + 1..close], macros) {
+                Ok(len) => Some(len as usize),
+                Err(e) => {
+                    log::warn!("Skipping {name}: {e}");
+                    continue;
+                }
+            }
+        } else {
+            None
+        };
+
+        let mut pos = assign + 1;
+        let Init::List(items) = parse_init(&statement, &mut pos)? else {
+            continue;
+        };
+        let element = Field {
+            name: name.clone(),
+            ty: ty.clone(),
+            len: None,
+            bits: None,
+        };
+        let values = match items
+            .iter()
+            .map(|(_, init)| typed(init, &element, &data, macros))
+            .collect::<Result<_, _>>()
+        {
+            Ok(values) => values,
+            Err(e) => {
+                log::warn!("Skipping {name}: {e}");
+                continue;
+            }
+        };
+
+        data.arrays.push(Array {
+            ty,
+            name,
+            len,
+            values,
+        });
+    }
+
+    Ok(data)
+}
+
+/// Top level statements split at `;`, without function bodies
+fn statements(tokens: &[String]) -> Vec<&[String]> {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut depth = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match token.as_str() {
+            "{" => depth += 1,
+            "}" => {
+                depth -= 1;
+                // function bodies end without `;`
+                if depth == 0 && i > 0 && !tokens[start..i].iter().any(|t| t == "=") {
+                    let is_function = tokens[start..i]
+                        .iter()
+                        .position(|t| t == "{")
+                        .is_some_and(|open| open > 0 && tokens[start + open - 1] == ")");
+                    if is_function {
+                        start = i + 1;
+                    }
+                }
+            }
+            ";" if depth == 0 => {
+                statements.push(&tokens[start..i]);
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+
+    statements
+}
+
+/// Remove `__attribute__((...))` from a statement
+fn strip_attributes(statement: &[String]) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < statement.len() {
+        if statement[i] == "__attribute__" {
+            let mut depth = 0;
+            i += 1;
+            while i < statement.len() {
+                match statement[i].as_str() {
+                    "(" => depth += 1,
+                    ")" => depth -= 1,
+                    _ => (),
+                }
+                i += 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            continue;
+        }
+        out.push(statement[i].clone());
+        i += 1;
+    }
+    out
+}
+
+fn eval(tokens: &[String], macros: &Macros) -> Result<i64, String> {
+    let expression = tokens.join(" ");
+    macros
+        .eval(&expression)
+        .map_err(|e| format!("`{expression}`: {e}"))
+}
+
+/// Define the constants of `[typedef] enum [tag] { A = 1, B, ... }` as
+/// macros, enumerators with values we cannot evaluate are left out
+fn define_enumerators(statement: &[String], macros: &mut Macros) -> Option<Enum> {
+    let open = statement.iter().position(|t| t == "{")?;
+    let close = statement.iter().rposition(|t| t == "}")?;
+    let name = match statement.get(close + 1) {
+        Some(name) if statement[0] == "typedef" => name.clone(),
+        _ if open == 2 => statement[1].clone(),
+        _ => String::new(),
+    };
+    let mut enumerators = Vec::new();
+
+    let mut next = Some(0);
+    for enumerator in statement[open + 1..close].split(|t| t == ",") {
+        let Some(name) = enumerator.first() else {
+            continue;
+        };
+        let value = match enumerator.iter().position(|t| t == "=") {
+            Some(assign) => eval(&enumerator[assign + 1..], macros).ok(),
+            None => next,
+        };
+        match value {
+            Some(value) => {
+                macros.define(name, &value.to_string());
+                enumerators.push((name.clone(), value));
+            }
+            None => log::debug!("Unable to evaluate enumerator {name}"),
+        }
+        next = value.map(|value| value + 1);
+    }
+    Some(Enum { name, enumerators })
+}
+
+/// `typedef struct [tag] { fields } name`, `None` for other typedefs and
+/// structs with members we cannot describe like nested aggregates or
+/// function pointers
+fn parse_struct(statement: &[String], macros: &Macros) -> Option<Struct> {
+    if statement.get(1).map(String::as_str) != Some("struct") {
+        return None;
+    }
+    let open = statement.iter().position(|t| t == "{")?;
+    let close = statement.iter().rposition(|t| t == "}")?;
+    let name = statement.get(close + 1)?;
+
+    let mut fields = Vec::new();
+    for declaration in statement[open + 1..close].split(|t| t == ";") {
+        if declaration.is_empty() {
+            continue;
+        }
+        if declaration
+            .iter()
+            .any(|t| ["{", "(", "*"].contains(&t.as_str()))
+        {
+            log::debug!(
+                "Skipping {name}, unsupported member {}",
+                declaration.join(" ")
+            );
+            return None;
+        }
+
+        // `type a, b[2], c : 3`
+        let first_name = declaration
+            .iter()
+            .position(|t| [",", "[", ":"].contains(&t.as_str()))
+            .unwrap_or(declaration.len())
+            .checked_sub(1)?;
+        let ty = declaration[..first_name].join(" ");
+        for declarator in declaration[first_name..].split(|t| t == ",") {
+            let Some(field_name) = declarator.first() else {
+                continue;
+            };
+            let mut len = None;
+            let mut bits = None;
+            if let Some(open) = declarator.iter().position(|t| t == "[") {
+                let close = declarator.iter().position(|t| t == "]").unwrap_or(open + 1);
+                len = Some(eval(&declarator[open + 1..close], macros).ok()? as usize);
+            }
+            if let Some(colon) = declarator.iter().position(|t| t == ":") {
+                bits = Some(eval(&declarator[colon + 1..], macros).ok()? as u32);
+            }
+            fields.push(Field {
+                name: field_name.clone(),
+                ty: ty.clone(),
+                len,
+                bits,
+            });
+        }
+    }
+
+    Some(Struct {
+        name: name.clone(),
+        fields,
+    })
+}
+
+fn parse_init(tokens: &[String], pos: &mut usize) -> Result<Init, String> {
+    match tokens.get(*pos).map(String::as_str) {
+        Some("{") => {
+            *pos += 1;
+            let mut items = Vec::new();
+            loop {
+                match tokens.get(*pos).map(String::as_str) {
+                    Some("}") => {
+                        *pos += 1;
+                        return Ok(Init::List(items));
+                    }
+                    Some(",") => *pos += 1,
+                    Some(".") => {
+                        // designated initializer `.field = value`
+                        let field = tokens.get(*pos + 1).cloned();
+                        *pos += 3;
+                        items.push((field, parse_init(tokens, pos)?));
+                    }
+                    Some(_) => items.push((None, parse_init(tokens, pos)?)),
+                    None => return Err("Unterminated initializer".to_string()),
+                }
+            }
+        }
+        Some(token) if token.starts_with('"') => {
+            let mut string = String::new();
+            while let Some(part) = tokens.get(*pos).filter(|t| t.starts_with('"')) {
+                string.push_str(part.trim_matches('"'));
+                *pos += 1;
+            }
+            Ok(Init::Str(string))
+        }
+        Some(_) => {
+            let mut expression = Vec::new();
+            let mut depth = 0;
+            while let Some(token) = tokens.get(*pos) {
+                match token.as_str() {
+                    "(" => depth += 1,
+                    ")" => depth -= 1,
+                    "," | "}" if depth == 0 => break,
+                    _ => (),
+                }
+                expression.push(token.clone());
+                *pos += 1;
+            }
+            Ok(Init::Expression(expression))
+        }
+        None => Err("Missing initializer".to_string()),
+    }
+}
+
+/// Zero value of a field, what C uses for missing initializers
+fn zero(field: &Field, data: &Data) -> Value {
+    if let Some(len) = field.len {
+        if is_char(&field.ty) {
+            return Value::String(String::new());
+        }
+        let element = Field {
+            len: None,
+            ..field.clone()
+        };
+        return Value::Array(vec![zero(&element, data); len]);
+    }
+    match data.structure(&field.ty) {
+        Some(structure) => Value::Object(
+            structure
+                .fields
+                .iter()
+                .map(|field| (field.name.clone(), zero(field, data)))
+                .collect(),
+        ),
+        None => Value::from(0),
+    }
+}
+
+pub fn is_char(ty: &str) -> bool {
+    ty == "char" || ty.ends_with(" char")
+}
+
+/// Apply the type of `field` to an initializer
+fn typed(init: &Init, field: &Field, data: &Data, macros: &Macros) -> Result<Value, String> {
+    if let Some(len) = field.len {
+        let element = Field {
+            len: None,
+            ..field.clone()
+        };
+        return match init {
+            Init::Str(string) if is_char(&field.ty) => Ok(Value::String(string.clone())),
+            Init::List(items) => {
+                let mut values = items
+                    .iter()
+                    .map(|(_, init)| typed(init, &element, data, macros))
+                    .collect::<Result<Vec<_>, _>>()?;
+                if values.len() > len {
+                    return Err(format!("Too many initializers for {}", field.name));
+                }
+                values.resize(len, zero(&element, data));
+                Ok(Value::Array(values))
+            }
+            _ => Err(format!("{} needs a list initializer", field.name)),
+        };
+    }
+
+    if let Some(structure) = data.structure(&field.ty) {
+        let Init::List(items) = init else {
+            return Err(format!("{} needs a list initializer", field.name));
+        };
+        let mut object = Map::new();
+        let mut next = 0;
+        for (designator, init) in items {
+            let index = match designator {
+                Some(name) => structure
+                    .fields
+                    .iter()
+                    .position(|field| field.name == *name)
+                    .ok_or_else(|| format!("{} has no field {name}", structure.name))?,
+                None => next,
+            };
+            let field = structure
+                .fields
+                .get(index)
+                .ok_or_else(|| format!("Too many initializers for {}", structure.name))?;
+            object.insert(field.name.clone(), typed(init, field, data, macros)?);
+            next = index + 1;
+        }
+        // keep the declaration order, missing fields are zero
+        return Ok(Value::Object(
+            structure
+                .fields
+                .iter()
+                .map(|field| {
+                    let value = object
+                        .remove(&field.name)
+                        .unwrap_or_else(|| zero(field, data));
+                    (field.name.clone(), value)
+                })
+                .collect(),
+        ));
+    }
+
+    match init {
+        Init::Expression(tokens) => Ok(Value::from(eval(tokens, macros)?)),
+        // `{ value }` for scalars
+        Init::List(items) if items.len() == 1 => typed(&items[0].1, field, data, macros),
+        _ => Err(format!("{} needs a scalar initializer", field.name)),
+    }
+}
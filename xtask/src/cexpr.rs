@@ -0,0 +1,635 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::cheader::strip_comments;
+
+/// C preprocessor macros and evaluation of integer constant expressions
+/// using them
+#[derive(Debug, Default, Clone)]
+pub struct Macros {
+    macros: BTreeMap<String, Macro>,
+}
+
+#[derive(Debug, Clone)]
+struct Macro {
+    /// `None` for object-like macros
+    params: Option<Vec<String>>,
+    body: String,
+}
+
+/// A frame of nested `#if`/`#elif`/`#else` conditionals
+struct Conditional {
+    /// Whether the enclosing conditionals are active
+    parent: bool,
+    /// Whether a branch was taken before
+    taken: bool,
+    active: bool,
+}
+
+impl Macros {
+    pub fn new() -> Macros {
+        Macros::default()
+    }
+
+    /// Define an object-like macro
+    pub fn define(&mut self, name: &str, body: &str) {
+        self.macros.insert(
+            name.to_string(),
+            Macro {
+                params: None,
+                body: body.to_string(),
+            },
+        );
+    }
+
+    pub fn undefine(&mut self, name: &str) {
+        self.macros.remove(name);
+    }
+
+    pub fn is_defined(&self, name: &str) -> bool {
+        self.macros.contains_key(name)
+    }
+
+    /// All definitions as name and `body` or `(a, b) body`
+    pub fn definitions(&self) -> impl Iterator<Item = (&str, String)> {
+        self.macros.iter().map(|(name, definition)| {
+            let text = match &definition.params {
+                Some(params) => format!("({}) {}", params.join(", "), definition.body),
+                None => definition.body.clone(),
+            };
+            (name.as_str(), text.trim().to_string())
+        })
+    }
+
+    /// Body of an object-like macro
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.macros
+            .get(name)
+            .filter(|m| m.params.is_none())
+            .map(|m| m.body.as_str())
+    }
+
+    /// Evaluate the conditionals of a source file and record its `#define`s
+    /// and `#undef`s, returning the lines of the active branches without
+    /// comments and directives (line numbers are kept)
+    pub fn preprocess(&mut self, source: &str) -> String {
+        let source = strip_comments(source);
+        let mut out = String::new();
+        let mut conditionals: Vec<Conditional> = Vec::new();
+
+        let mut lines = source.lines();
+        while let Some(line) = lines.next() {
+            // join continued lines, keeping the line count
+            let mut line = line.to_string();
+            while let Some(joined) = line.strip_suffix('\\') {
+                line = format!("{joined} {}", lines.next().unwrap_or_default());
+                out.push('\n');
+            }
+            let line = line.as_str();
+
+            let active = conditionals.last().is_none_or(|c| c.active);
+
+            let Some(directive) = line.trim_start().strip_prefix('#') else {
+                if active {
+                    out.push_str(line);
+                }
+                out.push('\n');
+                continue;
+            };
+            out.push('\n');
+
+            let directive = directive.trim();
+            let (keyword, rest) = directive
+                .split_once(|c: char| c.is_whitespace() || c == '(')
+                .map(|(keyword, _)| (keyword, directive[keyword.len()..].trim()))
+                .unwrap_or((directive, ""));
+
+            match keyword {
+                "if" | "ifdef" | "ifndef" => {
+                    let value = active
+                        && match keyword {
+                            "if" => self.eval_condition(rest),
+                            "ifdef" => self.is_defined(rest),
+                            _ => !self.is_defined(rest),
+                        };
+                    conditionals.push(Conditional {
+                        parent: active,
+                        taken: value,
+                        active: value,
+                    });
+                }
+                "elif" | "else" => {
+                    let Some(conditional) = conditionals.last() else {
+                        continue;
+                    };
+                    let value = conditional.parent
+                        && !conditional.taken
+                        && (keyword == "else" || self.eval_condition(rest));
+                    let conditional = conditionals.last_mut().unwrap();
+                    conditional.active = value;
+                    conditional.taken |= value;
+                }
+                "endif" => {
+                    conditionals.pop();
+                }
+                "define" if active => self.define_directive(rest),
+                "undef" if active => self.undefine(rest),
+                _ => (),
+            }
+        }
+
+        out
+    }
+
+    /// Record `NAME body` or `NAME(a, b) body`
+    fn define_directive(&mut self, definition: &str) {
+        let name_len = definition
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(definition.len());
+        let name = &definition[..name_len];
+        let rest = &definition[name_len..];
+
+        let (params, body) = match rest.strip_prefix('(') {
+            Some(rest) => {
+                let (params, body) = rest.split_once(')').unwrap_or((rest, ""));
+                let params = params
+                    .split(',')
+                    .map(|param| param.trim().to_string())
+                    .filter(|param| !param.is_empty())
+                    .collect();
+                (Some(params), body)
+            }
+            None => (None, rest),
+        };
+
+        self.macros.insert(
+            name.to_string(),
+            Macro {
+                params,
+                body: body.trim().to_string(),
+            },
+        );
+    }
+
+    /// Evaluate an `#if` condition, undefined identifiers are 0
+    pub fn eval_condition(&self, expression: &str) -> bool {
+        match self.evaluate(expression, false) {
+            Ok(value) => value != 0,
+            Err(error) => {
+                log::debug!("Unable to evaluate `{expression}`: {error}");
+                false
+            }
+        }
+    }
+
+    /// Add the definitions of `other`, replacing the ones of the same name
+    pub fn extend(&mut self, other: &Macros) {
+        self.macros
+            .extend(other.macros.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+
+    /// Expand the macros in `tokens`
+    pub fn expand_tokens(&self, tokens: &[String]) -> Vec<String> {
+        self.expand(tokens, &BTreeSet::new())
+    }
+
+    /// Evaluate an integer constant expression, failing on identifiers which
+    /// are no macros
+    pub fn eval(&self, expression: &str) -> Result<i64, String> {
+        self.evaluate(expression, true)
+    }
+
+    fn evaluate(&self, expression: &str, strict: bool) -> Result<i64, String> {
+        let tokens = self.replace_defined(&tokenize(expression));
+        let tokens = self.expand(&tokens, &BTreeSet::new());
+        let mut parser = Parser {
+            tokens: &tokens,
+            pos: 0,
+            strict,
+        };
+        let value = parser.ternary()?;
+        match parser.peek() {
+            None => Ok(value),
+            Some(token) => Err(format!("unexpected `{token}`")),
+        }
+    }
+
+    /// Replace `defined(X)` and `defined X` with 0 or 1 before expansion
+    fn replace_defined(&self, tokens: &[String]) -> Vec<String> {
+        let mut out = Vec::new();
+        let mut i = 0;
+        while i < tokens.len() {
+            if tokens[i] == "defined" {
+                let (name, next) = if tokens.get(i + 1).map(String::as_str) == Some("(") {
+                    (tokens.get(i + 2), i + 4)
+                } else {
+                    (tokens.get(i + 1), i + 2)
+                };
+                let defined = name.is_some_and(|name| self.is_defined(name));
+                out.push(if defined { "1" } else { "0" }.to_string());
+                i = next;
+            } else {
+                out.push(tokens[i].clone());
+                i += 1;
+            }
+        }
+        out
+    }
+
+    /// Expand macros, `hidden` are the macros currently being expanded
+    fn expand(&self, tokens: &[String], hidden: &BTreeSet<String>) -> Vec<String> {
+        let mut out = Vec::new();
+        let mut i = 0;
+
+        while i < tokens.len() {
+            let token = &tokens[i];
+            i += 1;
+
+            let Some(definition) = self.macros.get(token).filter(|_| !hidden.contains(token))
+            else {
+                out.push(token.clone());
+                continue;
+            };
+
+            let mut inner_hidden = hidden.clone();
+            inner_hidden.insert(token.clone());
+
+            let Some(params) = &definition.params else {
+                out.extend(self.expand(&tokenize(&definition.body), &inner_hidden));
+                continue;
+            };
+
+            // a function-like macro name without arguments stays as it is
+            if tokens.get(i).map(String::as_str) != Some("(") {
+                out.push(token.clone());
+                continue;
+            }
+            i += 1;
+
+            let mut args: Vec<Vec<String>> = vec![Vec::new()];
+            let mut depth = 0;
+            while i < tokens.len() {
+                let token = &tokens[i];
+                i += 1;
+                match token.as_str() {
+                    "(" => depth += 1,
+                    ")" if depth == 0 => break,
+                    ")" => depth -= 1,
+                    "," if depth == 0 => {
+                        args.push(Vec::new());
+                        continue;
+                    }
+                    _ => (),
+                }
+                args.last_mut().unwrap().push(token.clone());
+            }
+
+            let args: Vec<Vec<String>> = args.iter().map(|arg| self.expand(arg, hidden)).collect();
+            let mut body = Vec::new();
+            for token in tokenize(&definition.body) {
+                match params.iter().position(|param| *param == token) {
+                    Some(index) => body.extend(args.get(index).cloned().unwrap_or_default()),
+                    None => body.push(token),
+                }
+            }
+            out.extend(self.expand(&body, &inner_hidden));
+        }
+
+        out
+    }
+}
+
+pub fn tokenize(source: &str) -> Vec<String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        } else if c.is_ascii_alphanumeric() || c == '_' {
+            // identifiers and numbers including suffixes like `0x10UL`
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+        } else if c == '"' || c == '\'' {
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                if chars[i] == '\\' {
+                    i += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+        } else {
+            const PUNCTUATORS: &[&str] = &[
+                "<<", ">>", "<=", ">=", "==", "!=", "&&", "||", "##", "->", "++", "--",
+            ];
+            let two: String = chars[i..chars.len().min(i + 2)].iter().collect();
+            i += if PUNCTUATORS.contains(&two.as_str()) {
+                2
+            } else {
+                1
+            };
+        }
+        tokens.push(chars[start..i.min(chars.len())].iter().collect());
+    }
+
+    tokens
+}
+
+/// Value of an integer or character literal
+fn literal(token: &str) -> Option<i64> {
+    if let Some(char) = token.strip_prefix('\'').and_then(|t| t.strip_suffix('\'')) {
+        let value = match char {
+            "\\n" => '\n' as i64,
+            "\\t" => '\t' as i64,
+            "\\0" => 0,
+            "\\\\" => '\\' as i64,
+            "\\'" => '\'' as i64,
+            _ => char.chars().next()? as i64,
+        };
+        return Some(value);
+    }
+
+    let digits = token.trim_end_matches(['u', 'U', 'l', 'L']);
+    if let Some(hex) = digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+        u64::from_str_radix(hex, 16).ok().map(|v| v as i64)
+    } else if let Some(binary) = digits.strip_prefix("0b").or(digits.strip_prefix("0B")) {
+        u64::from_str_radix(binary, 2).ok().map(|v| v as i64)
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        u64::from_str_radix(&digits[1..], 8).ok().map(|v| v as i64)
+    } else {
+        digits.parse::<u64>().ok().map(|v| v as i64)
+    }
+}
+
+/// Bits and signedness of the integer types casts are applied for
+fn cast_type(tokens: &[String]) -> Option<(u32, bool)> {
+    let name = tokens.join(" ");
+    let cast = match name.as_str() {
+        "uint8_t" | "unsigned char" => (8, false),
+        "int8_t" | "char" | "signed char" => (8, true),
+        "uint16_t" | "unsigned short" => (16, false),
+        "int16_t" | "short" => (16, true),
+        "uint32_t" | "unsigned" | "unsigned int" | "unsigned long" | "size_t" | "uintptr_t" => {
+            (32, false)
+        }
+        "int32_t" | "int" | "long" | "signed" | "intptr_t" => (32, true),
+        "uint64_t" | "unsigned long long" => (64, false),
+        "int64_t" | "long long" => (64, true),
+        _ => return None,
+    };
+    Some(cast)
+}
+
+fn apply_cast(value: i64, (bits, signed): (u32, bool)) -> i64 {
+    if bits == 64 {
+        return value;
+    }
+    let masked = value & ((1i64 << bits) - 1);
+    if signed && masked & (1 << (bits - 1)) != 0 {
+        masked - (1 << bits)
+    } else {
+        masked
+    }
+}
+
+struct Parser<'a> {
+    tokens: &'a [String],
+    pos: usize,
+    strict: bool,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.pos).map(String::as_str)
+    }
+
+    fn next(&mut self) -> Result<&str, String> {
+        self.pos += 1;
+        self.tokens
+            .get(self.pos - 1)
+            .map(String::as_str)
+            .ok_or_else(|| "unexpected end of expression".to_string())
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), String> {
+        match self.next()? {
+            token if token == expected => Ok(()),
+            token => Err(format!("expected `{expected}`, found `{token}`")),
+        }
+    }
+
+    fn ternary(&mut self) -> Result<i64, String> {
+        let condition = self.binary(0)?;
+        if self.peek() != Some("?") {
+            return Ok(condition);
+        }
+        self.next()?;
+        let then = self.ternary()?;
+        self.expect(":")?;
+        let otherwise = self.ternary()?;
+        Ok(if condition != 0 { then } else { otherwise })
+    }
+
+    /// Binary operators by precedence climbing, lowest precedence first
+    fn binary(&mut self, level: usize) -> Result<i64, String> {
+        const LEVELS: &[&[&str]] = &[
+            &["||"],
+            &["&&"],
+            &["|"],
+            &["^"],
+            &["&"],
+            &["==", "!="],
+            &["<", ">", "<=", ">="],
+            &["<<", ">>"],
+            &["+", "-"],
+            &["*", "/", "%"],
+        ];
+
+        if level == LEVELS.len() {
+            return self.unary();
+        }
+
+        let mut left = self.binary(level + 1)?;
+        while let Some(op) = self.peek().filter(|op| LEVELS[level].contains(op)) {
+            let op = op.to_string();
+            self.next()?;
+            let right = self.binary(level + 1)?;
+            left = match op.as_str() {
+                "||" => (left != 0 || right != 0) as i64,
+                "&&" => (left != 0 && right != 0) as i64,
+                "|" => left | right,
+                "^" => left ^ right,
+                "&" => left & right,
+                "==" => (left == right) as i64,
+                "!=" => (left != right) as i64,
+                "<" => (left < right) as i64,
+                ">" => (left > right) as i64,
+                "<=" => (left <= right) as i64,
+                ">=" => (left >= right) as i64,
+                "<<" => left.wrapping_shl(right as u32),
+                ">>" => left.wrapping_shr(right as u32),
+                "+" => left.wrapping_add(right),
+                "-" => left.wrapping_sub(right),
+                "*" => left.wrapping_mul(right),
+                "/" | "%" if right == 0 => return Err("division by zero".to_string()),
+                "/" => left.wrapping_div(right),
+                _ => left.wrapping_rem(right),
+            };
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<i64, String> {
+        match self.peek() {
+            Some("-") => {
+                self.next()?;
+                Ok(self.unary()?.wrapping_neg())
+            }
+            Some("+") => {
+                self.next()?;
+                self.unary()
+            }
+            Some("!") => {
+                self.next()?;
+                Ok((self.unary()? == 0) as i64)
+            }
+            Some("~") => {
+                self.next()?;
+                Ok(!self.unary()?)
+            }
+            Some("(") => {
+                self.next()?;
+                // casts to integer types
+                if let Some(close) = self.tokens[self.pos..].iter().position(|t| t == ")") {
+                    if let Some(cast) = cast_type(&self.tokens[self.pos..self.pos + close]) {
+                        self.pos += close + 1;
+                        return Ok(apply_cast(self.unary()?, cast));
+                    }
+                }
+                let value = self.ternary()?;
+                self.expect(")")?;
+                Ok(value)
+            }
+            _ => self.primary(),
+        }
+    }
+
+    fn primary(&mut self) -> Result<i64, String> {
+        let strict = self.strict;
+        let token = self.next()?;
+        if let Some(value) = literal(token) {
+            return Ok(value);
+        }
+        match token {
+            "true" => Ok(1),
+            "false" => Ok(0),
+            _ if token.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') => {
+                if strict {
+                    Err(format!("`{token}` is not defined"))
+                } else {
+                    Ok(0)
+                }
+            }
+            _ => Err(format!("unexpected `{token}`")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluates_with_c_precedence() {
+        let macros = Macros::new();
+        assert_eq!(macros.eval("1 + 2 * 3"), Ok(7));
+        assert_eq!(macros.eval("(1 + 2) * 3"), Ok(9));
+        assert_eq!(macros.eval("1 << 4 | 1"), Ok(17));
+        assert_eq!(macros.eval("2 > 1 && 3 == 3"), Ok(1));
+        assert_eq!(macros.eval("0 ? 1 : 2 ? 3 : 4"), Ok(3));
+        assert_eq!(macros.eval("-7 / 2"), Ok(-3));
+        assert_eq!(macros.eval("~0 & 0xff"), Ok(255));
+        assert!(macros.eval("1 / 0").is_err());
+    }
+
+    #[test]
+    fn evaluates_literals_and_casts() {
+        let macros = Macros::new();
+        assert_eq!(macros.eval("0x10UL + 010 + 0b11"), Ok(27));
+        assert_eq!(macros.eval("'A'"), Ok(65));
+        assert_eq!(macros.eval("(uint8_t)0x1ff"), Ok(255));
+        assert_eq!(macros.eval("(int8_t)0xff"), Ok(-1));
+        assert_eq!(macros.eval("(unsigned long long)-1"), Ok(-1));
+    }
+
+    #[test]
+    fn undefined_identifiers() {
+        let macros = Macros::new();
+        assert_eq!(
+            macros.eval("UNKNOWN + 1"),
+            Err("`UNKNOWN` is not defined".to_string())
+        );
+        assert!(!macros.eval_condition("UNKNOWN"));
+        assert!(macros.eval_condition("!UNKNOWN"));
+    }
+
+    #[test]
+    fn expands_macros() {
+        let mut macros = Macros::new();
+        macros.preprocess(
+            "#define BASE 0x100\n\
+             #define OFFSET(n) (BASE + (n) * 4)\n\
+             #define SELF SELF\n",
+        );
+        assert_eq!(macros.eval("OFFSET(2)"), Ok(0x108));
+        assert_eq!(macros.get("BASE"), Some("0x100"));
+        assert_eq!(macros.get("OFFSET"), None);
+        assert_eq!(
+            macros.expand_tokens(&tokenize("OFFSET(1) OFFSET SELF")),
+            tokenize("(0x100 + (1) * 4) OFFSET SELF")
+        );
+    }
+
+    #[test]
+    fn defined_is_replaced_before_expansion() {
+        let mut macros = Macros::new();
+        macros.define("A", "");
+        assert!(macros.eval_condition("defined(A) && !defined B"));
+        macros.undefine("A");
+        assert!(!macros.eval_condition("defined A"));
+    }
+
+    #[test]
+    fn preprocess_keeps_active_branches_and_lines() {
+        let mut macros = Macros::new();
+        macros.define("CONFIG_A", "1");
+        let out = macros.preprocess(
+            "#if CONFIG_A\n\
+             a /* comment */\n\
+             #if CONFIG_B\n\
+             b\n\
+             #elif CONFIG_A > 0\n\
+             c\n\
+             #else\n\
+             d\n\
+             #endif\n\
+             #else\n\
+             #define X 1\n\
+             #endif\n\
+             #define Y \\\n    2\n\
+             e\n",
+        );
+        let lines: Vec<&str> = out.lines().map(str::trim).collect();
+        assert_eq!(
+            lines,
+            ["", "a", "", "", "", "c", "", "", "", "", "", "", "", "", "e"]
+        );
+        assert!(!macros.is_defined("X"));
+        assert_eq!(macros.eval("Y"), Ok(2));
+    }
+}
@@ -0,0 +1,553 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A top level function or variable declaration found in a header
+#[derive(Debug, Clone)]
+pub struct Declaration {
+    pub name: String,
+    pub kind: DeclarationKind,
+    /// The declaration with normalized whitespace, without the trailing `;`
+    pub text: String,
+    /// Path to use in `#include`, relative to the include directory
+    pub header: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeclarationKind {
+    Function,
+    Variable,
+}
+
+/// A struct, union or enum definition found in a header
+#[derive(Debug, Clone)]
+pub struct Aggregate {
+    pub name: String,
+    pub kind: AggregateKind,
+    /// Member declarations or enumerators with normalized whitespace
+    pub members: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AggregateKind {
+    Struct,
+    Union,
+    Enum,
+}
+
+/// Headers visible to a chip: `include/` without the other chips'
+/// directories, and `include/<chip>/`
+pub fn chip_headers(chip: &str) -> Vec<(PathBuf, String)> {
+    let mut headers = Vec::new();
+    collect_headers(Path::new("./include"), "", &mut headers, &|dir| {
+        !crate::CHIPS.contains(&dir)
+    });
+    collect_headers(
+        &Path::new("./include").join(chip),
+        "",
+        &mut headers,
+        &|_| true,
+    );
+    headers.sort();
+    headers
+}
+
+fn collect_headers(
+    dir: &Path,
+    prefix: &str,
+    headers: &mut Vec<(PathBuf, String)>,
+    descend: &dyn Fn(&str) -> bool,
+) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries {
+        let entry = entry.unwrap();
+        let name = entry.file_name().to_string_lossy().to_string();
+        let path = entry.path();
+        if path.is_dir() {
+            if descend(&name) {
+                collect_headers(&path, &format!("{prefix}{name}/"), headers, &|_| true);
+            }
+        } else if name.ends_with(".h") {
+            headers.push((path, format!("{prefix}{name}")));
+        }
+    }
+}
+
+/// Function and variable declarations of all headers visible to a chip
+pub fn chip_declarations(chip: &str) -> Vec<Declaration> {
+    chip_headers(chip)
+        .into_iter()
+        .flat_map(|(path, header)| {
+            let source =
+                fs::read_to_string(&path).unwrap_or_else(|_| panic!("Unable to read {path:?}"));
+            declarations(&source, &header)
+        })
+        .collect()
+}
+
+/// Remove comments, keeping line breaks
+pub fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    out.push(escaped);
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut last = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                    }
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+                out.push(' ');
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Remove preprocessor directives (and `#ifdef __cplusplus` blocks) from a
+/// header without comments, joining continued lines
+fn strip_directives(source: &str) -> String {
+    let source = source.replace("\\\n", " ");
+    let mut out = String::new();
+    // depth of the conditional currently skipped for C++, 0 if none
+    let mut cplusplus = 0;
+    let mut depth = 0;
+
+    for line in source.lines() {
+        let trimmed = line.trim_start();
+        if let Some(directive) = trimmed.strip_prefix('#') {
+            let directive = directive.trim_start();
+            if directive.starts_with("if") {
+                depth += 1;
+                if cplusplus == 0
+                    && (directive.starts_with("ifdef __cplusplus")
+                        || directive.starts_with("if defined(__cplusplus)")
+                        || directive.starts_with("if defined __cplusplus"))
+                {
+                    cplusplus = depth;
+                }
+            } else if directive.starts_with("endif") {
+                if depth == cplusplus {
+                    cplusplus = 0;
+                }
+                depth -= 1;
+            } else if (directive.starts_with("else") || directive.starts_with("elif"))
+                && depth == cplusplus
+            {
+                cplusplus = 0;
+            }
+            out.push('\n');
+            continue;
+        }
+
+        if cplusplus == 0 {
+            out.push_str(line);
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Top level function and variable declarations of a header
+pub fn declarations(source: &str, header: &str) -> Vec<Declaration> {
+    let source = strip_directives(&strip_comments(source));
+
+    let mut declarations = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    // a `{` directly after `)` starts a function body
+    let mut in_body = false;
+
+    for c in source.chars() {
+        match c {
+            '{' => {
+                if depth == 0 && current.trim_end().ends_with(')') {
+                    in_body = true;
+                }
+                depth += 1;
+                current.push(c);
+            }
+            '}' => {
+                depth -= 1;
+                current.push(c);
+                if depth == 0 && in_body {
+                    in_body = false;
+                    current.clear();
+                }
+            }
+            ';' if depth == 0 => {
+                let text = normalize(&current);
+                if let Some(declaration) = parse_declaration(&text, header) {
+                    declarations.push(declaration);
+                }
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+
+    declarations
+}
+
+/// Top level struct, union and enum definitions of a header, named by their
+/// typedef or tag
+pub fn aggregates(source: &str) -> Vec<Aggregate> {
+    let source = strip_directives(&strip_comments(source));
+
+    let mut aggregates = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut in_body = false;
+
+    for c in source.chars() {
+        match c {
+            '{' => {
+                if depth == 0 && current.trim_end().ends_with(')') {
+                    in_body = true;
+                }
+                depth += 1;
+                current.push(c);
+            }
+            '}' => {
+                depth -= 1;
+                current.push(c);
+                if depth == 0 && in_body {
+                    in_body = false;
+                    current.clear();
+                }
+            }
+            ';' if depth == 0 => {
+                let text = normalize(&strip_attributes(&current));
+                if let Some(aggregate) = parse_aggregate(&text) {
+                    aggregates.push(aggregate);
+                }
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+
+    aggregates
+}
+
+fn parse_aggregate(text: &str) -> Option<Aggregate> {
+    let text = text.strip_prefix("extern \"C\" ").unwrap_or(text);
+    let (typedef, text) = match text.strip_prefix("typedef ") {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (kind, text) = [
+        ("struct", AggregateKind::Struct),
+        ("union", AggregateKind::Union),
+        ("enum", AggregateKind::Enum),
+    ]
+    .into_iter()
+    .find_map(|(keyword, kind)| Some((kind, text.strip_prefix(keyword)?)))?;
+
+    let open = text.find('{')?;
+    let close = text.rfind('}')?;
+    let tag = text[..open].trim();
+    let declarators = text[close + 1..].trim();
+    let name = if typedef {
+        let first = declarators.split(',').next().unwrap_or_default();
+        trailing_identifier(first.trim_start_matches('*').trim())?
+    } else {
+        tag
+    };
+    if name.is_empty() {
+        return None;
+    }
+
+    let separator = if kind == AggregateKind::Enum {
+        ','
+    } else {
+        ';'
+    };
+    let mut members = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    for c in text[open + 1..close].chars() {
+        match c {
+            '{' | '(' => depth += 1,
+            '}' | ')' => depth -= 1,
+            _ => (),
+        }
+        if c == separator && depth == 0 {
+            members.push(normalize(&current));
+            current.clear();
+        } else {
+            current.push(c);
+        }
+    }
+    members.push(normalize(&current));
+    members.retain(|member| !member.is_empty());
+
+    Some(Aggregate {
+        name: name.to_string(),
+        kind,
+        members,
+    })
+}
+
+/// Remove `__attribute__((...))` specifiers
+fn strip_attributes(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("__attribute__") {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + "__attribute__".len()..];
+        let mut depth = 0;
+        let mut end = 0;
+        for (i, c) in rest.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                c if depth == 0 && !c.is_whitespace() => break,
+                _ => (),
+            }
+            end = i + c.len_utf8();
+            if depth == 0 && c == ')' {
+                break;
+            }
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Name of a struct member declaration like `uint8_t ssid[32]`,
+/// `uint32_t flag : 1` or `void (*cb)(void)`
+pub fn member_name(member: &str) -> Option<&str> {
+    if let Some(pointer) = member.find("(*") {
+        let rest = &member[pointer + 2..];
+        let end = rest.find(')')?;
+        return trailing_identifier(rest[..end].trim());
+    }
+    let end = member.find([':', '[']).unwrap_or(member.len());
+    trailing_identifier(member[..end].trim_end())
+}
+
+/// Collapse all whitespace runs into single spaces
+pub fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn parse_declaration(text: &str, header: &str) -> Option<Declaration> {
+    let text = text.strip_prefix("extern \"C\" ").unwrap_or(text);
+    // the event bases are declared by a macro of `esp_event_base.h`
+    let event_base = text
+        .strip_prefix("ESP_EVENT_DECLARE_BASE(")
+        .and_then(|id| id.strip_suffix(')'))
+        .map(|id| format!("extern esp_event_base_t const {}", id.trim()));
+    let text = event_base.as_deref().unwrap_or(text);
+    if text.is_empty()
+        || text.contains('{')
+        || text.starts_with("typedef")
+        || text.starts_with("static")
+    {
+        return None;
+    }
+
+    let declaration = |name: &str, kind| {
+        Some(Declaration {
+            name: name.to_string(),
+            kind,
+            text: text.to_string(),
+            header: header.to_string(),
+        })
+    };
+
+    if let Some(open) = text.find('(') {
+        let before = text[..open].trim_end();
+        let name = trailing_identifier(before)?;
+        let return_type = before[..before.len() - name.len()].trim();
+        // function pointer variables and macro invocations are no prototypes
+        if return_type.is_empty() || !text.ends_with(')') {
+            return None;
+        }
+        return declaration(name, DeclarationKind::Function);
+    }
+
+    let variable = text.strip_prefix("extern ")?;
+    let variable = match variable.find('[') {
+        Some(bracket) => &variable[..bracket],
+        None => variable,
+    };
+    let name = trailing_identifier(variable.trim_end())?;
+    declaration(name, DeclarationKind::Variable)
+}
+
+fn trailing_identifier(text: &str) -> Option<&str> {
+    let start = text
+        .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .map_or(0, |pos| pos + 1);
+    let name = &text[start..];
+    (!name.is_empty() && !name.starts_with(|c: char| c.is_ascii_digit())).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &str = r#"
+#pragma once
+#include "esp_err.h"
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* the event base, see `ESP_EVENT_DECLARE_BASE` */
+ESP_EVENT_DECLARE_BASE(WIFI_EVENT);
+
+typedef struct {
+    uint8_t ssid[32];   // "SSID"
+    uint32_t flag : 1;
+    void (*cb)(void *arg,
+               int len);
+} __attribute__((packed)) wifi_config_t;
+
+typedef enum {
+    WIFI_MODE_NULL = 0,
+    WIFI_MODE_STA,
+    WIFI_MODE_MAX = (1 << 2),
+} wifi_mode_t;
+
+struct tagged {
+    union {
+        int a;
+        float b;
+    } u;
+};
+
+extern const char *esp_version;
+extern uint8_t esp_mac[6];
+typedef void (*wifi_cb_t)(void);
+void (*esp_hook)(void);
+
+esp_err_t esp_wifi_init(const wifi_config_t *config);
+static inline int esp_helper(int x) { return x; }
+int esp_wifi_deinit(void) __attribute__((deprecated("use esp_wifi_stop")));
+ESP_STATIC_ASSERT(sizeof(int) == 4, "int");
+
+#ifdef __cplusplus
+}
+#endif
+"#;
+
+    #[test]
+    fn strips_comments_keeping_lines_and_strings() {
+        assert_eq!(
+            strip_comments("a /* b\nc */ d // e\n\"/* f */\""),
+            "a \n  d \n\"/* f */\""
+        );
+    }
+
+    #[test]
+    fn finds_declarations() {
+        let declarations = declarations(HEADER, "esp_wifi.h");
+        let found: Vec<(&str, DeclarationKind)> = declarations
+            .iter()
+            .map(|declaration| (declaration.name.as_str(), declaration.kind))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("WIFI_EVENT", DeclarationKind::Variable),
+                ("esp_version", DeclarationKind::Variable),
+                ("esp_mac", DeclarationKind::Variable),
+                ("esp_wifi_init", DeclarationKind::Function),
+                ("esp_wifi_deinit", DeclarationKind::Function),
+            ]
+        );
+        assert_eq!(
+            declarations[0].text,
+            "extern esp_event_base_t const WIFI_EVENT"
+        );
+        assert_eq!(
+            declarations[3].text,
+            "esp_err_t esp_wifi_init(const wifi_config_t *config)"
+        );
+        assert!(declarations.iter().all(|d| d.header == "esp_wifi.h"));
+    }
+
+    #[test]
+    fn finds_aggregates() {
+        let aggregates = aggregates(HEADER);
+        let found: Vec<(&str, AggregateKind)> = aggregates
+            .iter()
+            .map(|aggregate| (aggregate.name.as_str(), aggregate.kind))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("wifi_config_t", AggregateKind::Struct),
+                ("wifi_mode_t", AggregateKind::Enum),
+                ("tagged", AggregateKind::Struct),
+            ]
+        );
+        assert_eq!(
+            aggregates[0].members,
+            [
+                "uint8_t ssid[32]",
+                "uint32_t flag : 1",
+                "void (*cb)(void *arg, int len)"
+            ]
+        );
+        assert_eq!(
+            aggregates[1].members,
+            [
+                "WIFI_MODE_NULL = 0",
+                "WIFI_MODE_STA",
+                "WIFI_MODE_MAX = (1 << 2)"
+            ]
+        );
+        assert_eq!(aggregates[2].members, ["union { int a; float b; } u"]);
+    }
+
+    #[test]
+    fn member_names() {
+        assert_eq!(member_name("uint8_t ssid[32]"), Some("ssid"));
+        assert_eq!(member_name("uint32_t flag : 1"), Some("flag"));
+        assert_eq!(member_name("void (*cb)(void *arg, int len)"), Some("cb"));
+        assert_eq!(member_name("union { int a; float b; } u"), Some("u"));
+    }
+}
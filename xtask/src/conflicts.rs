@@ -0,0 +1,208 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+};
+
+use crate::archive::{Archive, Binding};
+
+/// Names provided by newlib and libgcc which the platform links anyway
+pub const LIBC_NAMES: &[&str] = &[
+    // stdio
+    "printf",
+    "sprintf",
+    "snprintf",
+    "vprintf",
+    "vsprintf",
+    "vsnprintf",
+    "fprintf",
+    "vfprintf",
+    "puts",
+    "putchar",
+    "fputs",
+    "fputc",
+    "sscanf",
+    "vsscanf",
+    // string
+    "memcpy",
+    "memmove",
+    "memset",
+    "memcmp",
+    "memchr",
+    "strlen",
+    "strnlen",
+    "strcpy",
+    "strncpy",
+    "strcat",
+    "strncat",
+    "strcmp",
+    "strncmp",
+    "strcasecmp",
+    "strncasecmp",
+    "strchr",
+    "strrchr",
+    "strstr",
+    "strdup",
+    "strndup",
+    "strtok",
+    "strtok_r",
+    "strerror",
+    "bzero",
+    // stdlib
+    "malloc",
+    "calloc",
+    "realloc",
+    "free",
+    "abort",
+    "exit",
+    "atoi",
+    "atol",
+    "strtol",
+    "strtoul",
+    "strtoll",
+    "strtoull",
+    "qsort",
+    "bsearch",
+    "rand",
+    "srand",
+    "rand_r",
+    "abs",
+    "labs",
+    // time
+    "time",
+    "gettimeofday",
+    "settimeofday",
+    "localtime",
+    "localtime_r",
+    "gmtime",
+    "gmtime_r",
+    "mktime",
+    "strftime",
+    // libgcc integer and soft-float helpers
+    "__divdi3",
+    "__udivdi3",
+    "__moddi3",
+    "__umoddi3",
+    "__muldi3",
+    "__ashldi3",
+    "__ashrdi3",
+    "__lshrdi3",
+    "__divsi3",
+    "__udivsi3",
+    "__modsi3",
+    "__umodsi3",
+    "__mulsi3",
+    "__clzsi2",
+    "__ctzsi2",
+    "__popcountsi2",
+    "__bswapsi2",
+    "__bswapdi2",
+    "__adddf3",
+    "__subdf3",
+    "__muldf3",
+    "__divdf3",
+    "__addsf3",
+    "__subsf3",
+    "__mulsf3",
+    "__divsf3",
+    "__fixdfsi",
+    "__fixunsdfsi",
+    "__floatsidf",
+    "__floatunsidf",
+    "__fixsfsi",
+    "__floatsisf",
+    "__extendsfdf2",
+    "__truncdfsf2",
+    "__eqdf2",
+    "__nedf2",
+    "__gedf2",
+    "__gtdf2",
+    "__ledf2",
+    "__ltdf2",
+];
+
+/// Check every chip for symbol conflicts and exit with an error if there
+/// are conflicts not yet recorded in `patch/<chip>/known_conflicts.txt`
+pub fn check(chips: &[String], bless: bool) {
+    let mut new_conflicts = 0;
+
+    for chip in chips {
+        log::info!("Checking {chip} for symbol conflicts");
+
+        let conflicts = find(&Archive::read_chip(chip));
+        let known_path = format!("./patch/{chip}/known_conflicts.txt");
+
+        if bless {
+            let mut contents = String::from(
+                "# Known symbol conflicts, update with `cargo xtask conflicts --bless`\n",
+            );
+            for conflict in &conflicts {
+                contents.push_str(conflict);
+                contents.push('\n');
+            }
+            fs::write(&known_path, contents).unwrap();
+            continue;
+        }
+
+        let known: BTreeSet<String> = fs::read_to_string(&known_path)
+            .unwrap_or_default()
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(String::from)
+            .collect();
+
+        for conflict in &conflicts {
+            if known.contains(conflict) {
+                log::debug!("{chip}: known {conflict}");
+            } else {
+                println!("{chip}: {conflict}");
+                new_conflicts += 1;
+            }
+        }
+        for resolved in known.difference(&conflicts) {
+            log::info!("{chip}: no longer present: {resolved}");
+        }
+    }
+
+    if new_conflicts > 0 {
+        eprintln!("{new_conflicts} new symbol conflicts");
+        std::process::exit(1);
+    }
+}
+
+/// One line per conflict, either `duplicate <symbol> <archive(member)...>`
+/// or `shadows-libc <symbol> <archive(member)>`
+fn find(archives: &[Archive]) -> BTreeSet<String> {
+    let mut definitions: BTreeMap<&str, BTreeMap<&str, &str>> = BTreeMap::new();
+    for archive in archives {
+        for member in &archive.members {
+            for symbol in &member.defined {
+                if symbol.binding == Binding::Strong {
+                    definitions
+                        .entry(&symbol.name)
+                        .or_default()
+                        .insert(&archive.name, &member.name);
+                }
+            }
+        }
+    }
+
+    let mut conflicts = BTreeSet::new();
+    for (symbol, defined_in) in definitions {
+        let defined_in: Vec<String> = defined_in
+            .iter()
+            .map(|(archive, member)| format!("{archive}({member})"))
+            .collect();
+
+        if defined_in.len() > 1 {
+            conflicts.insert(format!("duplicate {symbol} {}", defined_in.join(" ")));
+        }
+        if LIBC_NAMES.contains(&symbol) {
+            for location in defined_in {
+                conflicts.insert(format!("shadows-libc {symbol} {location}"));
+            }
+        }
+    }
+
+    conflicts
+}
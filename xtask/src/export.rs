@@ -0,0 +1,381 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use clap::Subcommand;
+
+use crate::{manifest, CHIPS};
+
+/// File marking a directory as an export, see [`replace_out`]
+const MARKER: &str = ".esp-wireless-export";
+
+#[derive(Debug, Subcommand)]
+pub enum Platform {
+    /// A self-contained directory with `include/`, `libs/` and `Make.defs`
+    /// and `CMakeLists.txt` fragments for a NuttX build
+    Nuttx {
+        #[arg(long)]
+        chip: String,
+
+        /// Output directory, defaults to `target/export/nuttx/<chip>`
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// A Zephyr module with `zephyr/module.yml`, `CMakeLists.txt` and
+    /// `Kconfig` wrapping the headers and libraries of the chips
+    Zephyr {
+        chips: Vec<String>,
+
+        /// Output directory, defaults to `target/export/zephyr`
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+}
+
+pub fn run(platform: Platform) {
+    match platform {
+        Platform::Nuttx { chip, out } => {
+            let out = out.unwrap_or_else(|| Path::new("./target/export/nuttx").join(&chip));
+            nuttx(&chip, &out)
+        }
+        Platform::Zephyr { chips, out } => {
+            let out = out.unwrap_or_else(|| PathBuf::from("./target/export/zephyr"));
+            zephyr(&crate::chips_or_default(chips), &out)
+        }
+    }
+}
+
+fn nuttx(chip: &str, out: &Path) {
+    check_chip(chip);
+    let version = fs::read_to_string("version").expect("Unable to read version");
+    let version = version.trim();
+
+    replace_out(out, "nuttx");
+    let include_dirs = copy_headers(&[chip], out);
+    let exported = copy_libraries(chip, &out.join("libs"));
+    copy(Path::new("version"), &out.join("version"));
+    let libraries: Vec<Vec<&str>> = manifest::link_order(chip)
+        .into_iter()
+        .map(|group| {
+            group
+                .into_iter()
+                .filter(|name| exported.iter().any(|library| library.name == *name))
+                .collect::<Vec<_>>()
+        })
+        .filter(|group| !group.is_empty())
+        .collect();
+
+    let header = format!(
+        "{version} wireless libraries for {chip}\n# Generated by `cargo xtask export nuttx --chip {chip}`"
+    );
+
+    let mut make = String::new();
+    writeln!(make, "{}", "#".repeat(76)).unwrap();
+    writeln!(make, "# {header}").unwrap();
+    writeln!(make, "{}\n", "#".repeat(76)).unwrap();
+    writeln!(
+        make,
+        "ESP_WIRELESS_DIR := $(patsubst %/,%,$(dir $(lastword $(MAKEFILE_LIST))))\n"
+    )
+    .unwrap();
+    for dir in &include_dirs {
+        writeln!(
+            make,
+            "CFLAGS += ${{INCDIR_PREFIX}}$(ESP_WIRELESS_DIR)/{dir}"
+        )
+        .unwrap();
+    }
+    for dir in &include_dirs {
+        writeln!(
+            make,
+            "CXXFLAGS += ${{INCDIR_PREFIX}}$(ESP_WIRELESS_DIR)/{dir}"
+        )
+        .unwrap();
+    }
+    writeln!(make, "\nEXTRA_LIBPATHS += -L $(ESP_WIRELESS_DIR)/libs\n").unwrap();
+    writeln!(
+        make,
+        "# In link order, libraries on one line depend on each other and rely on\n# the --start-group around EXTRA_LIBS"
+    )
+    .unwrap();
+    for group in &libraries {
+        let group: Vec<String> = group.iter().map(|name| format!("-l{name}")).collect();
+        writeln!(make, "EXTRA_LIBS += {}", group.join(" ")).unwrap();
+    }
+    write(&out.join("Make.defs"), &make);
+
+    let mut cmake = String::new();
+    writeln!(cmake, "# {header}\n").unwrap();
+    writeln!(cmake, "set(ESP_WIRELESS_INCLUDE_DIRS").unwrap();
+    for dir in &include_dirs {
+        writeln!(cmake, "    ${{CMAKE_CURRENT_LIST_DIR}}/{dir}").unwrap();
+    }
+    writeln!(cmake, ")\n").unwrap();
+    writeln!(cmake, "# In link order").unwrap();
+    writeln!(cmake, "set(ESP_WIRELESS_LIBRARIES").unwrap();
+    for name in libraries.iter().flatten() {
+        writeln!(cmake, "    ${{CMAKE_CURRENT_LIST_DIR}}/libs/lib{name}.a").unwrap();
+    }
+    writeln!(cmake, ")\n").unwrap();
+    writeln!(
+        cmake,
+        "if(TARGET arch)\n  target_include_directories(arch PRIVATE ${{ESP_WIRELESS_INCLUDE_DIRS}})\nendif()\n"
+    )
+    .unwrap();
+    writeln!(
+        cmake,
+        "if(COMMAND nuttx_add_extra_library)\n  nuttx_add_extra_library(${{ESP_WIRELESS_LIBRARIES}})\nendif()"
+    )
+    .unwrap();
+    write(&out.join("CMakeLists.txt"), &cmake);
+
+    log::info!("Exported {chip} to {out:?}");
+}
+
+fn zephyr(chips: &[String], out: &Path) {
+    for chip in chips {
+        check_chip(chip);
+    }
+    let version = fs::read_to_string("version").expect("Unable to read version");
+    let version = version.trim();
+    let header =
+        format!("# {version} wireless libraries\n# Generated by `cargo xtask export zephyr`\n");
+
+    replace_out(out, "zephyr");
+    let chip_names: Vec<&str> = chips.iter().map(|chip| chip.as_str()).collect();
+    copy_headers(&chip_names, out);
+    let mut exported = BTreeMap::new();
+    for chip in chips {
+        let libraries = copy_libraries(chip, &out.join("libs").join(chip));
+        exported.insert(chip.as_str(), libraries);
+    }
+    copy(Path::new("version"), &out.join("version"));
+
+    write(
+        &out.join("zephyr/module.yml"),
+        "name: esp-wireless\nbuild:\n  cmake: zephyr\n  kconfig: zephyr/Kconfig\n",
+    );
+
+    let series = |chip: &str| format!("SOC_SERIES_{}", chip.to_uppercase());
+    let mut kconfig = header.clone();
+    writeln!(kconfig, "\nconfig ESP_WIRELESS_CHIP\n\tstring").unwrap();
+    for chip in chips {
+        writeln!(kconfig, "\tdefault \"{chip}\" if {}", series(chip)).unwrap();
+    }
+    writeln!(
+        kconfig,
+        "\nmenuconfig ESP_WIRELESS\n\tbool \"Espressif wireless libraries\"\n\tdepends on ESP_WIRELESS_CHIP != \"\"\n\thelp\n\t  Link the {version} wireless libraries and add their headers.\n\nif ESP_WIRELESS"
+    )
+    .unwrap();
+    for feature in manifest::FEATURES {
+        let supported: Vec<String> = chips
+            .iter()
+            .filter(|chip| {
+                manifest::features(chip)
+                    .iter()
+                    .any(|supported| supported.name == feature.name)
+            })
+            .map(|chip| series(chip))
+            .collect();
+        if supported.is_empty() {
+            continue;
+        }
+        let symbol = feature_symbol(feature.name);
+        writeln!(
+            kconfig,
+            "\nconfig {symbol}\n\tbool \"{}\"",
+            feature.description
+        )
+        .unwrap();
+        if feature.requires.is_none() {
+            writeln!(kconfig, "\tdefault y").unwrap();
+        }
+        writeln!(kconfig, "\tdepends on {}", supported.join(" || ")).unwrap();
+        if let Some(requires) = feature.requires {
+            writeln!(kconfig, "\tdepends on {}", feature_symbol(requires)).unwrap();
+        }
+    }
+    writeln!(kconfig, "\nendif # ESP_WIRELESS").unwrap();
+    write(&out.join("zephyr/Kconfig"), &kconfig);
+
+    let mut cmake = header;
+    writeln!(cmake, "\nif(CONFIG_ESP_WIRELESS)").unwrap();
+    writeln!(
+        cmake,
+        "  set(ESP_WIRELESS_DIR ${{CMAKE_CURRENT_LIST_DIR}}/..)"
+    )
+    .unwrap();
+    writeln!(
+        cmake,
+        "  set(ESP_WIRELESS_LIBS ${{ESP_WIRELESS_DIR}}/libs/${{CONFIG_ESP_WIRELESS_CHIP}})\n"
+    )
+    .unwrap();
+    writeln!(cmake, "  zephyr_include_directories(").unwrap();
+    // the chip is only known to the Zephyr build
+    for dir in manifest::include_dirs("${CONFIG_ESP_WIRELESS_CHIP}") {
+        writeln!(cmake, "    ${{ESP_WIRELESS_DIR}}/{dir}").unwrap();
+    }
+    writeln!(cmake, "  )\n").unwrap();
+    writeln!(cmake, "  # In link order").unwrap();
+    writeln!(cmake, "  set(ESP_WIRELESS_LIBRARIES)").unwrap();
+    for chip in chips {
+        writeln!(cmake, "  if(CONFIG_{})", series(chip)).unwrap();
+        let libraries = &exported[chip.as_str()];
+        // consecutive libraries of a feature share one condition
+        let mut runs: Vec<(Option<&str>, Vec<&str>)> = Vec::new();
+        for name in manifest::link_order(chip).into_iter().flatten() {
+            let Some(library) = libraries.iter().find(|library| library.name == name) else {
+                continue;
+            };
+            match runs.last_mut() {
+                Some((feature, names)) if *feature == library.feature => names.push(name),
+                _ => runs.push((library.feature, vec![name])),
+            }
+        }
+        for (feature, names) in runs {
+            let paths: Vec<String> = names
+                .iter()
+                .map(|name| format!("${{ESP_WIRELESS_LIBS}}/lib{name}.a"))
+                .collect();
+            match feature {
+                Some(feature) => {
+                    writeln!(cmake, "    if(CONFIG_{})", feature_symbol(feature)).unwrap();
+                    writeln!(cmake, "      list(APPEND ESP_WIRELESS_LIBRARIES").unwrap();
+                    for path in paths {
+                        writeln!(cmake, "        {path}").unwrap();
+                    }
+                    writeln!(cmake, "      )\n    endif()").unwrap();
+                }
+                None => {
+                    writeln!(cmake, "    list(APPEND ESP_WIRELESS_LIBRARIES").unwrap();
+                    for path in paths {
+                        writeln!(cmake, "      {path}").unwrap();
+                    }
+                    writeln!(cmake, "    )").unwrap();
+                }
+            }
+        }
+        writeln!(cmake, "  endif()").unwrap();
+    }
+    writeln!(
+        cmake,
+        "\n  zephyr_link_libraries(-Wl,--start-group ${{ESP_WIRELESS_LIBRARIES}} -Wl,--end-group)"
+    )
+    .unwrap();
+    writeln!(cmake, "endif()").unwrap();
+    write(&out.join("zephyr/CMakeLists.txt"), &cmake);
+
+    log::info!("Exported {} to {out:?}", chips.join(", "));
+}
+
+fn feature_symbol(feature: &str) -> String {
+    format!("ESP_WIRELESS_{}", feature.to_uppercase())
+}
+
+/// Start an export in `out`. An existing directory is only replaced if it
+/// is empty or holds a previous export, anything else is left alone.
+fn replace_out(out: &Path, platform: &str) {
+    if out.exists() {
+        let empty = fs::read_dir(out).is_ok_and(|mut entries| entries.next().is_none());
+        if !empty && !out.join(MARKER).is_file() {
+            eprintln!("{out:?} exists and holds no previous export, refusing to replace it");
+            std::process::exit(1);
+        }
+        fs::remove_dir_all(out).unwrap_or_else(|e| panic!("Unable to remove {out:?}: {e}"));
+    }
+    write(
+        &out.join(MARKER),
+        &format!("Generated by `cargo xtask export {platform}`, replaced by the next export\n"),
+    );
+}
+
+fn check_chip(chip: &str) {
+    if !CHIPS.contains(&chip) {
+        eprintln!("Unknown chip {chip}");
+        std::process::exit(1);
+    }
+}
+
+/// Copy the include directories of the chips to `out` in the layout of
+/// this repository, returning them in search order
+fn copy_headers(chips: &[&str], out: &Path) -> Vec<String> {
+    let include_dirs = manifest::include_dirs(chips[0]);
+    for dir in &include_dirs {
+        // nested include directories are copied with their parent
+        if include_dirs
+            .iter()
+            .any(|parent| dir.starts_with(&format!("{parent}/")))
+        {
+            continue;
+        }
+        // headers of the other chips and the per-chip update inputs in
+        // `patch/` are left out
+        copy_tree(Path::new(dir), &out.join(dir), &|path: &Path| {
+            path.is_dir()
+                && path.file_name().is_some_and(|name| {
+                    let name = &*name.to_string_lossy();
+                    CHIPS.contains(&name)
+                        && !(chips.contains(&name) && path == Path::new("include").join(name))
+                })
+        });
+    }
+    include_dirs
+}
+
+/// Copy the vendored libraries and linker scripts of a chip to `libs`,
+/// returning the libraries
+fn copy_libraries(chip: &str, libs: &Path) -> Vec<manifest::Library> {
+    let mut exported = Vec::new();
+    for library in manifest::libraries(chip) {
+        let from = Path::new("./libs").join(chip).join(library.file_name());
+        if !from.exists() {
+            log::warn!("No {from:?}, left out of the export");
+            continue;
+        }
+        copy(&from, &libs.join(library.file_name()));
+        exported.push(library);
+    }
+    let ld = Path::new("./libs").join(chip).join("ld");
+    if ld.exists() {
+        copy_tree(&ld, &libs.join("ld"), &|_: &Path| false);
+    }
+    exported
+}
+
+fn copy_tree(from: &Path, to: &Path, skip: &dyn Fn(&Path) -> bool) {
+    fs::create_dir_all(to).unwrap();
+    let mut entries: Vec<PathBuf> = fs::read_dir(from)
+        .unwrap_or_else(|_| panic!("Unable to read {from:?}"))
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        if skip(&path) {
+            continue;
+        }
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_tree(&path, &target, skip);
+        } else {
+            copy(&path, &target);
+        }
+    }
+}
+
+fn copy(from: &Path, to: &Path) {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent).unwrap();
+    }
+    fs::copy(from, to).unwrap_or_else(|_| panic!("Unable to copy {from:?} to {to:?}"));
+}
+
+fn write(path: &Path, content: &str) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).unwrap();
+    }
+    fs::write(path, content).unwrap_or_else(|_| panic!("Unable to write {path:?}"));
+}
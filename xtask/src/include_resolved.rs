@@ -0,0 +1,229 @@
+use std::{collections::BTreeSet, fs, path::Path};
+
+use crate::{
+    cexpr::{self, Macros},
+    cheader::{self, strip_comments},
+    sdkconfig,
+};
+
+const DIR: &str = "./include-resolved";
+
+/// Write `include-resolved/<chip>/`, the headers visible to each chip with
+/// the conditionals depending on its sdkconfig and capabilities resolved
+pub fn generate(chips: &[String]) {
+    for chip in chips {
+        let dst = Path::new(DIR).join(chip);
+        fs::remove_dir_all(&dst).ok();
+
+        let config = config(chip);
+        // the defaults of `soc_caps.h` are guarded by `#ifndef`
+        let sdkconfig = sdkconfig::macros(chip);
+        let headers = cheader::chip_headers(chip);
+        for (path, header) in &headers {
+            let source =
+                fs::read_to_string(path).unwrap_or_else(|_| panic!("Unable to read {path:?}"));
+            let to = dst.join(header);
+            fs::create_dir_all(to.parent().unwrap()).unwrap();
+            let config = if header == "soc/soc_caps.h" {
+                &sdkconfig
+            } else {
+                &config
+            };
+            fs::write(&to, resolve(&source, config))
+                .unwrap_or_else(|_| panic!("Unable to write {to:?}"));
+        }
+        log::info!("Wrote {} headers to {dst:?}", headers.len());
+    }
+}
+
+/// The macros conditionals are resolved against: `sdkconfig.h` and
+/// `soc/soc_caps.h` of the chip
+fn config(chip: &str) -> Macros {
+    let mut macros = sdkconfig::macros(chip);
+    let path = format!("./include/{chip}/soc/soc_caps.h");
+    match fs::read_to_string(&path) {
+        Ok(source) => {
+            macros.preprocess(&source);
+        }
+        Err(_) => log::warn!("No {path}, SOC_* conditionals are kept"),
+    }
+    macros
+}
+
+/// Whether a conditional of the source holds for the chip, `None` if it
+/// names anything but Kconfig options, capabilities and macros defined
+/// outside of kept conditionals. Undefined ones are disabled.
+fn eval(config: &Macros, uncertain: &BTreeSet<String>, condition: &str) -> Option<bool> {
+    let tokens = cexpr::tokenize(condition);
+    let mut resolved = Vec::new();
+    for (i, token) in tokens.iter().enumerate() {
+        let identifier = token.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_');
+        if !identifier || token == "defined" {
+            resolved.push(token.as_str());
+            continue;
+        }
+        if uncertain.contains(token) {
+            return None;
+        }
+        let defined = config.is_defined(token);
+        if !(defined || token.starts_with("CONFIG_") || token.starts_with("SOC_")) {
+            return None;
+        }
+        let operand = match i {
+            1.. if tokens[i - 1] == "defined" => true,
+            2.. => tokens[i - 1] == "(" && tokens[i - 2] == "defined",
+            _ => false,
+        };
+        resolved.push(if defined || operand { token } else { "0" });
+    }
+    config
+        .eval(&resolved.join(" "))
+        .ok()
+        .map(|value| value != 0)
+}
+
+/// A conditional group of the source
+struct Group {
+    /// Whether the lines around the group are written
+    parent: bool,
+    /// Whether an `#if` of the group was written, so its `#endif` is too
+    kept: bool,
+    /// Whether a branch known to hold was seen, the others are dropped
+    done: bool,
+    /// Whether the lines of the current branch are written
+    active: bool,
+}
+
+/// The source with the conditionals `config` decides replaced by their
+/// active branch, everything else including comments as it is
+fn resolve(source: &str, config: &Macros) -> String {
+    let mut config = config.clone();
+    // defined or undefined in a kept conditional
+    let mut uncertain = BTreeSet::new();
+    let stripped = strip_comments(source);
+    let mut raw = source.lines();
+    let mut lines = stripped.lines();
+    let mut out = String::with_capacity(source.len());
+    let mut groups: Vec<Group> = Vec::new();
+
+    while let Some(line) = lines.next() {
+        let mut text = vec![raw.next().unwrap_or_default()];
+        let mut line = line.to_string();
+        while let Some(joined) = line.strip_suffix('\\') {
+            line = format!("{joined} {}", lines.next().unwrap_or_default());
+            text.push(raw.next().unwrap_or_default());
+        }
+        let active = groups.last().is_none_or(|group| group.active);
+        let mut write = |text: &[&str]| {
+            for line in text {
+                out.push_str(line);
+                out.push('\n');
+            }
+        };
+
+        let Some(directive) = line.trim_start().strip_prefix('#') else {
+            if active {
+                write(&text);
+            }
+            continue;
+        };
+        let directive = directive.trim();
+        let keyword_len = directive
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(directive.len());
+        let (keyword, rest) = (&directive[..keyword_len], directive[keyword_len..].trim());
+        let indent = &text[0][..text[0].len() - text[0].trim_start().len()];
+
+        match keyword {
+            "if" | "ifdef" | "ifndef" => {
+                let condition = match keyword {
+                    "if" => rest.to_string(),
+                    "ifdef" => format!("defined({rest})"),
+                    _ => format!("!defined({rest})"),
+                };
+                let value = if active {
+                    eval(&config, &uncertain, &condition)
+                } else {
+                    None
+                };
+                if active && value.is_none() {
+                    write(&text);
+                }
+                groups.push(Group {
+                    parent: active,
+                    kept: active && value.is_none(),
+                    done: value == Some(true),
+                    active: active && value != Some(false),
+                });
+            }
+            "elif" | "else" => {
+                let Some(group) = groups.last_mut() else {
+                    write(&text);
+                    continue;
+                };
+                if !group.parent || group.done {
+                    group.active = false;
+                    continue;
+                }
+                let value = if keyword == "else" {
+                    Some(true)
+                } else {
+                    eval(&config, &uncertain, rest)
+                };
+                match (value, group.kept) {
+                    (Some(false), _) => group.active = false,
+                    (Some(true), true) => {
+                        if keyword == "else" {
+                            write(&text);
+                        } else {
+                            write(&[&format!("{indent}#else")]);
+                        }
+                        group.done = true;
+                        group.active = true;
+                    }
+                    (Some(true), false) => {
+                        group.done = true;
+                        group.active = true;
+                    }
+                    (None, true) => {
+                        write(&text);
+                        group.active = true;
+                    }
+                    // the first branch not known to be false
+                    (None, false) => {
+                        let rewritten = text[0].replacen("elif", "if", 1);
+                        let mut text = text.clone();
+                        text[0] = &rewritten;
+                        write(&text);
+                        group.kept = true;
+                        group.active = true;
+                    }
+                }
+            }
+            "endif" => match groups.pop() {
+                Some(group) if !group.kept => (),
+                _ => write(&text),
+            },
+            "define" | "undef" if active => {
+                write(&text);
+                let name_len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len());
+                let name = &rest[..name_len];
+                if groups.iter().any(|group| group.kept) {
+                    uncertain.insert(name.to_string());
+                } else if keyword == "define" {
+                    config.preprocess(&format!("#define {rest}"));
+                } else {
+                    config.undefine(name);
+                }
+            }
+            _ => {
+                if active {
+                    write(&text);
+                }
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,288 @@
+use std::{collections::BTreeMap, fmt::Write, fs};
+
+use crate::{
+    cdata::{self, Enum},
+    sdkconfig, CHIPS,
+};
+
+const DIR: &str = "./interrupts";
+const RUST: &str = "./interrupts/interrupts.rs";
+const MARKDOWN: &str = "./interrupts/wireless.md";
+
+/// Headers defining the interrupt matrix sources, older releases have them
+/// in `periph_defs.h`
+const HEADERS: &[&str] = &["soc/interrupts.h", "soc/periph_defs.h"];
+
+/// Words of the normalized source names the Wi-Fi, BT, IEEE 802.15.4 and
+/// coex drivers use
+const WIRELESS: &[&str] = &["WIFI", "BT", "BLE", "RWBT", "RWBLE", "ZB", "COEX"];
+
+/// An interrupt matrix source of a chip
+#[derive(Debug)]
+struct Source {
+    /// The enumerator, e.g. `ETS_WIFI_MAC_INTR_SOURCE`
+    c_name: String,
+    /// Without `ETS_` and `_INTR_SOURCE`/`_SOURCE`, e.g. `WIFI_MAC`
+    name: String,
+    value: u32,
+}
+
+/// Parse the `ETS_*_SOURCE` enumeration of every chip and write the Rust
+/// enums `interrupts/interrupts.rs` and the table of the wireless sources
+/// `interrupts/wireless.md`
+pub fn generate() {
+    let version = fs::read_to_string("version").expect("Unable to read version");
+    let version = version.trim();
+
+    let chips: Vec<(&str, Vec<Source>)> = CHIPS
+        .iter()
+        .filter_map(|chip| Some((*chip, sources(chip)?)))
+        .collect();
+    if chips.is_empty() {
+        eprintln!("No include/<chip>/soc/interrupts.h, run the update first");
+        std::process::exit(1);
+    }
+
+    fs::create_dir_all(DIR).unwrap();
+    write(RUST, &rust(version, &chips));
+    write(MARKDOWN, &markdown(version, &chips));
+    log::info!("Wrote {RUST} and {MARKDOWN}");
+}
+
+/// The interrupt sources of a chip in value order, `None` if its headers
+/// are missing
+fn sources(chip: &str) -> Option<Vec<Source>> {
+    let mut macros = sdkconfig::macros(chip);
+    let mut found = false;
+    let mut enumeration: Option<Enum> = None;
+    for header in HEADERS {
+        let path = format!("./include/{chip}/{header}");
+        let Ok(source) = fs::read_to_string(&path) else {
+            continue;
+        };
+        found = true;
+        let preprocessed = macros.preprocess(&source);
+        let data = cdata::parse(&preprocessed, &macros).unwrap_or_else(|e| {
+            eprintln!("Unable to parse {path}: {e}");
+            std::process::exit(1);
+        });
+        enumeration = enumeration.or(data.enums.into_iter().find(|definition| {
+            definition
+                .enumerators
+                .iter()
+                .any(|(name, _)| name.starts_with("ETS_") && name.ends_with("_SOURCE"))
+        }));
+    }
+    if !found {
+        return None;
+    }
+    let Some(enumeration) = enumeration else {
+        eprintln!("No ETS_*_SOURCE enumeration for {chip} in {HEADERS:?}");
+        std::process::exit(1);
+    };
+
+    let mut sources: Vec<Source> = Vec::new();
+    for (c_name, value) in enumeration.enumerators {
+        // the count, not a source
+        if c_name.starts_with("ETS_MAX_") {
+            continue;
+        }
+        let value = u32::try_from(value)
+            .unwrap_or_else(|_| panic!("{chip}: {c_name} has the invalid value {value}"));
+        let name = c_name.trim_start_matches("ETS_");
+        let name = name
+            .strip_suffix("_INTR_SOURCE")
+            .or_else(|| name.strip_suffix("_SOURCE"))
+            .unwrap_or(name)
+            .to_string();
+        sources.push(Source {
+            c_name,
+            name,
+            value,
+        });
+    }
+    // aliases come after the source they name
+    sources.sort_by_key(|source| source.value);
+    Some(sources)
+}
+
+/// Normalized name → chip → value of the wireless sources
+fn wireless_table<'a>(
+    chips: &[(&'a str, Vec<Source>)],
+) -> BTreeMap<String, BTreeMap<&'a str, u32>> {
+    let mut table: BTreeMap<String, BTreeMap<&str, u32>> = BTreeMap::new();
+    for (chip, sources) in chips {
+        for source in sources.iter().filter(|source| is_wireless(&source.name)) {
+            table
+                .entry(source.name.clone())
+                .or_default()
+                .insert(chip, source.value);
+        }
+    }
+    table
+}
+
+fn is_wireless(name: &str) -> bool {
+    name.split('_').any(|word| WIRELESS.contains(&word))
+}
+
+/// `WIFI_MAC_NMI` → `WifiMacNmi`
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_string() + &chars.as_str().to_ascii_lowercase(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn rust(version: &str, chips: &[(&str, Vec<Source>)]) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! Interrupt matrix sources of {version}, generated by `cargo xtask interrupts`\n//!\n//! `InterruptSource` of a chip module is the `intr_source` argument of\n//! `wifi_osi_funcs_t::_set_intr` and friends.\n"
+    )
+    .unwrap();
+    writeln!(out, "pub const IDF_VERSION: &str = \"{version}\";").unwrap();
+
+    for (chip, sources) in chips {
+        writeln!(out, "\npub mod {chip} {{").unwrap();
+        writeln!(
+            out,
+            "    /// The `periph_interrupt_t` of `include/{chip}/soc/interrupts.h`"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]"
+        )
+        .unwrap();
+        writeln!(out, "    #[repr(u32)]").unwrap();
+        writeln!(out, "    pub enum InterruptSource {{").unwrap();
+        let mut variants: BTreeMap<u32, String> = BTreeMap::new();
+        let mut aliases = Vec::new();
+        for source in sources {
+            let variant = camel_case(&source.name);
+            match variants.get(&source.value) {
+                Some(original) => aliases.push((source, original.clone())),
+                None => {
+                    writeln!(out, "        /// `{}`", source.c_name).unwrap();
+                    writeln!(out, "        {variant} = {},", source.value).unwrap();
+                    variants.insert(source.value, variant);
+                }
+            }
+        }
+        writeln!(out, "    }}\n").unwrap();
+
+        writeln!(out, "    impl InterruptSource {{").unwrap();
+        for (source, original) in &aliases {
+            writeln!(out, "        /// `{}`", source.c_name).unwrap();
+            writeln!(
+                out,
+                "        pub const {}: Self = Self::{original};",
+                source.name
+            )
+            .unwrap();
+        }
+        let wireless: Vec<String> = sources
+            .iter()
+            .filter(|source| {
+                is_wireless(&source.name) && variants[&source.value] == camel_case(&source.name)
+            })
+            .map(|source| format!("Self::{}", camel_case(&source.name)))
+            .collect();
+        writeln!(
+            out,
+            "        /// The sources of the Wi-Fi, BT, IEEE 802.15.4 and coex hardware"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "        pub const WIRELESS: &'static [Self] = &[{}];",
+            wireless.join(", ")
+        )
+        .unwrap();
+        let count = sources
+            .iter()
+            .map(|source| source.value + 1)
+            .max()
+            .unwrap_or(0);
+        writeln!(
+            out,
+            "        /// One more than the highest source, `ETS_MAX_INTR_SOURCE`"
+        )
+        .unwrap();
+        writeln!(out, "        pub const COUNT: u32 = {count};").unwrap();
+        writeln!(out, "    }}\n").unwrap();
+
+        writeln!(out, "    impl TryFrom<u32> for InterruptSource {{").unwrap();
+        writeln!(out, "        type Error = u32;\n").unwrap();
+        writeln!(
+            out,
+            "        fn try_from(value: u32) -> Result<Self, Self::Error> {{"
+        )
+        .unwrap();
+        writeln!(out, "            match value {{").unwrap();
+        for (value, variant) in &variants {
+            writeln!(out, "                {value} => Ok(Self::{variant}),").unwrap();
+        }
+        writeln!(out, "                _ => Err(value),").unwrap();
+        writeln!(out, "            }}\n        }}\n    }}\n").unwrap();
+
+        writeln!(out, "    impl From<InterruptSource> for u32 {{").unwrap();
+        writeln!(
+            out,
+            "        fn from(source: InterruptSource) -> u32 {{\n            source as u32\n        }}\n    }}"
+        )
+        .unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+
+    let table = wireless_table(chips);
+    let names: Vec<&str> = chips.iter().map(|(chip, _)| *chip).collect();
+    writeln!(
+        out,
+        "\n/// The Wi-Fi, BT, IEEE 802.15.4 and coex sources by name, with their\n/// number on the chips having them"
+    )
+    .unwrap();
+    writeln!(out, "pub const WIRELESS: &[(&str, &[(&str, u32)])] = &[").unwrap();
+    for (name, values) in &table {
+        let values: Vec<String> = names
+            .iter()
+            .filter_map(|chip| Some(format!("(\"{chip}\", {})", values.get(chip)?)))
+            .collect();
+        writeln!(out, "    (\"{name}\", &[{}]),", values.join(", ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    out
+}
+
+fn markdown(version: &str, chips: &[(&str, Vec<Source>)]) -> String {
+    let table = wireless_table(chips);
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "# Wireless interrupt sources\n\nThe Wi-Fi, BT, IEEE 802.15.4 and coex interrupt matrix sources of {version}, as `ETS_<name>_INTR_SOURCE` or `ETS_<name>_SOURCE`, generated by `cargo xtask interrupts`.\n"
+    )
+    .unwrap();
+    let names: Vec<&str> = chips.iter().map(|(chip, _)| *chip).collect();
+    writeln!(out, "| Source | {} |", names.join(" | ")).unwrap();
+    writeln!(out, "|---|{}", "---|".repeat(names.len())).unwrap();
+    for (name, values) in &table {
+        let cells: Vec<String> = names
+            .iter()
+            .map(|chip| values.get(chip).map(u32::to_string).unwrap_or_default())
+            .collect();
+        writeln!(out, "| `{name}` | {} |", cells.join(" | ")).unwrap();
+    }
+    out
+}
+
+fn write(path: &str, content: &str) {
+    fs::write(path, content).unwrap_or_else(|_| panic!("Unable to write {path}"));
+}
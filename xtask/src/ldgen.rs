@@ -0,0 +1,536 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
+
+use crate::sdkconfig::Sdkconfig;
+
+/// Components whose linker fragments place code of the vendored archives
+const COMPONENTS: &[&str] = &["esp_wifi", "esp_phy", "esp_coex", "bt", "ieee802154"];
+
+/// Output section targets every port has, defined even without rules
+const TARGETS: &[&str] = &[
+    "iram0_text",
+    "iram0_data",
+    "iram0_bss",
+    "dram0_data",
+    "dram0_bss",
+    "flash_text",
+    "flash_rodata",
+    "rtc_text",
+    "rtc_data",
+    "rtc_bss",
+];
+
+/// Sections and schemes of ESP-IDF's common linker fragments, which the
+/// wireless fragments build on
+const BUILTIN: &str = "
+[sections:text]
+entries:
+    .text+
+    .literal+
+
+[sections:data]
+entries:
+    .data+
+
+[sections:bss]
+entries:
+    .bss+
+
+[sections:common]
+entries:
+    COMMON
+
+[sections:rodata]
+entries:
+    .rodata+
+
+[sections:rtc_text]
+entries:
+    .rtc.text+
+    .rtc.literal
+
+[sections:rtc_data]
+entries:
+    .rtc.data+
+
+[sections:rtc_rodata]
+entries:
+    .rtc.rodata+
+
+[sections:rtc_bss]
+entries:
+    .rtc.bss
+
+[sections:iram]
+entries:
+    .iram1+
+
+[sections:dram]
+entries:
+    .dram1+
+
+[scheme:default]
+entries:
+    text -> flash_text
+    rodata -> flash_rodata
+    data -> dram0_data
+    bss -> dram0_bss
+    common -> dram0_bss
+    iram -> iram0_text
+    dram -> dram0_data
+    rtc_text -> rtc_text
+    rtc_data -> rtc_data
+    rtc_rodata -> rtc_data
+    rtc_bss -> rtc_bss
+
+[scheme:rtc]
+entries:
+    text -> rtc_text
+    data -> rtc_data
+    rodata -> rtc_data
+    bss -> rtc_bss
+    common -> rtc_bss
+
+[scheme:noflash]
+entries:
+    text -> iram0_text
+    rodata -> dram0_data
+
+[scheme:noflash_text]
+entries:
+    text -> iram0_text
+
+[scheme:noflash_data]
+entries:
+    rodata -> dram0_data
+";
+
+/// Linker fragments with their conditions evaluated
+#[derive(Debug, Default)]
+struct Fragments {
+    /// Input section patterns, `.name+` also matches `.name.*`
+    sections: BTreeMap<String, Vec<String>>,
+    /// Sections fragment and target of each scheme
+    schemes: BTreeMap<String, Vec<(String, String)>>,
+    mappings: Vec<Mapping>,
+}
+
+#[derive(Debug)]
+struct Mapping {
+    archive: String,
+    entries: Vec<MappingEntry>,
+}
+
+/// `object:symbol (scheme)`, `*` for the whole archive
+#[derive(Debug)]
+struct MappingEntry {
+    object: String,
+    symbol: Option<String>,
+    scheme: String,
+}
+
+/// A frame of nested `if`/`elif`/`else` conditions
+struct Condition {
+    indent: usize,
+    /// Whether the enclosing conditions hold
+    parent: bool,
+    /// Whether a branch of this conditional was taken before
+    taken: bool,
+    active: bool,
+}
+
+/// Copy the linker fragments of the wireless components to `dst`, named
+/// after their component
+pub fn copy_fragments(idf_path: &str, dst: &str) {
+    for component in COMPONENTS {
+        let dir = format!("{idf_path}/components/{component}");
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut names: Vec<String> = entries
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .filter(|name| name.ends_with(".lf"))
+            .collect();
+        names.sort();
+
+        for name in names {
+            let to = match name.as_str() {
+                "linker.lf" => format!("{component}.lf"),
+                _ => format!("{component}_{name}"),
+            };
+            crate::copy_file(&format!("{dir}/{name}"), &format!("{dst}/{to}"));
+        }
+    }
+}
+
+/// Render `libs/<chip>/ld/wireless_sections.ld` from the vendored linker
+/// fragments and the chip's sdkconfig.h
+pub fn render(chip: &str) {
+    log::info!("Render linker fragments for {chip}");
+
+    let dir = format!("./libs/{chip}/ld");
+    let mut paths: Vec<_> = fs::read_dir(&dir)
+        .into_iter()
+        .flatten()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "lf"))
+        .collect();
+    if paths.is_empty() {
+        eprintln!("{chip}: no linker fragments in {dir}, run the update first");
+        std::process::exit(1);
+    }
+    paths.sort();
+
+    let config = Sdkconfig::read(chip);
+    let mut fragments = Fragments::default();
+    parse(BUILTIN, &config, &mut fragments);
+    for path in &paths {
+        let source = fs::read_to_string(path).unwrap_or_else(|_| panic!("Unable to read {path:?}"));
+        parse(&source, &config, &mut fragments);
+    }
+
+    let archives: BTreeSet<String> = fs::read_dir(format!("./libs/{chip}"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|name| name.ends_with(".a"))
+        .collect();
+
+    let rules = rules(&fragments, &archives);
+
+    let mut out = format!(
+        "/*
+ * Generated by `cargo xtask ld-sections {chip}`, do not edit
+ *
+ * Placement of the wireless libraries according to the ESP-IDF linker
+ * fragments next to this file, evaluated against include/{chip}/sdkconfig.h.
+ * Run the linker script through the C preprocessor and use each macro in
+ * the output section of its target, before the catch-all input sections.
+ */
+"
+    );
+
+    let mut targets: Vec<&str> = TARGETS.to_vec();
+    targets.extend(
+        rules
+            .keys()
+            .map(String::as_str)
+            .filter(|target| !TARGETS.contains(target)),
+    );
+    for target in targets {
+        let macro_name = format!("WIRELESS_{}", target.to_uppercase());
+        let lines = rules.get(target).map(Vec::as_slice).unwrap_or_default();
+        out.push_str(&format!("\n#define {macro_name}"));
+        for line in lines {
+            out.push_str(&format!(" \\\n    {line}"));
+        }
+        out.push('\n');
+    }
+
+    fs::write(Path::new(&dir).join("wireless_sections.ld"), out).unwrap();
+}
+
+/// Add the fragments of a linker fragment file to `fragments`, skipping
+/// the branches of conditions `config` does not satisfy
+fn parse(source: &str, config: &Sdkconfig, fragments: &mut Fragments) {
+    // kind and name of the current fragment
+    let mut fragment: Option<(String, String)> = None;
+    let mut in_entries = false;
+    let mut conditions: Vec<Condition> = Vec::new();
+
+    for line in source.lines() {
+        let line = match line.find('#') {
+            Some(comment) => &line[..comment],
+            None => line,
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+        let line = line.trim();
+
+        if let Some(header) = line.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+            let (kind, name) = header.split_once(':').unwrap_or((header, ""));
+            fragment = Some((kind.trim().to_string(), name.trim().to_string()));
+            in_entries = false;
+            conditions.clear();
+            if kind == "mapping" {
+                fragments.mappings.push(Mapping {
+                    archive: String::new(),
+                    entries: Vec::new(),
+                });
+            }
+            continue;
+        }
+        let Some((kind, name)) = &fragment else {
+            continue;
+        };
+
+        let is_branch = line.starts_with("elif ") || line == "else:";
+        while let Some(condition) = conditions.last() {
+            if indent < condition.indent || (indent == condition.indent && !is_branch) {
+                conditions.pop();
+            } else {
+                break;
+            }
+        }
+        let active = conditions.last().is_none_or(|condition| condition.active);
+
+        if let Some(expression) = line.strip_prefix("if ") {
+            let value = active && config.eval(expression.trim_end_matches(':'));
+            conditions.push(Condition {
+                indent,
+                parent: active,
+                taken: value,
+                active: value,
+            });
+            continue;
+        }
+        if is_branch {
+            let condition = conditions
+                .last_mut()
+                .unwrap_or_else(|| panic!("`{line}` without `if` in {kind}:{name}"));
+            let value = match line.strip_prefix("elif ") {
+                Some(expression) => config.eval(expression.trim_end_matches(':')),
+                None => true,
+            };
+            condition.active = condition.parent && !condition.taken && value;
+            condition.taken |= condition.active;
+            continue;
+        }
+        if !active {
+            continue;
+        }
+
+        if let Some(archive) = line.strip_prefix("archive:") {
+            if let Some(mapping) = fragments.mappings.last_mut() {
+                mapping.archive = archive.trim().to_string();
+            }
+            continue;
+        }
+        if line == "entries:" {
+            in_entries = true;
+            continue;
+        }
+        if !in_entries {
+            continue;
+        }
+
+        match kind.as_str() {
+            "sections" => fragments
+                .sections
+                .entry(name.clone())
+                .or_default()
+                .push(line.to_string()),
+            "scheme" => {
+                let Some((sections, target)) = line.split_once("->") else {
+                    continue;
+                };
+                fragments
+                    .schemes
+                    .entry(name.clone())
+                    .or_default()
+                    .push((sections.trim().to_string(), target.trim().to_string()));
+            }
+            "mapping" => {
+                // flags like `text->flash_text KEEP()` below an entry
+                if line.contains("->") {
+                    log::debug!("Ignoring flags `{line}` in mapping {name}");
+                    continue;
+                }
+                let Some((target, scheme)) = line.split_once('(') else {
+                    continue;
+                };
+                let scheme = scheme.trim_end_matches(';').trim_end_matches(')').trim();
+                let target = target.trim();
+                let (object, symbol) = match target.split_once(':') {
+                    Some((object, symbol)) => (object, Some(symbol.to_string())),
+                    None => (target, None),
+                };
+                if let Some(mapping) = fragments.mappings.last_mut() {
+                    mapping.entries.push(MappingEntry {
+                        object: object.to_string(),
+                        symbol,
+                        scheme: scheme.to_string(),
+                    });
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Input section descriptions per target for the mappings of the given
+/// archives
+fn rules(fragments: &Fragments, archives: &BTreeSet<String>) -> BTreeMap<String, Vec<String>> {
+    let mut rules: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for mapping in &fragments.mappings {
+        if !archives.contains(&mapping.archive) {
+            continue;
+        }
+        let archive = &mapping.archive;
+
+        // objects mapped on their own are excluded from archive-wide rules
+        // for the sections their scheme places
+        let object_sections = |sections: &str| -> Vec<&str> {
+            mapping
+                .entries
+                .iter()
+                .filter(|entry| entry.object != "*" && entry.symbol.is_none())
+                .filter(|entry| {
+                    fragments
+                        .schemes
+                        .get(&entry.scheme)
+                        .is_some_and(|scheme| scheme.iter().any(|(other, _)| other == sections))
+                })
+                .map(|entry| entry.object.as_str())
+                .collect()
+        };
+
+        for entry in &mapping.entries {
+            // archive-wide default placement is what ports do anyway
+            if entry.scheme == "default" && entry.object == "*" && entry.symbol.is_none() {
+                continue;
+            }
+            if entry.symbol.is_some() && entry.scheme == "default" {
+                log::warn!(
+                    "{archive}: {}:{} moved back to its default placement is not supported",
+                    entry.object,
+                    entry.symbol.as_deref().unwrap_or_default()
+                );
+                continue;
+            }
+
+            let Some(scheme) = fragments.schemes.get(&entry.scheme) else {
+                panic!(
+                    "Unknown scheme {} in the mapping of {archive}",
+                    entry.scheme
+                );
+            };
+
+            for (sections, target) in scheme {
+                let Some(patterns) = fragments.sections.get(sections) else {
+                    panic!("Unknown sections {sections} in scheme {}", entry.scheme);
+                };
+
+                let patterns: Vec<String> = patterns
+                    .iter()
+                    .flat_map(|pattern| match (pattern.strip_suffix('+'), &entry.symbol) {
+                        (Some(base), Some(symbol)) => vec![format!("{base}.{symbol}")],
+                        (None, Some(_)) => vec![],
+                        (Some(base), None) => vec![base.to_string(), format!("{base}.*")],
+                        (None, None) => vec![pattern.clone()],
+                    })
+                    .collect();
+                if patterns.is_empty() {
+                    continue;
+                }
+
+                let patterns = patterns.join(" ");
+                let rule = if entry.object == "*" {
+                    let excluded: Vec<String> = object_sections(sections)
+                        .iter()
+                        .map(|object| format!("*{archive}:{object}.*"))
+                        .collect();
+                    if excluded.is_empty() {
+                        format!("*{archive}:({patterns})")
+                    } else {
+                        format!(
+                            "EXCLUDE_FILE({}) *{archive}:({patterns})",
+                            excluded.join(" ")
+                        )
+                    }
+                } else {
+                    format!("*{archive}:{}.*({patterns})", entry.object)
+                };
+                rules.entry(target.clone()).or_default().push(rule);
+            }
+        }
+    }
+
+    rules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAGMENT: &str = "
+[mapping:phy]
+archive: libphy.a
+entries:
+    if ESP_PHY_IRAM_OPT = y:
+        * (noflash_data)
+        phy_init (noflash)
+    else:
+        * (default)
+    if SOC_WIFI_SUPPORTED = y && BT_ENABLED:
+        phy_init:phy_enable (noflash)
+    elif SOC_WIFI_SUPPORTED:
+        phy_init:phy_disable (noflash)
+    else:
+        phy_init:phy_never (noflash)
+
+[mapping:other]
+archive: libother.a
+entries:
+    * (noflash)
+";
+
+    fn render_rules(sdkconfig: &str) -> BTreeMap<String, Vec<String>> {
+        let config = Sdkconfig::parse(sdkconfig);
+        let mut fragments = Fragments::default();
+        parse(BUILTIN, &config, &mut fragments);
+        parse(FRAGMENT, &config, &mut fragments);
+        rules(&fragments, &BTreeSet::from(["libphy.a".to_string()]))
+    }
+
+    #[test]
+    fn conditions_select_the_entries() {
+        let rules = render_rules(
+            "#define CONFIG_ESP_PHY_IRAM_OPT 1\n\
+             #define CONFIG_SOC_WIFI_SUPPORTED 1\n",
+        );
+        assert_eq!(
+            rules["iram0_text"],
+            [
+                "*libphy.a:phy_init.*(.text .text.* .literal .literal.*)",
+                "*libphy.a:phy_init.*(.text.phy_disable .literal.phy_disable)",
+            ]
+        );
+        assert_eq!(
+            rules["dram0_data"],
+            [
+                "EXCLUDE_FILE(*libphy.a:phy_init.*) *libphy.a:(.rodata .rodata.*)",
+                "*libphy.a:phy_init.*(.rodata .rodata.*)",
+                "*libphy.a:phy_init.*(.rodata.phy_disable)",
+            ]
+        );
+        assert_eq!(rules.len(), 2);
+    }
+
+    #[test]
+    fn default_placement_needs_no_rules() {
+        let rules = render_rules("#define CONFIG_BT_ENABLED 1\n");
+        assert_eq!(
+            rules["iram0_text"],
+            ["*libphy.a:phy_init.*(.text.phy_never .literal.phy_never)"]
+        );
+        assert_eq!(
+            rules["dram0_data"],
+            ["*libphy.a:phy_init.*(.rodata.phy_never)"]
+        );
+    }
+
+    #[test]
+    fn archives_not_vendored_are_skipped() {
+        let rules = render_rules("");
+        assert!(rules
+            .values()
+            .flatten()
+            .all(|rule| !rule.contains("libother.a")));
+    }
+}
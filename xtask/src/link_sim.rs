@@ -0,0 +1,295 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+use crate::archive::{Archive, Size};
+
+/// Simulate GNU ld pulling archive members for the given entry symbols and
+/// print the pulled members, the unresolved symbols and their size
+///
+/// Archives are searched in the given order, each one until it stops
+/// providing members. With `group` all archives are searched again until
+/// nothing changes, like `--start-group`/`--end-group`.
+pub fn run(chip: &str, libs: &[String], entries: &[String], group: bool) {
+    let archives: Vec<Archive> = if libs.is_empty() {
+        Archive::read_chip(chip)
+    } else {
+        libs.iter()
+            .map(|lib| Archive::read(&lib_path(chip, lib)))
+            .collect()
+    };
+
+    let link = simulate(&archives, entries, group);
+
+    println!("Pulled members:");
+    let mut size = Size::default();
+    for (archive, member, symbol, referrer) in &link.pulled {
+        let archive = &archives[*archive];
+        let member = &archive.members[*member];
+        size += member.size;
+        println!(
+            "  {}({}) for {symbol} referenced by {referrer}",
+            archive.name, member.name
+        );
+    }
+
+    let rom = crate::rom::chip_symbols(chip);
+    let (in_rom, unresolved): (Vec<_>, Vec<_>) = link
+        .unresolved
+        .iter()
+        .partition(|(symbol, _)| rom.contains_key(*symbol));
+
+    println!("Unresolved symbols:");
+    for (symbol, referrers) in &unresolved {
+        println!("  {symbol} referenced by {}", referrers.join(" "));
+    }
+
+    if !in_rom.is_empty() {
+        println!("Provided by ROM:");
+        for (symbol, _) in &in_rom {
+            let rom_symbol = &rom[*symbol];
+            println!(
+                "  {symbol} = 0x{:08x} ({})",
+                rom_symbol.address, rom_symbol.script
+            );
+        }
+    }
+
+    if !link.weak_unresolved.is_empty() {
+        println!("Unresolved weak symbols (resolve to 0):");
+        for symbol in &link.weak_unresolved {
+            println!("  {symbol}");
+        }
+    }
+
+    println!(
+        "Size without --gc-sections: text {}, data {}, bss {}",
+        size.text, size.data, size.bss
+    );
+}
+
+/// `libs/<chip>/<lib>`, also accepting `pp` or `libpp` for `libpp.a` and
+/// paths to other archives
+fn lib_path(chip: &str, lib: &str) -> PathBuf {
+    let dir = Path::new("./libs").join(chip);
+    let name = lib.trim_start_matches("lib").trim_end_matches(".a");
+    let candidates = [
+        PathBuf::from(lib),
+        dir.join(lib),
+        dir.join(format!("lib{name}.a")),
+    ];
+
+    candidates
+        .into_iter()
+        .find(|path| path.is_file())
+        .unwrap_or_else(|| {
+            eprintln!("No library {lib} for {chip}");
+            std::process::exit(1);
+        })
+}
+
+struct Link {
+    /// Archive and member index, the symbol they were pulled for and the
+    /// referrer of that symbol, in extraction order
+    pulled: Vec<(usize, usize, String, String)>,
+    /// Strongly referenced symbols nothing defines, with their referrers
+    unresolved: BTreeMap<String, Vec<String>>,
+    /// Symbols only referenced weakly which nothing defines
+    weak_unresolved: BTreeSet<String>,
+}
+
+fn simulate(archives: &[Archive], entries: &[String], group: bool) -> Link {
+    let mut defined = BTreeSet::new();
+    // undefined symbol and the first object referencing it
+    let mut undefined: BTreeMap<String, String> = entries
+        .iter()
+        .map(|entry| (entry.clone(), "--entry".to_string()))
+        .collect();
+    let mut referrers: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut weak = BTreeSet::new();
+    let mut pulled: Vec<(usize, usize, String, String)> = Vec::new();
+    let mut is_pulled: BTreeSet<(usize, usize)> = BTreeSet::new();
+
+    loop {
+        let pulled_before = pulled.len();
+
+        for (archive_index, archive) in archives.iter().enumerate() {
+            // ld searches the symbol index of an archive until it stops
+            // resolving anything
+            loop {
+                let mut changed = false;
+                for (member_index, member) in archive.members.iter().enumerate() {
+                    if is_pulled.contains(&(archive_index, member_index)) {
+                        continue;
+                    }
+                    let Some(symbol) = member
+                        .defined
+                        .iter()
+                        .find(|symbol| undefined.contains_key(&symbol.name))
+                    else {
+                        continue;
+                    };
+
+                    let referrer = undefined[&symbol.name].clone();
+                    pulled.push((archive_index, member_index, symbol.name.clone(), referrer));
+                    is_pulled.insert((archive_index, member_index));
+                    changed = true;
+
+                    for symbol in &member.defined {
+                        undefined.remove(&symbol.name);
+                        defined.insert(symbol.name.clone());
+                    }
+
+                    let object = format!("{}({})", archive.name, member.name);
+                    for symbol in &member.undefined {
+                        if defined.contains(symbol) {
+                            continue;
+                        }
+                        if member.weak_undefined.contains(symbol) {
+                            // weak references do not pull members
+                            weak.insert(symbol.clone());
+                            continue;
+                        }
+                        undefined
+                            .entry(symbol.clone())
+                            .or_insert_with(|| object.clone());
+                        referrers
+                            .entry(symbol.clone())
+                            .or_default()
+                            .push(object.clone());
+                    }
+                }
+                if !changed {
+                    break;
+                }
+            }
+        }
+
+        if !group || pulled.len() == pulled_before {
+            break;
+        }
+    }
+
+    let unresolved: BTreeMap<String, Vec<String>> = undefined
+        .into_iter()
+        .map(|(symbol, referrer)| {
+            let symbol_referrers = referrers.remove(&symbol).unwrap_or(vec![referrer]);
+            (symbol, symbol_referrers)
+        })
+        .collect();
+    let weak_unresolved = weak
+        .into_iter()
+        .filter(|symbol| !defined.contains(symbol) && !unresolved.contains_key(symbol))
+        .collect();
+
+    Link {
+        pulled,
+        unresolved,
+        weak_unresolved,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::archive::{Binding, Member, Symbol};
+
+    fn member(name: &str, defined: &[&str], undefined: &[&str], weak: &[&str]) -> Member {
+        Member {
+            name: name.to_string(),
+            defined: defined
+                .iter()
+                .map(|name| Symbol {
+                    name: name.to_string(),
+                    binding: Binding::Strong,
+                })
+                .collect(),
+            undefined: undefined
+                .iter()
+                .chain(weak)
+                .map(|s| s.to_string())
+                .collect(),
+            weak_undefined: weak.iter().map(|s| s.to_string()).collect(),
+            called: BTreeSet::new(),
+            size: Size::default(),
+        }
+    }
+
+    fn archives() -> Vec<Archive> {
+        vec![
+            Archive {
+                name: "libnet80211.a".to_string(),
+                members: vec![
+                    member("ieee80211.o", &["ieee80211_init"], &["pp_init"], &[]),
+                    member("ieee80211_ioctl.o", &["ieee80211_ioctl"], &[], &[]),
+                ],
+            },
+            Archive {
+                name: "libpp.a".to_string(),
+                members: vec![
+                    member("lmac.o", &["lmac_init"], &[], &[]),
+                    member(
+                        "pp.o",
+                        &["pp_init"],
+                        &["lmac_init", "ieee80211_ioctl", "phy_init"],
+                        &["coex_hook"],
+                    ),
+                ],
+            },
+        ]
+    }
+
+    fn pulled(link: &Link) -> Vec<(&str, &str)> {
+        link.pulled
+            .iter()
+            .map(|(_, _, symbol, referrer)| (symbol.as_str(), referrer.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn archives_are_searched_in_order() {
+        let link = simulate(&archives(), &["ieee80211_init".to_string()], false);
+        assert_eq!(
+            pulled(&link),
+            [
+                ("ieee80211_init", "--entry"),
+                ("pp_init", "libnet80211.a(ieee80211.o)"),
+                // members before the puller are found by searching again
+                ("lmac_init", "libpp.a(pp.o)"),
+            ]
+        );
+        assert_eq!(
+            link.unresolved.keys().collect::<Vec<_>>(),
+            ["ieee80211_ioctl", "phy_init"]
+        );
+        assert_eq!(link.unresolved["phy_init"], ["libpp.a(pp.o)"]);
+        assert_eq!(
+            link.weak_unresolved,
+            BTreeSet::from(["coex_hook".to_string()])
+        );
+    }
+
+    #[test]
+    fn groups_are_searched_until_nothing_changes() {
+        let link = simulate(&archives(), &["ieee80211_init".to_string()], true);
+        assert_eq!(
+            pulled(&link).last(),
+            Some(&("ieee80211_ioctl", "libpp.a(pp.o)"))
+        );
+        assert_eq!(link.unresolved.keys().collect::<Vec<_>>(), ["phy_init"]);
+    }
+
+    #[test]
+    fn weak_references_pull_nothing() {
+        let mut archives = archives();
+        archives[0]
+            .members
+            .push(member("coex.o", &["coex_hook"], &[], &[]));
+        let link = simulate(&archives, &["pp_init".to_string()], true);
+        assert!(!pulled(&link)
+            .iter()
+            .any(|(symbol, _)| *symbol == "coex_hook"));
+        assert!(link.weak_unresolved.contains("coex_hook"));
+    }
+}
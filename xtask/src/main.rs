@@ -4,45 +4,371 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use manifest::Source;
+
+mod api_diff;
+mod archive;
+mod bt_config;
+mod caps;
+mod cdata;
+mod cexpr;
+mod cheader;
+mod conflicts;
+mod export;
+mod include_resolved;
+mod interrupts;
+mod ldgen;
+mod link_sim;
+mod manifest;
+mod new_chip;
+mod package;
+mod phy_init;
+mod prepare_idf;
+mod printf;
+mod reg_base;
+mod regulatory;
+mod rom;
+mod sbom;
+mod sdkconfig;
+mod stubs;
+mod sys_crates;
+mod test_printf;
+
+/// Chips processed when none are given explicitly
+const CHIPS: &[&str] = &[
+    "esp32", "esp32s2", "esp32s3", "esp32c2", "esp32c3", "esp32c6", "esp32h2", "esp32c5",
+    "esp32c61",
+];
 
 #[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Chips to update from the activated ESP-IDF installation
     chips: Vec<String>,
+
+    /// Prefix the symbols of the vendored libprintf.a, see `prefix-printf`
+    #[arg(long, value_parser = printf::parse_prefix)]
+    printf_prefix: Option<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Check out an ESP-IDF tag from a local mirror into `target/idf/<tag>`
+    /// and run the update against it
+    PrepareIdf {
+        /// Local git mirror of ESP-IDF
+        #[arg(long)]
+        mirror: PathBuf,
+
+        #[arg(long)]
+        tag: String,
+
+        chips: Vec<String>,
+
+        /// Prefix the symbols of the vendored libprintf.a, see `prefix-printf`
+        #[arg(long, value_parser = printf::parse_prefix)]
+        printf_prefix: Option<String>,
+    },
+    /// Report strong symbols defined by more than one archive of a chip
+    /// and blob symbols shadowing libc/libgcc
+    Conflicts {
+        chips: Vec<String>,
+
+        /// Record the current conflicts as known instead of checking
+        #[arg(long)]
+        bless: bool,
+    },
+    /// Prefix the libprintf.a symbols imported by the blobs, in the printf
+    /// library and in the blobs, and write a header declaring them
+    PrefixPrintf {
+        chips: Vec<String>,
+
+        #[arg(long, default_value = "esp_printf_", value_parser = printf::parse_prefix)]
+        prefix: String,
+    },
+    /// Compile the bundled printf for the host and compare it against a
+    /// reference formatter
+    TestPrintf {
+        /// Number of generated cases
+        #[arg(long, default_value_t = 100_000)]
+        count: usize,
+
+        /// Seed of the case generator
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+    },
+    /// Generate weak stubs for the symbols a chip's libraries import but
+    /// nothing vendored defines, for link-testing ports
+    Stubs {
+        chip: String,
+
+        /// Archives, objects or symbol lists (one name per line) the port
+        /// already provides
+        #[arg(long)]
+        provided: Vec<PathBuf>,
+
+        #[arg(long, value_enum, default_value_t = stubs::Mode::Trap)]
+        mode: stubs::Mode,
+
+        /// Output directory, defaults to `target/stubs/<chip>`
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Write the PHY init data partition image of a chip, or decode one
+    PhyInit {
+        chip: String,
+
+        /// TOML file with changes to the default init data
+        #[arg(long = "override")]
+        overrides: Option<PathBuf>,
+
+        /// Dump this image as TOML instead
+        #[arg(long, conflicts_with = "overrides")]
+        decode: Option<PathBuf>,
+
+        /// Output file, defaults to `target/phy-init/<chip>/phy_init_data.bin`
+        /// (stdout when decoding)
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Render `libs/<chip>/ld/wireless_sections.ld` from the vendored
+    /// linker fragments and the chip's sdkconfig.h
+    LdSections { chips: Vec<String> },
+    /// List the blob imports the chip ROM provides, according to the
+    /// vendored ROM linker scripts
+    RomImports { chips: Vec<String> },
+    /// Simulate which archive members the linker pulls in for the given
+    /// entry symbols and what stays unresolved
+    LinkSim {
+        chip: String,
+
+        /// Archives in link order, all of the chip's archives if omitted
+        #[arg(long = "lib")]
+        libs: Vec<String>,
+
+        /// Symbols the application references
+        #[arg(long = "entry", required = true)]
+        entries: Vec<String>,
+
+        /// Search the archives repeatedly, like `--start-group`
+        #[arg(long)]
+        group: bool,
+    },
+    /// Write the Wi-Fi regulatory tables as `regulatory/regulatory.json`
+    /// and `regulatory/regulatory.rs`
+    RegulatoryExport {
+        /// Regulatory source, defaults to the vendored
+        /// `regulatory/esp_wifi_regulatory.c`
+        #[arg(long)]
+        source: Option<PathBuf>,
+    },
+    /// Generate `sys/<chip>/`, crates linking the vendored libraries of the
+    /// chips
+    GenSysCrates { chips: Vec<String> },
+    /// Write `libs/<chip>/esp-wireless.pc` and
+    /// `libs/<chip>/esp-wireless-<chip>Config.cmake`
+    PackageFiles { chips: Vec<String> },
+    /// Write an SPDX 2.3 document listing the vendored files with their
+    /// origin and license
+    Sbom {
+        /// Output file, defaults to `target/sbom/esp-wireless.spdx.json`
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Export the headers and libraries of a chip for another platform's
+    /// build system
+    Export {
+        #[command(subcommand)]
+        platform: export::Platform,
+    },
+    /// Build a drop-in `libregulatory.a` from a TOML country table
+    Regulatory {
+        /// Country code → rules, see README.md
+        #[arg(long)]
+        table: PathBuf,
+
+        #[arg(long)]
+        chip: String,
+
+        /// Output directory, defaults to `target/regulatory/<chip>`
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Show the changes in allowed channels and power between two exported
+    /// regulatory databases
+    RegulatoryDiff {
+        old: PathBuf,
+
+        /// Defaults to `regulatory/regulatory.json`
+        new: Option<PathBuf>,
+    },
+    /// Write the evaluated `SOC_*` capabilities of every chip as
+    /// `caps/caps.json`, `caps/caps.md` and `caps/caps.rs`
+    Caps,
+    /// Write the interrupt matrix sources of every chip as Rust enums to
+    /// `interrupts/interrupts.rs` and the wireless ones to
+    /// `interrupts/wireless.md`
+    Interrupts,
+    /// Write the `DR_REG_*_BASE` addresses of every chip to
+    /// `reg_base/reg_base.json` and `reg_base/reg_base.rs`
+    RegBase,
+    /// Write the evaluated `BT_CONTROLLER_INIT_CONFIG_DEFAULT()` of every
+    /// chip to `bt_config/bt_config.json` and `bt_config/bt_config.rs`
+    BtConfig,
+    /// Check that the archives in `libs/<chip>/` match the chips'
+    /// `soc_caps.h` and sdkconfig
+    CheckCaps { chips: Vec<String> },
+    /// Scaffold support for a new chip after an existing one and report the
+    /// ESP-IDF paths the update needs that are missing
+    NewChip {
+        name: String,
+
+        /// Supported chip to copy the sdkconfig.defaults from
+        #[arg(long)]
+        like: String,
+
+        /// ESP-IDF tree to probe, defaults to `IDF_PATH`
+        #[arg(long)]
+        idf: Option<PathBuf>,
+    },
+    /// Write `include-resolved/<chip>/`, the headers of `include/` with the
+    /// conditionals on the chip's sdkconfig and capabilities resolved
+    IncludeResolved { chips: Vec<String> },
+    /// Show the functions, structs, enums and macros of `include/` added,
+    /// removed or changed between two git refs
+    ApiDiff {
+        old: String,
+
+        /// Defaults to the working tree
+        new: Option<String>,
+    },
 }
 
 fn main() {
     env_logger::init();
     let args = Cli::parse();
 
-    if !std::env::var("IDF_PATH").is_ok() {
-        eprintln!("No activated ESP-IDF installation");
-        std::process::exit(-2);
-    }
-
     if !Path::new("version").exists() {
         eprintln!("Execute in the root of the project");
         std::process::exit(-1);
     }
 
-    let chips = if args.chips.is_empty() {
-        vec![
-            "esp32".to_string(),
-            "esp32s2".to_string(),
-            "esp32s3".to_string(),
-            "esp32c2".to_string(),
-            "esp32c3".to_string(),
-            "esp32c6".to_string(),
-            "esp32h2".to_string(),
-            "esp32c5".to_string(),
-            "esp32c61".to_string(),
-        ]
+    match args.command {
+        Some(Command::Conflicts { chips, bless }) => {
+            conflicts::check(&chips_or_default(chips), bless)
+        }
+        Some(Command::PrefixPrintf { chips, prefix }) => {
+            for chip in chips_or_default(chips) {
+                printf::prefix(&chip, &prefix);
+            }
+        }
+        Some(Command::TestPrintf { count, seed }) => test_printf::run(count, seed),
+        Some(Command::Stubs {
+            chip,
+            provided,
+            mode,
+            out,
+        }) => {
+            let out = out.unwrap_or_else(|| Path::new("./target/stubs").join(&chip));
+            stubs::generate(&chip, &provided, mode, &out)
+        }
+        Some(Command::LinkSim {
+            chip,
+            libs,
+            entries,
+            group,
+        }) => link_sim::run(&chip, &libs, &entries, group),
+        Some(Command::PhyInit {
+            chip,
+            overrides,
+            decode,
+            out,
+        }) => match decode {
+            Some(image) => phy_init::decode(&chip, &image, out),
+            None => phy_init::generate(&chip, overrides.as_deref(), out),
+        },
+        Some(Command::LdSections { chips }) => {
+            for chip in chips_or_default(chips) {
+                ldgen::render(&chip);
+            }
+        }
+        Some(Command::RomImports { chips }) => rom::imports(&chips_or_default(chips)),
+        Some(Command::RegulatoryExport { source }) => {
+            regulatory::export(source.as_deref());
+        }
+        Some(Command::RegulatoryDiff { old, new }) => regulatory::diff(&old, new.as_deref()),
+        Some(Command::ApiDiff { old, new }) => api_diff::diff(&old, new.as_deref()),
+        Some(Command::Caps) => caps::export(),
+        Some(Command::CheckCaps { chips }) => caps::check(&chips_or_default(chips)),
+        Some(Command::Interrupts) => interrupts::generate(),
+        Some(Command::RegBase) => reg_base::export(),
+        Some(Command::BtConfig) => bt_config::export(),
+        Some(Command::IncludeResolved { chips }) => {
+            include_resolved::generate(&chips_or_default(chips))
+        }
+        Some(Command::NewChip { name, like, idf }) => {
+            new_chip::scaffold(&name, &like, idf.as_deref())
+        }
+        Some(Command::Regulatory { table, chip, out }) => regulatory::build(&chip, &table, out),
+        Some(Command::Export { platform }) => export::run(platform),
+        Some(Command::Sbom { out }) => sbom::write(out),
+        Some(Command::PackageFiles { chips }) => {
+            for chip in chips_or_default(chips) {
+                package::generate(&chip);
+            }
+        }
+        Some(Command::GenSysCrates { chips }) => sys_crates::generate(&chips_or_default(chips)),
+        Some(Command::PrepareIdf {
+            mirror,
+            tag,
+            chips,
+            printf_prefix,
+        }) => prepare_idf::prepare(&mirror, &tag, chips, printf_prefix.as_deref()),
+        None => update(args.chips, args.printf_prefix.as_deref()),
+    }
+}
+
+fn chips_or_default(chips: Vec<String>) -> Vec<String> {
+    if chips.is_empty() {
+        CHIPS.iter().map(|chip| chip.to_string()).collect()
     } else {
-        args.chips
-    };
+        chips
+    }
+}
+
+/// Header directories copied to `include/` by the update, as component,
+/// directory in the component and destination. The files are recorded in
+/// `sources.json` with their component.
+const COMMON_HEADERS: &[(&str, &str, &str)] = &[
+    ("wpa_supplicant", "esp_supplicant/include", ""),
+    ("esp_phy", "include", ""),
+    ("esp_phy", "include/esp_private", ""),
+    ("esp_coex", "include", ""),
+    ("esp_wifi", "include", ""),
+    ("esp_wifi", "include/esp_private", "esp_private"),
+    ("esp_wifi", "include/local", "local"),
+    ("esp_coex", "include/private", ""),
+    ("ieee802154", "include", ""),
+    ("esp_timer", "include", ""),
+    ("esp_system", "include", ""),
+    ("esp_event", "include", ""),
+    ("nvs_flash", "include", ""),
+];
+
+fn update(chips: Vec<String>, printf_prefix: Option<&str>) {
+    if std::env::var("IDF_PATH").is_err() {
+        eprintln!("No activated ESP-IDF installation");
+        std::process::exit(-2);
+    }
+
+    let chips = chips_or_default(chips);
 
-    for chip in chips {
-        process(&chip);
+    for chip in &chips {
+        process(chip, printf_prefix);
     }
 
     // copy chip independent headers
@@ -50,54 +376,12 @@ fn main() {
     let dst = "./include";
 
     log::info!("Copy common headers");
-    copy_files(
-        &format!("{idf_path}/components/wpa_supplicant/esp_supplicant/include"),
-        &format!("{dst}"),
-    );
-    copy_files(
-        &format!("{idf_path}/components/esp_phy/include"),
-        &format!("{dst}"),
-    );
-    copy_files(
-        &format!("{idf_path}/components/esp_phy/include/esp_private"),
-        &format!("{dst}"),
-    );
-    copy_files(
-        &format!("{idf_path}/components/esp_coex/include"),
-        &format!("{dst}"),
-    );
-    copy_files(
-        &format!("{idf_path}/components/esp_wifi/include"),
-        &format!("{dst}"),
-    );
-    copy_files(
-        &format!("{idf_path}/components/esp_wifi/include/esp_private"),
-        &format!("{dst}/esp_private"),
-    );
-    copy_files(
-        &format!("{idf_path}/components/esp_wifi/include/local"),
-        &format!("{dst}/local"),
-    );
-    copy_files(
-        &format!("{idf_path}/components/esp_coex/include/private"),
-        &format!("{dst}"),
-    );
-    copy_files(
-        &format!("{idf_path}/components/esp_timer/include"),
-        &format!("{dst}"),
-    );
-    copy_files(
-        &format!("{idf_path}/components/esp_system/include"),
-        &format!("{dst}"),
-    );
-    copy_files(
-        &format!("{idf_path}/components/esp_event/include"),
-        &format!("{dst}"),
-    );
-    copy_files(
-        &format!("{idf_path}/components/nvs_flash/include"),
-        &format!("{dst}"),
-    );
+    for (component, dir, to) in COMMON_HEADERS {
+        copy_files(
+            &format!("{idf_path}/components/{component}/{dir}"),
+            &format!("{dst}/{to}"),
+        );
+    }
     copy_file(
         &format!("{idf_path}/components/esp_common/include/esp_err.h"),
         &format!("{dst}/esp_err.h"),
@@ -153,6 +437,13 @@ fn main() {
         &format!("{dst}/hal/modem_clock_hal.h"),
     );
 
+    let regulatory_source =
+        format!("{idf_path}/components/esp_wifi/regulatory/esp_wifi_regulatory.c");
+    let regulatory = Path::new(&regulatory_source).exists();
+    if regulatory {
+        copy_file(&regulatory_source, regulatory::SOURCE);
+    }
+
     replace_in_file(&format!("{dst}/esp_coexist_internal.h"), "private/", "");
     replace_in_file(
         &format!("{dst}/esp_event.h"),
@@ -215,9 +506,29 @@ fn main() {
     log::info!("ESP-IDF version {}", &version);
 
     fs::write("version", &version).unwrap();
+
+    if regulatory {
+        let previous = fs::read_to_string(regulatory::JSON).ok();
+        let exported = regulatory::export(None);
+        if let Some(previous) = previous.and_then(|json| serde_json::from_str(&json).ok()) {
+            regulatory::print_diff(&previous, &exported);
+        }
+    }
+
+    caps::export();
+    caps::check(&chips);
+    interrupts::generate();
+    reg_base::export();
+    bt_config::export();
+
+    for chip in &chips {
+        package::generate(chip);
+    }
+    sys_crates::generate(&chips);
+    sbom::save();
 }
 
-fn process(chip: &str) {
+fn process(chip: &str, printf_prefix: Option<&str>) {
     log::info!("Processing {chip}");
 
     // clean
@@ -240,215 +551,29 @@ fn process(chip: &str) {
         &[&format!("-DIDF_TARGET={chip}"), "build"],
     );
 
-    let dst = format!("./libs/{chip}/");
-    remove_dir_all(&dst);
-    mk_dir(&dst);
-
-    if chip != "esp32h2" {
-        log::info!("Create libregulatory.a");
-        ar(chip, "helper_project",
-            &[&format!("../{dst}/libregulatory.a"), "./build/esp-idf/esp_wifi/CMakeFiles/__idf_esp_wifi.dir/regulatory/esp_wifi_regulatory.c.obj",],
-        );
-    }
-
-    // copy static libraries
-    log::info!("Copy static libraries");
-
-    // the printf compat library
-    copy_file(
-        "./helper_project/build/esp-idf/main/libprintf.a",
-        &format!("{dst}/libprintf.a"),
-    );
-
-    // the just built supplicant
-    if chip != "esp32h2" {
-        copy_file(
-            "./helper_project/build/esp-idf/wpa_supplicant/libwpa_supplicant.a",
-            &format!("{dst}/libwpa_supplicant.a"),
-        );
-    }
-
-    // blobs from ESP-IDF installation
-    let idf_path = std::env::var("IDF_PATH").unwrap();
-
-    // phy
-    copy_file(
-        &format!("{idf_path}/components/esp_phy/lib/{chip}/libphy.a"),
-        &format!("{dst}/libphy.a"),
-    );
-
-    if chip == "esp32" {
-        copy_file(
-            &format!("{idf_path}/components/esp_phy/lib/{chip}/librtc.a"),
-            &format!("{dst}/librtc.a"),
-        );
-    }
-
-    if chip != "esp32" && chip != "esp32s2" {
-        copy_file(
-            &format!("{idf_path}/components/esp_phy/lib/{chip}/libbtbb.a"),
-            &format!("{dst}/libbtbb.a"),
-        );
-    }
-
-    // wifi
-    if chip != "esp32h2" {
-        copy_file(
-            &format!("{idf_path}/components/esp_wifi/lib/{chip}/libcore.a"),
-            &format!("{dst}/libcore.a"),
-        );
-        copy_file(
-            &format!("{idf_path}/components/esp_wifi/lib/{chip}/libpp.a"),
-            &format!("{dst}/libpp.a"),
-        );
-        copy_file(
-            &format!("{idf_path}/components/esp_wifi/lib/{chip}/libespnow.a"),
-            &format!("{dst}/libespnow.a"),
-        );
-        if chip != "esp32c2" {
-            copy_file(
-                &format!("{idf_path}/components/esp_wifi/lib/{chip}/libmesh.a"),
-                &format!("{dst}/libmesh.a"),
-            );
-        }
-        copy_file(
-            &format!("{idf_path}/components/esp_wifi/lib/{chip}/libnet80211.a"),
-            &format!("{dst}/libnet80211.a"),
-        );
-        copy_file(
-            &format!("{idf_path}/components/esp_wifi/lib/{chip}/libsmartconfig.a"),
-            &format!("{dst}/libsmartconfig.a"),
-        );
-        if chip != "esp32c2" {
-            copy_file(
-                &format!("{idf_path}/components/esp_wifi/lib/{chip}/libwapi.a"),
-                &format!("{dst}/libwapi.a"),
-            );
-        }
-    }
-
-    // coex
-    copy_file(
-        &format!("{idf_path}/components/esp_coex/lib/{chip}/libcoexist.a"),
-        &format!("{dst}/libcoexist.a"),
-    );
-
-    // bt
-    match chip {
-        "esp32" => {
-            copy_file(
-                &format!("{idf_path}/components/bt/controller/lib_esp32/esp32/libbtdm_app.a"),
-                &format!("{dst}/libbtdm_app.a"),
-            );
-        }
-        "esp32c3" => {
-            copy_file(
-                &format!(
-                    "{idf_path}/components/bt/controller/lib_esp32c3_family/esp32c3/libbtdm_app.a"
-                ),
-                &format!("{dst}/libbtdm_app.a"),
-            );
-        }
-        "esp32s3" => {
-            copy_file(
-                &format!(
-                    "{idf_path}/components/bt/controller/lib_esp32c3_family/esp32s3/libbtdm_app.a"
-                ),
-                &format!("{dst}/libbtdm_app.a"),
-            );
-        }
-        "esp32s2" => (),
-        "esp32c2" => {
-            copy_file(
-                &format!(
-                    "{idf_path}/components/bt/controller/lib_esp32c2/esp32c2-bt-lib/libble_app.a"
-                ),
-                &format!("{dst}/libble_app.a"),
-            );
-        }
-        "esp32c6" => {
-            copy_file(
-                &format!(
-                    "{idf_path}/components/bt/controller/lib_esp32c6/esp32c6-bt-lib/esp32c6/libble_app.a"
-                ),
-                &format!("{dst}/libble_app.a"),
-            );
-        }
-        "esp32h2" => {
-            copy_file(
-                &format!(
-                    "{idf_path}/components/bt/controller/lib_esp32h2/esp32h2-bt-lib/libble_app.a"
-                ),
-                &format!("{dst}/libble_app.a"),
-            );
-        }
-        "esp32c5" => {
-            copy_file(
-                &format!(
-                    "{idf_path}/components/bt/controller/lib_esp32c5/esp32c5-bt-lib/libble_app.a"
-                ),
-                &format!("{dst}/libble_app.a"),
-            );
-        }
-        "esp32c61" => {
-            copy_file(
-                &format!(
-                    "{idf_path}/components/bt/controller/lib_esp32c6/esp32c6-bt-lib/esp32c61/libble_app.a"
-                ),
-                &format!("{dst}/libble_app.a"),
-            );
-        }
-        _ => panic!("Unknown chip to copy bt libs"),
-    }
-
-    // copy headers
+    // copy headers, the libraries depend on the chip's soc_caps.h
     log::info!("Copy chip specific headers");
+    let idf_path = std::env::var("IDF_PATH").unwrap();
     let dst = format!("./include/{chip}/");
     remove_dir_all(&dst);
     mk_dir(&dst);
 
-    copy_files(
-        &format!("{idf_path}/components/esp_phy/{chip}/include"),
-        &format!("{dst}"),
-    );
-    if chip != "esp32s2" {
-        if chip == "esp32s3" {
-            copy_files(
-                &format!("{idf_path}/components/bt/include/esp32c3/include"),
-                &format!("{dst}"),
-            );
-        } else if chip == "esp32c61" {
-            copy_files(
-                &format!("{idf_path}/components/bt/include/esp32c6/include"),
-                &format!("{dst}"),
-            );
+    for (from, to) in chip_files(&idf_path, chip) {
+        if Path::new(&from).is_dir() {
+            copy_files(&from, &to);
         } else {
-            copy_files(
-                &format!("{idf_path}/components/bt/include/{chip}/include"),
-                &format!("{dst}"),
-            );
+            copy_file(&from, &to);
         }
     }
+    // the default PHY init data, see `cargo xtask phy-init`
+    let phy_init_data = format!("{idf_path}/components/esp_phy/{chip}/phy_init_data.c");
+    if Path::new(&phy_init_data).exists() {
+        copy_file(&phy_init_data, &format!("{dst}/phy_init_data.c"));
+    }
     copy_file(
         "./helper_project/build/config/sdkconfig.h",
         &format!("{dst}/sdkconfig.h"),
     );
-    copy_file(
-        &format!("{idf_path}/components/soc/{chip}/include/soc/soc_caps.h"),
-        &format!("{dst}/soc/soc_caps.h"),
-    );
-    copy_file(
-        &format!("{idf_path}/components/soc/{chip}/include/soc/periph_defs.h"),
-        &format!("{dst}/soc/periph_defs.h"),
-    );
-    copy_file(
-        &format!("{idf_path}/components/soc/{chip}/include/soc/interrupts.h"),
-        &format!("{dst}/soc/interrupts.h"),
-    );
-    copy_file(
-        &format!("{idf_path}/components/soc/{chip}/include/soc/clk_tree_defs.h"),
-        &format!("{dst}/soc/clk_tree_defs.h"),
-    );
     if chip != "esp32s2" {
         replace_in_file(
             &format!("{dst}/esp_bt.h"),
@@ -457,81 +582,156 @@ fn process(chip: &str) {
         );
     }
 
-    if chip != "esp32s2" {
+    // point `esp_bt.h` at the vendored copy of its controller config
+    if let Some(controller) = bt_controller(chip) {
         replace_in_file(
             &format!("{dst}/esp_bt.h"),
-            r#"#include "../../../../controller/"#,
-            r#"//#include "../../../../controller/"#,
+            &format!(r#"#include "../../../../controller/{controller}/esp_bt_cfg.h""#),
+            r#"#include "esp_bt_cfg.h""#,
         );
     }
 
-    if chip == "esp32c2" || chip == "esp32c6" || chip == "esp32h2" {
-        copy_file(
-            &format!("{idf_path}/components/bt/controller/{chip}/esp_bt_cfg.h"),
-            &format!("{dst}/esp_bt_cfg.h"),
-        );
+    let dst = format!("./libs/{chip}/");
+    remove_dir_all(&dst);
+    mk_dir(&dst);
+
+    // copy static libraries
+    log::info!("Copy static libraries");
+    for library in manifest::libraries(chip) {
+        let to = format!("{dst}/{}", library.file_name());
+        match library.source {
+            Source::HelperObject(object) => {
+                log::info!("Create {}", library.file_name());
+                ar(chip, "helper_project", &[&format!("../{to}"), object]);
+                sbom::record(&format!("./helper_project/{object}"), &to);
+            }
+            Source::Helper(path) => copy_file(&format!("./helper_project/{path}"), &to),
+            Source::Idf(path) => copy_file(&format!("{idf_path}/{path}"), &to),
+        }
     }
 
-    copy_file(
-        &format!("{idf_path}/components/soc/{chip}/include/soc/soc.h"),
-        &format!("{dst}/soc/soc.h"),
-    );
-    copy_file(
-        &format!("{idf_path}/components/soc/{chip}/register/soc/reg_base.h"),
-        &format!("{dst}/soc/reg_base.h"),
+    // ROM symbols the blobs import
+    log::info!("Copy ROM linker scripts");
+    mk_dir(&format!("{dst}/ld"));
+    rom::copy_scripts(&idf_path, chip, &format!("{dst}/ld"));
+
+    // IRAM placement of the blobs
+    log::info!("Copy linker fragments");
+    ldgen::copy_fragments(&idf_path, &format!("{dst}/ld"));
+
+    ldgen::render(chip);
+
+    if let Some(prefix) = printf_prefix {
+        printf::prefix(chip, prefix);
+    }
+}
+
+/// The chip specific files copied from ESP-IDF to `include/<chip>/`, as
+/// source → destination. Directories are copied without their
+/// subdirectories.
+fn chip_files(idf_path: &str, chip: &str) -> Vec<(String, String)> {
+    let dst = format!("./include/{chip}");
+    let mut files = Vec::new();
+    let mut add = |from: String, to: &str| files.push((from, format!("{dst}/{to}")));
+
+    add(format!("{idf_path}/components/esp_phy/{chip}/include"), "");
+    if chip != "esp32s2" {
+        let bt_include = match chip {
+            "esp32s3" => "esp32c3",
+            "esp32c61" => "esp32c6",
+            _ => chip,
+        };
+        add(
+            format!("{idf_path}/components/bt/include/{bt_include}/include"),
+            "",
+        );
+    }
+    for header in [
+        "soc_caps.h",
+        "periph_defs.h",
+        "interrupts.h",
+        "clk_tree_defs.h",
+        "soc.h",
+    ] {
+        add(
+            format!("{idf_path}/components/soc/{chip}/include/soc/{header}"),
+            &format!("soc/{header}"),
+        );
+    }
+    add(
+        format!("{idf_path}/components/soc/{chip}/register/soc/reg_base.h"),
+        "soc/reg_base.h",
     );
 
+    if let Some(controller) = bt_controller(chip) {
+        add(
+            format!("{idf_path}/components/bt/controller/{controller}/esp_bt_cfg.h"),
+            "esp_bt_cfg.h",
+        );
+    }
+
     let soc_pmu_supported = ["esp32c6", "esp32h2"].contains(&chip);
     if soc_pmu_supported {
-        copy_file(
-            &format!("{idf_path}/components/hal/{chip}/include/hal/pmu_hal.h"),
-            &format!("{dst}/hal/pmu_hal.h"),
+        add(
+            format!("{idf_path}/components/hal/{chip}/include/hal/pmu_hal.h"),
+            "hal/pmu_hal.h",
         );
-        copy_file(
-            &format!("{idf_path}/components/hal/{chip}/include/hal/pmu_ll.h"),
-            &format!("{dst}/hal/pmu_ll.h"),
+        add(
+            format!("{idf_path}/components/hal/{chip}/include/hal/pmu_ll.h"),
+            "hal/pmu_ll.h",
         );
-        copy_file(
-            &format!("{idf_path}/components/soc/{chip}/register/soc/pmu_struct.h"),
-            &format!("{dst}/soc/pmu_struct.h"),
+        add(
+            format!("{idf_path}/components/soc/{chip}/register/soc/pmu_struct.h"),
+            "soc/pmu_struct.h",
         );
-        copy_file(
-            &format!(
-                "{idf_path}/components/esp_hw_support/port/{chip}/private_include/pmu_param.h"
-            ),
-            &format!("{dst}/pmu_param.h"),
+        add(
+            format!("{idf_path}/components/esp_hw_support/port/{chip}/private_include/pmu_param.h"),
+            "pmu_param.h",
         );
-        copy_file(
-            &format!(
+        add(
+            format!(
                 "{idf_path}/components/esp_hw_support/port/{chip}/private_include/pmu_bit_defs.h"
             ),
-            &format!("{dst}/pmu_bit_defs.h"),
+            "pmu_bit_defs.h",
         );
-        copy_file(
-            &format!("{idf_path}/components/soc/{chip}/register/soc/pmu_reg.h"),
-            &format!("{dst}/soc/pmu_reg.h"),
+        add(
+            format!("{idf_path}/components/soc/{chip}/register/soc/pmu_reg.h"),
+            "soc/pmu_reg.h",
         );
     }
 
     let modem_lock_is_independent = ["esp32c6", "esp32h2"].contains(&chip);
     if modem_lock_is_independent {
-        copy_file(
-            &format!("{idf_path}/components/soc/{chip}/include/modem/modem_syscon_struct.h"),
-            &format!("{dst}/modem/modem_syscon_struct.h"),
+        add(
+            format!("{idf_path}/components/soc/{chip}/include/modem/modem_syscon_struct.h"),
+            "modem/modem_syscon_struct.h",
         );
-        copy_file(
-            &format!("{idf_path}/components/soc/{chip}/include/modem/modem_lpcon_struct.h"),
-            &format!("{dst}/modem/modem_lpcon_struct.h"),
+        add(
+            format!("{idf_path}/components/soc/{chip}/include/modem/modem_lpcon_struct.h"),
+            "modem/modem_lpcon_struct.h",
         );
-        copy_file(
-            &format!("{idf_path}/components/hal/{chip}/include/hal/modem_lpcon_ll.h"),
-            &format!("{dst}/hal/modem_lpcon_ll.h"),
+        add(
+            format!("{idf_path}/components/hal/{chip}/include/hal/modem_lpcon_ll.h"),
+            "hal/modem_lpcon_ll.h",
         );
-        copy_file(
-            &format!("{idf_path}/components/hal/{chip}/include/hal/modem_syscon_ll.h"),
-            &format!("{dst}/hal/modem_syscon_ll.h"),
+        add(
+            format!("{idf_path}/components/hal/{chip}/include/hal/modem_syscon_ll.h"),
+            "hal/modem_syscon_ll.h",
         );
     }
+
+    files
+}
+
+/// The controller directory whose `esp_bt_cfg.h` configures the chip's BT
+/// controller, `esp_bt.h` includes it by a relative path. The older
+/// controllers are configured in `esp_bt.h` itself.
+fn bt_controller(chip: &str) -> Option<&str> {
+    match chip {
+        "esp32" | "esp32s2" | "esp32c3" | "esp32s3" => None,
+        "esp32c61" => Some("esp32c6"),
+        _ => Some(chip),
+    }
 }
 
 fn remove_dir_all(path: &str) {
@@ -551,7 +751,8 @@ fn copy_file(from: &str, to: &str) {
         windows_safe_path(&cwd.join(from)),
         windows_safe_path(&cwd.join(to)),
     )
-    .expect(&format!("Unable to copy {from} to {to}"));
+    .unwrap_or_else(|_| panic!("Unable to copy {from} to {to}"));
+    sbom::record(from, to);
 }
 
 fn copy_files(from: &str, to: &str) {
@@ -578,6 +779,7 @@ fn copy_files(from: &str, to: &str) {
             windows_safe_path(&cwd.join(to).join(&fname)),
         )
         .expect("Unable to copy sdkconfig.defaults");
+        sbom::record(&format!("{from}/{fname}"), &format!("{to}/{fname}"));
     }
 }
 
@@ -613,18 +815,28 @@ fn build(cwd: &str, args: &[&str]) {
     }
 }
 
-fn ar(chip: &str, cwd: &str, args: &[&str]) {
-    let ar = if ["esp32", "esp32s2", "esp32s3"].contains(&chip) {
-        "xtensa-esp-elf-ar"
+fn is_xtensa(chip: &str) -> bool {
+    ["esp32", "esp32s2", "esp32s3"].contains(&chip)
+}
+
+/// Prefix of the ESP-IDF toolchain binaries for the chip, like
+/// `xtensa-esp32-elf` in `xtensa-esp32-elf-gcc`
+fn toolchain_prefix(chip: &str) -> String {
+    if is_xtensa(chip) {
+        format!("xtensa-{chip}-elf")
     } else {
-        "riscv32-esp-elf-ar"
-    };
+        "riscv32-esp-elf".to_string()
+    }
+}
+
+fn ar(chip: &str, cwd: &str, args: &[&str]) {
+    let ar = format!("{}-ar", toolchain_prefix(chip));
 
     let mut args: Vec<&str> = Vec::from(args);
     args.insert(0, "-rc");
 
     let cwd = windows_safe_path(&env::current_dir().unwrap().join(cwd));
-    let output = std::process::Command::new(ar)
+    let output = std::process::Command::new(&ar)
         .args(args)
         .current_dir(cwd)
         .stdout(std::process::Stdio::inherit())
@@ -669,6 +881,29 @@ fn idf_version() -> String {
     output.to_string()
 }
 
+/// `ESP-IDF v5.5.1` → `5.5.1`, `ESP-IDF v5.5-rc1` → `5.5.0-rc1`
+fn version_number(idf_version: &str) -> String {
+    let version = idf_version
+        .rsplit(' ')
+        .next()
+        .unwrap()
+        .trim_start_matches('v');
+    let (numbers, pre) = match version.split_once('-') {
+        Some((numbers, pre)) => (numbers, Some(pre)),
+        None => (version, None),
+    };
+    let mut numbers: Vec<&str> = numbers.split('.').collect();
+    if numbers.is_empty() || numbers.len() > 3 || numbers.iter().any(|n| n.parse::<u32>().is_err())
+    {
+        panic!("Unable to derive a version number from {idf_version:?}");
+    }
+    numbers.resize(3, "0");
+    match pre {
+        Some(pre) => format!("{}-{pre}", numbers.join(".")),
+        None => numbers.join("."),
+    }
+}
+
 fn mk_dir(p: &str) {
     let cwd = env::current_dir().unwrap();
     let p = windows_safe_path(&cwd.join(p));
@@ -0,0 +1,430 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+use crate::{
+    archive::{Archive, Binding},
+    caps,
+};
+
+/// Where a vendored library comes from
+#[derive(Debug, Clone)]
+pub enum Source {
+    /// Built by `helper_project`, relative to its directory
+    Helper(&'static str),
+    /// An object file of the `helper_project` build, archived on its own
+    HelperObject(&'static str),
+    /// A blob of the ESP-IDF installation, relative to `IDF_PATH`
+    Idf(String),
+}
+
+/// A static library vendored into `libs/<chip>/`
+#[derive(Debug, Clone)]
+pub struct Library {
+    /// Name without `lib` and `.a`, e.g. `pp`
+    pub name: &'static str,
+    pub source: Source,
+    /// The [`FEATURES`] entry it implements, `None` if always needed
+    pub feature: Option<&'static str>,
+}
+
+/// Optional functionality ports can leave out
+#[derive(Debug)]
+pub struct Feature {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Feature this one builds on
+    pub requires: Option<&'static str>,
+}
+
+pub const FEATURES: &[Feature] = &[
+    Feature {
+        name: "wifi",
+        description: "Wi-Fi",
+        requires: None,
+    },
+    Feature {
+        name: "espnow",
+        description: "ESP-NOW",
+        requires: Some("wifi"),
+    },
+    Feature {
+        name: "mesh",
+        description: "ESP-WIFI-MESH",
+        requires: Some("wifi"),
+    },
+    Feature {
+        name: "smartconfig",
+        description: "SmartConfig provisioning",
+        requires: Some("wifi"),
+    },
+    Feature {
+        name: "wapi",
+        description: "WAPI security",
+        requires: Some("wifi"),
+    },
+    Feature {
+        name: "ble",
+        description: "Bluetooth controller",
+        requires: None,
+    },
+    Feature {
+        name: "ieee802154",
+        description: "IEEE 802.15.4 radio",
+        requires: None,
+    },
+];
+
+impl Library {
+    pub fn file_name(&self) -> String {
+        format!("lib{}.a", self.name)
+    }
+}
+
+/// The libraries vendored for a chip, in the order they are copied
+pub fn libraries(chip: &str) -> Vec<Library> {
+    let idf = |name, path| Library {
+        name,
+        source: Source::Idf(path),
+        feature: None,
+    };
+    let has_wifi = chip != "esp32h2";
+    let has_ieee802154 = caps::supported(chip, "SOC_IEEE802154_SUPPORTED");
+
+    let mut libraries = Vec::new();
+    if has_wifi {
+        libraries.push(Library {
+            name: "regulatory",
+            source: Source::HelperObject(
+                "./build/esp-idf/esp_wifi/CMakeFiles/__idf_esp_wifi.dir/regulatory/esp_wifi_regulatory.c.obj",
+            ),
+            feature: Some("wifi"),
+        });
+    }
+
+    // the printf compat library
+    libraries.push(Library {
+        name: "printf",
+        source: Source::Helper("build/esp-idf/main/libprintf.a"),
+        feature: None,
+    });
+
+    // the just built supplicant
+    if has_wifi {
+        libraries.push(Library {
+            name: "wpa_supplicant",
+            source: Source::Helper("build/esp-idf/wpa_supplicant/libwpa_supplicant.a"),
+            feature: Some("wifi"),
+        });
+    }
+
+    // the just built IEEE 802.15.4 driver
+    if has_ieee802154 {
+        libraries.push(Library {
+            name: "ieee802154",
+            source: Source::Helper("build/esp-idf/ieee802154/libieee802154.a"),
+            feature: Some("ieee802154"),
+        });
+    }
+
+    // phy
+    libraries.push(idf(
+        "phy",
+        format!("components/esp_phy/lib/{chip}/libphy.a"),
+    ));
+    if chip == "esp32" {
+        libraries.push(idf(
+            "rtc",
+            format!("components/esp_phy/lib/{chip}/librtc.a"),
+        ));
+    }
+    if chip != "esp32" && chip != "esp32s2" {
+        libraries.push(idf(
+            "btbb",
+            format!("components/esp_phy/lib/{chip}/libbtbb.a"),
+        ));
+    }
+
+    // wifi
+    if has_wifi {
+        let wifi = |name, feature| Library {
+            feature: Some(feature),
+            ..idf(name, format!("components/esp_wifi/lib/{chip}/lib{name}.a"))
+        };
+        libraries.push(wifi("core", "wifi"));
+        libraries.push(wifi("pp", "wifi"));
+        libraries.push(wifi("espnow", "espnow"));
+        if chip != "esp32c2" {
+            libraries.push(wifi("mesh", "mesh"));
+        }
+        libraries.push(wifi("net80211", "wifi"));
+        libraries.push(wifi("smartconfig", "smartconfig"));
+        if chip != "esp32c2" {
+            libraries.push(wifi("wapi", "wapi"));
+        }
+    }
+
+    // coex
+    libraries.push(idf(
+        "coexist",
+        format!("components/esp_coex/lib/{chip}/libcoexist.a"),
+    ));
+
+    // bt
+    let bt = match chip {
+        "esp32" => Some(("btdm_app", "lib_esp32/esp32/libbtdm_app.a")),
+        "esp32c3" => Some(("btdm_app", "lib_esp32c3_family/esp32c3/libbtdm_app.a")),
+        "esp32s3" => Some(("btdm_app", "lib_esp32c3_family/esp32s3/libbtdm_app.a")),
+        "esp32s2" => None,
+        "esp32c2" => Some(("ble_app", "lib_esp32c2/esp32c2-bt-lib/libble_app.a")),
+        "esp32c6" => Some(("ble_app", "lib_esp32c6/esp32c6-bt-lib/esp32c6/libble_app.a")),
+        "esp32h2" => Some(("ble_app", "lib_esp32h2/esp32h2-bt-lib/libble_app.a")),
+        "esp32c5" => Some(("ble_app", "lib_esp32c5/esp32c5-bt-lib/libble_app.a")),
+        "esp32c61" => Some((
+            "ble_app",
+            "lib_esp32c6/esp32c6-bt-lib/esp32c61/libble_app.a",
+        )),
+        // a new chip, `cargo xtask check-caps` reports the missing controller
+        _ => {
+            log::warn!("No BT controller library known for {chip}");
+            None
+        }
+    };
+    if let Some((name, path)) = bt {
+        libraries.push(Library {
+            feature: Some("ble"),
+            ..idf(name, format!("components/bt/controller/{path}"))
+        });
+    }
+
+    libraries
+}
+
+/// The libraries of a chip present in `libs/<chip>/`
+pub fn vendored(chip: &str) -> Vec<Library> {
+    libraries(chip)
+        .into_iter()
+        .filter(|library| {
+            Path::new("./libs")
+                .join(chip)
+                .join(library.file_name())
+                .exists()
+        })
+        .collect()
+}
+
+/// The features a chip has vendored libraries for
+pub fn features(chip: &str) -> Vec<&'static Feature> {
+    let vendored = vendored(chip);
+    FEATURES
+        .iter()
+        .filter(|feature| {
+            vendored
+                .iter()
+                .any(|library| library.feature == Some(feature.name))
+        })
+        .collect()
+}
+
+/// Include directories of a chip, in search order
+pub fn include_dirs(chip: &str) -> Vec<String> {
+    vec![
+        "include".to_string(),
+        format!("include/{chip}"),
+        "patch".to_string(),
+    ]
+}
+
+/// Link order of the vendored libraries of a chip, derived from the
+/// symbols they import from each other. Every library comes before the
+/// ones it depends on, libraries depending on each other form one group
+/// which has to be searched repeatedly (`--start-group`).
+pub fn link_order(chip: &str) -> Vec<Vec<&'static str>> {
+    let names: Vec<&'static str> = libraries(chip).iter().map(|library| library.name).collect();
+    order(&names, &dependencies(chip))
+}
+
+/// Order `names` so every library comes before its dependencies, grouping
+/// libraries which depend on each other. The members of a group keep the
+/// order of `names`.
+fn order(
+    names: &[&'static str],
+    dependencies: &BTreeMap<&'static str, BTreeSet<&'static str>>,
+) -> Vec<Vec<&'static str>> {
+    // Tarjan's algorithm emits the groups dependencies first
+    let mut state = Tarjan {
+        dependencies,
+        index: BTreeMap::new(),
+        low: BTreeMap::new(),
+        stack: Vec::new(),
+        groups: Vec::new(),
+    };
+    for name in names {
+        if !state.index.contains_key(name) {
+            state.visit(name);
+        }
+    }
+
+    let position = |name: &str| names.iter().position(|other| *other == name);
+    let mut groups = state.groups;
+    for group in &mut groups {
+        group.sort_by_key(|name| position(name));
+    }
+    groups.reverse();
+    groups
+}
+
+/// Library → libraries defining symbols it needs
+pub fn dependencies(chip: &str) -> BTreeMap<&'static str, BTreeSet<&'static str>> {
+    let libraries = libraries(chip);
+    let archives = Archive::read_chip(chip);
+    let name_of = |archive: &Archive| {
+        libraries
+            .iter()
+            .find(|library| library.file_name() == archive.name)
+            .map(|library| library.name)
+    };
+
+    let mut defined_by: BTreeMap<&str, &'static str> = BTreeMap::new();
+    for archive in &archives {
+        let Some(name) = name_of(archive) else {
+            continue;
+        };
+        for member in &archive.members {
+            for symbol in &member.defined {
+                if symbol.binding != Binding::Common {
+                    defined_by.entry(&symbol.name).or_insert(name);
+                }
+            }
+        }
+    }
+
+    let mut dependencies = BTreeMap::new();
+    for archive in &archives {
+        let Some(name) = name_of(archive) else {
+            log::warn!("{} is not vendored by the update", archive.name);
+            continue;
+        };
+        let needed: &mut BTreeSet<&str> = dependencies.entry(name).or_default();
+        for member in &archive.members {
+            for symbol in &member.undefined {
+                if member.weak_undefined.contains(symbol) {
+                    continue;
+                }
+                if let Some(other) = defined_by.get(symbol.as_str()) {
+                    if *other != name {
+                        needed.insert(other);
+                    }
+                }
+            }
+        }
+    }
+    dependencies
+}
+
+struct Tarjan<'a> {
+    dependencies: &'a BTreeMap<&'static str, BTreeSet<&'static str>>,
+    index: BTreeMap<&'static str, usize>,
+    low: BTreeMap<&'static str, usize>,
+    stack: Vec<&'static str>,
+    groups: Vec<Vec<&'static str>>,
+}
+
+impl Tarjan<'_> {
+    fn visit(&mut self, name: &'static str) {
+        let index = self.index.len();
+        self.index.insert(name, index);
+        self.low.insert(name, index);
+        self.stack.push(name);
+
+        for &dependency in self.dependencies.get(name).into_iter().flatten() {
+            if !self.index.contains_key(dependency) {
+                self.visit(dependency);
+                let low = self.low[name].min(self.low[dependency]);
+                self.low.insert(name, low);
+            } else if self.stack.contains(&dependency) {
+                let low = self.low[name].min(self.index[dependency]);
+                self.low.insert(name, low);
+            }
+        }
+
+        if self.low[name] == index {
+            let start = self.stack.iter().position(|other| *other == name).unwrap();
+            self.groups.push(self.stack.split_off(start));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(
+        edges: &[(&'static str, &'static str)],
+    ) -> BTreeMap<&'static str, BTreeSet<&'static str>> {
+        let mut dependencies: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+        for (library, dependency) in edges {
+            dependencies
+                .entry(*library)
+                .or_default()
+                .insert(*dependency);
+        }
+        dependencies
+    }
+
+    #[test]
+    fn dependents_come_first() {
+        let dependencies = graph(&[("net80211", "pp"), ("pp", "phy"), ("coexist", "phy")]);
+        let groups = order(&["phy", "pp", "coexist", "net80211"], &dependencies);
+        let position = |name| groups.iter().position(|group| group == &[name]).unwrap();
+        assert_eq!(groups.len(), 4);
+        assert!(position("net80211") < position("pp"));
+        assert!(position("pp") < position("phy"));
+        assert!(position("coexist") < position("phy"));
+    }
+
+    #[test]
+    fn cycles_form_one_group() {
+        let dependencies = graph(&[
+            ("net80211", "pp"),
+            ("pp", "net80211"),
+            ("pp", "phy"),
+            ("phy", "rtc"),
+            ("rtc", "phy"),
+            ("wpa", "net80211"),
+        ]);
+        assert_eq!(
+            order(&["wpa", "net80211", "pp", "phy", "rtc"], &dependencies),
+            [vec!["wpa"], vec!["net80211", "pp"], vec!["phy", "rtc"]]
+        );
+    }
+
+    #[test]
+    fn every_library_comes_before_its_dependencies() {
+        let dependencies = graph(&[
+            ("a", "d"),
+            ("b", "a"),
+            ("c", "b"),
+            ("d", "e"),
+            ("e", "d"),
+            ("f", "c"),
+        ]);
+        let groups = order(&["a", "b", "c", "d", "e", "f"], &dependencies);
+        let position = |name| {
+            groups
+                .iter()
+                .position(|group| group.contains(&name))
+                .unwrap()
+        };
+        for (library, needed) in &dependencies {
+            for dependency in needed {
+                assert!(
+                    position(library) <= position(dependency),
+                    "{library} -> {dependency}"
+                );
+            }
+        }
+        assert_eq!(groups.iter().map(Vec::len).sum::<usize>(), 6);
+    }
+}
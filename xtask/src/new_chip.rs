@@ -0,0 +1,172 @@
+use std::{fs, path::Path};
+
+use crate::{manifest, CHIPS};
+
+const MAIN: &str = "./xtask/src/main.rs";
+
+/// Scaffold a new chip after an existing one: copy its
+/// `patch/<chip>/sdkconfig.defaults`, add the chip to `CHIPS` and report
+/// the ESP-IDF paths the update will read that don't exist
+pub fn scaffold(name: &str, like: &str, idf_path: Option<&Path>) {
+    if !CHIPS.contains(&like) {
+        eprintln!("Unknown chip {like}, expected one of {}", CHIPS.join(", "));
+        std::process::exit(1);
+    }
+    if CHIPS.contains(&name) {
+        eprintln!("{name} is already supported");
+        std::process::exit(1);
+    }
+    if !name.starts_with("esp32")
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    {
+        eprintln!("Chip names are ESP-IDF targets like esp32c6, not {name:?}");
+        std::process::exit(1);
+    }
+    let idf_path = match idf_path {
+        Some(path) => path.to_string_lossy().to_string(),
+        None => std::env::var("IDF_PATH").unwrap_or_else(|_| {
+            eprintln!("No activated ESP-IDF installation, pass --idf");
+            std::process::exit(1);
+        }),
+    };
+
+    let defaults = format!("./patch/{name}/sdkconfig.defaults");
+    if Path::new(&defaults).exists() {
+        eprintln!("{defaults} already exists");
+        std::process::exit(1);
+    }
+    fs::create_dir_all(format!("./patch/{name}")).unwrap();
+    fs::copy(format!("./patch/{like}/sdkconfig.defaults"), &defaults)
+        .unwrap_or_else(|_| panic!("Unable to copy the sdkconfig.defaults of {like}"));
+    println!("Created {defaults} from {like}");
+
+    add_to_chips(name);
+    println!("Added {name} to CHIPS in {MAIN}");
+
+    let missing: Vec<String> = expected_paths(&idf_path, name, like)
+        .into_iter()
+        .filter(|path| !Path::new(&format!("{idf_path}/{path}")).exists())
+        .collect();
+    if missing.is_empty() {
+        println!("All expected paths exist in {idf_path}");
+    } else {
+        println!("Missing in {idf_path}:");
+        for path in &missing {
+            println!("  {path}");
+        }
+    }
+
+    let branches = branches(like);
+    if !branches.is_empty() {
+        println!("Chip specific code naming {like}, {name} may need the same:");
+        for branch in branches {
+            println!("  {branch}");
+        }
+    }
+    println!("Run `cargo xtask conflicts {name} --bless` after the first update");
+}
+
+/// The paths relative to `IDF_PATH` the update reads for `name`, and the
+/// ones `like` reads with its name replaced: the new chip likely needs them
+/// once the chip specific code knows it
+fn expected_paths(idf_path: &str, name: &str, like: &str) -> Vec<String> {
+    let mut paths = vec![
+        format!("components/soc/{name}"),
+        format!("components/esp_rom/{name}/ld"),
+    ];
+    for library in manifest::libraries(like) {
+        if let manifest::Source::Idf(path) = library.source {
+            paths.push(path.replace(like, name));
+        }
+    }
+    let relative = |from: String| {
+        from.strip_prefix(&format!("{idf_path}/"))
+            .map(str::to_string)
+            .unwrap_or(from)
+    };
+    for (from, _) in crate::chip_files(idf_path, name) {
+        paths.push(relative(from));
+    }
+    for (from, _) in crate::chip_files(idf_path, like) {
+        paths.push(relative(from).replace(like, name));
+    }
+
+    let mut unique = Vec::new();
+    for path in paths {
+        if !unique.contains(&path) {
+            unique.push(path);
+        }
+    }
+    unique
+}
+
+/// Append `name` to the `CHIPS` list of `main.rs`, wrapped like rustfmt
+fn add_to_chips(name: &str) {
+    let source = fs::read_to_string(MAIN).unwrap_or_else(|_| panic!("Unable to read {MAIN}"));
+    let start_marker = "const CHIPS: &[&str] = &[";
+    let Some(start) = source.find(start_marker) else {
+        eprintln!("No CHIPS list in {MAIN}");
+        std::process::exit(1);
+    };
+    let start = start + start_marker.len();
+    let end = start + source[start..].find("];").unwrap();
+
+    let mut chips: Vec<String> = source[start..end]
+        .split(',')
+        .map(|chip| chip.trim().to_string())
+        .filter(|chip| !chip.is_empty())
+        .collect();
+    chips.push(format!("\"{name}\""));
+
+    let mut list = String::from("\n");
+    let mut line = String::from("   ");
+    for chip in chips {
+        if line.len() + chip.len() + 2 > 100 {
+            list.push_str(&line);
+            list.push('\n');
+            line = String::from("   ");
+        }
+        line.push_str(&format!(" {chip},"));
+    }
+    list.push_str(&line);
+    list.push('\n');
+
+    let source = format!("{}{list}{}", &source[..start], &source[end..]);
+    fs::write(MAIN, source).unwrap_or_else(|_| panic!("Unable to write {MAIN}"));
+}
+
+/// `file:line: code` of the xtask lines naming `chip` outside of `CHIPS`
+fn branches(chip: &str) -> Vec<String> {
+    let quoted = format!("\"{chip}\"");
+    let mut paths: Vec<_> = fs::read_dir("./xtask/src")
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .collect();
+    paths.sort();
+
+    let mut branches = Vec::new();
+    for path in paths {
+        let source = fs::read_to_string(&path).unwrap();
+        let mut in_chips = false;
+        for (number, line) in source.lines().enumerate() {
+            if line.starts_with("const CHIPS") {
+                in_chips = true;
+            }
+            if !in_chips && line.contains(&quoted) {
+                branches.push(format!(
+                    "{}:{}: {}",
+                    path.display(),
+                    number + 1,
+                    line.trim()
+                ));
+            }
+            if in_chips && line.contains("];") {
+                in_chips = false;
+            }
+        }
+    }
+    branches
+}
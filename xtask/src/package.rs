@@ -0,0 +1,165 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    fs,
+};
+
+use crate::manifest;
+
+/// Write `libs/<chip>/esp-wireless.pc` and
+/// `libs/<chip>/esp-wireless-<chip>Config.cmake` for builds outside ESP-IDF
+pub fn generate(chip: &str) {
+    log::info!("Write the pkg-config and CMake package files of {chip}");
+
+    let idf_version = fs::read_to_string("version").expect("Unable to read version");
+    let idf_version = idf_version.trim();
+    let version = crate::version_number(idf_version);
+
+    let vendored: Vec<&str> = manifest::vendored(chip)
+        .iter()
+        .map(|library| library.name)
+        .collect();
+    let groups: Vec<Vec<&str>> = manifest::link_order(chip)
+        .into_iter()
+        .map(|group| {
+            group
+                .into_iter()
+                .filter(|name| vendored.contains(name))
+                .collect::<Vec<_>>()
+        })
+        .filter(|group| !group.is_empty())
+        .collect();
+    let include_dirs = manifest::include_dirs(chip);
+
+    let mut pc = String::new();
+    writeln!(pc, "# Generated by `cargo xtask package-files`").unwrap();
+    // the include directories are relative to the repository root
+    writeln!(pc, "prefix=${{pcfiledir}}/../..").unwrap();
+    writeln!(pc, "libdir=${{pcfiledir}}\n").unwrap();
+    writeln!(pc, "Name: esp-wireless").unwrap();
+    writeln!(
+        pc,
+        "Description: {idf_version} wireless libraries for the {chip}"
+    )
+    .unwrap();
+    writeln!(pc, "Version: {version}").unwrap();
+    let cflags: Vec<String> = include_dirs
+        .iter()
+        .map(|dir| format!("-I${{prefix}}/{dir}"))
+        .collect();
+    writeln!(pc, "Cflags: {}", cflags.join(" ")).unwrap();
+    let mut libs = vec!["-L${libdir}".to_string()];
+    for group in &groups {
+        // libraries depending on each other are searched repeatedly
+        if group.len() > 1 {
+            libs.push("-Wl,--start-group".to_string());
+        }
+        libs.extend(group.iter().map(|name| format!("-l{name}")));
+        if group.len() > 1 {
+            libs.push("-Wl,--end-group".to_string());
+        }
+    }
+    writeln!(pc, "Libs: {}", libs.join(" ")).unwrap();
+    write(&format!("./libs/{chip}/esp-wireless.pc"), &pc);
+
+    let dependencies: BTreeMap<&str, BTreeSet<&str>> = manifest::dependencies(chip);
+    let mut cmake = String::new();
+    writeln!(
+        cmake,
+        "# {idf_version} wireless libraries for the {chip}\n# Generated by `cargo xtask package-files`\n"
+    )
+    .unwrap();
+    writeln!(
+        cmake,
+        "if(TARGET esp_wireless::esp_wireless)\n  return()\nendif()\n"
+    )
+    .unwrap();
+    writeln!(
+        cmake,
+        "set(esp-wireless-{chip}_VERSION {version})\nget_filename_component(_esp_wireless_root \"${{CMAKE_CURRENT_LIST_DIR}}/../..\" ABSOLUTE)\n"
+    )
+    .unwrap();
+
+    let include_dirs: Vec<String> = include_dirs
+        .iter()
+        .map(|dir| format!("${{_esp_wireless_root}}/{dir}"))
+        .collect();
+    writeln!(
+        cmake,
+        "add_library(esp_wireless::headers INTERFACE IMPORTED)"
+    )
+    .unwrap();
+    writeln!(
+        cmake,
+        "set_target_properties(esp_wireless::headers PROPERTIES\n  INTERFACE_INCLUDE_DIRECTORIES \"{}\"\n)\n",
+        include_dirs.join(";")
+    )
+    .unwrap();
+
+    for name in groups.iter().flatten() {
+        writeln!(cmake, "add_library(esp_wireless::{name} STATIC IMPORTED)").unwrap();
+    }
+    for group in &groups {
+        for name in group {
+            let mut link = vec!["esp_wireless::headers".to_string()];
+            // in link order, as CMake keeps it
+            for other in groups.iter().flatten() {
+                if dependencies
+                    .get(name)
+                    .is_some_and(|needed| needed.contains(other))
+                {
+                    link.push(format!("esp_wireless::{other}"));
+                }
+            }
+            writeln!(
+                cmake,
+                "\nset_target_properties(esp_wireless::{name} PROPERTIES"
+            )
+            .unwrap();
+            writeln!(
+                cmake,
+                "  IMPORTED_LOCATION \"${{CMAKE_CURRENT_LIST_DIR}}/lib{name}.a\""
+            )
+            .unwrap();
+            writeln!(cmake, "  INTERFACE_LINK_LIBRARIES \"{}\"", link.join(";")).unwrap();
+            // libraries depending on each other are repeated on the command
+            // line until the cycle is resolved
+            if group.len() > 1 {
+                writeln!(
+                    cmake,
+                    "  IMPORTED_LINK_INTERFACE_MULTIPLICITY {}",
+                    group.len()
+                )
+                .unwrap();
+            }
+            writeln!(cmake, ")").unwrap();
+        }
+    }
+
+    let all: Vec<String> = groups
+        .iter()
+        .flatten()
+        .map(|name| format!("esp_wireless::{name}"))
+        .collect();
+    writeln!(cmake, "\n# All libraries of the chip").unwrap();
+    writeln!(
+        cmake,
+        "add_library(esp_wireless::esp_wireless INTERFACE IMPORTED)"
+    )
+    .unwrap();
+    writeln!(
+        cmake,
+        "set_target_properties(esp_wireless::esp_wireless PROPERTIES\n  INTERFACE_LINK_LIBRARIES \"{}\"\n)\n",
+        all.join(";")
+    )
+    .unwrap();
+    writeln!(cmake, "unset(_esp_wireless_root)").unwrap();
+    write(
+        &format!("./libs/{chip}/esp-wireless-{chip}Config.cmake"),
+        &cmake,
+    );
+}
+
+fn write(path: &str, content: &str) {
+    fs::write(path, content).unwrap_or_else(|_| panic!("Unable to write {path}"));
+}
@@ -0,0 +1,406 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{cexpr::Macros, sdkconfig};
+
+/// Layout of a PHY init data partition image: the magic, the parameters and
+/// the magic again
+#[derive(Debug)]
+struct Layout {
+    magic: Vec<u8>,
+    params_len: usize,
+    /// Offset and number of the TX power limits in the parameters
+    tx_power_offset: usize,
+    tx_power_num: usize,
+}
+
+/// Changes applied to the default PHY init data, in the order of the fields
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Overrides {
+    /// Informational, images decoded for another chip are rejected
+    chip: Option<String>,
+    /// sdkconfig options (without `CONFIG_`) the default table is evaluated
+    /// with
+    #[serde(default)]
+    sdkconfig: BTreeMap<String, toml::Value>,
+    /// Shorthand for `sdkconfig.ESP_PHY_MAX_TX_POWER`
+    max_tx_power_dbm: Option<u8>,
+    /// The complete parameter table
+    params: Option<Vec<u8>>,
+    /// TX power limits in 0.25 dBm
+    tx_power_qdbm: Option<Vec<u8>>,
+    /// Single parameters by offset
+    #[serde(default)]
+    bytes: BTreeMap<String, u8>,
+}
+
+/// Write the PHY init data partition image of a chip with the overrides
+/// of an optional TOML file applied
+pub fn generate(chip: &str, overrides: Option<&Path>, out: Option<PathBuf>) {
+    let overrides: Overrides = match overrides {
+        Some(path) => {
+            let source =
+                fs::read_to_string(path).unwrap_or_else(|_| panic!("Unable to read {path:?}"));
+            toml::from_str(&source).unwrap_or_else(|e| {
+                eprintln!("Invalid overrides in {path:?}: {e}");
+                std::process::exit(1);
+            })
+        }
+        None => Overrides::default(),
+    };
+    if overrides.chip.as_deref().is_some_and(|other| other != chip) {
+        eprintln!("The overrides are for {}", overrides.chip.unwrap());
+        std::process::exit(1);
+    }
+
+    let mut macros = sdkconfig::macros(chip);
+    for (name, value) in &overrides.sdkconfig {
+        let value = match value {
+            toml::Value::Boolean(true) => "1".to_string(),
+            toml::Value::Boolean(false) => {
+                macros.undefine(&format!("CONFIG_{name}"));
+                continue;
+            }
+            toml::Value::String(string) => format!("\"{string}\""),
+            other => other.to_string(),
+        };
+        macros.define(&format!("CONFIG_{name}"), &value);
+    }
+    if let Some(dbm) = overrides.max_tx_power_dbm {
+        macros.define("CONFIG_ESP_PHY_MAX_TX_POWER", &dbm.to_string());
+    }
+
+    let layout = layout(chip, &mut macros);
+    let mut params = default_params(chip, &macros, &layout);
+
+    if let Some(table) = &overrides.params {
+        if table.len() != layout.params_len {
+            eprintln!(
+                "params has {} entries, {chip} needs {}",
+                table.len(),
+                layout.params_len
+            );
+            std::process::exit(1);
+        }
+        params.clone_from(table);
+    }
+    if let Some(tx_power) = &overrides.tx_power_qdbm {
+        if tx_power.len() != layout.tx_power_num {
+            eprintln!(
+                "tx_power_qdbm has {} entries, {chip} has {}",
+                tx_power.len(),
+                layout.tx_power_num
+            );
+            std::process::exit(1);
+        }
+        params[layout.tx_power_offset..][..layout.tx_power_num].copy_from_slice(tx_power);
+    }
+    for (offset, value) in &overrides.bytes {
+        let offset: usize = offset
+            .parse()
+            .ok()
+            .filter(|offset| *offset < layout.params_len)
+            .unwrap_or_else(|| {
+                eprintln!("Invalid parameter offset {offset}");
+                std::process::exit(1);
+            });
+        params[offset] = *value;
+    }
+
+    let image = layout.image(&params);
+
+    let out = out.unwrap_or_else(|| {
+        Path::new("./target/phy-init")
+            .join(chip)
+            .join("phy_init_data.bin")
+    });
+    if let Some(parent) = out.parent() {
+        fs::create_dir_all(parent).unwrap();
+    }
+    fs::write(&out, image).unwrap_or_else(|_| panic!("Unable to write {out:?}"));
+    log::info!("Wrote {out:?}");
+}
+
+/// Dump a PHY init data partition image as TOML accepted by `--override`
+pub fn decode(chip: &str, image: &Path, out: Option<PathBuf>) {
+    let data = fs::read(image).unwrap_or_else(|_| panic!("Unable to read {image:?}"));
+    let mut macros = sdkconfig::macros(chip);
+    let layout = layout(chip, &mut macros);
+
+    let Some(params) = layout.params(&data) else {
+        eprintln!("{image:?} is no {chip} PHY init data image");
+        std::process::exit(1);
+    };
+    let tx_power = &params[layout.tx_power_offset..][..layout.tx_power_num];
+
+    let list = |values: &[u8]| {
+        values
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let mut toml = format!(
+        "# PHY init data of {}\nchip = \"{chip}\"\n\n# TX power limits in 0.25 dBm, parameters {} to {}\ntx_power_qdbm = [{}]\n\nparams = [\n",
+        image.display(),
+        layout.tx_power_offset,
+        layout.tx_power_offset + layout.tx_power_num - 1,
+        list(tx_power)
+    );
+    for (row, chunk) in params.chunks(16).enumerate() {
+        let values: Vec<String> = chunk.iter().map(|value| format!("0x{value:02x}")).collect();
+        toml.push_str(&format!("    {}, # {}\n", values.join(", "), row * 16));
+    }
+    toml.push_str("]\n");
+
+    match out {
+        Some(out) => fs::write(&out, toml).unwrap_or_else(|_| panic!("Unable to write {out:?}")),
+        None => print!("{toml}"),
+    }
+}
+
+impl Layout {
+    /// The parameters framed by the magic
+    fn image(&self, params: &[u8]) -> Vec<u8> {
+        let mut image = self.magic.clone();
+        image.extend_from_slice(params);
+        image.extend_from_slice(&self.magic);
+        image
+    }
+
+    /// The parameters of an image, `None` if it does not match the layout
+    fn params<'a>(&self, image: &'a [u8]) -> Option<&'a [u8]> {
+        let magic_len = self.magic.len();
+        (image.len() == 2 * magic_len + self.params_len
+            && image[..magic_len] == self.magic
+            && image[magic_len + self.params_len..] == self.magic)
+            .then(|| &image[magic_len..][..self.params_len])
+    }
+}
+
+/// Read the image layout from `esp_phy_init.h` and `phy_init_data.h`
+fn layout(chip: &str, macros: &mut Macros) -> Layout {
+    let init_h = fs::read_to_string("./include/esp_phy_init.h")
+        .expect("Unable to read include/esp_phy_init.h");
+    let path = format!("./include/{chip}/phy_init_data.h");
+    let data_h = fs::read_to_string(&path).unwrap_or_else(|_| panic!("Unable to read {path}"));
+    parse_layout(chip, macros, &init_h, &data_h)
+}
+
+/// Evaluate the image layout given the sources of `esp_phy_init.h` and
+/// `phy_init_data.h`
+fn parse_layout(chip: &str, macros: &mut Macros, init_h: &str, data_h: &str) -> Layout {
+    let init_h = macros.preprocess(init_h);
+    let params_len = init_h
+        .split_once("params[")
+        .and_then(|(_, rest)| rest.split_once(']'))
+        .and_then(|(len, _)| macros.eval(len).ok())
+        .expect("No params array in esp_phy_init_data_t") as usize;
+
+    macros.preprocess(data_h);
+    let Some(magic) = macros.get("PHY_INIT_MAGIC") else {
+        eprintln!("{chip} has no PHY init data");
+        std::process::exit(1);
+    };
+    let mut magic = magic.trim_matches('"').as_bytes().to_vec();
+    let magic_len = eval(macros, "PHY_INIT_MAGIC_LEN") as usize;
+    magic.resize(magic_len, 0);
+
+    Layout {
+        magic,
+        params_len,
+        tx_power_offset: eval(macros, "PHY_TX_POWER_OFFSET") as usize,
+        tx_power_num: eval(macros, "PHY_TX_POWER_NUM") as usize,
+    }
+}
+
+fn eval(macros: &Macros, expression: &str) -> i64 {
+    macros
+        .eval(expression)
+        .unwrap_or_else(|e| panic!("Unable to evaluate {expression}: {e}"))
+}
+
+/// Evaluate the `phy_init_data` initializer of `include/<chip>/phy_init_data.c`
+fn default_params(chip: &str, macros: &Macros, layout: &Layout) -> Vec<u8> {
+    let path = format!("./include/{chip}/phy_init_data.c");
+    let source = fs::read_to_string(&path).unwrap_or_else(|_| {
+        eprintln!("No {path}, run the update first");
+        std::process::exit(1);
+    });
+    parse_params(&path, &source, macros, layout)
+}
+
+/// Evaluate the `phy_init_data` initializer in `source`, which is read from
+/// `path`
+fn parse_params(path: &str, source: &str, macros: &Macros, layout: &Layout) -> Vec<u8> {
+    let mut macros = macros.clone();
+    let source = macros.preprocess(source);
+
+    let initializer = source
+        .find("phy_init_data")
+        .and_then(|start| {
+            let rest = &source[start..];
+            let rest = &rest[rest.find('=')?..];
+            let open = rest.find('{')?;
+            let mut depth = 0;
+            for (i, c) in rest[open..].char_indices() {
+                match c {
+                    '{' => depth += 1,
+                    '}' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(&rest[open..open + i]);
+                        }
+                    }
+                    _ => (),
+                }
+            }
+            None
+        })
+        .unwrap_or_else(|| panic!("No phy_init_data initializer in {path}"));
+
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    for c in initializer.chars().chain([',']) {
+        match c {
+            '{' | '}' => current.push(' '),
+            '(' => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' => {
+                depth -= 1;
+                current.push(c);
+            }
+            ',' if depth == 0 => {
+                if !current.trim().is_empty() {
+                    let value = eval(&macros, current.trim());
+                    if !(-128..=255).contains(&value) {
+                        panic!("`{}` = {value} does not fit into a byte", current.trim());
+                    }
+                    values.push(value as u8);
+                }
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if values.len() > layout.params_len {
+        panic!(
+            "{path} has {} parameters, esp_phy_init_data_t {}",
+            values.len(),
+            layout.params_len
+        );
+    }
+    // like in C missing initializers are zero
+    values.resize(layout.params_len, 0);
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INIT_H: &str = "typedef struct {
+#if CONFIG_IDF_TARGET_ESP32C5
+    uint8_t params[256];
+#else
+    uint8_t params[16];
+#endif
+} esp_phy_init_data_t;
+";
+
+    const DATA_H: &str =
+        "#define LIMIT(val, low, high) ((val < low) ? low : (val > high) ? high : val)
+#define PHY_INIT_MAGIC \"PHYINIT\"
+#define PHY_INIT_MAGIC_LEN 8 // should be strlen(PHY_INIT_MAGIC) + 1
+#define PHY_TX_POWER_LOWEST LIMIT(CONFIG_ESP_PHY_MAX_TX_POWER * 4, 0, 52)
+#define PHY_TX_POWER_OFFSET 2
+#define PHY_TX_POWER_NUM    4
+";
+
+    fn macros() -> Macros {
+        let mut macros = Macros::new();
+        macros.define("CONFIG_ESP_PHY_MAX_TX_POWER", "20");
+        macros
+    }
+
+    #[test]
+    fn layout_of_headers() {
+        let layout = parse_layout("esp32c3", &mut macros(), INIT_H, DATA_H);
+        assert_eq!(layout.magic, b"PHYINIT\0");
+        assert_eq!(layout.params_len, 16);
+        assert_eq!(layout.tx_power_offset, 2);
+        assert_eq!(layout.tx_power_num, 4);
+
+        let mut c5 = macros();
+        c5.define("CONFIG_IDF_TARGET_ESP32C5", "1");
+        assert_eq!(
+            parse_layout("esp32c5", &mut c5, INIT_H, DATA_H).params_len,
+            256
+        );
+    }
+
+    #[test]
+    fn initializer() {
+        let mut macros = macros();
+        let layout = parse_layout("esp32c3", &mut macros, INIT_H, DATA_H);
+        let source = "const esp_phy_init_data_t phy_init_data = { {
+    0x01,
+    0x00,
+    LIMIT(PHY_TX_POWER_LOWEST + 8, 0, 255),
+    PHY_TX_POWER_LOWEST,
+    -1,
+} };
+";
+        assert_eq!(
+            parse_params("phy_init_data.c", source, &macros, &layout),
+            [1, 0, 60, 52, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+
+        let mut low = macros.clone();
+        low.define("CONFIG_ESP_PHY_MAX_TX_POWER", "10");
+        assert_eq!(
+            parse_params("phy_init_data.c", source, &low, &layout)[2..4],
+            [48, 40]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit into a byte")]
+    fn initializer_out_of_range() {
+        let mut macros = macros();
+        let layout = parse_layout("esp32c3", &mut macros, INIT_H, DATA_H);
+        parse_params(
+            "phy_init_data.c",
+            "phy_init_data = { 256 };",
+            &macros,
+            &layout,
+        );
+    }
+
+    #[test]
+    fn image_round_trip() {
+        let layout = parse_layout("esp32c3", &mut macros(), INIT_H, DATA_H);
+        let params: Vec<u8> = (0..16).collect();
+        let image = layout.image(&params);
+        assert_eq!(image.len(), 8 + 16 + 8);
+        assert_eq!(&image[..8], b"PHYINIT\0");
+        assert_eq!(&image[24..], b"PHYINIT\0");
+        assert_eq!(layout.params(&image), Some(&params[..]));
+
+        assert_eq!(layout.params(&image[..31]), None);
+        let mut corrupt = image.clone();
+        corrupt[31] = b'X';
+        assert_eq!(layout.params(&corrupt), None);
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use serde_json::Value;
+
+/// Managed worktrees, one per tag
+const WORKTREES: &str = "./target/idf";
+
+/// Tools of `tools/tools.json` the update needs, the compilers are required
+const TOOLS: &[(&str, bool)] = &[
+    ("xtensa-esp-elf", true),
+    ("riscv32-esp-elf", true),
+    ("cmake", false),
+    ("ninja", false),
+];
+
+/// Check out `tag` from a local ESP-IDF mirror into `target/idf/<tag>`, set
+/// up the environment `export.sh` would and run the update against it
+pub fn prepare(mirror: &Path, tag: &str, chips: Vec<String>, printf_prefix: Option<&str>) {
+    let mirror = mirror
+        .canonicalize()
+        .unwrap_or_else(|_| panic!("No ESP-IDF mirror at {mirror:?}"));
+    let commit = git(
+        &mirror,
+        &["rev-parse", "--verify", &format!("{tag}^{{commit}}")],
+    );
+
+    fs::create_dir_all(WORKTREES).unwrap();
+    let worktree = Path::new(WORKTREES).canonicalize().unwrap().join(tag);
+    checkout(&mirror, &worktree, tag, &commit);
+    update_submodules(&worktree);
+
+    let tools = locate_tools(&worktree, tag);
+    let path = env::var_os("PATH").unwrap_or_default();
+    let path = env::join_paths(tools.iter().cloned().chain(env::split_paths(&path)))
+        .expect("Unable to extend PATH");
+    env::set_var("PATH", path);
+    env::set_var("IDF_PATH", &worktree);
+
+    crate::update(chips, printf_prefix);
+
+    let version = fs::read_to_string("version").expect("Unable to read version");
+    if version.trim() != format!("ESP-IDF {tag}") {
+        eprintln!(
+            "The update recorded {:?} instead of ESP-IDF {tag}",
+            version.trim()
+        );
+        std::process::exit(1);
+    }
+}
+
+/// A clean worktree of `mirror` at `commit`, reusing an existing one
+fn checkout(mirror: &Path, worktree: &Path, tag: &str, commit: &str) {
+    if worktree.exists() {
+        let head = git(worktree, &["rev-parse", "HEAD"]);
+        let status = git(worktree, &["status", "--porcelain", "--ignore-submodules"]);
+        if head == commit && status.is_empty() {
+            log::info!("Reuse {worktree:?} at {tag}");
+            return;
+        }
+
+        log::info!("Recreate {worktree:?}");
+        fs::remove_dir_all(worktree).unwrap();
+    }
+    git(mirror, &["worktree", "prune"]);
+
+    log::info!("Check out {tag} to {worktree:?}");
+    git(
+        mirror,
+        &[
+            "worktree",
+            "add",
+            "--detach",
+            &worktree.to_string_lossy(),
+            commit,
+        ],
+    );
+}
+
+/// Initialize the submodules, besides the vendored blobs the helper project
+/// build needs e.g. mbedtls
+fn update_submodules(worktree: &Path) {
+    log::info!("Update the submodules of {worktree:?}");
+    git(
+        worktree,
+        &[
+            "submodule",
+            "update",
+            "--init",
+            "--recursive",
+            "--depth",
+            "1",
+        ],
+    );
+}
+
+/// The `bin` directories of the installed ESP-IDF tools in the versions the
+/// worktree recommends
+fn locate_tools(worktree: &Path, tag: &str) -> Vec<PathBuf> {
+    let tools_path = match env::var_os("IDF_TOOLS_PATH") {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(&env::var_os("HOME").expect("No HOME")).join(".espressif"),
+    };
+    let json = fs::read_to_string(worktree.join("tools/tools.json"))
+        .expect("Unable to read tools/tools.json");
+    let json: Value = serde_json::from_str(&json).expect("Invalid tools/tools.json");
+
+    let mut paths = vec![worktree.join("tools")];
+    for (name, required) in TOOLS {
+        let tool = json["tools"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|tool| tool["name"] == *name);
+        let version = tool.and_then(|tool| {
+            tool["versions"]
+                .as_array()?
+                .iter()
+                .find(|version| version["status"] == "recommended")?["name"]
+                .as_str()
+        });
+        let (Some(tool), Some(version)) = (tool, version) else {
+            log::warn!("No recommended {name} in tools/tools.json");
+            continue;
+        };
+
+        let mut found = false;
+        for export in tool["export_paths"].as_array().into_iter().flatten() {
+            let mut path = tools_path.join("tools").join(name).join(version);
+            for part in export.as_array().into_iter().flatten() {
+                path.push(part.as_str().unwrap());
+            }
+            if path.is_dir() {
+                log::info!("Found {name} {version} in {path:?}");
+                paths.push(path);
+                found = true;
+            }
+        }
+        if !found && *required {
+            eprintln!(
+                "{name} {version} is not installed in {tools_path:?}, run {:?}",
+                worktree.join("install.sh")
+            );
+            std::process::exit(1);
+        }
+        if !found {
+            log::warn!("{name} {version} is not installed, using the one in PATH");
+        }
+    }
+
+    // idf.py runs with the `python` first in PATH
+    let python_envs = tools_path.join("python_env");
+    let release: Vec<&str> = tag
+        .trim_start_matches('v')
+        .split(['.', '-'])
+        .take(2)
+        .collect();
+    let prefix = format!("idf{}_py", release.join("."));
+    let mut envs: Vec<PathBuf> = fs::read_dir(&python_envs)
+        .into_iter()
+        .flatten()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with(&prefix))
+        })
+        .collect();
+    envs.sort();
+    match envs.pop() {
+        Some(python_env) => {
+            log::info!("Found the Python environment {python_env:?}");
+            env::set_var("IDF_PYTHON_ENV_PATH", &python_env);
+            paths.insert(0, python_env.join("bin"));
+        }
+        None => log::warn!("No {prefix}* Python environment in {python_envs:?}"),
+    }
+
+    paths
+}
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    log::debug!("git {}", args.join(" "));
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .expect("Unable to run git");
+    if !output.status.success() {
+        eprintln!(
+            "git {} failed in {dir:?}: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        std::process::exit(1);
+    }
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
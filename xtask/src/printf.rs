@@ -0,0 +1,220 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::Path,
+};
+
+use crate::archive::{self, Archive, Binding};
+
+/// Prototypes of the API exported by `helper_project/main/printf/printf.h`
+const PROTOTYPES: &[(&str, &str)] = &[
+    ("printf", "int {}(const char* format, ...);"),
+    ("sprintf", "int {}(char* buffer, const char* format, ...);"),
+    (
+        "snprintf",
+        "int {}(char* buffer, size_t count, const char* format, ...);",
+    ),
+    (
+        "vsnprintf",
+        "int {}(char* buffer, size_t count, const char* format, va_list va);",
+    ),
+    ("vprintf", "int {}(const char* format, va_list va);"),
+    (
+        "fctprintf",
+        "int {}(void (*out)(char character, void* arg), void* arg, const char* format, ...);",
+    ),
+];
+
+/// Output hook the printf implementation expects from the platform
+const PUTCHAR: &str = "_putchar";
+
+/// Prefix the symbols of `libs/<chip>/libprintf.a` the blobs import and
+/// rename the references in the blobs accordingly
+///
+/// Everything else exported by the printf library is made local, and
+/// `_putchar` becomes `<prefix>putchar`. The renamed API is declared in
+/// `include/<chip>/<prefix>.h`.
+pub fn prefix(chip: &str, prefix: &str) {
+    log::info!("Prefix libprintf.a for {chip} with {prefix}");
+
+    let libs = format!("./libs/{chip}");
+    let archives = Archive::read_chip(chip);
+
+    let (printf, blobs): (Vec<&Archive>, Vec<&Archive>) = archives
+        .iter()
+        .partition(|archive| archive.name == "libprintf.a");
+    let Some(printf) = printf.first() else {
+        panic!("No libprintf.a vendored for {chip}");
+    };
+
+    let exported: BTreeSet<&str> = printf
+        .members
+        .iter()
+        .flat_map(|member| &member.defined)
+        .filter(|symbol| symbol.binding == Binding::Strong)
+        .map(|symbol| symbol.name.as_str())
+        .collect();
+    let imported: BTreeSet<&str> = blobs
+        .iter()
+        .flat_map(|archive| &archive.members)
+        .flat_map(|member| &member.undefined)
+        .map(String::as_str)
+        .collect();
+
+    // names already carrying the prefix are left alone, which makes this
+    // safe to run again on prefixed libraries
+    let kept: BTreeSet<&str> = exported
+        .intersection(&imported)
+        .map(|name| name.strip_prefix(prefix).unwrap_or(name))
+        .collect();
+    let localize: BTreeSet<String> = exported
+        .difference(&imported)
+        .map(|name| name.to_string())
+        .collect();
+
+    let mut renames: BTreeMap<String, String> = kept
+        .iter()
+        .filter(|name| exported.contains(*name))
+        .map(|name| (name.to_string(), format!("{prefix}{name}")))
+        .collect();
+
+    log::info!("Keeping {kept:?}, making {localize:?} local");
+
+    for archive in &blobs {
+        rewrite(
+            &Path::new(&libs).join(&archive.name),
+            &renames,
+            &BTreeSet::new(),
+        );
+    }
+
+    renames.insert(PUTCHAR.to_string(), format!("{prefix}putchar"));
+    rewrite(&Path::new(&libs).join(&printf.name), &renames, &localize);
+
+    write_header(chip, prefix, &kept);
+}
+
+fn rewrite(path: &Path, renames: &BTreeMap<String, String>, localize: &BTreeSet<String>) {
+    let mut members = archive::read_members(path);
+
+    let mut changed = false;
+    for (name, data) in members.iter_mut() {
+        if let Some(new_data) = archive::rename_symbols(data, renames, localize) {
+            log::debug!("Renamed symbols in {name} of {path:?}");
+            *data = new_data;
+            changed = true;
+        }
+    }
+
+    if changed {
+        archive::write_members(path, &members);
+    }
+}
+
+/// Check a `--prefix`/`--printf-prefix` argument, it has to start a C
+/// identifier and name the header
+pub fn parse_prefix(prefix: &str) -> Result<String, String> {
+    if prefix.trim_end_matches('_').is_empty() {
+        return Err("the prefix needs more than underscores".to_string());
+    }
+    if prefix.starts_with(|c: char| c.is_ascii_digit())
+        || !prefix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return Err(format!("{prefix} does not start a C identifier"));
+    }
+    Ok(prefix.to_string())
+}
+
+/// Path of the header declaring the API prefixed by `prefix`
+pub fn header_path(chip: &str, prefix: &str) -> String {
+    format!("./include/{chip}/{}.h", prefix.trim_end_matches('_'))
+}
+
+/// The prefix `libs/<chip>/libprintf.a` was renamed with, `None` if it
+/// wasn't
+pub fn applied_prefix(chip: &str) -> Option<String> {
+    let path = Path::new("./libs").join(chip).join("libprintf.a");
+    if !path.exists() {
+        return None;
+    }
+    // the printf library references the renamed `_putchar`
+    Archive::read(&path)
+        .members
+        .iter()
+        .flat_map(|member| &member.undefined)
+        .filter(|name| *name != PUTCHAR)
+        .find_map(|name| name.strip_suffix("putchar"))
+        .map(str::to_string)
+}
+
+fn write_header(chip: &str, prefix: &str, kept: &BTreeSet<&str>) {
+    let guard = prefix.trim_end_matches('_').to_uppercase();
+
+    let mut header = format!(
+        "/*
+ * Generated by `cargo xtask prefix-printf`, do not edit
+ *
+ * The printf implementation used by the wireless libraries, with its
+ * symbols prefixed by `{prefix}`.
+ */
+
+#ifndef _{guard}_H_
+#define _{guard}_H_
+
+#include <stdarg.h>
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern \"C\" {{
+#endif
+
+/**
+ * Output a character, has to be provided by the platform
+ */
+void {prefix}putchar(char character);
+
+"
+    );
+
+    for (name, prototype) in PROTOTYPES {
+        if kept.contains(name) {
+            header.push_str(&prototype.replace("{}", &format!("{prefix}{name}")));
+            header.push('\n');
+        }
+    }
+
+    header.push_str(&format!(
+        "
+#ifdef __cplusplus
+}}
+#endif
+
+#endif  // _{guard}_H_
+"
+    ));
+
+    let path = header_path(chip, prefix);
+    fs::write(&path, header).unwrap_or_else(|_| panic!("Unable to write {path}"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefixes() {
+        assert_eq!(parse_prefix("esp_printf_"), Ok("esp_printf_".to_string()));
+        assert!(parse_prefix("").is_err());
+        assert!(parse_prefix("__").is_err());
+        assert!(parse_prefix("1pf_").is_err());
+        assert!(parse_prefix("my-pf_").is_err());
+
+        assert_eq!(
+            header_path("esp32c3", "esp_printf_"),
+            "./include/esp32c3/esp_printf.h"
+        );
+        assert_eq!(header_path("esp32c3", "pf"), "./include/esp32c3/pf.h");
+    }
+}
@@ -0,0 +1,145 @@
+use std::{collections::BTreeMap, fmt::Write, fs};
+
+use serde_json::{json, Map, Value};
+
+use crate::{sdkconfig, CHIPS};
+
+const DIR: &str = "./reg_base";
+const JSON: &str = "./reg_base/reg_base.json";
+const RUST: &str = "./reg_base/reg_base.rs";
+
+/// Headers defining the `DR_REG_*_BASE` macros, `soc.h` has a few more and
+/// aliases on some chips
+const HEADERS: &[&str] = &["soc/reg_base.h", "soc/soc.h"];
+
+const BIT_DEFS: &str = "./include/esp_bit_defs.h";
+
+/// The `DR_REG_*_BASE` addresses of a chip, `None` if its headers are
+/// missing
+fn evaluate(chip: &str) -> Option<BTreeMap<String, u32>> {
+    let mut macros = sdkconfig::macros(chip);
+    let bit_defs =
+        fs::read_to_string(BIT_DEFS).unwrap_or_else(|_| panic!("Unable to read {BIT_DEFS}"));
+    macros.preprocess(&bit_defs);
+
+    let mut found = false;
+    for header in HEADERS {
+        if let Ok(source) = fs::read_to_string(format!("./include/{chip}/{header}")) {
+            macros.preprocess(&source);
+            found = true;
+        }
+    }
+    if !found {
+        return None;
+    }
+
+    let mut bases = BTreeMap::new();
+    let names: Vec<String> = macros
+        .definitions()
+        .map(|(name, _)| name.to_string())
+        .filter(|name| name.starts_with("DR_REG_") && name.ends_with("_BASE"))
+        .collect();
+    for name in names {
+        let Some(body) = macros.get(&name) else {
+            continue;
+        };
+        match macros.eval(&name).map(u32::try_from) {
+            Ok(Ok(address)) => {
+                bases.insert(name, address);
+            }
+            Ok(Err(_)) => log::warn!("{chip}: {name} `{body}` is no 32 bit address"),
+            Err(error) => log::warn!("{chip}: unable to evaluate {name} `{body}`: {error}"),
+        }
+    }
+    Some(bases)
+}
+
+/// Evaluate the register base addresses of every chip and write
+/// `reg_base/reg_base.json` and `reg_base/reg_base.rs`, warning about
+/// addresses that differ from the previous `reg_base.json`
+pub fn export() {
+    let version = fs::read_to_string("version").expect("Unable to read version");
+    let version = version.trim();
+
+    let chips: Vec<(&str, BTreeMap<String, u32>)> = CHIPS
+        .iter()
+        .filter_map(|chip| Some((*chip, evaluate(chip)?)))
+        .collect();
+    if chips.is_empty() {
+        eprintln!("No include/<chip>/soc/reg_base.h, run the update first");
+        std::process::exit(1);
+    }
+
+    let json = json!({
+        "idf_version": version,
+        "chips": chips
+            .iter()
+            .map(|(chip, bases)| {
+                let bases: Map<String, Value> = bases
+                    .iter()
+                    .map(|(name, address)| (name.clone(), json!(format!("{address:#010x}"))))
+                    .collect();
+                (chip.to_string(), Value::Object(bases))
+            })
+            .collect::<Map<_, _>>(),
+    });
+
+    if let Some(previous) = fs::read_to_string(JSON)
+        .ok()
+        .and_then(|json| serde_json::from_str::<Value>(&json).ok())
+    {
+        warn_changes(&previous, &json);
+    }
+
+    fs::create_dir_all(DIR).unwrap();
+    write(JSON, &(serde_json::to_string_pretty(&json).unwrap() + "\n"));
+    write(RUST, &rust(version, &chips));
+    log::info!("Wrote {JSON} and {RUST}");
+}
+
+/// Warn about every base address that moved, peripherals added or removed
+/// are expected with new releases
+fn warn_changes(old: &Value, new: &Value) {
+    let version = |json: &Value| json["idf_version"].as_str().unwrap_or("?").to_string();
+    let (old_version, new_version) = (version(old), version(new));
+
+    let empty = Map::new();
+    for (chip, bases) in new["chips"].as_object().unwrap_or(&empty) {
+        let Some(old_bases) = old["chips"][chip].as_object() else {
+            continue;
+        };
+        for (name, address) in bases.as_object().unwrap_or(&empty) {
+            match old_bases.get(name) {
+                Some(old_address) if old_address != address => log::warn!(
+                    "{chip}: {name} moved from {} in {old_version} to {} in {new_version}",
+                    old_address.as_str().unwrap_or("?"),
+                    address.as_str().unwrap_or("?")
+                ),
+                _ => (),
+            }
+        }
+    }
+}
+
+fn rust(version: &str, chips: &[(&str, BTreeMap<String, u32>)]) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! Peripheral register base addresses of {version}, generated by\n//! `cargo xtask reg-base`\n"
+    )
+    .unwrap();
+    writeln!(out, "pub const IDF_VERSION: &str = \"{version}\";").unwrap();
+
+    for (chip, bases) in chips {
+        writeln!(out, "\npub mod {chip} {{").unwrap();
+        for (name, address) in bases {
+            writeln!(out, "    pub const {name}: u32 = {address:#010x};").unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+    out
+}
+
+fn write(path: &str, content: &str) {
+    fs::write(path, content).unwrap_or_else(|_| panic!("Unable to write {path}"));
+}
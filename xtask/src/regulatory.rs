@@ -0,0 +1,1081 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use object::{Object, ObjectSymbol};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    archive,
+    cdata::{self, Array, Data, Field, Struct},
+    cexpr::{tokenize, Macros},
+    sdkconfig::{self, Sdkconfig},
+    CHIPS,
+};
+
+/// The regulatory source vendored by the update
+pub const SOURCE: &str = "./regulatory/esp_wifi_regulatory.c";
+pub const JSON: &str = "./regulatory/regulatory.json";
+const RUST: &str = "./regulatory/regulatory.rs";
+
+/// Header declaring the regulatory types
+const TYPES: &str = "./include/esp_wifi_types_generic.h";
+
+/// The regulatory tables as evaluated for a chip
+#[derive(Debug, PartialEq)]
+struct Database {
+    /// Country code → regulatory rules
+    countries: Map<String, Value>,
+    /// All initialized arrays of the source
+    tables: Map<String, Value>,
+    rule: Struct,
+}
+
+/// Parse the regulatory source for every chip and write `regulatory.json`
+/// and `regulatory.rs`, chips with identical tables share a database.
+/// Returns the written JSON.
+pub fn export(source: Option<&Path>) -> Value {
+    let path = source.unwrap_or(Path::new(SOURCE));
+    let source = fs::read_to_string(path).unwrap_or_else(|_| {
+        eprintln!("Unable to read {path:?}, run the update first or pass --source");
+        std::process::exit(1);
+    });
+    let version = fs::read_to_string("version").expect("Unable to read version");
+    let version = version.trim();
+
+    let mut databases: Vec<(Vec<&str>, Database)> = Vec::new();
+    for chip in CHIPS {
+        if !Path::new(&format!("./include/{chip}/sdkconfig.h")).exists() {
+            continue;
+        }
+        let database = database(chip, &source);
+        match databases.iter_mut().find(|(_, other)| *other == database) {
+            Some((chips, _)) => chips.push(chip),
+            None => databases.push((vec![chip], database)),
+        }
+    }
+
+    let json = json!({
+        "idf_version": version,
+        "source": path.file_name().map(|name| name.to_string_lossy()),
+        "databases": databases
+            .iter()
+            .map(|(chips, database)| json!({
+                "chips": chips,
+                "countries": database.countries,
+                "tables": database.tables,
+            }))
+            .collect::<Vec<_>>(),
+    });
+
+    fs::create_dir_all("./regulatory").unwrap();
+    fs::write(JSON, serde_json::to_string_pretty(&json).unwrap() + "\n")
+        .unwrap_or_else(|_| panic!("Unable to write {JSON}"));
+    fs::write(RUST, rust(version, &databases)).unwrap_or_else(|_| panic!("Unable to write {RUST}"));
+    log::info!("Wrote {JSON} and {RUST}");
+
+    json
+}
+
+/// The two tables of the regulatory source and the fields connecting them
+struct Tables<'a> {
+    /// Entries of a rule count and an array of rules
+    rules: &'a Array,
+    rules_struct: &'a Struct,
+    rules_field: &'a Field,
+    count_field: Option<&'a Field>,
+    rule: &'a Struct,
+    /// Entries of a country code and an index into `rules`
+    countries: &'a Array,
+    countries_struct: &'a Struct,
+    code_field: &'a Field,
+    index_field: &'a Field,
+}
+
+/// Parse the regulatory source with a chip's sdkconfig.h
+fn parse(chip: &str, source: &str) -> Data {
+    let types = fs::read_to_string(TYPES).unwrap_or_else(|_| panic!("Unable to read {TYPES}"));
+    parse_with(chip, &mut sdkconfig::macros(chip), &types, source)
+}
+
+/// Parse the regulatory source following the header declaring its types
+fn parse_with(chip: &str, macros: &mut Macros, types: &str, source: &str) -> Data {
+    let mut preprocessed = macros.preprocess(types);
+    preprocessed.push_str(&macros.preprocess(source));
+
+    cdata::parse(&preprocessed, macros).unwrap_or_else(|e| {
+        eprintln!("Unable to parse the regulatory source for {chip}: {e}");
+        std::process::exit(1);
+    })
+}
+
+fn tables<'a>(chip: &str, data: &'a Data) -> Tables<'a> {
+    // the rules table holds structs with a rule count and an array of
+    // rules, the country table structs with a code and an index into it
+    let Some((rules, rules_struct)) = data.struct_arrays().find(|(_, s)| {
+        s.fields
+            .iter()
+            .any(|field| field.len.is_some() && data.structure(&field.ty).is_some())
+    }) else {
+        eprintln!("No regulatory rules table in the source for {chip}");
+        std::process::exit(1);
+    };
+    let Some((countries, countries_struct)) = data.struct_arrays().find(|(_, s)| {
+        s.fields.len() == 2 && s.fields.iter().any(|field| cdata::is_char(&field.ty))
+    }) else {
+        eprintln!("No country table in the source for {chip}");
+        std::process::exit(1);
+    };
+
+    let rules_field = rules_struct
+        .fields
+        .iter()
+        .find(|field| field.len.is_some())
+        .unwrap();
+
+    Tables {
+        rules,
+        rules_struct,
+        rules_field,
+        count_field: rules_struct.fields.iter().find(|field| field.len.is_none()),
+        rule: data.structure(&rules_field.ty).unwrap(),
+        countries,
+        countries_struct,
+        code_field: countries_struct
+            .fields
+            .iter()
+            .find(|field| cdata::is_char(&field.ty))
+            .unwrap(),
+        index_field: countries_struct
+            .fields
+            .iter()
+            .find(|field| !cdata::is_char(&field.ty))
+            .unwrap(),
+    }
+}
+
+/// Evaluate the regulatory source with a chip's sdkconfig.h
+fn database(chip: &str, source: &str) -> Database {
+    let data = parse(chip, source);
+    let tables = tables(chip, &data);
+
+    Database {
+        countries: countries(chip, &tables),
+        tables: data
+            .arrays
+            .iter()
+            .map(|array| (array.name.clone(), Value::from(array.values.clone())))
+            .collect(),
+        rule: tables.rule.clone(),
+    }
+}
+
+/// Country code → rules, without reserved fields
+fn countries(chip: &str, tables: &Tables) -> Map<String, Value> {
+    let mut by_country = Map::new();
+    for country in &tables.countries.values {
+        let code = country[&tables.code_field.name]
+            .as_str()
+            .unwrap_or_default();
+        // unused entries of a fixed size table
+        if code.is_empty() {
+            continue;
+        }
+        let index = country[&tables.index_field.name]
+            .as_u64()
+            .unwrap_or(u64::MAX) as usize;
+        let Some(entry) = tables.rules.values.get(index) else {
+            eprintln!(
+                "{code} refers to {}[{index}], which does not exist ({chip})",
+                tables.rules.name
+            );
+            std::process::exit(1);
+        };
+        let mut rules = entry[&tables.rules_field.name].as_array().unwrap().clone();
+        if let Some(count) = tables
+            .count_field
+            .and_then(|field| entry[&field.name].as_u64())
+        {
+            rules.truncate(count as usize);
+        }
+        let rules = rules
+            .into_iter()
+            .map(|mut rule| {
+                if let Some(rule) = rule.as_object_mut() {
+                    rule.retain(|name, _| !name.starts_with("reserved"));
+                }
+                rule
+            })
+            .collect();
+        by_country.insert(code.to_string(), Value::Array(rules));
+    }
+    by_country
+}
+
+/// Rust type of a rule field
+fn rust_type(field: &Field) -> &'static str {
+    match field.bits {
+        Some(1) => "bool",
+        Some(bits) if bits <= 8 => "u8",
+        Some(bits) if bits <= 16 => "u16",
+        Some(_) => "u32",
+        None => match field.ty.as_str() {
+            "bool" => "bool",
+            "int8_t" => "i8",
+            "int16_t" => "i16",
+            "int32_t" | "int" => "i32",
+            "uint16_t" => "u16",
+            "uint32_t" | "unsigned int" => "u32",
+            _ => "u8",
+        },
+    }
+}
+
+fn rust(version: &str, databases: &[(Vec<&str>, Database)]) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! Wi-Fi regulatory database of {version}, generated by `cargo xtask regulatory-export`\n"
+    )
+    .unwrap();
+    writeln!(out, "pub const IDF_VERSION: &str = \"{version}\";\n").unwrap();
+
+    let fields: Vec<&Field> = databases
+        .first()
+        .map(|(_, database)| {
+            database
+                .rule
+                .fields
+                .iter()
+                .filter(|field| !field.name.starts_with("reserved") && field.len.is_none())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if let Some((_, database)) = databases.first() {
+        writeln!(out, "/// Regulatory rule, see `{}`", database.rule.name).unwrap();
+    }
+    writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]").unwrap();
+    writeln!(out, "pub struct Rule {{").unwrap();
+    for field in &fields {
+        writeln!(out, "    pub {}: {},", field.name, rust_type(field)).unwrap();
+    }
+    writeln!(out, "}}\n").unwrap();
+
+    writeln!(out, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]").unwrap();
+    writeln!(out, "pub struct Country {{").unwrap();
+    writeln!(out, "    pub code: &'static str,").unwrap();
+    writeln!(out, "    pub rules: &'static [Rule],").unwrap();
+    writeln!(out, "}}\n").unwrap();
+
+    for (index, (chips, database)) in databases.iter().enumerate() {
+        writeln!(out, "/// {}", chips.join(", ")).unwrap();
+        writeln!(out, "pub const COUNTRIES_{index}: &[Country] = &[").unwrap();
+        for (code, rules) in &database.countries {
+            writeln!(out, "    Country {{").unwrap();
+            writeln!(out, "        code: \"{code}\",").unwrap();
+            writeln!(out, "        rules: &[").unwrap();
+            for rule in rules.as_array().unwrap() {
+                let values: Vec<String> = fields
+                    .iter()
+                    .map(|field| {
+                        let value = &rule[&field.name];
+                        match rust_type(field) {
+                            "bool" => format!("{}: {}", field.name, value.as_i64() != Some(0)),
+                            _ => format!("{}: {value}", field.name),
+                        }
+                    })
+                    .collect();
+                writeln!(out, "            Rule {{ {} }},", values.join(", ")).unwrap();
+            }
+            writeln!(out, "        ],").unwrap();
+            writeln!(out, "    }},").unwrap();
+        }
+        writeln!(out, "];\n").unwrap();
+    }
+
+    writeln!(
+        out,
+        "/// The regulatory database of a chip\npub fn countries(chip: &str) -> Option<&'static [Country]> {{\n    match chip {{"
+    )
+    .unwrap();
+    for (index, (chips, _)) in databases.iter().enumerate() {
+        let chips: Vec<String> = chips.iter().map(|chip| format!("\"{chip}\"")).collect();
+        writeln!(
+            out,
+            "        {} => Some(COUNTRIES_{index}),",
+            chips.join(" | ")
+        )
+        .unwrap();
+    }
+    writeln!(out, "        _ => None,\n    }}\n}}").unwrap();
+
+    out
+}
+
+/// Print the changes between two exported databases
+pub fn diff(old: &Path, new: Option<&Path>) {
+    let read = |path: &Path| -> Value {
+        let json = fs::read_to_string(path).unwrap_or_else(|_| panic!("Unable to read {path:?}"));
+        serde_json::from_str(&json).unwrap_or_else(|e| {
+            eprintln!("Invalid regulatory database {path:?}: {e}");
+            std::process::exit(1);
+        })
+    };
+    let new = read(new.unwrap_or(Path::new(JSON)));
+    print_diff(&read(old), &new);
+}
+
+pub fn print_diff(old: &Value, new: &Value) {
+    let version = |json: &Value| json["idf_version"].as_str().unwrap_or("?").to_string();
+    println!("Regulatory changes {} -> {}", version(old), version(new));
+
+    let (old, new) = (by_chip(old), by_chip(new));
+    let chips: Vec<&String> = new
+        .keys()
+        .chain(old.keys().filter(|chip| !new.contains_key(*chip)))
+        .collect();
+
+    // chips sharing a database usually share the changes
+    let mut changes: Vec<(Vec<&str>, Vec<String>)> = Vec::new();
+    for chip in chips {
+        let lines = match (old.get(chip), new.get(chip)) {
+            (Some(old), Some(new)) => country_changes(old, new),
+            (None, _) => vec!["new chip".to_string()],
+            (_, None) => vec!["removed chip".to_string()],
+        };
+        if lines.is_empty() {
+            continue;
+        }
+        match changes.iter_mut().find(|(_, other)| *other == lines) {
+            Some((chips, _)) => chips.push(chip),
+            None => changes.push((vec![chip], lines)),
+        }
+    }
+
+    if changes.is_empty() {
+        println!("No changes");
+    }
+    for (chips, lines) in changes {
+        println!("{}:", chips.join(", "));
+        for line in lines {
+            println!("  {line}");
+        }
+    }
+}
+
+/// Chip → countries of an exported database
+fn by_chip(json: &Value) -> BTreeMap<String, Map<String, Value>> {
+    let mut chips = BTreeMap::new();
+    for database in json["databases"].as_array().into_iter().flatten() {
+        let countries = database["countries"]
+            .as_object()
+            .cloned()
+            .unwrap_or_default();
+        for chip in database["chips"].as_array().into_iter().flatten() {
+            if let Some(chip) = chip.as_str() {
+                chips.insert(chip.to_string(), countries.clone());
+            }
+        }
+    }
+    chips
+}
+
+fn country_changes(old: &Map<String, Value>, new: &Map<String, Value>) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (code, rules) in new {
+        match old.get(code) {
+            None => {
+                lines.push(format!("+ {code}"));
+                for rule in rules.as_array().into_iter().flatten() {
+                    lines.push(format!("    + {}", format_rule(rule)));
+                }
+            }
+            Some(old_rules) if old_rules != rules => {
+                lines.push(format!("~ {code}"));
+                let old_rules = old_rules.as_array().cloned().unwrap_or_default();
+                let rules = rules.as_array().cloned().unwrap_or_default();
+                for rule in old_rules.iter().filter(|rule| !rules.contains(rule)) {
+                    lines.push(format!("    - {}", format_rule(rule)));
+                }
+                for rule in rules.iter().filter(|rule| !old_rules.contains(rule)) {
+                    lines.push(format!("    + {}", format_rule(rule)));
+                }
+            }
+            Some(_) => (),
+        }
+    }
+    for code in old.keys().filter(|code| !new.contains_key(*code)) {
+        lines.push(format!("- {code}"));
+    }
+    lines
+}
+
+fn format_rule(rule: &Value) -> String {
+    match rule.as_object() {
+        Some(fields) => fields
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join(" "),
+        None => rule.to_string(),
+    }
+}
+
+/// A user supplied country table for `cargo xtask regulatory`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Table {
+    /// Informational, tables for another chip are rejected
+    chip: Option<String>,
+    /// Start from the ESP-IDF tables and replace or add the listed countries
+    #[serde(default)]
+    inherit: bool,
+    /// Country code → rules
+    #[serde(default)]
+    countries: BTreeMap<String, Vec<toml::Table>>,
+}
+
+/// Generate `esp_wifi_regulatory.c` for a chip from a TOML country table,
+/// validate it and compile it into a drop-in `libregulatory.a`
+pub fn build(chip: &str, table: &Path, out: Option<PathBuf>) {
+    let toml = fs::read_to_string(table).unwrap_or_else(|_| panic!("Unable to read {table:?}"));
+    let table: Table = toml::from_str(&toml).unwrap_or_else(|e| {
+        eprintln!("Invalid country table {table:?}: {e}");
+        std::process::exit(1);
+    });
+    if table.chip.as_deref().is_some_and(|other| other != chip) {
+        eprintln!("The country table is for {}", table.chip.unwrap());
+        std::process::exit(1);
+    }
+
+    let source = fs::read_to_string(SOURCE).unwrap_or_else(|_| {
+        eprintln!("Unable to read {SOURCE}, run the update first");
+        std::process::exit(1);
+    });
+    let data = parse(chip, &source);
+    let tables = tables(chip, &data);
+
+    let mut countries = if table.inherit {
+        self::countries(chip, &tables)
+    } else {
+        Map::new()
+    };
+    let mut errors = Vec::new();
+    for (code, rules) in &table.countries {
+        let rules = rules
+            .iter()
+            .enumerate()
+            .filter_map(|(index, rule)| match rule_value(rule, tables.rule) {
+                Ok(rule) => Some(rule),
+                Err(e) => {
+                    errors.push(format!("{code} rule {index}: {e}"));
+                    None
+                }
+            })
+            .collect();
+        countries.insert(code.clone(), Value::Array(rules));
+    }
+    // rules with invalid fields would only add confusing follow-up errors
+    if errors.is_empty() {
+        errors = validate(chip, &countries, &tables);
+    }
+    if !errors.is_empty() {
+        for error in errors {
+            eprintln!("{error}");
+        }
+        std::process::exit(1);
+    }
+
+    let generated = generate(&source, &countries, &tables);
+
+    // the generated source has to describe exactly the requested tables
+    let check = parse(chip, &generated);
+    if self::countries(chip, &self::tables(chip, &check)) != countries {
+        panic!("The generated source does not evaluate to the requested tables");
+    }
+
+    let out = out.unwrap_or_else(|| Path::new("./target/regulatory").join(chip));
+    fs::create_dir_all(&out).unwrap();
+    let c_file = out.join("esp_wifi_regulatory.c");
+    fs::write(&c_file, generated).unwrap_or_else(|_| panic!("Unable to write {c_file:?}"));
+    log::info!("Wrote {c_file:?}");
+
+    compile(chip, &out);
+    compare_symbols(chip, &out.join("libregulatory.a"), &tables);
+    log::info!("Wrote {:?}", out.join("libregulatory.a"));
+}
+
+/// A TOML rule as JSON in the field order of the rule struct, single bit
+/// flags default to 0
+fn rule_value(rule: &toml::Table, rule_struct: &Struct) -> Result<Value, String> {
+    if let Some(unknown) = rule.keys().find(|name| {
+        name.starts_with("reserved") || !rule_struct.fields.iter().any(|field| field.name == **name)
+    }) {
+        return Err(format!("unknown field {unknown}"));
+    }
+
+    let mut value = Map::new();
+    for field in &rule_struct.fields {
+        if field.name.starts_with("reserved") {
+            continue;
+        }
+        let number = match rule.get(&field.name) {
+            Some(toml::Value::Integer(number)) => *number,
+            Some(toml::Value::Boolean(flag)) => *flag as i64,
+            Some(other) => return Err(format!("{} = {other} is no number", field.name)),
+            None if field.bits == Some(1) => 0,
+            None => return Err(format!("{} is missing", field.name)),
+        };
+        value.insert(field.name.clone(), Value::from(number));
+    }
+    Ok(Value::Object(value))
+}
+
+/// Range a struct field can hold
+fn field_range(field: &Field) -> (i64, i64) {
+    match (field.bits, field.ty.as_str()) {
+        (Some(bits), _) => (0, (1 << bits) - 1),
+        (None, "bool") => (0, 1),
+        (None, "int8_t") => (i8::MIN.into(), i8::MAX.into()),
+        (None, "int16_t") => (i16::MIN.into(), i16::MAX.into()),
+        (None, "uint16_t") => (0, u16::MAX.into()),
+        (None, "uint32_t") => (0, u32::MAX.into()),
+        _ => (0, u8::MAX.into()),
+    }
+}
+
+/// Check that the tables fit the C types and describe sensible rules
+fn validate(chip: &str, countries: &Map<String, Value>, tables: &Tables) -> Vec<String> {
+    let supports_5g = Sdkconfig::read(chip).get("SOC_WIFI_SUPPORT_5G").is_some();
+    let max_rules = tables.rules_field.len.unwrap_or_default();
+    let mut errors = Vec::new();
+
+    if let Some(len) = tables.countries.len {
+        if countries.len() > len {
+            errors.push(format!(
+                "{} countries, {} holds {len}",
+                countries.len(),
+                tables.countries.name
+            ));
+        }
+    }
+
+    let band = |channel: i64| match channel {
+        1..=14 => Some("2.4 GHz"),
+        36..=177 if supports_5g => Some("5 GHz"),
+        _ => None,
+    };
+
+    for (code, rules) in countries {
+        let code_len = tables.code_field.len.unwrap_or(2).min(2);
+        if code.len() != code_len
+            || !code
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        {
+            errors.push(format!(
+                "{code}: country codes are two uppercase letters or digits"
+            ));
+        }
+
+        let rules = rules.as_array().cloned().unwrap_or_default();
+        if rules.is_empty() || rules.len() > max_rules {
+            errors.push(format!(
+                "{code}: {} rules, {chip} allows 1 to {max_rules}",
+                rules.len()
+            ));
+        }
+
+        let mut ranges = Vec::new();
+        for (index, rule) in rules.iter().enumerate() {
+            for field in &tables.rule.fields {
+                let Some(value) = rule[&field.name].as_i64() else {
+                    continue;
+                };
+                let (min, max) = field_range(field);
+                if !(min..=max).contains(&value) {
+                    errors.push(format!(
+                        "{code} rule {index}: {} = {value} is outside {min}..={max}",
+                        field.name
+                    ));
+                }
+            }
+
+            let start = rule["start_channel"].as_i64();
+            let end = rule["end_channel"].as_i64();
+            if let (Some(start), Some(end)) = (start, end) {
+                match (band(start), band(end)) {
+                    (Some(start_band), Some(end_band))
+                        if start <= end && start_band == end_band =>
+                    {
+                        if rule["is_dfs"].as_i64().unwrap_or_default() != 0 && start_band != "5 GHz"
+                        {
+                            errors.push(format!("{code} rule {index}: DFS on 2.4 GHz channels"));
+                        }
+                        ranges.push((start, end));
+                    }
+                    _ => errors.push(format!(
+                        "{code} rule {index}: invalid channel range {start} to {end} for {chip}"
+                    )),
+                }
+            }
+            if let Some(bandwidth) = rule["max_bandwidth"].as_i64() {
+                // 1: 20 MHz, 2: 40 MHz, 3: 80 MHz, 4: 160 MHz
+                if !(1..=4).contains(&bandwidth) {
+                    errors.push(format!(
+                        "{code} rule {index}: max_bandwidth {bandwidth} is none of 1 (20 MHz) to 4 (160 MHz)"
+                    ));
+                }
+            }
+        }
+
+        ranges.sort();
+        for pair in ranges.windows(2) {
+            if pair[0].1 >= pair[1].0 {
+                errors.push(format!(
+                    "{code}: channels {}-{} and {}-{} overlap",
+                    pair[0].0, pair[0].1, pair[1].0, pair[1].1
+                ));
+            }
+        }
+    }
+
+    errors
+}
+
+/// Byte ranges of the initializers `{ ... }` of every definition of the
+/// array `name`, conditional blocks can define it more than once
+fn initializers(source: &str, name: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut offset = 0;
+    // the initializers themselves aren't searched for definitions
+    let mut skip_to = 0;
+    for line in source.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        if start < skip_to {
+            continue;
+        }
+        let code = line.trim_start();
+        let Some((declaration, _)) = line.split_once('=') else {
+            continue;
+        };
+        let tokens = tokenize(declaration);
+        if code.starts_with(['#', '/', '*'])
+            || !tokens
+                .windows(2)
+                .any(|pair| pair[0] == name && pair[1] == "[")
+        {
+            continue;
+        }
+        let open = start + declaration.len();
+        if let Some(range) = braces(source, open) {
+            skip_to = range.end;
+            ranges.push(range);
+        }
+    }
+    ranges
+}
+
+/// The range from the first `{` at or after `from` to its matching `}`,
+/// skipping comments and literals
+fn braces(source: &str, from: usize) -> Option<Range<usize>> {
+    let bytes = source.as_bytes();
+    let mut open = None;
+    let mut depth = 0;
+    let mut i = from;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = source[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| i + 2 + end + 1);
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i = source[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            }
+            quote @ (b'"' | b'\'') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            b'{' => {
+                open.get_or_insert(i);
+                depth += 1;
+            }
+            b'}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open.unwrap()..i + 1);
+                }
+            }
+            b';' if depth == 0 => return None,
+            _ => (),
+        }
+        i += 1;
+    }
+    None
+}
+
+/// C initializer of a value built in struct field order
+fn c_value(value: &Value) -> String {
+    match value {
+        Value::Object(fields) => format!(
+            "{{{}}}",
+            fields.values().map(c_value).collect::<Vec<_>>().join(", ")
+        ),
+        Value::Array(values) => format!(
+            "{{{}}}",
+            values.iter().map(c_value).collect::<Vec<_>>().join(", ")
+        ),
+        Value::String(string) => format!("\"{string}\""),
+        Value::Bool(flag) => (*flag as u8).to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// The vendored source with the initializers of both tables replaced,
+/// identical rule lists share an entry like in ESP-IDF
+fn generate(source: &str, countries: &Map<String, Value>, tables: &Tables) -> String {
+    let mut rule_lists: Vec<&Value> = Vec::new();
+    let mut country_entries = Vec::new();
+    for (code, rules) in countries {
+        let index = match rule_lists.iter().position(|other| *other == rules) {
+            Some(index) => index,
+            None => {
+                rule_lists.push(rules);
+                rule_lists.len() - 1
+            }
+        };
+        let entry: Map<String, Value> = tables
+            .countries_struct
+            .fields
+            .iter()
+            .map(|field| {
+                let value = if field.name == tables.code_field.name {
+                    Value::from(code.as_str())
+                } else {
+                    Value::from(index)
+                };
+                (field.name.clone(), value)
+            })
+            .collect();
+        country_entries.push(format!("    {},", c_value(&Value::Object(entry))));
+    }
+
+    let rule_entries: Vec<String> = rule_lists
+        .iter()
+        .enumerate()
+        .map(|(index, rules)| {
+            let rules = rules.as_array().cloned().unwrap_or_default();
+            let entry: Map<String, Value> = tables
+                .rules_struct
+                .fields
+                .iter()
+                .map(|field| {
+                    let value = if field.name == tables.rules_field.name {
+                        // reserved fields are zero
+                        Value::Array(
+                            rules
+                                .iter()
+                                .map(|rule| {
+                                    Value::Object(
+                                        tables
+                                            .rule
+                                            .fields
+                                            .iter()
+                                            .map(|field| {
+                                                let value = rule
+                                                    .get(&field.name)
+                                                    .cloned()
+                                                    .unwrap_or(Value::from(0));
+                                                (field.name.clone(), value)
+                                            })
+                                            .collect(),
+                                    )
+                                })
+                                .collect(),
+                        )
+                    } else if Some(field) == tables.count_field {
+                        Value::from(rules.len())
+                    } else {
+                        Value::from(0)
+                    };
+                    (field.name.clone(), value)
+                })
+                .collect();
+            format!("    /* {index} */ {},", c_value(&Value::Object(entry)))
+        })
+        .collect();
+
+    let mut replacements = Vec::new();
+    for (name, entries) in [
+        (&tables.rules.name, rule_entries),
+        (&tables.countries.name, country_entries),
+    ] {
+        let ranges = initializers(source, name);
+        if ranges.is_empty() {
+            panic!("Unable to find the definition of {name} in {SOURCE}");
+        }
+        let initializer = format!(
+            "{{\n    /* Generated by `cargo xtask regulatory` */\n{}\n}}",
+            entries.join("\n")
+        );
+        replacements.extend(ranges.into_iter().map(|range| (range, initializer.clone())));
+    }
+    replacements.sort_by_key(|(range, _)| range.start);
+
+    // everything around the initializers is kept as it is
+    let mut out = String::new();
+    let mut copied = 0;
+    for (range, initializer) in replacements {
+        out.push_str(&source[copied..range.start]);
+        out.push_str(&initializer);
+        copied = range.end;
+    }
+    out.push_str(&source[copied..]);
+    out
+}
+
+/// Compile `esp_wifi_regulatory.c` in `out` and archive it like ESP-IDF
+fn compile(chip: &str, out: &Path) {
+    let mut args = vec![
+        "-c".to_string(),
+        "-Os".to_string(),
+        "-ffunction-sections".to_string(),
+        "-fdata-sections".to_string(),
+    ];
+    if crate::is_xtensa(chip) {
+        args.push("-mlongcalls".to_string());
+    } else if ["esp32c2", "esp32c3"].contains(&chip) {
+        args.push("-march=rv32imc_zicsr_zifencei".to_string());
+    } else {
+        args.push("-march=rv32imac_zicsr_zifencei".to_string());
+    }
+    let cwd = std::env::current_dir().unwrap();
+    for include in ["include", &format!("include/{chip}"), "include/local"] {
+        args.push(format!("-I{}", cwd.join(include).display()));
+    }
+    args.extend([
+        "-o".to_string(),
+        "esp_wifi_regulatory.c.obj".to_string(),
+        "esp_wifi_regulatory.c".to_string(),
+    ]);
+
+    let gcc = format!("{}-gcc", crate::toolchain_prefix(chip));
+    let output = Command::new(&gcc)
+        .args(&args)
+        .current_dir(out)
+        .output()
+        .unwrap_or_else(|_| {
+            eprintln!("Unable to run {gcc}, is the ESP-IDF toolchain activated?");
+            std::process::exit(1);
+        });
+    if !output.status.success() {
+        eprintln!(
+            "Failed to compile esp_wifi_regulatory.c\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        std::process::exit(1);
+    }
+
+    fs::remove_file(out.join("libregulatory.a")).ok();
+    crate::ar(
+        chip,
+        out.to_str().unwrap(),
+        &["libregulatory.a", "esp_wifi_regulatory.c.obj"],
+    );
+}
+
+/// Global symbols defined by an archive with their sizes
+fn defined_symbols(path: &Path) -> BTreeMap<String, u64> {
+    let mut symbols = BTreeMap::new();
+    for (name, data) in archive::read_members(path) {
+        let file = object::File::parse(&*data)
+            .unwrap_or_else(|e| panic!("Unable to parse {name} in {path:?}: {e}"));
+        for symbol in file.symbols() {
+            if symbol.is_global() && symbol.is_definition() {
+                if let Ok(symbol_name) = symbol.name() {
+                    symbols.insert(symbol_name.to_string(), symbol.size());
+                }
+            }
+        }
+    }
+    symbols
+}
+
+/// The new archive has to define what the vendored one defines, with the
+/// same sizes except for the rules table, which the country table indexes
+fn compare_symbols(chip: &str, built: &Path, tables: &Tables) {
+    let vendored = Path::new("./libs").join(chip).join("libregulatory.a");
+    if !vendored.exists() {
+        log::warn!("No {vendored:?} to compare with");
+        return;
+    }
+    let (vendored, built) = (defined_symbols(&vendored), defined_symbols(built));
+
+    let mut compatible = true;
+    for (name, size) in &vendored {
+        match built.get(name) {
+            None => {
+                eprintln!("{name} is missing");
+                compatible = false;
+            }
+            Some(built_size) if built_size != size && *name != tables.rules.name => {
+                eprintln!("{name} has {built_size} bytes instead of {size}");
+                compatible = false;
+            }
+            _ => (),
+        }
+    }
+    if !compatible {
+        eprintln!("The built libregulatory.a is no drop-in replacement for {chip}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TYPES: &str = "#ifdef CONFIG_SOC_WIFI_SUPPORT_5G
+#define WIFI_MAX_REGULATORY_RULE_NUM  7
+#else
+#define WIFI_MAX_REGULATORY_RULE_NUM  2
+#endif
+
+typedef struct {
+    uint8_t start_channel;
+    uint8_t end_channel;
+    uint16_t max_bandwidth : 3;
+    uint16_t max_eirp : 6;
+    uint16_t is_dfs : 1;
+    uint16_t reserved : 6;
+} wifi_reg_rule_t;
+
+typedef struct {
+    uint8_t n_reg_rules;
+    wifi_reg_rule_t reg_rules[WIFI_MAX_REGULATORY_RULE_NUM];
+} wifi_regulatory_t;
+
+typedef struct {
+    char cn[2];
+    uint8_t regulatory_type;
+} wifi_regdomain_t;
+";
+
+    const SOURCE: &str = "#include \"esp_wifi_types.h\"
+
+const wifi_regulatory_t regulatory_data[] = {
+    /* 0 */ {1, {{1, 13, 2, 20, 0, 0}}},
+    /* 1 */ {2, {{1, 11, 2, 30, 0, 0}, {12, 13, 1, 20, 0, 0}}},
+};
+
+#if CONFIG_SOC_WIFI_SUPPORT_5G
+const wifi_regdomain_t regdomain_table[4] = {
+    {\"DE\", 0},
+};
+#else
+const wifi_regdomain_t regdomain_table[4] = {
+    {\"DE\", 0}, /* {\"AT\", 0}, */
+    {\"US\", 1},
+    {\"FR\", 0},
+};
+#endif
+
+const wifi_regulatory_t *esp_wifi_regulatory_rules(const char *code)
+{
+    return code[0] == '{' ? NULL : &regulatory_data[0];
+}
+";
+
+    fn parse(source: &str) -> Data {
+        parse_with("esp32c3", &mut Macros::new(), TYPES, source)
+    }
+
+    fn rule(start: u64, end: u64, eirp: u64) -> Value {
+        json!({
+            "start_channel": start,
+            "end_channel": end,
+            "max_bandwidth": 2,
+            "max_eirp": eirp,
+            "is_dfs": 0,
+        })
+    }
+
+    #[test]
+    fn countries_of_source() {
+        let data = parse(SOURCE);
+        let tables = tables("esp32c3", &data);
+        assert_eq!(tables.rules.name, "regulatory_data");
+        assert_eq!(tables.countries.name, "regdomain_table");
+        assert_eq!(tables.rules_field.len, Some(2));
+
+        let countries = countries("esp32c3", &tables);
+        // the unused fourth entry is skipped, n_reg_rules truncates
+        assert_eq!(countries.len(), 3);
+        assert_eq!(countries["DE"], json!([rule(1, 13, 20)]));
+        assert_eq!(countries["FR"], countries["DE"]);
+        assert_eq!(countries["US"][1]["end_channel"], 13);
+    }
+
+    #[test]
+    fn generate_round_trip() {
+        let data = parse(SOURCE);
+        let tables = tables("esp32c3", &data);
+        let mut countries = countries("esp32c3", &tables);
+        countries.insert("JP".to_string(), json!([rule(1, 14, 18)]));
+        countries.insert("CH".to_string(), countries["DE"].clone());
+
+        let generated = generate(SOURCE, &countries, &tables);
+        // only the initializers are replaced
+        assert!(generated.starts_with(
+            "#include \"esp_wifi_types.h\"\n\nconst wifi_regulatory_t regulatory_data[] = {\n"
+        ));
+        assert!(generated.ends_with(&SOURCE[SOURCE.find("};\n#endif").unwrap()..]));
+        assert!(!generated.contains("AT"));
+        // identical rule lists share an entry
+        assert_eq!(generated.matches("/* 2 */").count(), 1);
+        assert!(!generated.contains("/* 3 */"));
+        // in both branches of the conditional
+        assert_eq!(generated.matches("{\"JP\", 2}").count(), 2);
+
+        let check = parse(&generated);
+        let tables = self::tables("esp32c3", &check);
+        assert_eq!(self::countries("esp32c3", &tables), countries);
+        assert_eq!(generate(&generated, &countries, &tables), generated);
+    }
+
+    #[test]
+    fn rules_from_toml() {
+        let data = parse(SOURCE);
+        let rule_struct = data.structure("wifi_reg_rule_t").unwrap();
+
+        let table: toml::Table =
+            toml::from_str("start_channel = 1\nend_channel = 13\nmax_bandwidth = 2\nmax_eirp = 20")
+                .unwrap();
+        assert_eq!(rule_value(&table, rule_struct), Ok(rule(1, 13, 20)));
+
+        let reserved: toml::Table = toml::from_str("reserved = 1").unwrap();
+        assert_eq!(
+            rule_value(&reserved, rule_struct),
+            Err("unknown field reserved".to_string())
+        );
+        let missing: toml::Table = toml::from_str("start_channel = 1").unwrap();
+        assert_eq!(
+            rule_value(&missing, rule_struct),
+            Err("end_channel is missing".to_string())
+        );
+    }
+}
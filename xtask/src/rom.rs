@@ -0,0 +1,283 @@
+use std::{collections::BTreeMap, fs};
+
+use crate::{archive::Archive, cheader::strip_comments, sdkconfig::Sdkconfig};
+
+/// The sdkconfig of the helper project the update builds
+const SDKCONFIG: &str = "./helper_project/build/config/sdkconfig.h";
+
+/// `<chip>.rom.<name>.ld` scripts `esp_rom/CMakeLists.txt` only links for
+/// some configurations, with the Kconfig expression selecting them. The
+/// `eco<N>` variants depend on the minimum revision, see [eco_revision], all
+/// other scripts are always linked.
+const CONDITIONAL: &[(&str, &str)] = &[
+    (
+        "libgcc",
+        "COMPILER_FLOAT_LIB_FROM_GCCLIB || !ESP_ROM_HAS_RVFPLIB",
+    ),
+    ("rvfp", "COMPILER_FLOAT_LIB_FROM_RVFPLIB"),
+    ("newlib", "ESP_ROM_HAS_NEWLIB && !LIBC_PICOLIBC"),
+    (
+        "newlib-nano",
+        "(LIBC_NEWLIB_NANO_FORMAT || NEWLIB_NANO_FORMAT) && ESP_ROM_HAS_NEWLIB_NANO_FORMAT",
+    ),
+    (
+        "newlib-normal",
+        "!(LIBC_NEWLIB_NANO_FORMAT || NEWLIB_NANO_FORMAT) && ESP_ROM_HAS_NEWLIB_NORMAL_FORMAT",
+    ),
+    // only for a 32 bit `time_t`, the ESP-IDF 5 toolchains have 64 bits
+    ("newlib-time", "n"),
+    ("version", "ESP_ROM_HAS_VERSION"),
+    ("heap", "HEAP_TLSF_USE_ROM_IMPL"),
+    ("wdt", "HAL_WDT_USE_ROM_IMPL"),
+    ("systimer", "HAL_SYSTIMER_USE_ROM_IMPL"),
+    ("mbedtls", "MBEDTLS_USE_CRYPTO_ROM_IMPL"),
+    (
+        "spiflash",
+        "SPI_FLASH_ROM_IMPL || (IDF_TARGET_ESP32 && !SPI_FLASH_ROM_DRIVER_PATCH)",
+    ),
+];
+
+/// A symbol the chip ROM provides
+#[derive(Debug)]
+pub struct RomSymbol {
+    pub address: u64,
+    /// Linker script defining the symbol, e.g. `esp32c3.rom.ld`
+    pub script: String,
+}
+
+/// The minimum revision (`major * 100 + minor`) from which the `eco<N>`
+/// ROM scripts of a chip are linked
+fn eco_revision(chip: &str, eco: u32) -> Option<i64> {
+    match (chip, eco) {
+        ("esp32", 3) => Some(300),
+        ("esp32c2", 4) => Some(200),
+        ("esp32c3", 3) => Some(3),
+        ("esp32c3", 7) => Some(101),
+        _ => None,
+    }
+}
+
+/// Whether ESP-IDF links the ROM linker script `file_name` of `esp_rom/<chip>/ld/`
+/// for the sdkconfig `config`, `false` for other scripts
+pub fn linked(chip: &str, file_name: &str, config: &Sdkconfig) -> bool {
+    let Some(name) = file_name
+        .strip_prefix(&format!("{chip}.rom"))
+        .and_then(|name| name.strip_suffix(".ld"))
+    else {
+        return false;
+    };
+    let name = name.strip_prefix('.').unwrap_or(name);
+
+    if let Some(eco) = name.find("eco").and_then(|start| {
+        let digits: String = name[start + 3..]
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        digits.parse().ok()
+    }) {
+        let option = format!("{}_REV_MIN_FULL", chip.to_uppercase());
+        let revision: i64 = config
+            .get(&option)
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(0);
+        return match eco_revision(chip, eco) {
+            Some(minimum) => revision >= minimum,
+            None => {
+                log::warn!("{file_name}: unknown revision of eco{eco}, not linked");
+                false
+            }
+        };
+    }
+
+    match CONDITIONAL.iter().find(|(script, _)| *script == name) {
+        Some((_, condition)) => config.eval(condition),
+        None => true,
+    }
+}
+
+/// Copy the ROM linker scripts ESP-IDF links for the helper project's
+/// sdkconfig to `to`
+pub fn copy_scripts(idf_path: &str, chip: &str, to: &str) {
+    let config = Sdkconfig::parse(
+        &fs::read_to_string(SDKCONFIG).unwrap_or_else(|_| panic!("Unable to read {SDKCONFIG}")),
+    );
+    let from = format!("{idf_path}/components/esp_rom/{chip}/ld");
+    let entries = fs::read_dir(&from).unwrap_or_else(|_| panic!("Unable to read {from}"));
+    let mut names: Vec<String> = entries
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    for name in names {
+        if linked(chip, &name, &config) {
+            crate::copy_file(&format!("{from}/{name}"), &format!("{to}/{name}"));
+        } else {
+            log::debug!("Skipping {name}, not linked for the sdkconfig");
+        }
+    }
+}
+
+/// Symbols provided by the ROM linker scripts vendored to `libs/<chip>/ld/`
+/// which are linked for the chip's sdkconfig
+pub fn chip_symbols(chip: &str) -> BTreeMap<String, RomSymbol> {
+    let Ok(entries) = fs::read_dir(format!("./libs/{chip}/ld")) else {
+        return BTreeMap::new();
+    };
+    let config = Sdkconfig::read(chip);
+    let mut paths: Vec<_> = entries
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            linked(chip, &name, &config)
+        })
+        .collect();
+    paths.sort();
+
+    let mut symbols = BTreeMap::new();
+    for path in paths {
+        let script = path.file_name().unwrap().to_string_lossy().to_string();
+        let source =
+            fs::read_to_string(&path).unwrap_or_else(|_| panic!("Unable to read {path:?}"));
+        for (name, address) in provides(&source) {
+            symbols.entry(name).or_insert(RomSymbol {
+                address,
+                script: script.clone(),
+            });
+        }
+    }
+    symbols
+}
+
+/// `PROVIDE(name = address)` and `name = address` assignments of a linker
+/// script, assignments of other symbols are resolved if those are known
+pub fn provides(source: &str) -> Vec<(String, u64)> {
+    let source = strip_comments(source);
+    let mut assignments: Vec<(String, u64)> = Vec::new();
+
+    for statement in source.split(';') {
+        let statement = statement.trim();
+        let statement = ["PROVIDE_HIDDEN", "PROVIDE"]
+            .iter()
+            .find_map(|provide| statement.strip_prefix(provide))
+            .map(|inner| inner.trim().trim_start_matches('(').trim_end_matches(')'))
+            .unwrap_or(statement);
+
+        let Some((name, value)) = statement.split_once('=') else {
+            continue;
+        };
+        let name = name.trim();
+        let value = value.trim();
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+            || name == "."
+        {
+            continue;
+        }
+
+        let address = match value.strip_prefix("0x").or(value.strip_prefix("0X")) {
+            Some(hex) => u64::from_str_radix(hex, 16).ok(),
+            None => value.parse().ok().or_else(|| {
+                assignments
+                    .iter()
+                    .rev()
+                    .find(|(other, _)| other == value)
+                    .map(|(_, address)| *address)
+            }),
+        };
+        match address {
+            Some(address) => assignments.push((name.to_string(), address)),
+            None => log::debug!("Skipping {name} = {value}"),
+        }
+    }
+
+    assignments
+}
+
+/// Print the imports of the chip's archives nothing vendored defines but
+/// the ROM provides
+pub fn imports(chips: &[String]) {
+    for chip in chips {
+        let rom = chip_symbols(chip);
+        if rom.is_empty() {
+            eprintln!("{chip}: no ROM linker scripts in libs/{chip}/ld, run the update first");
+            continue;
+        }
+
+        let archives = Archive::read_chip(chip);
+        let unresolved = crate::stubs::unresolved(&archives);
+
+        let mut in_rom = 0;
+        for name in &unresolved {
+            if let Some(symbol) = rom.get(*name) {
+                println!("{chip}: {name} 0x{:08x} {}", symbol.address, symbol.script);
+                in_rom += 1;
+            }
+        }
+        log::info!(
+            "{chip}: ROM provides {in_rom} of {} unresolved imports",
+            unresolved.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scripts_are_selected_by_the_sdkconfig() {
+        let config = Sdkconfig::parse(
+            "#define CONFIG_ESP_ROM_HAS_NEWLIB 1\n\
+             #define CONFIG_ESP_ROM_HAS_NEWLIB_NANO_FORMAT 1\n\
+             #define CONFIG_ESP_ROM_HAS_NEWLIB_NORMAL_FORMAT 1\n\
+             #define CONFIG_ESP_ROM_HAS_VERSION 1\n\
+             #define CONFIG_ESP32C3_REV_MIN_FULL 3\n",
+        );
+        let linked = |name| linked("esp32c3", name, &config);
+
+        assert!(linked("esp32c3.rom.ld"));
+        assert!(linked("esp32c3.rom.api.ld"));
+        assert!(linked("esp32c3.rom.libgcc.ld"));
+        assert!(linked("esp32c3.rom.newlib.ld"));
+        assert!(linked("esp32c3.rom.newlib-normal.ld"));
+        assert!(!linked("esp32c3.rom.newlib-nano.ld"));
+        assert!(!linked("esp32c3.rom.newlib-time.ld"));
+        assert!(linked("esp32c3.rom.version.ld"));
+        assert!(!linked("esp32c3.rom.heap.ld"));
+        assert!(linked("esp32c3.rom.eco3.ld"));
+        assert!(!linked("esp32c3.rom.eco7.ld"));
+        assert!(!linked("esp32c3.rom.eco9.ld"));
+        assert!(!linked("esp32c2.rom.ld"));
+        assert!(!linked("esp32c3.peripherals.ld"));
+    }
+
+    #[test]
+    fn nano_format_selects_newlib_nano() {
+        let config = Sdkconfig::parse(
+            "#define CONFIG_LIBC_NEWLIB_NANO_FORMAT 1\n\
+             #define CONFIG_ESP_ROM_HAS_NEWLIB_NANO_FORMAT 1\n\
+             #define CONFIG_ESP_ROM_HAS_NEWLIB_NORMAL_FORMAT 1\n",
+        );
+        assert!(linked("esp32c6", "esp32c6.rom.newlib-nano.ld", &config));
+        assert!(!linked("esp32c6", "esp32c6.rom.newlib-normal.ld", &config));
+    }
+
+    #[test]
+    fn provides_resolves_assignments() {
+        let source = "/* ROM functions */\n\
+             PROVIDE ( esp_rom_printf = 0x40000040 );\n\
+             PROVIDE_HIDDEN(hidden = 16);\n\
+             ets_printf = esp_rom_printf;\n\
+             unknown = other;\n\
+             . = ALIGN(4);\n";
+        assert_eq!(
+            provides(source),
+            [
+                ("esp_rom_printf".to_string(), 0x4000_0040),
+                ("hidden".to_string(), 16),
+                ("ets_printf".to_string(), 0x4000_0040),
+            ]
+        );
+    }
+}
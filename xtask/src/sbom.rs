@@ -0,0 +1,419 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::{json, Value};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use crate::{manifest, printf, CHIPS};
+
+/// Vendored file → the file it was copied from, recorded by the update
+pub const SOURCES: &str = "./sources.json";
+
+/// Directories the SBOM covers
+const VENDORED: &[&str] = &["include", "libs"];
+
+/// Files this repository generates itself, besides the printf headers of
+/// [`printf::header_path`]
+const GENERATED: &[&str] = &["wireless_sections.ld", "esp-wireless.pc", "Config.cmake"];
+
+const REPOSITORY: &str = "esp-wireless-drivers";
+
+/// Copies of the running update, see [`save`]
+static RECORDED: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
+
+/// Remember where a vendored file comes from
+pub fn record(from: &str, to: &str) {
+    let to = normalize(to);
+    // build inputs of the helper project are not vendored
+    if to.starts_with("helper_project/") {
+        return;
+    }
+
+    let from = normalize(from);
+    let from = match std::env::var("IDF_PATH") {
+        Ok(idf_path) => match from.strip_prefix(&normalize(&idf_path)) {
+            Some(path) => format!("esp-idf/{}", path.trim_start_matches('/')),
+            None => from,
+        },
+        Err(_) => from,
+    };
+    RECORDED.lock().unwrap().insert(to, from);
+}
+
+/// Merge the recorded copies into [`SOURCES`], dropping files which are no
+/// longer vendored
+pub fn save() {
+    let mut sources = read_sources();
+    sources.retain(|to, _| Path::new(to).exists());
+    sources.extend(std::mem::take(&mut *RECORDED.lock().unwrap()));
+    fs::write(
+        SOURCES,
+        serde_json::to_string_pretty(&sources).unwrap() + "\n",
+    )
+    .unwrap_or_else(|_| panic!("Unable to write {SOURCES}"));
+}
+
+fn read_sources() -> BTreeMap<String, String> {
+    match fs::read_to_string(SOURCES) {
+        Ok(json) => serde_json::from_str(&json).unwrap_or_else(|_| panic!("Invalid {SOURCES}")),
+        Err(_) => BTreeMap::new(),
+    }
+}
+
+/// `./libs/esp32//libpp.a` → `libs/esp32/libpp.a`
+fn normalize(path: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split(['/', '\\']) {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            _ => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+/// A vendored file with what is known about it
+struct File {
+    path: String,
+    source: Option<String>,
+    package: String,
+    sha1: String,
+    sha256: String,
+    licenses: Vec<String>,
+    copyright: Option<String>,
+}
+
+/// Write an SPDX 2.3 document with a package per upstream component and
+/// every file under `include/` and `libs/`
+pub fn write(out: Option<PathBuf>) {
+    let out = out.unwrap_or_else(|| PathBuf::from("./target/sbom/esp-wireless.spdx.json"));
+    let idf_version = fs::read_to_string("version").expect("Unable to read version");
+    let idf_version = idf_version.trim();
+    let tag = idf_version.rsplit(' ').next().unwrap();
+
+    let mut sources = read_sources();
+    // archives are known from the manifest even without a record
+    for chip in CHIPS {
+        for library in manifest::libraries(chip) {
+            let source = match &library.source {
+                manifest::Source::Helper(path) => format!("helper_project/{path}"),
+                manifest::Source::HelperObject(path) => {
+                    normalize(&format!("helper_project/{path}"))
+                }
+                manifest::Source::Idf(path) => format!("esp-idf/{path}"),
+            };
+            sources
+                .entry(format!("libs/{chip}/{}", library.file_name()))
+                .or_insert(source);
+        }
+    }
+
+    let mut paths = Vec::new();
+    for dir in VENDORED {
+        collect(Path::new(dir), &mut paths);
+    }
+    paths.sort();
+
+    let printf_headers: BTreeSet<String> = CHIPS
+        .iter()
+        .filter_map(|chip| {
+            printf::applied_prefix(chip)
+                .map(|prefix| normalize(&printf::header_path(chip, &prefix)))
+        })
+        .collect();
+
+    let mut unrecorded = Vec::new();
+    let files: Vec<File> = paths
+        .into_iter()
+        .map(|path| {
+            let path = normalize(&path.to_string_lossy());
+            let data = fs::read(&path).unwrap_or_else(|_| panic!("Unable to read {path}"));
+            let source = sources.get(&path).cloned();
+            let package = match &source {
+                Some(source) => component(source),
+                None => {
+                    if !GENERATED.iter().any(|name| path.ends_with(name))
+                        && !printf_headers.contains(&path)
+                    {
+                        unrecorded.push(path.clone());
+                    }
+                    REPOSITORY.to_string()
+                }
+            };
+            let (licenses, copyright) = spdx_header(&data);
+            File {
+                source,
+                package,
+                sha1: hex(&Sha1::digest(&data)),
+                sha256: hex(&Sha256::digest(&data)),
+                licenses,
+                copyright,
+                path,
+            }
+        })
+        .collect();
+    // vendored files of unknown origin would be claimed by this repository
+    if !unrecorded.is_empty() {
+        for path in &unrecorded {
+            eprintln!("{path}");
+        }
+        eprintln!(
+            "The origin of {} vendored files is not recorded in {SOURCES}, run the update",
+            unrecorded.len()
+        );
+        std::process::exit(1);
+    }
+
+    let mut packages: BTreeMap<&str, Vec<&File>> = BTreeMap::new();
+    for file in &files {
+        packages.entry(&file.package).or_default().push(file);
+    }
+
+    let mut ids = BTreeSet::new();
+    let mut file_ids = BTreeMap::new();
+    for file in &files {
+        let mut id = format!("SPDXRef-File-{}", spdx_id(&file.path));
+        while !ids.insert(id.clone()) {
+            id.push('-');
+        }
+        file_ids.insert(file.path.as_str(), id);
+    }
+
+    let mut package_values = Vec::new();
+    let mut file_values = Vec::new();
+    let mut relationships = Vec::new();
+    for (name, files) in &packages {
+        let id = format!("SPDXRef-Package-{}", spdx_id(name));
+        let declared = declared_license(name);
+
+        // SHA1 of the sorted SHA1s of the files, SPDX 2.3 section 7.9
+        let mut sha1s: Vec<&str> = files.iter().map(|file| file.sha1.as_str()).collect();
+        sha1s.sort();
+        let verification = hex(&Sha1::digest(sha1s.concat().as_bytes()));
+
+        let (version, download, supplier) = match *name {
+            REPOSITORY => (
+                Value::Null,
+                "NOASSERTION".to_string(),
+                "NOASSERTION".to_string(),
+            ),
+            "printf" => (
+                Value::Null,
+                "git+https://github.com/mpaland/printf.git".to_string(),
+                "Person: Marco Paland".to_string(),
+            ),
+            _ => (
+                json!(tag),
+                format!("git+https://github.com/espressif/esp-idf.git@{tag}#components/{name}"),
+                "Organization: Espressif Systems".to_string(),
+            ),
+        };
+        let mut package = json!({
+            "name": name,
+            "SPDXID": id,
+            "downloadLocation": download,
+            "supplier": supplier,
+            "filesAnalyzed": true,
+            "packageVerificationCode": { "packageVerificationCodeValue": verification },
+            "licenseConcluded": declared,
+            "licenseDeclared": declared,
+            "licenseInfoFromFiles": license_info(files.iter().flat_map(|file| &file.licenses)),
+            "copyrightText": "NOASSERTION",
+            "comment": format!("Vendored from {idf_version}"),
+            "hasFiles": files.iter().map(|file| &file_ids[file.path.as_str()]).collect::<Vec<_>>(),
+        });
+        if !version.is_null() {
+            package["versionInfo"] = version;
+        }
+        package_values.push(package);
+        relationships.push(json!({
+            "spdxElementId": "SPDXRef-DOCUMENT",
+            "relationshipType": "DESCRIBES",
+            "relatedSpdxElement": id,
+        }));
+
+        for file in files {
+            let concluded = match file.licenses.as_slice() {
+                [] => declared.to_string(),
+                [license] => license.clone(),
+                licenses => format!("({})", licenses.join(") AND (")),
+            };
+            let comment = match &file.source {
+                Some(source) => format!("Source: {source}"),
+                None => "Generated".to_string(),
+            };
+            file_values.push(json!({
+                "fileName": format!("./{}", file.path),
+                "SPDXID": file_ids[file.path.as_str()],
+                "checksums": [
+                    { "algorithm": "SHA1", "checksumValue": file.sha1 },
+                    { "algorithm": "SHA256", "checksumValue": file.sha256 },
+                ],
+                "licenseConcluded": concluded,
+                "licenseInfoInFiles": license_info(&file.licenses),
+                "copyrightText": file.copyright.as_deref().unwrap_or("NOASSERTION"),
+                "comment": comment,
+            }));
+        }
+    }
+
+    // unique per content, as SPDX asks for the namespace
+    let mut all: Vec<&str> = files.iter().map(|file| file.sha256.as_str()).collect();
+    all.sort();
+    let digest = hex(&Sha256::digest(all.concat().as_bytes()));
+    let document = json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": format!("{REPOSITORY} {idf_version}"),
+        "documentNamespace": format!("https://spdx.org/spdxdocs/{REPOSITORY}-{tag}-{}", &digest[..16]),
+        "creationInfo": {
+            "created": created(),
+            "creators": ["Tool: cargo-xtask-sbom"],
+            "comment": format!("Files under include/ and libs/ vendored from {idf_version}"),
+        },
+        "packages": package_values,
+        "files": file_values,
+        "relationships": relationships,
+    });
+
+    if let Some(parent) = out.parent() {
+        fs::create_dir_all(parent).unwrap();
+    }
+    fs::write(
+        &out,
+        serde_json::to_string_pretty(&document).unwrap() + "\n",
+    )
+    .unwrap_or_else(|_| panic!("Unable to write {out:?}"));
+    log::info!(
+        "Wrote {} files in {} packages to {out:?}",
+        files.len(),
+        packages.len()
+    );
+}
+
+fn collect(dir: &Path, paths: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap_or_else(|_| panic!("Unable to read {dir:?}")) {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect(&path, paths);
+        } else if path.file_name().is_some_and(|name| name != ".gitkeep") {
+            paths.push(path);
+        }
+    }
+}
+
+/// The upstream component of a source path
+fn component(source: &str) -> String {
+    let parts: Vec<&str> = source.split('/').collect();
+    match parts.as_slice() {
+        ["esp-idf", "components", component, ..] => component.to_string(),
+        ["helper_project", "main", ..] | ["helper_project", "build", "esp-idf", "main", ..] => {
+            "printf".to_string()
+        }
+        ["helper_project", "build", "esp-idf", component, ..] => component.to_string(),
+        _ => REPOSITORY.to_string(),
+    }
+}
+
+/// License of a component's binaries, from the component's license files:
+/// ESP-IDF and its blobs are Apache-2.0, the supplicant is hostap's BSD
+/// code with Espressif's Apache-2.0 additions
+fn declared_license(package: &str) -> &'static str {
+    match package {
+        "printf" => "MIT",
+        "wpa_supplicant" => "BSD-3-Clause AND Apache-2.0",
+        _ => "Apache-2.0",
+    }
+}
+
+/// `SPDX-License-Identifier` and `SPDX-FileCopyrightText` tags in the
+/// beginning of a text file
+fn spdx_header(data: &[u8]) -> (Vec<String>, Option<String>) {
+    let head = String::from_utf8_lossy(&data[..data.len().min(4096)]);
+    let mut licenses = Vec::new();
+    let mut copyright = Vec::new();
+    for line in head.lines() {
+        let tag = |name: &str| {
+            line.split_once(name)
+                .map(|(_, value)| value.trim().trim_end_matches("*/").trim().to_string())
+        };
+        if let Some(license) = tag("SPDX-License-Identifier:") {
+            licenses.push(license);
+        }
+        if let Some(text) = tag("SPDX-FileCopyrightText:") {
+            copyright.push(text);
+        }
+        if let Some(text) = tag("SPDX-FileContributor:") {
+            copyright.push(text);
+        }
+    }
+    let copyright = (!copyright.is_empty()).then(|| copyright.join("\n"));
+    (licenses, copyright)
+}
+
+fn license_info<'a>(licenses: impl IntoIterator<Item = &'a String>) -> Vec<String> {
+    let licenses: BTreeSet<&String> = licenses.into_iter().collect();
+    if licenses.is_empty() {
+        vec!["NOASSERTION".to_string()]
+    } else {
+        licenses.into_iter().cloned().collect()
+    }
+}
+
+/// Letters, digits, `.` and `-` only
+fn spdx_id(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Now or `SOURCE_DATE_EPOCH` for reproducible documents, as
+/// `YYYY-MM-DDThh:mm:ssZ`
+fn created() -> String {
+    let seconds = match std::env::var("SOURCE_DATE_EPOCH") {
+        Ok(epoch) => epoch.parse().expect("Invalid SOURCE_DATE_EPOCH"),
+        Err(_) => SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+    };
+    let (days, time) = (seconds / 86400, seconds % 86400);
+
+    // days since 1970-01-01 to a civil date, Howard Hinnant's algorithm
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
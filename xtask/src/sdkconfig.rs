@@ -0,0 +1,251 @@
+use std::{collections::BTreeMap, fs};
+
+use crate::cexpr::Macros;
+
+/// The options of a chip's `include/<chip>/sdkconfig.h`, without the
+/// `CONFIG_` prefix
+#[derive(Debug)]
+pub struct Sdkconfig {
+    options: BTreeMap<String, String>,
+}
+
+impl Sdkconfig {
+    pub fn read(chip: &str) -> Sdkconfig {
+        let path = format!("./include/{chip}/sdkconfig.h");
+        let source = fs::read_to_string(&path).unwrap_or_else(|_| panic!("Unable to read {path}"));
+        Sdkconfig::parse(&source)
+    }
+
+    pub fn parse(source: &str) -> Sdkconfig {
+        let mut options = BTreeMap::new();
+        for line in source.lines() {
+            let Some(define) = line.trim().strip_prefix("#define ") else {
+                continue;
+            };
+            let (name, value) = define.split_once(' ').unwrap_or((define, ""));
+            let Some(name) = name.strip_prefix("CONFIG_") else {
+                continue;
+            };
+            options.insert(name.to_string(), value.trim().to_string());
+        }
+
+        // compatibility aliases like `CONFIG_OLD CONFIG_NEW`
+        let aliases: Vec<(String, String)> = options
+            .iter()
+            .filter_map(|(name, value)| {
+                let target = value.strip_prefix("CONFIG_")?;
+                Some((name.clone(), options.get(target)?.clone()))
+            })
+            .collect();
+        options.extend(aliases);
+
+        Sdkconfig { options }
+    }
+
+    /// Raw value of an option, `None` if it is not set
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    /// Evaluate a Kconfig expression like `A = y && (B || !C)` as used in
+    /// linker fragments
+    pub fn eval(&self, expression: &str) -> bool {
+        let tokens = tokenize(expression);
+        let mut parser = Parser {
+            config: self,
+            tokens: &tokens,
+            pos: 0,
+        };
+        let value = parser.or();
+        if parser.pos != tokens.len() {
+            panic!("Unable to evaluate `{expression}`");
+        }
+        value
+    }
+}
+
+/// The macros of a chip's `include/<chip>/sdkconfig.h`, for evaluating C
+/// sources and headers
+pub fn macros(chip: &str) -> Macros {
+    let mut macros = Macros::new();
+    let path = format!("./include/{chip}/sdkconfig.h");
+    macros
+        .preprocess(&fs::read_to_string(&path).unwrap_or_else(|_| panic!("Unable to read {path}")));
+    macros
+}
+
+fn tokenize(expression: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '"' {
+            chars.next();
+            let mut token = String::from('"');
+            for c in chars.by_ref() {
+                if c == '"' {
+                    break;
+                }
+                token.push(c);
+            }
+            tokens.push(token);
+        } else if c.is_ascii_alphanumeric() || c == '_' {
+            let mut token = String::new();
+            while let Some(&c) = chars.peek() {
+                if !(c.is_ascii_alphanumeric() || c == '_') {
+                    break;
+                }
+                token.push(c);
+                chars.next();
+            }
+            tokens.push(token);
+        } else {
+            chars.next();
+            let mut token = String::from(c);
+            if let Some(&next) = chars.peek() {
+                if matches!((c, next), ('&', '&') | ('|', '|') | ('!' | '<' | '>', '=')) {
+                    token.push(next);
+                    chars.next();
+                }
+            }
+            tokens.push(token);
+        }
+    }
+
+    tokens
+}
+
+struct Parser<'a> {
+    config: &'a Sdkconfig,
+    tokens: &'a [String],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.pos).map(String::as_str)
+    }
+
+    fn next(&mut self) -> &str {
+        self.pos += 1;
+        self.tokens
+            .get(self.pos - 1)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+
+    fn or(&mut self) -> bool {
+        let mut value = self.and();
+        while self.peek() == Some("||") {
+            self.next();
+            value |= self.and();
+        }
+        value
+    }
+
+    fn and(&mut self) -> bool {
+        let mut value = self.not();
+        while self.peek() == Some("&&") {
+            self.next();
+            value &= self.not();
+        }
+        value
+    }
+
+    fn not(&mut self) -> bool {
+        if self.peek() == Some("!") {
+            self.next();
+            return !self.not();
+        }
+        if self.peek() == Some("(") {
+            self.next();
+            let value = self.or();
+            if self.next() != ")" {
+                panic!("Missing `)` in {:?}", self.tokens);
+            }
+            return value;
+        }
+
+        let left = self.value();
+        let Some(op) = self
+            .peek()
+            .filter(|op| ["=", "!=", "<", ">", "<=", ">="].contains(op))
+        else {
+            return left == "y";
+        };
+        let op = op.to_string();
+        self.next();
+        let right = self.value();
+
+        match (left.parse::<i64>(), right.parse::<i64>(), op.as_str()) {
+            (Ok(left), Ok(right), "<") => left < right,
+            (Ok(left), Ok(right), ">") => left > right,
+            (Ok(left), Ok(right), "<=") => left <= right,
+            (Ok(left), Ok(right), ">=") => left >= right,
+            (_, _, "=") => same(&left, &right),
+            (_, _, "!=") => !same(&left, &right),
+            _ => false,
+        }
+    }
+
+    /// Value of a symbol or constant, `y`/`n` for booleans
+    fn value(&mut self) -> String {
+        let token = self.next().to_string();
+        if let Some(string) = token.strip_prefix('"') {
+            return string.to_string();
+        }
+        match self.config.get(&token) {
+            Some("1") => "y".to_string(),
+            Some(value) => value.trim_matches('"').to_string(),
+            // constants and unset options
+            None if ["y", "n", "m"].contains(&token.as_str())
+                || token.starts_with(|c: char| c.is_ascii_digit()) =>
+            {
+                token
+            }
+            None => "n".to_string(),
+        }
+    }
+}
+
+/// sdkconfig.h writes enabled booleans as `1`, like integer options
+fn same(left: &str, right: &str) -> bool {
+    left == right || matches!((left, right), ("y", "1") | ("1", "y"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SDKCONFIG: &str = "
+#pragma once
+#define CONFIG_IDF_TARGET \"esp32c3\"
+#define CONFIG_BT_ENABLED 1
+#define CONFIG_ESP32C3_REV_MIN_FULL 3
+#define CONFIG_NEWLIB_NANO_FORMAT CONFIG_LIBC_NEWLIB_NANO_FORMAT
+#define CONFIG_LIBC_NEWLIB_NANO_FORMAT 1
+";
+
+    #[test]
+    fn parses_options_and_aliases() {
+        let config = Sdkconfig::parse(SDKCONFIG);
+        assert_eq!(config.get("IDF_TARGET"), Some("\"esp32c3\""));
+        assert_eq!(config.get("ESP32C3_REV_MIN_FULL"), Some("3"));
+        assert_eq!(config.get("NEWLIB_NANO_FORMAT"), Some("1"));
+        assert_eq!(config.get("WIFI_ENABLED"), None);
+    }
+
+    #[test]
+    fn evaluates_kconfig_expressions() {
+        let config = Sdkconfig::parse(SDKCONFIG);
+        assert!(config.eval("BT_ENABLED"));
+        assert!(config.eval("BT_ENABLED = y"));
+        assert!(!config.eval("WIFI_ENABLED"));
+        assert!(config.eval("WIFI_ENABLED = n"));
+        assert!(config.eval("IDF_TARGET = \"esp32c3\""));
+        assert!(config.eval("ESP32C3_REV_MIN_FULL >= 3 && ESP32C3_REV_MIN_FULL < 101"));
+        assert!(config.eval("!(WIFI_ENABLED || !NEWLIB_NANO_FORMAT) || n"));
+    }
+}
@@ -0,0 +1,357 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use clap::ValueEnum;
+use object::{Object, ObjectSymbol};
+
+use crate::{
+    archive::Archive,
+    cheader::{self, Declaration, DeclarationKind},
+};
+
+/// Size of the storage given to stubbed data symbols
+const DATA_SIZE: usize = 256;
+
+/// Headers the vendored ones include but the toolchain or the port provide,
+/// stood in for when checking the generated header
+const STAND_INS: &[(&str, &str)] = &[
+    // `patch/espidf_types.h` has the types the Wi-Fi headers need
+    ("esp_netif.h", ""),
+    ("sys/lock.h", "typedef struct __lock *_lock_t;\n"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Mode {
+    /// Stubbed functions trap
+    Trap,
+    /// Stubbed functions call `esp_stub_log(name)` and return 0
+    Log,
+}
+
+#[derive(Debug)]
+enum Stub<'a> {
+    Function(Option<&'a Declaration>),
+    Data(Option<&'a Declaration>),
+}
+
+/// Write weak stubs for every symbol the chip's libraries import but
+/// neither define themselves nor get from libc, libgcc, the ROM or
+/// `provided`
+pub fn generate(chip: &str, provided: &[PathBuf], mode: Mode, out: &Path) {
+    log::info!("Generate stubs for {chip}");
+
+    let archives = Archive::read_chip(chip);
+    let unresolved = unresolved(&archives);
+
+    let rom = crate::rom::chip_symbols(chip);
+    let mut provided_symbols = BTreeSet::new();
+    for path in provided {
+        provided_symbols.extend(provided_by(path));
+    }
+
+    // keep the first declaration of names declared by several headers
+    let all_declarations = cheader::chip_declarations(chip);
+    let mut declarations: BTreeMap<&str, &Declaration> = BTreeMap::new();
+    for declaration in &all_declarations {
+        declarations.entry(&declaration.name).or_insert(declaration);
+    }
+
+    let called: BTreeSet<&str> = archives
+        .iter()
+        .flat_map(|archive| &archive.members)
+        .flat_map(|member| &member.called)
+        .map(String::as_str)
+        .collect();
+    // only RISC-V relocations tell calls apart from data references
+    let calls_known = !crate::is_xtensa(chip);
+
+    let mut stubs = BTreeMap::new();
+    for name in unresolved {
+        if provided_symbols.contains(name) || rom.contains_key(name) {
+            continue;
+        }
+
+        let declaration = declarations.get(name).copied();
+        let stub = match declaration.map(|declaration| declaration.kind) {
+            Some(DeclarationKind::Function) => Stub::Function(declaration),
+            Some(DeclarationKind::Variable) => Stub::Data(declaration),
+            None if called.contains(name) || !calls_known => Stub::Function(None),
+            None => Stub::Data(None),
+        };
+        stubs.insert(name, stub);
+    }
+
+    log::info!("{} symbols need stubs", stubs.len());
+
+    fs::create_dir_all(out).unwrap();
+    let header_path = out.join("esp_wireless_stubs.h");
+    fs::write(&header_path, header(chip, mode, &stubs)).unwrap();
+    fs::write(
+        out.join("esp_wireless_stubs.c"),
+        c_source(chip, mode, &stubs),
+    )
+    .unwrap();
+    fs::write(
+        out.join("esp_wireless_stubs.rs"),
+        rust_source(chip, mode, &stubs),
+    )
+    .unwrap();
+
+    check_header(chip, &header_path);
+}
+
+/// Compile the generated header with the host C compiler against the
+/// vendored headers, failing on errors like conflicting redeclarations
+fn check_header(chip: &str, header: &Path) {
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let stand_ins = Path::new("./target/stubs-check").join(chip);
+    for (name, content) in STAND_INS {
+        let path = stand_ins.join(name);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+    }
+
+    let output = Command::new(&cc)
+        .args(["-fsyntax-only", "-x", "c", "-I./include"])
+        .arg(format!("-I./include/{chip}"))
+        .arg("-I./patch")
+        .arg(format!("-I{}", stand_ins.display()))
+        .args(["-include", "./patch/espidf_types.h"])
+        .arg(header)
+        .output();
+    let output = match output {
+        Ok(output) => output,
+        Err(_) => {
+            log::warn!("Unable to run {cc}, {} is not checked", header.display());
+            return;
+        }
+    };
+
+    if !output.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        eprintln!("{} doesn't compile", header.display());
+        std::process::exit(1);
+    }
+}
+
+/// Symbols imported by some archive and defined by none, without the ones
+/// libc and libgcc provide
+pub fn unresolved(archives: &[Archive]) -> BTreeSet<&str> {
+    let defined: BTreeSet<&str> = archives
+        .iter()
+        .flat_map(|archive| &archive.members)
+        .flat_map(|member| &member.defined)
+        .map(|symbol| symbol.name.as_str())
+        .collect();
+
+    archives
+        .iter()
+        .flat_map(|archive| &archive.members)
+        .flat_map(|member| &member.undefined)
+        .map(String::as_str)
+        .filter(|name| !defined.contains(name))
+        .filter(|name| !name.starts_with("__") && !crate::conflicts::LIBC_NAMES.contains(name))
+        .collect()
+}
+
+/// Symbols defined by an archive or object file, or listed one per line in
+/// a text file
+fn provided_by(path: &Path) -> BTreeSet<String> {
+    let data = fs::read(path).unwrap_or_else(|_| panic!("Unable to read {path:?}"));
+
+    if data.starts_with(b"!<arch>\n") {
+        return Archive::read(path)
+            .members
+            .into_iter()
+            .flat_map(|member| member.defined)
+            .map(|symbol| symbol.name)
+            .collect();
+    }
+
+    if let Ok(object) = object::File::parse(&*data) {
+        return object
+            .symbols()
+            .filter(|symbol| symbol.is_global() && symbol.is_definition())
+            .filter_map(|symbol| symbol.name().ok().map(String::from))
+            .collect();
+    }
+
+    String::from_utf8_lossy(&data)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect()
+}
+
+fn header(chip: &str, mode: Mode, stubs: &BTreeMap<&str, Stub>) -> String {
+    let mut out = format!(
+        "/*
+ * Generated by `cargo xtask stubs {chip}`, do not edit
+ *
+ * Symbols imported by the {chip} wireless libraries which the port does
+ * not provide yet, see esp_wireless_stubs.c
+ */
+
+#ifndef _ESP_WIRELESS_STUBS_H_
+#define _ESP_WIRELESS_STUBS_H_
+
+#include <stdint.h>
+
+"
+    );
+
+    let headers: BTreeSet<&str> = stubs
+        .values()
+        .filter_map(|stub| match stub {
+            Stub::Function(declaration) | Stub::Data(declaration) => *declaration,
+        })
+        .map(|declaration| declaration.header.as_str())
+        .collect();
+    for header in &headers {
+        out.push_str(&format!("#include \"{header}\"\n"));
+    }
+
+    out.push_str(
+        "
+#ifdef __cplusplus
+extern \"C\" {
+#endif
+
+",
+    );
+
+    if mode == Mode::Log {
+        out.push_str(
+            "/* Called by every stub with its name */\nvoid esp_stub_log(const char* name);\n\n",
+        );
+    }
+
+    for (name, stub) in stubs {
+        match stub {
+            Stub::Function(Some(declaration)) | Stub::Data(Some(declaration)) => {
+                let text = &declaration.text;
+                let text = match declaration.kind {
+                    DeclarationKind::Variable if !text.starts_with("extern") => {
+                        format!("extern {text}")
+                    }
+                    _ => text.to_string(),
+                };
+                out.push_str(&format!("{text}; /* {} */\n", declaration.header));
+            }
+            Stub::Function(None) => out.push_str(&format!("int {name}(void);\n")),
+            Stub::Data(None) => out.push_str(&format!("extern uint8_t {name}[];\n")),
+        }
+    }
+
+    out.push_str(
+        "
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* _ESP_WIRELESS_STUBS_H_ */
+",
+    );
+    out
+}
+
+fn c_source(chip: &str, mode: Mode, stubs: &BTreeMap<&str, Stub>) -> String {
+    let (behavior, function) = match mode {
+        Mode::Trap => (
+            "Functions trap when called",
+            "__attribute__((weak)) int name(void) { __builtin_trap(); }",
+        ),
+        Mode::Log => (
+            "Functions call esp_stub_log() with their name and return 0",
+            "__attribute__((weak)) int name(void) { esp_stub_log(#name); return 0; }",
+        ),
+    };
+
+    let mut out = format!(
+        "/*
+ * Generated by `cargo xtask stubs {chip}`, do not edit
+ *
+ * Weak definitions of the symbols the {chip} wireless libraries import and
+ * the port does not provide yet. {behavior}, data
+ * symbols get ESP_STUB_DATA_SIZE zeroed bytes.
+ */
+
+#include <stdint.h>
+
+#ifndef ESP_STUB_DATA_SIZE
+#define ESP_STUB_DATA_SIZE {DATA_SIZE}
+#endif
+
+"
+    );
+
+    if mode == Mode::Log {
+        out.push_str(
+            "__attribute__((weak)) void esp_stub_log(const char* name)\n{\n    (void)name;\n}\n\n",
+        );
+    }
+
+    out.push_str(&format!(
+        "#define ESP_STUB_FUNCTION(name) \\\n    {function}\n\
+         #define ESP_STUB_DATA(name) \\\n    __attribute__((weak, aligned(8))) uint8_t name[ESP_STUB_DATA_SIZE];\n\n"
+    ));
+
+    for (name, stub) in stubs {
+        match stub {
+            Stub::Function(_) => out.push_str(&format!("ESP_STUB_FUNCTION({name})\n")),
+            Stub::Data(_) => out.push_str(&format!("ESP_STUB_DATA({name})\n")),
+        }
+    }
+
+    out
+}
+
+fn rust_source(chip: &str, mode: Mode, stubs: &BTreeMap<&str, Stub>) -> String {
+    let behavior = match mode {
+        Mode::Trap => "Functions panic when called",
+        Mode::Log => "Functions call `esp_stub_log(name)` and return 0",
+    };
+
+    let mut out = format!(
+        "//! Generated by `cargo xtask stubs {chip}`, do not edit
+//!
+//! Definitions of the symbols the {chip} wireless libraries import and the
+//! port does not provide yet. {behavior}, data symbols
+//! get {DATA_SIZE} zeroed bytes. Rust has no stable weak linkage, so stubs the port
+//! implements have to be removed.
+
+#![allow(non_snake_case, non_upper_case_globals, dead_code)]
+
+"
+    );
+
+    if mode == Mode::Log {
+        out.push_str("extern \"C\" {\n    fn esp_stub_log(name: *const core::ffi::c_char);\n}\n\n");
+    }
+
+    for (name, stub) in stubs {
+        match stub {
+            Stub::Function(_) => {
+                let body = match mode {
+                    Mode::Trap => format!("    panic!(\"{name} is a stub\")\n"),
+                    Mode::Log => {
+                        format!("    unsafe {{ esp_stub_log(c\"{name}\".as_ptr()) }};\n    0\n")
+                    }
+                };
+                out.push_str(&format!(
+                    "#[no_mangle]\npub extern \"C\" fn {name}() -> i32 {{\n{body}}}\n\n"
+                ));
+            }
+            Stub::Data(_) => out.push_str(&format!(
+                "#[no_mangle]\npub static mut {name}: [u8; {DATA_SIZE}] = [0; {DATA_SIZE}];\n\n"
+            )),
+        }
+    }
+
+    out
+}
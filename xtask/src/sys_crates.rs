@@ -0,0 +1,161 @@
+use std::{fmt::Write, fs, path::Path};
+
+use crate::manifest;
+
+/// Directory of the generated crates, a workspace member glob
+const DIR: &str = "./sys";
+
+/// Generate `sys/<chip>/`, a crate linking the chip's vendored libraries
+pub fn generate(chips: &[String]) {
+    let idf_version = fs::read_to_string("version").expect("Unable to read version");
+    let idf_version = idf_version.trim();
+    let version = crate::version_number(idf_version);
+
+    for chip in chips {
+        log::info!("Generate the {chip} sys crate");
+        let dir = Path::new(DIR).join(chip);
+        fs::remove_dir_all(&dir).ok();
+        fs::create_dir_all(dir.join("src")).unwrap();
+
+        let features = manifest::features(chip);
+        let name = format!("esp-wireless-{chip}-sys");
+        let mut cargo = String::new();
+        writeln!(
+            cargo,
+            "# Generated by `cargo xtask gen-sys-crates`, do not edit\n"
+        )
+        .unwrap();
+        writeln!(cargo, "[package]").unwrap();
+        writeln!(cargo, "name        = \"{name}\"").unwrap();
+        writeln!(cargo, "version     = \"{version}\"").unwrap();
+        writeln!(cargo, "edition     = \"2021\"").unwrap();
+        writeln!(
+            cargo,
+            "description = \"Links the {idf_version} wireless libraries of the {chip}\""
+        )
+        .unwrap();
+        writeln!(cargo, "license     = \"Apache-2.0\"").unwrap();
+        writeln!(cargo, "links       = \"esp-wireless-{chip}\"").unwrap();
+        writeln!(cargo, "publish     = false").unwrap();
+        writeln!(cargo, "\n[features]").unwrap();
+        let default: Vec<String> = features
+            .iter()
+            .filter(|feature| feature.requires.is_none())
+            .map(|feature| format!("\"{}\"", feature.name))
+            .collect();
+        writeln!(cargo, "default = [{}]", default.join(", ")).unwrap();
+        for feature in &features {
+            writeln!(cargo, "# {}", feature.description).unwrap();
+            match feature.requires {
+                Some(requires) => writeln!(cargo, "{} = [\"{requires}\"]", feature.name),
+                None => writeln!(cargo, "{} = []", feature.name),
+            }
+            .unwrap();
+        }
+        write(&dir.join("Cargo.toml"), &cargo);
+
+        let vendored = manifest::vendored(chip);
+        let mut libraries = String::new();
+        for group in manifest::link_order(chip) {
+            let group: Vec<String> = group
+                .into_iter()
+                .filter_map(|name| vendored.iter().find(|library| library.name == name))
+                .map(|library| {
+                    let feature = match library.feature {
+                        Some(feature) => format!("Some(\"{feature}\")"),
+                        None => "None".to_string(),
+                    };
+                    format!("(\"{}\", {feature})", library.name)
+                })
+                .collect();
+            // formatted like rustfmt does
+            let line = format!("    &[{}],", group.join(", "));
+            if group.is_empty() {
+                continue;
+            } else if line.len() <= 100 {
+                writeln!(libraries, "{line}").unwrap();
+            } else {
+                let lines = group.join(",\n        ");
+                writeln!(libraries, "    &[\n        {lines},\n    ],").unwrap();
+            }
+        }
+        let arch = if crate::is_xtensa(chip) {
+            "xtensa"
+        } else {
+            "riscv32"
+        };
+        write(
+            &dir.join("build.rs"),
+            &BUILD_RS
+                .replace("{chip}", chip)
+                .replace("{arch}", arch)
+                .replace("{libraries}", &libraries),
+        );
+
+        write(
+            &dir.join("src/lib.rs"),
+            &LIB_RS
+                .replace("{chip}", chip)
+                .replace("{idf_version}", idf_version),
+        );
+    }
+}
+
+fn write(path: &Path, content: &str) {
+    fs::write(path, content).unwrap_or_else(|_| panic!("Unable to write {path:?}"));
+}
+
+const BUILD_RS: &str = r#"// Generated by `cargo xtask gen-sys-crates`, do not edit
+
+use std::{env, path::Path};
+
+/// The vendored libraries in link order, with the feature enabling them.
+/// Libraries depending on each other share a group.
+const LIBRARIES: &[&[(&str, Option<&str>)]] = &[
+{libraries}];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    // host builds of the workspace only check the crate
+    if env::var("CARGO_CFG_TARGET_ARCH").unwrap() != "{arch}" {
+        return;
+    }
+
+    let libs = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("../../libs/{chip}");
+    println!("cargo:rustc-link-search=native={}", libs.display());
+    for group in LIBRARIES {
+        let enabled: Vec<&str> = group
+            .iter()
+            .filter(|(_, feature)| {
+                feature.is_none_or(|feature| {
+                    env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase())).is_some()
+                })
+            })
+            .map(|(name, _)| *name)
+            .collect();
+        for name in &enabled {
+            println!("cargo:rerun-if-changed={}/lib{name}.a", libs.display());
+        }
+        // `rustc-link-arg` doesn't reach the crates linking this one, so
+        // instead of `--start-group` a group is repeated once per library
+        let passes = if enabled.len() > 1 { enabled.len() } else { 1 };
+        for _ in 0..passes {
+            for name in &enabled {
+                println!("cargo:rustc-link-lib=static={name}");
+            }
+        }
+    }
+}
+"#;
+
+const LIB_RS: &str = r#"//! Links the {idf_version} wireless libraries of the {chip} vendored in
+//! `libs/{chip}/`. Optional libraries are enabled by the cargo features.
+//!
+//! Generated by `cargo xtask gen-sys-crates`, do not edit.
+
+#![no_std]
+
+/// The ESP-IDF version the libraries come from
+pub const IDF_VERSION: &str = "{idf_version}";
+"#;
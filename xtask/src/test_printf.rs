@@ -0,0 +1,1057 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    io::{BufRead, BufReader, Write},
+    process::{Command, Stdio},
+};
+
+const PRINTF_SRC: &str = "./helper_project/main/printf/printf.c";
+const BUILD_DIR: &str = "./target/test-printf";
+
+/// API of printf.c, renamed so the shim can use the host libc next to it
+const RENAMED: &[&str] = &[
+    "printf",
+    "sprintf",
+    "snprintf",
+    "vsnprintf",
+    "vprintf",
+    "fctprintf",
+];
+
+/// Buffer size used when a case does not test truncation
+const LARGE_BUFFER: usize = 256;
+
+/// Where printf.c knowingly departs from C99, with the cases it shows in
+///
+/// Mismatches in these cases are counted but don't fail the run, so a failure
+/// means printf.c behaves differently from both C99 and this list.
+const KNOWN: &[(&str, Applies)] = &[
+    (
+        "a negative `*` precision counts as 0 instead of as omitted",
+        |c, _| c.negative_precision,
+    ),
+    (
+        "left-justified integers don't get their precision zeros",
+        |c, arg| {
+            c.is_integer() && c.spec.left && c.spec.precision > Some(c.ntoa_fill(arg.unwrap()).0)
+        },
+    ),
+    (
+        "integers longer than PRINTF_NTOA_BUFFER_SIZE (32) are cut, like 64 bit `%b`",
+        |c, arg| c.is_integer() && c.ntoa_len(arg.unwrap()) > 32,
+    ),
+    (
+        "`#` takes its prefix out of digits filling the width and adds a 0 to octal zeros",
+        |c, arg| {
+            c.is_integer() && c.spec.hash && {
+                let arg = arg.unwrap();
+                let (_, value) = c.integer(arg);
+                let (filled, width) = c.ntoa_fill(arg);
+                let fills_width = c.spec.precision.is_none() && filled == width;
+                value != 0
+                    && match c.conversion {
+                        'o' => fills_width || filled > c.digits(arg),
+                        'x' | 'X' | 'b' => fills_width,
+                        _ => false,
+                    }
+            }
+        },
+    ),
+    (
+        "`%#o` of 0 with a 0 precision prints nothing instead of 0",
+        |c, arg| {
+            c.conversion == 'o'
+                && c.spec.hash
+                && c.spec.precision == Some(0)
+                && c.integer(arg.unwrap()).1 == 0
+        },
+    ),
+    (
+        "`#` is ignored by the floating point conversions",
+        |c, _| c.is_float() && c.spec.hash,
+    ),
+    (
+        "infinities and NaN are lower case and unsigned, -0 loses its sign",
+        |c, arg| {
+            c.is_float() && {
+                let value = c.double(arg.unwrap());
+                !value.is_finite() || (value == 0.0 && value.is_sign_negative())
+            }
+        },
+    ),
+    (
+        "floating point precisions above 9 are padded with zeros",
+        |c, _| c.is_float() && c.spec.precision.unwrap_or(6) > 9,
+    ),
+    (
+        "`%f` beyond PRINTF_MAX_FLOAT (1e9) is printed like `%e`",
+        |c, arg| "fF".contains(c.conversion) && c.double(arg.unwrap()).abs() > 1e9,
+    ),
+    (
+        "`%f` misses the carry into the whole part when the fraction rounds half up",
+        |c, arg| {
+            "fF".contains(c.conversion)
+                && halfway_carry(c.double(arg.unwrap()), c.spec.precision.unwrap_or(6))
+        },
+    ),
+    ("`%e` of 0 has the exponent -308", |c, arg| {
+        "eE".contains(c.conversion) && c.double(arg.unwrap()) == 0.0
+    }),
+    (
+        "`%e` divides by an estimated power of ten and doesn't renormalize after rounding",
+        |c, arg| {
+            "eE".contains(c.conversion) && {
+                let value = c.double(arg.unwrap()).abs();
+                let precision = c.spec.precision.unwrap_or(6);
+                let (exponent, mantissa) = etoa_scale(value);
+                format!("{mantissa:.precision$}e{exponent}") != format!("{value:.precision$e}")
+            }
+        },
+    ),
+    (
+        "`%g` counts the precision and picks the notation differently",
+        |c, _| "gG".contains(c.conversion),
+    ),
+];
+
+/// Whether a known limitation applies to a conversion and its argument
+type Applies = fn(&Conversion, Option<&Arg>) -> bool;
+
+/// Compile the bundled printf for the host and compare it against the
+/// reference formatter over `count` generated cases
+pub fn run(count: usize, seed: u64) {
+    let shim = build_shim();
+
+    let mut rng = Rng(seed.max(1));
+    let cases: Vec<Case> = (0..count).map(|_| Case::generate(&mut rng)).collect();
+
+    let mut child = Command::new(&shim)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|_| panic!("Unable to run {shim}"));
+
+    let mut stdin = child.stdin.take().unwrap();
+    let input: String = cases.iter().map(Case::to_line).collect();
+    let writer = std::thread::spawn(move || {
+        stdin.write_all(input.as_bytes()).unwrap();
+    });
+
+    let mut lines = BufReader::new(child.stdout.take().unwrap()).lines();
+    let sizes = Sizes::parse(&lines.next().expect("No output from shim").unwrap());
+    log::info!("Host sizes {sizes:?}");
+
+    let mut mismatches: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    let mut known = vec![0; KNOWN.len()];
+    let mut failed = 0;
+    for case in &cases {
+        let line = lines
+            .next()
+            .unwrap_or_else(|| panic!("Shim stopped at {:?}", case.format))
+            .unwrap();
+        let actual = Output::parse(&line);
+        let expected = Output::expected(&format(&case.format, &case.args, &sizes), case.size);
+
+        if actual == expected {
+            continue;
+        }
+        if let Some(limitation) = case.limitation(&sizes) {
+            known[limitation] += 1;
+            log::debug!(
+                "{:?} {:?}: {} ({actual})",
+                case.format,
+                case.args,
+                KNOWN[limitation].0
+            );
+        } else {
+            failed += 1;
+            mismatches.entry(&case.format).or_default().push(format!(
+                "size {} args {:?}: expected {expected}, got {actual}",
+                case.size, case.args
+            ));
+        }
+    }
+
+    writer.join().unwrap();
+    child.wait().unwrap();
+
+    for (format, cases) in &mismatches {
+        println!("{format:?}");
+        for case in cases {
+            println!("    {case}");
+        }
+    }
+
+    for ((limitation, _), cases) in KNOWN.iter().zip(known) {
+        if cases > 0 {
+            println!("{cases} cases differ as known: {limitation}");
+        }
+    }
+
+    println!(
+        "{failed} of {count} cases failed in {} of {} format strings",
+        mismatches.len(),
+        cases
+            .iter()
+            .map(|case| &case.format)
+            .collect::<std::collections::BTreeSet<_>>()
+            .len()
+    );
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Compile printf.c and the shim with the host C compiler
+fn build_shim() -> String {
+    fs::create_dir_all(BUILD_DIR).unwrap();
+
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let shim_src = format!("{BUILD_DIR}/shim.c");
+    let printf_obj = format!("{BUILD_DIR}/printf.o");
+    let shim = format!("{BUILD_DIR}/shim");
+
+    fs::write(&shim_src, include_str!("test_printf_shim.c")).unwrap();
+
+    let mut args = vec!["-std=c99".to_string(), "-O1".to_string(), "-c".to_string()];
+    args.extend(RENAMED.iter().map(|name| format!("-D{name}=tp_{name}")));
+    args.extend([PRINTF_SRC.to_string(), "-o".to_string(), printf_obj.clone()]);
+    compile(&cc, &args);
+
+    compile(
+        &cc,
+        &[
+            "-O1".to_string(),
+            shim_src,
+            printf_obj,
+            "-o".to_string(),
+            shim.clone(),
+        ],
+    );
+
+    shim
+}
+
+fn compile(cc: &str, args: &[String]) {
+    log::debug!("{cc} {}", args.join(" "));
+    let status = Command::new(cc)
+        .args(args)
+        .status()
+        .unwrap_or_else(|_| panic!("Unable to run {cc}"));
+
+    if !status.success() {
+        eprintln!("Failed to compile the printf test shim");
+        std::process::exit(-1);
+    }
+}
+
+/// Type sizes of the host the shim runs on, in bytes
+#[derive(Debug)]
+struct Sizes {
+    int: u32,
+    long: u32,
+    pointer: u32,
+}
+
+impl Sizes {
+    fn parse(line: &str) -> Sizes {
+        let fields: Vec<u32> = line
+            .split('\t')
+            .skip(1)
+            .map(|field| field.parse().unwrap())
+            .collect();
+        Sizes {
+            int: fields[0],
+            long: fields[1],
+            pointer: fields[2],
+        }
+    }
+}
+
+/// Return value and buffer contents of one `snprintf` call
+#[derive(Debug, PartialEq, Eq)]
+struct Output {
+    ret: usize,
+    written: Vec<u8>,
+    overflow: bool,
+}
+
+impl Output {
+    fn parse(line: &str) -> Output {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let written = (0..fields[1].len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&fields[1][i..i + 2], 16).unwrap())
+            .collect();
+        Output {
+            ret: fields[0].parse().unwrap(),
+            written,
+            overflow: fields[2] == "overflow",
+        }
+    }
+
+    /// What C99 `snprintf` produces for a buffer of `size` bytes
+    fn expected(formatted: &[u8], size: usize) -> Output {
+        Output {
+            ret: formatted.len(),
+            written: formatted[..formatted.len().min(size.saturating_sub(1))].to_vec(),
+            overflow: false,
+        }
+    }
+}
+
+impl std::fmt::Display for Output {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} (ret {})",
+            String::from_utf8_lossy(&self.written),
+            self.ret
+        )?;
+        if self.overflow {
+            write!(f, " writing past the buffer")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Arg {
+    /// `*` width or precision
+    Star(i32),
+    Int(i32),
+    Long(i64),
+    LongLong(i64),
+    Unsigned(u32),
+    UnsignedLong(u64),
+    UnsignedLongLong(u64),
+    Double(f64),
+    Str(&'static str),
+    Char(u8),
+    Pointer(u64),
+}
+
+impl Arg {
+    fn type_code(&self) -> char {
+        match self {
+            Arg::Star(_) => '*',
+            Arg::Int(_) => 'i',
+            Arg::Long(_) => 'l',
+            Arg::LongLong(_) => 'L',
+            Arg::Unsigned(_) => 'u',
+            Arg::UnsignedLong(_) => 'k',
+            Arg::UnsignedLongLong(_) => 'K',
+            Arg::Double(_) => 'd',
+            Arg::Str(_) => 's',
+            Arg::Char(_) => 'c',
+            Arg::Pointer(_) => 'p',
+        }
+    }
+
+    fn encode(&self) -> String {
+        match self {
+            Arg::Star(v) | Arg::Int(v) => v.to_string(),
+            Arg::Long(v) | Arg::LongLong(v) => v.to_string(),
+            Arg::Unsigned(v) => v.to_string(),
+            Arg::UnsignedLong(v) | Arg::UnsignedLongLong(v) => v.to_string(),
+            Arg::Double(v) => format!("{:x}", v.to_bits()),
+            Arg::Str(v) => v.to_string(),
+            Arg::Char(v) => v.to_string(),
+            Arg::Pointer(v) => format!("{v:x}"),
+        }
+    }
+
+    /// Raw bits of an integer argument, sign extended
+    fn bits(&self) -> u64 {
+        match *self {
+            Arg::Star(v) | Arg::Int(v) => v as i64 as u64,
+            Arg::Long(v) | Arg::LongLong(v) => v as u64,
+            Arg::Unsigned(v) => v as u64,
+            Arg::UnsignedLong(v) | Arg::UnsignedLongLong(v) | Arg::Pointer(v) => v,
+            Arg::Char(v) => v as u64,
+            Arg::Double(_) | Arg::Str(_) => panic!("{self:?} is not an integer"),
+        }
+    }
+}
+
+/// One generated `snprintf` call
+struct Case {
+    size: usize,
+    format: String,
+    args: Vec<Arg>,
+}
+
+impl Case {
+    fn to_line(&self) -> String {
+        let types: String = self.args.iter().map(Arg::type_code).collect();
+        let mut line = format!("{}\t{types}\t{}", self.size, self.format);
+        for arg in &self.args {
+            line.push('\t');
+            line.push_str(&arg.encode());
+        }
+        line.push('\n');
+        line
+    }
+
+    /// Index of the first known limitation of printf.c this case runs into
+    fn limitation(&self, sizes: &Sizes) -> Option<usize> {
+        let mut chars = self.format.bytes().peekable();
+        let mut args = self.args.iter();
+        while let Some(c) = chars.next() {
+            if c != b'%' {
+                continue;
+            }
+            let conversion = Conversion::parse(&mut chars, &mut args, sizes);
+            if conversion.conversion != '%' {
+                let arg = args.next();
+                return KNOWN
+                    .iter()
+                    .position(|(_, applies)| applies(&conversion, arg));
+            }
+        }
+        None
+    }
+
+    fn generate(rng: &mut Rng) -> Case {
+        const CONVERSIONS: &[u8] = b"diuxXobcsfFeEgGp%";
+        const INT_LENGTHS: &[&str] = &["", "", "", "hh", "h", "l", "ll", "z", "j", "t"];
+        const TEXT: &[&str] = &["", "", "", "x", "value=", "%% ", "[", "]"];
+        const STRINGS: &[&str] = &["", "a", "hello", "Espressif", "a longer string of text"];
+
+        let conversion = *rng.pick(CONVERSIONS) as char;
+        let mut args = Vec::new();
+
+        let mut spec = String::from("%");
+        if !"cs%p".contains(conversion) {
+            for flag in ['-', '+', ' ', '0', '#'] {
+                if rng.below(4) == 0 {
+                    spec.push(flag);
+                }
+            }
+        } else if conversion != 'p' && rng.below(4) == 0 {
+            spec.push('-');
+        }
+
+        if conversion != 'p' && conversion != '%' {
+            match rng.below(10) {
+                0..=4 => (),
+                5 => {
+                    spec.push('*');
+                    args.push(Arg::Star(rng.below(41) as i32 - 20));
+                }
+                _ => spec.push_str(&(1 + rng.below(24)).to_string()),
+            }
+
+            match rng.below(10) {
+                0..=4 => (),
+                5 => spec.push('.'),
+                6 if conversion != 'c' => {
+                    spec.push_str(".*");
+                    args.push(Arg::Star(rng.below(15) as i32 - 2));
+                }
+                _ if conversion != 'c' => {
+                    let max = if "fFeEgG".contains(conversion) { 9 } else { 12 };
+                    spec.push_str(&format!(".{}", rng.below(max + 1)));
+                }
+                _ => (),
+            }
+        }
+
+        let signed = "di".contains(conversion);
+        match conversion {
+            'd' | 'i' | 'u' | 'x' | 'X' | 'o' | 'b' => {
+                let length = *rng.pick(INT_LENGTHS);
+                spec.push_str(length);
+                let value = rng.integer();
+                args.push(match (length, signed) {
+                    ("l" | "z" | "t", true) => Arg::Long(value as i64),
+                    ("l" | "z" | "t", false) => Arg::UnsignedLong(value),
+                    ("ll" | "j", true) => Arg::LongLong(value as i64),
+                    ("ll" | "j", false) => Arg::UnsignedLongLong(value),
+                    (_, true) => Arg::Int(value as i32),
+                    (_, false) => Arg::Unsigned(value as u32),
+                });
+            }
+            'c' => args.push(Arg::Char(b' ' + rng.below(95) as u8)),
+            's' => {
+                let s: &&'static str = rng.pick(STRINGS);
+                args.push(Arg::Str(s))
+            }
+            'p' => args.push(Arg::Pointer(rng.integer())),
+            '%' => (),
+            _ => args.push(Arg::Double(rng.double())),
+        }
+        spec.push(conversion);
+
+        let format = format!("{}{spec}{}", rng.pick(TEXT), rng.pick(TEXT));
+
+        // every fifth case exercises truncation
+        let size = if rng.below(5) == 0 {
+            rng.below(24) as usize
+        } else {
+            LARGE_BUFFER
+        };
+
+        Case { size, format, args }
+    }
+}
+
+/// xorshift64*, good enough to spread the corpus and reproducible by seed
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn coin(&mut self) -> bool {
+        self.below(2) == 0
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    fn integer(&mut self) -> u64 {
+        const EDGES: &[u64] = &[
+            0,
+            1,
+            u64::MAX, // -1
+            7,
+            42,
+            255,
+            256,
+            0xffff,
+            0x1_0000,
+            i32::MAX as u64,
+            i32::MIN as i64 as u64,
+            u32::MAX as u64,
+            i64::MAX as u64,
+            i64::MIN as u64,
+        ];
+        if self.coin() {
+            *self.pick(EDGES)
+        } else {
+            // random magnitude, so small numbers show up as often as large ones
+            let bits = 1 + self.below(64);
+            let value = self.next() >> (64 - bits);
+            if self.coin() {
+                value.wrapping_neg()
+            } else {
+                value
+            }
+        }
+    }
+
+    fn double(&mut self) -> f64 {
+        const EDGES: &[f64] = &[
+            0.0,
+            -0.0,
+            0.5,
+            1.5,
+            2.5,
+            -2.5,
+            0.1,
+            0.05,
+            0.999_999_5,
+            1e-5,
+            123.456,
+            1e6,
+            999_999.5,
+            999_999_999.0,
+            1e10,
+            1e-300,
+            1e300,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+        ];
+        if self.coin() {
+            *self.pick(EDGES)
+        } else {
+            let mantissa = (self.next() >> 11) as f64 / (1u64 << 53) as f64;
+            let exponent = self.below(21) as i32 - 10;
+            let value = mantissa * 10f64.powi(exponent);
+            if self.coin() {
+                -value
+            } else {
+                value
+            }
+        }
+    }
+}
+
+/// Reference formatter following C99 7.19.6.1
+///
+/// `%b` formats binary like C23 does. `%p` follows the documented choice of
+/// the bundled implementation: upper case hex, zero padded to the pointer
+/// width.
+fn format(format: &str, args: &[Arg], sizes: &Sizes) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut args = args.iter();
+    let mut chars = format.bytes().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != b'%' {
+            out.push(c);
+            continue;
+        }
+
+        let Conversion {
+            spec,
+            bits,
+            conversion,
+            ..
+        } = Conversion::parse(&mut chars, &mut args, sizes);
+        let field = match conversion {
+            'd' | 'i' => {
+                let raw = args.next().unwrap().bits();
+                let value = (raw << (64 - bits)) as i64 >> (64 - bits);
+                spec.integer(value < 0, value.unsigned_abs(), 10, conversion, true)
+            }
+            'u' | 'x' | 'X' | 'o' | 'b' => {
+                let raw = args.next().unwrap().bits();
+                let value = (raw << (64 - bits)) >> (64 - bits);
+                let base = match conversion {
+                    'u' => 10,
+                    'o' => 8,
+                    'b' => 2,
+                    _ => 16,
+                };
+                spec.integer(false, value, base, conversion, false)
+            }
+            'f' | 'F' | 'e' | 'E' | 'g' | 'G' => {
+                let Some(Arg::Double(value)) = args.next() else {
+                    panic!("Expected a double for {format:?}");
+                };
+                spec.float(*value, conversion)
+            }
+            'c' => {
+                let Some(Arg::Char(c)) = args.next() else {
+                    panic!("Expected a char for {format:?}");
+                };
+                spec.pad(vec![*c])
+            }
+            's' => {
+                let Some(Arg::Str(s)) = args.next() else {
+                    panic!("Expected a string for {format:?}");
+                };
+                let len = spec.precision.unwrap_or(s.len()).min(s.len());
+                spec.pad(s.as_bytes()[..len].to_vec())
+            }
+            'p' => {
+                let value = args.next().unwrap().bits();
+                format!("{value:0width$X}", width = 2 * sizes.pointer as usize).into_bytes()
+            }
+            '%' => vec![b'%'],
+            other => panic!("Unsupported conversion {other:?} in {format:?}"),
+        };
+        out.extend(field);
+    }
+
+    out
+}
+
+/// One conversion specification with its `*` arguments applied
+struct Conversion {
+    spec: Spec,
+    /// Width of the converted integer from the length modifier
+    bits: u32,
+    conversion: char,
+    /// The precision was a negative `*` argument
+    negative_precision: bool,
+}
+
+impl Conversion {
+    /// Parse the specification following a `%`, taking `*` arguments from
+    /// `args`
+    fn parse(
+        chars: &mut std::iter::Peekable<std::str::Bytes>,
+        args: &mut std::slice::Iter<Arg>,
+        sizes: &Sizes,
+    ) -> Conversion {
+        let mut spec = Spec::default();
+        let mut negative_precision = false;
+        while let Some(&flag) = chars.peek() {
+            match flag {
+                b'-' => spec.left = true,
+                b'+' => spec.plus = true,
+                b' ' => spec.space = true,
+                b'0' => spec.zero = true,
+                b'#' => spec.hash = true,
+                _ => break,
+            }
+            chars.next();
+        }
+
+        if chars.peek() == Some(&b'*') {
+            chars.next();
+            let width = args.next().unwrap().bits() as i32;
+            if width < 0 {
+                spec.left = true;
+            }
+            spec.width = width.unsigned_abs() as usize;
+        } else {
+            while let Some(digit @ b'0'..=b'9') = chars.peek().copied() {
+                spec.width = spec.width * 10 + (digit - b'0') as usize;
+                chars.next();
+            }
+        }
+
+        if chars.peek() == Some(&b'.') {
+            chars.next();
+            if chars.peek() == Some(&b'*') {
+                chars.next();
+                let precision = args.next().unwrap().bits() as i32;
+                // a negative precision is taken as if it were omitted
+                spec.precision = (precision >= 0).then_some(precision as usize);
+                negative_precision = precision < 0;
+            } else {
+                let mut precision = 0;
+                while let Some(digit @ b'0'..=b'9') = chars.peek().copied() {
+                    precision = precision * 10 + (digit - b'0') as usize;
+                    chars.next();
+                }
+                spec.precision = Some(precision);
+            }
+        }
+
+        let mut bits = sizes.int * 8;
+        while let Some(&length) = chars.peek() {
+            bits = match length {
+                b'h' if bits == 16 => 8,
+                b'h' => 16,
+                b'l' if bits == sizes.long * 8 => 64,
+                b'l' => sizes.long * 8,
+                b'z' | b't' => sizes.pointer * 8,
+                b'j' => 64,
+                _ => break,
+            };
+            chars.next();
+        }
+
+        let conversion = chars.next().expect("Incomplete conversion") as char;
+        Conversion {
+            spec,
+            bits,
+            conversion,
+            negative_precision,
+        }
+    }
+
+    fn is_integer(&self) -> bool {
+        "diuxXob".contains(self.conversion)
+    }
+
+    fn is_float(&self) -> bool {
+        "fFeEgG".contains(self.conversion)
+    }
+
+    /// The integer argument truncated to the length modifier, as sign and
+    /// magnitude
+    fn integer(&self, arg: &Arg) -> (bool, u64) {
+        let shift = 64 - self.bits;
+        if "di".contains(self.conversion) {
+            let value = (arg.bits() << shift) as i64 >> shift;
+            (value < 0, value.unsigned_abs())
+        } else {
+            (false, (arg.bits() << shift) >> shift)
+        }
+    }
+
+    fn double(&self, arg: &Arg) -> f64 {
+        let Arg::Double(value) = arg else {
+            panic!("Expected a double for %{}", self.conversion);
+        };
+        *value
+    }
+
+    /// Number of digits of the integer argument
+    fn digits(&self, arg: &Arg) -> usize {
+        let base = match self.conversion {
+            'o' => 8,
+            'b' => 2,
+            'x' | 'X' => 16,
+            _ => 10,
+        };
+        let (_, mut value) = self.integer(arg);
+        let mut digits = 1;
+        while value >= base {
+            value /= base;
+            digits += 1;
+        }
+        digits
+    }
+
+    /// Characters printf.c puts into its conversion buffer before the `#`
+    /// prefix and the sign, and the width it pads to with zeros
+    fn ntoa_fill(&self, arg: &Arg) -> (usize, usize) {
+        let (negative, value) = self.integer(arg);
+        let signed = "di".contains(self.conversion);
+        let sign = signed && (negative || self.spec.plus || self.spec.space);
+        // the 0 flag is dropped when a precision is given
+        let zero = self.spec.zero && self.spec.precision.is_none();
+
+        let mut filled = match self.spec.precision {
+            Some(_) if value == 0 => 0,
+            _ => self.digits(arg),
+        };
+        let mut width = self.spec.width;
+        if !self.spec.left {
+            if width > 0 && zero && sign {
+                width -= 1;
+            }
+            filled = filled.max(self.spec.precision.unwrap_or(0));
+            if zero {
+                filled = filled.max(width);
+            }
+        }
+        (filled, width)
+    }
+
+    /// Characters printf.c puts into its conversion buffer before padding
+    /// with spaces
+    fn ntoa_len(&self, arg: &Arg) -> usize {
+        let (negative, value) = self.integer(arg);
+        let prefix = match self.conversion {
+            _ if !self.spec.hash || value == 0 => 0,
+            'x' | 'X' | 'b' => 2,
+            'o' => 1,
+            _ => 0,
+        };
+        let sign =
+            (negative || self.spec.plus || self.spec.space) && "di".contains(self.conversion);
+        self.digits(arg).max(self.spec.precision.unwrap_or(0)) + prefix + sign as usize
+    }
+}
+
+#[derive(Debug, Default)]
+struct Spec {
+    left: bool,
+    plus: bool,
+    space: bool,
+    zero: bool,
+    hash: bool,
+    width: usize,
+    precision: Option<usize>,
+}
+
+impl Spec {
+    fn integer(
+        &self,
+        negative: bool,
+        value: u64,
+        base: u32,
+        conversion: char,
+        signed: bool,
+    ) -> Vec<u8> {
+        let mut digits = match (self.precision, value) {
+            (Some(0), 0) => String::new(),
+            _ => match base {
+                2 => format!("{value:b}"),
+                8 => format!("{value:o}"),
+                16 if conversion == 'X' => format!("{value:X}"),
+                16 => format!("{value:x}"),
+                _ => value.to_string(),
+            },
+        };
+        if let Some(precision) = self.precision {
+            while digits.len() < precision {
+                digits.insert(0, '0');
+            }
+        }
+
+        let mut prefix = String::new();
+        if signed {
+            prefix.push_str(self.sign(negative));
+        }
+        if self.hash {
+            match conversion {
+                'o' if !digits.starts_with('0') => digits.insert(0, '0'),
+                'x' | 'X' | 'b' if value != 0 => {
+                    prefix.push('0');
+                    prefix.push(conversion);
+                }
+                _ => (),
+            }
+        }
+
+        // the 0 flag is ignored when a precision is given
+        self.pad_number(prefix, digits, self.precision.is_none())
+    }
+
+    fn float(&self, value: f64, conversion: char) -> Vec<u8> {
+        let upper = conversion.is_ascii_uppercase();
+        let sign = self.sign(value.is_sign_negative()).to_string();
+
+        if !value.is_finite() {
+            let body = if value.is_nan() { "nan" } else { "inf" };
+            let body = if upper {
+                body.to_uppercase()
+            } else {
+                body.to_string()
+            };
+            return self.pad_number(sign, body, false);
+        }
+
+        let value = value.abs();
+        let precision = self.precision.unwrap_or(6);
+
+        let body = match conversion.to_ascii_lowercase() {
+            'f' => {
+                let mut body = format!("{value:.precision$}");
+                if self.hash && precision == 0 {
+                    body.push('.');
+                }
+                body
+            }
+            'e' => exponential(value, precision, self.hash, upper),
+            _ => {
+                let precision = precision.max(1);
+                let exponent = exponent_of(value, precision - 1);
+                let mut body = if exponent < -4 || exponent >= precision as i32 {
+                    exponential(value, precision - 1, self.hash, upper)
+                } else {
+                    let decimals = (precision as i32 - 1 - exponent) as usize;
+                    let mut body = format!("{value:.decimals$}");
+                    if self.hash && decimals == 0 {
+                        body.push('.');
+                    }
+                    body
+                };
+                if !self.hash {
+                    body = strip_trailing_zeros(&body);
+                }
+                body
+            }
+        };
+
+        self.pad_number(sign, body, true)
+    }
+
+    fn sign(&self, negative: bool) -> &'static str {
+        if negative {
+            "-"
+        } else if self.plus {
+            "+"
+        } else if self.space {
+            " "
+        } else {
+            ""
+        }
+    }
+
+    fn pad_number(&self, prefix: String, body: String, zero_allowed: bool) -> Vec<u8> {
+        let len = prefix.len() + body.len();
+        if self.left || len >= self.width {
+            return self.pad(format!("{prefix}{body}").into_bytes());
+        }
+
+        let padding = self.width - len;
+        if self.zero && zero_allowed {
+            format!("{prefix}{}{body}", "0".repeat(padding)).into_bytes()
+        } else {
+            format!("{}{prefix}{body}", " ".repeat(padding)).into_bytes()
+        }
+    }
+
+    fn pad(&self, mut field: Vec<u8>) -> Vec<u8> {
+        let padding = self.width.saturating_sub(field.len());
+        if self.left {
+            field.extend(std::iter::repeat_n(b' ', padding));
+            field
+        } else {
+            let mut padded = vec![b' '; padding];
+            padded.extend(field);
+            padded
+        }
+    }
+}
+
+/// Whether printf.c's `_ftoa` rounds the fraction of `value` half up to the
+/// next whole number, where it misses the carry
+fn halfway_carry(value: f64, precision: usize) -> bool {
+    let scale = 10f64.powi(precision as i32);
+    let value = value.abs();
+    let tmp = (value - (value as i32) as f64) * scale;
+    let frac = tmp as u64;
+    (1..=9).contains(&precision)
+        && tmp - frac as f64 == 0.5
+        && (frac == 0 || frac & 1 == 1)
+        && (frac + 1) as f64 >= scale
+}
+
+/// Decimal exponent and mantissa of `value` the way printf.c's `_etoa`
+/// estimates them, which can be off by one in the exponent and in the last
+/// digits of the mantissa
+// the constants are printf.c's, not the exact ones, to get the same estimate
+#[allow(clippy::approx_constant)]
+fn etoa_scale(value: f64) -> (i32, f64) {
+    let bits = value.to_bits();
+    let exp2 = ((bits >> 52) & 0x7ff) as i32 - 1023;
+    let fraction = f64::from_bits((bits & ((1 << 52) - 1)) | (1023 << 52));
+    let mut exponent = (0.1760912590558
+        + exp2 as f64 * 0.301029995663981
+        + (fraction - 1.5) * 0.289529654602168) as i32;
+    let exp2 = (exponent as f64 * 3.321928094887362 + 0.5) as i32;
+    let z = exponent as f64 * 2.302585092994046 - exp2 as f64 * 0.6931471805599453;
+    let z2 = z * z;
+    let mut scale = f64::from_bits(((exp2 + 1023) as u64) << 52);
+    scale *= 1.0 + 2.0 * z / (2.0 - z + (z2 / (6.0 + (z2 / (10.0 + z2 / 14.0)))));
+    if value < scale {
+        exponent -= 1;
+        scale /= 10.0;
+    }
+
+    if exponent == 0 {
+        (exponent, value)
+    } else {
+        (exponent, value / scale)
+    }
+}
+
+/// Decimal exponent of `value` after rounding to `precision` decimals in
+/// exponential notation
+fn exponent_of(value: f64, precision: usize) -> i32 {
+    let formatted = format!("{value:.precision$e}");
+    formatted.split_once('e').unwrap().1.parse().unwrap()
+}
+
+/// `%e` body without sign, with at least two exponent digits like C
+fn exponential(value: f64, precision: usize, hash: bool, upper: bool) -> String {
+    let formatted = format!("{value:.precision$e}");
+    let (mantissa, exponent) = formatted.split_once('e').unwrap();
+    let exponent: i32 = exponent.parse().unwrap();
+
+    let mut body = mantissa.to_string();
+    if hash && precision == 0 {
+        body.push('.');
+    }
+    body.push(if upper { 'E' } else { 'e' });
+    body.push(if exponent < 0 { '-' } else { '+' });
+    body.push_str(&format!("{:02}", exponent.unsigned_abs()));
+    body
+}
+
+/// Remove trailing fractional zeros and a trailing decimal point, as `%g`
+/// does without `#`
+fn strip_trailing_zeros(body: &str) -> String {
+    let (number, exponent) = match body.find(['e', 'E']) {
+        Some(pos) => body.split_at(pos),
+        None => (body, ""),
+    };
+    let number = if number.contains('.') {
+        number.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        number
+    };
+    format!("{number}{exponent}")
+}
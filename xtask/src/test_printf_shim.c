@@ -0,0 +1,139 @@
+// Host shim driving the bundled printf implementation for `cargo xtask test-printf`
+//
+// printf.c is compiled with its API renamed to `tp_*` so it does not clash
+// with the host libc used here for I/O.
+//
+// Reads one case per line from stdin:
+//   <buffer size>\t<argument types>\t<format>[\t<argument>...]
+// and writes one result per line to stdout:
+//   <return value>\t<buffer contents as hex>\t<ok|overflow>
+//
+// Argument types: i int, l long, L long long, u unsigned, k unsigned long,
+// K unsigned long long, d double (IEEE bits as hex), s string, c char,
+// p pointer (hex), an empty field means no argument. Each leading '*' is an
+// int consumed by a `*` width or precision.
+
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+int tp_snprintf(char* buffer, size_t count, const char* format, ...);
+
+void _putchar(char character)
+{
+  (void)character;
+}
+
+#define BUFFER_LEN 1024
+#define SENTINEL   0x7f
+
+static char line[4096];
+static char buffer[BUFFER_LEN];
+
+static long long arg_ll(const char* s)
+{
+  return strtoll(s, NULL, 10);
+}
+
+static unsigned long long arg_ull(const char* s)
+{
+  return strtoull(s, NULL, 10);
+}
+
+static double arg_double(const char* s)
+{
+  uint64_t bits = strtoull(s, NULL, 16);
+  double value;
+  memcpy(&value, &bits, sizeof(value));
+  return value;
+}
+
+static void* arg_ptr(const char* s)
+{
+  return (void*)(uintptr_t)strtoull(s, NULL, 16);
+}
+
+int main(void)
+{
+  printf("sizes\t%zu\t%zu\t%zu\n", sizeof(int), sizeof(long), sizeof(void*));
+  fflush(stdout);
+
+  while (fgets(line, sizeof(line), stdin)) {
+    line[strcspn(line, "\n")] = 0;
+
+    char* fields[8] = { 0 };
+    size_t count = 0;
+    char* rest = line;
+    while (count < 8) {
+      fields[count++] = rest;
+      char* tab = strchr(rest, '\t');
+      if (!tab) {
+        break;
+      }
+      *tab = 0;
+      rest = tab + 1;
+    }
+    if (count < 3) {
+      fprintf(stderr, "malformed case: %s\n", line);
+      return 1;
+    }
+
+    const size_t size = (size_t)strtoull(fields[0], NULL, 10);
+    const char* types = fields[1];
+    const char* format = fields[2];
+
+    memset(buffer, SENTINEL, sizeof(buffer));
+    int ret;
+
+    // '*' widths and precisions come first, then the converted value
+    int star[2] = { 0 };
+    size_t stars = 0;
+    while (types[stars] == '*' && stars < 2) {
+      star[stars] = (int)arg_ll(fields[3 + stars]);
+      stars++;
+    }
+    const char type = types[stars];
+    const char* value = 3 + stars < count ? fields[3 + stars] : "";
+
+#define DISPATCH(arg)                                                                 \
+    switch (stars) {                                                                  \
+      case 0: ret = tp_snprintf(buffer, size, format, arg); break;                    \
+      case 1: ret = tp_snprintf(buffer, size, format, star[0], arg); break;           \
+      default: ret = tp_snprintf(buffer, size, format, star[0], star[1], arg); break; \
+    }
+
+    switch (type) {
+      case 0:   ret = tp_snprintf(buffer, size, format); break;
+      case 'i': DISPATCH((int)arg_ll(value)); break;
+      case 'l': DISPATCH((long)arg_ll(value)); break;
+      case 'L': DISPATCH(arg_ll(value)); break;
+      case 'u': DISPATCH((unsigned)arg_ull(value)); break;
+      case 'k': DISPATCH((unsigned long)arg_ull(value)); break;
+      case 'K': DISPATCH(arg_ull(value)); break;
+      case 'd': DISPATCH(arg_double(value)); break;
+      case 's': DISPATCH(value); break;
+      case 'c': DISPATCH((int)arg_ll(value)); break;
+      case 'p': DISPATCH(arg_ptr(value)); break;
+      default:
+        fprintf(stderr, "unsupported argument types: %s\n", types);
+        return 1;
+    }
+
+    printf("%d\t", ret);
+    if (size > 0) {
+      for (size_t i = 0; i < size && i < BUFFER_LEN && buffer[i]; i++) {
+        printf("%02x", (unsigned char)buffer[i]);
+      }
+    }
+    int overflow = 0;
+    for (size_t i = size; i < BUFFER_LEN; i++) {
+      if ((unsigned char)buffer[i] != SENTINEL) {
+        overflow = 1;
+      }
+    }
+    printf("\t%s\n", overflow ? "overflow" : "ok");
+  }
+
+  return 0;
+}